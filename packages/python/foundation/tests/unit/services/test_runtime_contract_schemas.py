@@ -39,6 +39,26 @@ def test_discover_match_schema_accepts_contract_payload() -> None:
     _validate("omni.discover.match.v1.schema.json", payload)
 
 
+def test_discover_match_schema_accepts_ranking_explanation() -> None:
+    payload = {
+        "tool": "git.commit",
+        "usage": '@omni("git.commit", {})',
+        "score": 0.3273,
+        "final_score": 0.82,
+        "confidence": "high",
+        "ranking_reason": "vector=0.091 | keyword=0.136 | boost=0.100",
+        "input_schema_digest": "sha256:abc123def456",
+        "explanation": {
+            "vector_score": 0.81,
+            "keyword_score": 7.4,
+            "vector_contribution": 0.0909,
+            "keyword_contribution": 0.1364,
+            "boost": 0.1,
+        },
+    }
+    _validate("omni.discover.match.v1.schema.json", payload)
+
+
 def test_memory_gate_event_schema_accepts_contract_payload() -> None:
     payload = {
         "session_id": "telegram:group-1:user-9",
@@ -60,6 +80,25 @@ def test_memory_gate_event_schema_accepts_contract_payload() -> None:
     _validate("omni.memory.gate_event.v1.schema.json", payload)
 
 
+def test_memory_gate_event_schema_accepts_forced_store_payload() -> None:
+    payload = {
+        "session_id": "telegram:group-1:user-9",
+        "turn_id": 43,
+        "memory_id": "mem:forced",
+        "state_before": "active",
+        "state_after": "active",
+        "ttl_score": 1.0,
+        "decision": {
+            "verdict": "forced_store",
+            "confidence": 1.0,
+            "omega_factors": ["forced_store=true"],
+            "reason": "forced_store: operator pinned",
+            "next_action": "forced_store",
+        },
+    }
+    _validate("omni.memory.gate_event.v1.schema.json", payload)
+
+
 def test_route_trace_schema_accepts_contract_payload() -> None:
     payload = {
         "session_id": "telegram:group-1:user-9",
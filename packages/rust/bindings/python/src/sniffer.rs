@@ -10,6 +10,7 @@
 use omni_sniffer::{OmniSniffer, SnifferEngine, SnifferRule};
 use pyo3::prelude::*;
 use pyo3::types::{PyAny, PyDict, PyList};
+use std::time::Duration;
 
 /// Python wrapper for EnvironmentSnapshot.
 /// Uses omni_types::EnvironmentSnapshot for type unification.
@@ -19,6 +20,7 @@ pub struct PyEnvironmentSnapshot {
     git_branch: String,
     git_modified: usize,
     git_staged: usize,
+    git_modified_submodules: usize,
     active_context_lines: usize,
     dirty_files: Vec<String>,
     timestamp: f64,
@@ -41,6 +43,11 @@ impl PyEnvironmentSnapshot {
         self.git_staged
     }
 
+    #[getter]
+    fn git_modified_submodules(&self) -> usize {
+        self.git_modified_submodules
+    }
+
     #[getter]
     fn active_context_lines(&self) -> usize {
         self.active_context_lines
@@ -75,14 +82,21 @@ impl PyEnvironmentSnapshot {
             }
         };
 
+        let submodule_desc = if self.git_modified_submodules > 0 {
+            format!(" | Submodules modified: {}", self.git_modified_submodules)
+        } else {
+            String::new()
+        };
+
         format!(
             "[LIVE ENVIRONMENT STATE]\n\
-            - Git: Branch: {} | Modified: {} | Staged: {} | Status: {}\n\
+            - Git: Branch: {} | Modified: {} | Staged: {} | Status: {}{}\n\
             - Active Context: {} lines in SCRATCHPAD.md",
             self.git_branch,
             self.git_modified,
             self.git_staged,
             dirty_desc,
+            submodule_desc,
             self.active_context_lines
         )
     }
@@ -100,11 +114,16 @@ pub struct PyOmniSniffer {
 
 #[pymethods]
 impl PyOmniSniffer {
+    /// `snapshot_ttl_secs` is how long `get_snapshot` reuses the last snapshot (0 disables caching).
     #[new]
-    fn new(project_root: &str) -> Self {
-        Self {
-            sniffer: OmniSniffer::new(project_root),
-        }
+    #[pyo3(signature = (project_root, snapshot_ttl_secs=0.0))]
+    fn new(project_root: &str, snapshot_ttl_secs: f64) -> PyResult<Self> {
+        let ttl = Duration::try_from_secs_f64(snapshot_ttl_secs).map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!("invalid snapshot_ttl_secs: {e}"))
+        })?;
+        Ok(Self {
+            sniffer: OmniSniffer::new(project_root).with_snapshot_ttl(ttl),
+        })
     }
 
     /// Get environment snapshot (high-performance Rust implementation)
@@ -114,6 +133,7 @@ impl PyOmniSniffer {
             git_branch: snapshot.git_branch,
             git_modified: snapshot.git_modified,
             git_staged: snapshot.git_staged,
+            git_modified_submodules: snapshot.git_modified_submodules,
             active_context_lines: snapshot.active_context_lines,
             dirty_files: snapshot.dirty_files,
             timestamp: snapshot.timestamp,
@@ -131,10 +151,13 @@ impl PyOmniSniffer {
 
 /// Convenience function to get a sniffer for the current directory
 #[pyfunction]
-#[pyo3(signature = (project_root=None))]
-pub fn py_get_sniffer(project_root: Option<&str>) -> PyOmniSniffer {
+#[pyo3(signature = (project_root=None, snapshot_ttl_secs=0.0))]
+pub fn py_get_sniffer(
+    project_root: Option<&str>,
+    snapshot_ttl_secs: f64,
+) -> PyResult<PyOmniSniffer> {
     let root = project_root.unwrap_or(".");
-    PyOmniSniffer::new(root)
+    PyOmniSniffer::new(root, snapshot_ttl_secs)
 }
 
 /// Get environment snapshot as a formatted prompt string.
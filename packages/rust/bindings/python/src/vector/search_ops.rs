@@ -3,7 +3,9 @@
 //! Contains: search_optimized, search_hybrid, create_index,
 //!           search_tools, load_tool_registry, scan_skill_tools_raw
 
-use omni_vector::{AgenticSearchConfig, QueryIntent, SearchOptions, ToolSearchOptions};
+use omni_vector::{
+    AgenticSearchConfig, QueryIntent, ScoreTransform, SearchOptions, ToolSearchOptions,
+};
 use pyo3::{
     prelude::*,
     types::{PyAny, PyDict, PyList},
@@ -518,6 +520,9 @@ pub(crate) fn search_tools_async(
                     rerank,
                     semantic_weight: None,
                     keyword_weight: None,
+                    score_transform: ScoreTransform::default(),
+                    projection: Vec::new(),
+                    relative_threshold: None,
                 },
                 None,
             )
@@ -626,6 +631,9 @@ pub(crate) fn search_tools_ipc_async(
                     rerank,
                     semantic_weight: None,
                     keyword_weight: None,
+                    score_transform: ScoreTransform::default(),
+                    projection: Vec::new(),
+                    relative_threshold: None,
                 },
                 None,
             )
@@ -680,6 +688,9 @@ pub(crate) fn agentic_search_async(
                 rerank,
                 semantic_weight,
                 keyword_weight,
+                score_transform: ScoreTransform::default(),
+                projection: Vec::new(),
+                relative_threshold: None,
             },
             skill_name_filter,
             category_filter,
@@ -26,6 +26,11 @@ fn test_build_index_entry_deduplicates_tools() {
         require_refs: vec![],
         repository: "".to_string(),
         permissions: vec![],
+        templates: vec![],
+        assets: vec![],
+        data: vec![],
+        tests: vec![],
+        structure: None,
     };
 
     // Create tools with duplicate names
@@ -129,6 +134,11 @@ fn test_build_index_entry_preserves_order() {
         require_refs: vec![],
         repository: "".to_string(),
         permissions: vec![],
+        templates: vec![],
+        assets: vec![],
+        data: vec![],
+        tests: vec![],
+        structure: None,
     };
 
     // Create tools where tool_b appears before tool_a
@@ -198,6 +208,11 @@ fn test_build_index_entry_empty_tools() {
         require_refs: vec![],
         repository: "".to_string(),
         permissions: vec![],
+        templates: vec![],
+        assets: vec![],
+        data: vec![],
+        tests: vec![],
+        structure: None,
     };
 
     let scanner = SkillScanner::new();
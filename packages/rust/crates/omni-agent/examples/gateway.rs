@@ -87,7 +87,7 @@ async fn run_gateway(bind_addr: String, mcp_config_path: PathBuf) -> anyhow::Res
         ..AgentConfig::default()
     };
     let agent = Agent::from_config(config).await?;
-    run_http(agent, &bind_addr, None, None).await
+    run_http(agent, &bind_addr, None, None, None).await
 }
 
 async fn run_stdio_mode(session_id: String, mcp_config_path: PathBuf) -> anyhow::Result<()> {
@@ -5,6 +5,8 @@ use xiuxian_qianhuan::{
     PromptContextCategory, RoleMixProfile, RoleMixRole,
 };
 
+use super::render::rendered_token_count;
+
 pub(super) fn assemble_snapshot(
     session_id: &str,
     turn_id: u64,
@@ -49,6 +51,9 @@ pub(super) fn assemble_snapshot(
     let (final_blocks, mut budget_dropped, truncated_block_ids) =
         apply_char_budget(prioritize_anchors(retained), policy.max_chars);
     dropped_block_ids.append(&mut budget_dropped);
+    let (final_blocks, mut token_dropped) =
+        apply_token_budget(final_blocks, policy.max_tokens, role_mix.as_ref());
+    dropped_block_ids.append(&mut token_dropped);
 
     let mut snapshot = InjectionSnapshot::from_blocks(
         format!("injection:{session_id}:{turn_id}"),
@@ -133,6 +138,64 @@ fn apply_char_budget(
     (kept, dropped_block_ids, truncated_block_ids)
 }
 
+fn apply_token_budget(
+    mut blocks: Vec<PromptContextBlock>,
+    max_tokens: Option<usize>,
+    role_mix: Option<&RoleMixProfile>,
+) -> (Vec<PromptContextBlock>, Vec<String>) {
+    let Some(max_tokens) = max_tokens else {
+        return (blocks, Vec::new());
+    };
+
+    let mut dropped_block_ids = Vec::new();
+    while !blocks.is_empty() && rendered_token_count(&blocks) > max_tokens {
+        let Some(evict_index) = blocks
+            .iter()
+            .enumerate()
+            .min_by(|(_, left), (_, right)| {
+                left.anchor
+                    .cmp(&right.anchor)
+                    .then_with(|| left.priority.cmp(&right.priority))
+                    .then_with(|| {
+                        role_weight(role_mix, left.category)
+                            .total_cmp(&role_weight(role_mix, right.category))
+                    })
+            })
+            .map(|(index, _)| index)
+        else {
+            break;
+        };
+        dropped_block_ids.push(blocks.remove(evict_index).block_id);
+    }
+
+    (blocks, dropped_block_ids)
+}
+
+fn role_weight(role_mix: Option<&RoleMixProfile>, category: PromptContextCategory) -> f32 {
+    let Some(profile) = role_mix else {
+        return 0.0;
+    };
+    profile
+        .roles
+        .iter()
+        .find(|role| role.role == role_for_category(category))
+        .map_or(0.0, |role| role.weight)
+}
+
+fn role_for_category(category: PromptContextCategory) -> &'static str {
+    match category {
+        PromptContextCategory::Safety | PromptContextCategory::Policy => "governance_guardian",
+        PromptContextCategory::MemoryRecall | PromptContextCategory::WindowSummary => {
+            "memory_strategist"
+        }
+        PromptContextCategory::SessionXml => "session_context_curator",
+        PromptContextCategory::Knowledge => "knowledge_synthesizer",
+        PromptContextCategory::Reflection | PromptContextCategory::RuntimeHint => {
+            "reflection_optimizer"
+        }
+    }
+}
+
 fn prioritize_anchors(blocks: Vec<PromptContextBlock>) -> Vec<PromptContextBlock> {
     let (anchors, others): (Vec<_>, Vec<_>) = blocks.into_iter().partition(|block| block.anchor);
     anchors.into_iter().chain(others).collect()
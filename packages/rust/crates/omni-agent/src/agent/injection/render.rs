@@ -1,12 +1,21 @@
-use xiuxian_qianhuan::InjectionSnapshot;
+use xiuxian_qianhuan::{InjectionSnapshot, PromptContextBlock};
 
 use crate::session::ChatMessage;
 
 use super::builder::message_name_for_category;
 
 pub(super) fn render_snapshot_messages(snapshot: &InjectionSnapshot) -> Vec<ChatMessage> {
-    snapshot
-        .blocks
+    render_block_messages(&snapshot.blocks)
+}
+
+/// Token count of the messages rendered for `blocks`, serialized as sent to the model.
+pub(super) fn rendered_token_count(blocks: &[PromptContextBlock]) -> usize {
+    let rendered = serde_json::to_string(&render_block_messages(blocks)).unwrap_or_default();
+    omni_tokenizer::count_tokens(&rendered)
+}
+
+fn render_block_messages(blocks: &[PromptContextBlock]) -> Vec<ChatMessage> {
+    blocks
         .iter()
         .map(|block| ChatMessage {
             role: "system".to_string(),
@@ -4,6 +4,7 @@ use xiuxian_qianhuan::{
 };
 
 use super::assembler::assemble_snapshot;
+use super::render::render_snapshot_messages;
 
 fn make_block(
     id: &str,
@@ -146,3 +147,64 @@ fn role_mix_is_not_attached_for_single_domain_non_hybrid_policy() {
         "single-domain classified snapshot should not attach role-mix profile"
     );
 }
+
+#[test]
+fn token_budget_drops_lowest_priority_blocks_first() {
+    let policy = InjectionPolicy {
+        mode: InjectionMode::Classified,
+        max_tokens: Some(60),
+        ordering: InjectionOrderStrategy::PriorityDesc,
+        ..InjectionPolicy::default()
+    };
+    let payload = "alpha beta gamma delta epsilon zeta eta theta iota kappa";
+    let blocks = vec![
+        make_block(
+            "knowledge-high",
+            PromptContextSource::Knowledge,
+            PromptContextCategory::Knowledge,
+            900,
+            payload,
+        ),
+        make_block(
+            "memory-mid",
+            PromptContextSource::MemoryRecall,
+            PromptContextCategory::MemoryRecall,
+            600,
+            payload,
+        ),
+        make_block(
+            "reflection-low",
+            PromptContextSource::Reflection,
+            PromptContextCategory::Reflection,
+            300,
+            payload,
+        ),
+        make_block(
+            "hint-lowest",
+            PromptContextSource::RuntimeHint,
+            PromptContextCategory::RuntimeHint,
+            100,
+            payload,
+        ),
+    ];
+
+    let snapshot = assemble_snapshot("telegram:test:4", 11, policy, blocks);
+    snapshot
+        .validate()
+        .expect("snapshot should satisfy policy after assembly");
+
+    let rendered = serde_json::to_string(&render_snapshot_messages(&snapshot))
+        .expect("rendered messages should serialize");
+    assert!(omni_tokenizer::count_tokens(&rendered) <= 60);
+
+    let kept_ids = snapshot
+        .blocks
+        .iter()
+        .map(|block| block.block_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(kept_ids, vec!["knowledge-high", "memory-mid"]);
+    assert_eq!(
+        snapshot.dropped_block_ids,
+        vec!["hint-lowest".to_string(), "reflection-low".to_string()]
+    );
+}
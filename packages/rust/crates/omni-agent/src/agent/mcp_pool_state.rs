@@ -1,5 +1,7 @@
 use super::Agent;
-use crate::mcp_pool::{McpDiscoverCacheStatsSnapshot, McpToolsListCacheStatsSnapshot};
+use crate::mcp_pool::{
+    McpClientHealth, McpDiscoverCacheStatsSnapshot, McpToolsListCacheStatsSnapshot,
+};
 
 impl Agent {
     /// Return Rust MCP pool `tools/list` cache snapshot when MCP is enabled.
@@ -15,4 +17,12 @@ impl Agent {
             .as_ref()
             .and_then(|pool| pool.discover_cache_stats_snapshot())
     }
+
+    /// Ping every pooled MCP client and report per-client liveness when MCP is enabled.
+    pub async fn probe_mcp_health(&self) -> Option<Vec<McpClientHealth>> {
+        match self.mcp.as_ref() {
+            Some(pool) => Some(pool.ping_clients().await),
+            None => None,
+        }
+    }
 }
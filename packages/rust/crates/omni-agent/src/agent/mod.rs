@@ -22,10 +22,15 @@ mod persistence;
 mod reflection;
 mod reflection_runtime_state;
 mod session_context;
+mod session_partition_override_state;
 mod system_prompt_injection_state;
+mod tool_confirmation_state;
+mod turn_context;
+mod turn_loop;
+mod turn_progress;
+mod turn_shortcuts;
 
 use anyhow::{Context, Result};
-use omni_tokenizer::count_tokens;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::AtomicU64;
@@ -36,21 +41,20 @@ use omni_memory::{EpisodeStore, StoreConfig};
 use xiuxian_qianhuan::{InjectionPolicy, InjectionSnapshot};
 
 use crate::config::AgentConfig;
-use crate::contracts::{OmegaDecision, OmegaFallbackPolicy, OmegaRoute};
+use crate::contracts::{OmegaDecision, OmegaToolGateAction};
 use crate::embedding::EmbeddingClient;
 use crate::llm::LlmClient;
 use crate::mcp_pool::{McpPoolConnectConfig, connect_pool};
 use crate::observability::SessionEvent;
-use crate::session::{BoundedSessionStore, ChatMessage, SessionStore, SessionSummarySegment};
+use crate::session::{BoundedSessionStore, ChatMessage, SessionStore};
 use crate::shortcuts::{
-    CRAWL_TOOL_NAME, WorkflowBridgeMode, parse_crawl_shortcut, parse_react_shortcut,
-    parse_workflow_bridge_shortcut,
+    WorkflowBridgeMode, parse_crawl_shortcut, parse_react_shortcut, parse_workflow_bridge_shortcut,
 };
 use embedding_dimension::{
     EMBEDDING_SOURCE_EMBEDDING, EMBEDDING_SOURCE_EMBEDDING_REPAIRED, EMBEDDING_SOURCE_HASH,
     repair_embedding_dimension,
 };
-use memory::{RecalledEpisodeCandidate, apply_recall_credit, select_recall_credit_candidates};
+use memory::{RecalledEpisodeCandidate, apply_recall_credit};
 use memory_recall::{
     MEMORY_RECALL_MESSAGE_NAME, MemoryRecallInput, build_memory_context_message,
     estimate_messages_tokens, filter_recalled_episodes, plan_memory_recall,
@@ -64,6 +68,8 @@ use memory_stream_consumer::spawn_memory_stream_consumer;
 use omega::ShortcutFallbackAction;
 use reflection::PolicyHintDirective;
 use system_prompt_injection_state::SYSTEM_PROMPT_INJECTION_CONTEXT_MESSAGE_NAME;
+use turn_loop::ReactTurn;
+use turn_shortcuts::WorkflowBridgeOutcome;
 
 const MEMORY_EMBED_FALLBACK_TIMEOUT: Duration = Duration::from_secs(3);
 
@@ -74,10 +80,15 @@ pub use graph_bridge::{GraphBridgeRequest, GraphBridgeResult, validate_graph_bri
 pub use memory_recall_metrics::{MemoryRecallLatencyBucketsSnapshot, MemoryRecallMetricsSnapshot};
 pub use memory_recall_state::{SessionMemoryRecallDecision, SessionMemoryRecallSnapshot};
 pub use memory_state::MemoryRuntimeStatusSnapshot;
+pub use omega::{
+    OMEGA_DECISION_LOG_ENV, OmegaDecisionLog, OmegaDecisionLogEntry, omega_tool_gate,
+    read_entries as read_omega_decision_log_entries,
+};
 pub use session_context::{
     SessionContextMode, SessionContextSnapshotInfo, SessionContextStats, SessionContextWindowInfo,
 };
 pub use system_prompt_injection_state::SessionSystemPromptInjectionSnapshot;
+pub use turn_progress::{TurnProgressEvent, TurnProgressSink};
 
 /// Explicit session-level recall feedback direction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -94,6 +105,14 @@ pub struct SessionRecallFeedbackUpdate {
     pub direction: SessionRecallFeedbackDirection,
 }
 
+/// Identity of the turn being run, shared by the turn helpers.
+#[derive(Debug, Clone, Copy)]
+struct TurnScope<'a> {
+    session_id: &'a str,
+    turn_id: u64,
+    user_message: &'a str,
+}
+
 /// Agent: config + session store (or bounded session) + LLM client + optional MCP pool + optional memory.
 pub struct Agent {
     config: AgentConfig,
@@ -118,8 +137,13 @@ pub struct Agent {
     system_prompt_injection: Arc<RwLock<HashMap<String, SessionSystemPromptInjectionSnapshot>>>,
     /// One-shot next-turn policy hints derived from reflection lifecycle.
     reflection_policy_hints: Arc<RwLock<HashMap<String, PolicyHintDirective>>>,
+    /// Tool calls awaiting confirmation and one-shot approvals, by session id.
+    tool_confirmations:
+        Arc<RwLock<HashMap<String, tool_confirmation_state::SessionToolConfirmations>>>,
     /// Counter used by periodic memory decay policy.
     memory_decay_turn_counter: Arc<AtomicU64>,
+    /// When set (via `OMNI_AGENT_OMEGA_DECISION_LOG`), every route decision is appended here.
+    omega_decision_log: Option<OmegaDecisionLog>,
     llm: LlmClient,
     mcp: Option<crate::mcp_pool::McpClientPool>,
     memory_stream_consumer_task: Option<tokio::task::JoinHandle<()>>,
@@ -277,7 +301,9 @@ impl Agent {
             memory_recall_feedback: Arc::new(RwLock::new(HashMap::new())),
             system_prompt_injection: Arc::new(RwLock::new(HashMap::new())),
             reflection_policy_hints: Arc::new(RwLock::new(HashMap::new())),
+            tool_confirmations: Arc::new(RwLock::new(HashMap::new())),
             memory_decay_turn_counter: Arc::new(AtomicU64::new(0)),
+            omega_decision_log: OmegaDecisionLog::from_env(),
             llm,
             mcp: mcp_client,
             memory_stream_consumer_task,
@@ -358,6 +384,7 @@ impl Agent {
     fn record_omega_decision(
         &self,
         session_id: &str,
+        user_message: &str,
         decision: &OmegaDecision,
         workflow_mode: Option<WorkflowBridgeMode>,
         tool_name: Option<&str>,
@@ -376,6 +403,66 @@ impl Agent {
             policy_id = ?decision.policy_id,
             "omega route decision selected"
         );
+        if let Some(log) = &self.omega_decision_log
+            && let Err(error) = log.append(session_id, user_message, decision)
+        {
+            tracing::warn!(
+                session_id,
+                path = %log.path().display(),
+                error = %error,
+                "failed to append omega decision log entry"
+            );
+        }
+    }
+
+    /// Apply the Omega tool gate; returns the tool message and progress event to send
+    /// instead of executing.
+    async fn withhold_gated_tool_call(
+        &self,
+        session_id: &str,
+        tool_name: &str,
+    ) -> Option<(String, TurnProgressEvent)> {
+        let gate = omega::omega_tool_gate(tool_name, &self.config.tool_gate);
+        match gate.action {
+            OmegaToolGateAction::AutoExecute => return None,
+            OmegaToolGateAction::RequireConfirmation => {
+                if self.take_tool_call_approval(session_id, tool_name).await {
+                    return None;
+                }
+                self.record_pending_tool_confirmation(session_id, tool_name)
+                    .await;
+            }
+            OmegaToolGateAction::Block => {}
+        }
+        tracing::warn!(
+            session_id,
+            tool_name,
+            gate_action = gate.action.as_str(),
+            tool_trust_class = gate.tool_trust_class.as_str(),
+            reason = %gate.reason,
+            "omega tool gate withheld tool call"
+        );
+        if gate.action == OmegaToolGateAction::Block {
+            let output = format!("tool `{tool_name}` was blocked: {}", gate.reason);
+            return Some((
+                output.clone(),
+                TurnProgressEvent::ToolResult {
+                    name: tool_name.to_string(),
+                    is_error: true,
+                    output,
+                },
+            ));
+        }
+        Some((
+            format!(
+                "tool `{tool_name}` is awaiting user confirmation and was not executed yet: {}",
+                gate.reason
+            ),
+            TurnProgressEvent::ToolConfirmationRequired {
+                name: tool_name.to_string(),
+                reason: gate.reason,
+            },
+        ))
     }
 
     fn record_shortcut_fallback(
@@ -537,6 +624,22 @@ impl Agent {
     /// Run one user turn: history + user message -> LLM (with tools if MCP connected) -> handle tool_calls -> return final text.
     /// When memory is enabled, two_phase_recall(current intent) is injected as system context before the conversation.
     pub async fn run_turn(&self, session_id: &str, user_message: &str) -> Result<String> {
+        self.run_turn_with_progress(session_id, user_message, None)
+            .await
+    }
+
+    /// Same as [`Agent::run_turn`], additionally reporting tool calls, tool results and
+    /// intermediate assistant text to `progress` as the LLM/tool loop advances.
+    ///
+    /// # Errors
+    /// Returns an error when the LLM call, a tool call, or session persistence fails, or when
+    /// `max_tool_rounds` is exceeded.
+    pub async fn run_turn_with_progress(
+        &self,
+        session_id: &str,
+        user_message: &str,
+        progress: Option<&TurnProgressSink>,
+    ) -> Result<String> {
         let forced_react_message = parse_react_shortcut(user_message);
         let mut force_react = forced_react_message.is_some();
         let mut user_message_owned =
@@ -546,706 +649,41 @@ impl Agent {
         if !force_react
             && let Some(shortcut) = parse_workflow_bridge_shortcut(user_message_owned.as_str())
         {
-            let decision = omega::decide_for_shortcut(
-                shortcut.mode,
-                user_message_owned.as_str(),
-                &shortcut.tool_name,
-            );
-            self.record_omega_decision(
-                session_id,
-                &decision,
-                Some(shortcut.mode),
-                Some(shortcut.tool_name.as_str()),
-            );
-
-            if decision.route == OmegaRoute::Graph {
-                let shortcut_snapshot = self
-                    .build_shortcut_injection_snapshot(
-                        session_id,
-                        turn_id,
-                        user_message_owned.as_str(),
-                    )
-                    .await?;
-                if let Some(snapshot) = &shortcut_snapshot {
-                    self.record_injection_snapshot(session_id, snapshot);
-                }
-                let arguments = injection::augment_shortcut_arguments(
-                    shortcut.arguments.clone(),
-                    shortcut_snapshot.as_ref(),
-                    &decision,
-                    shortcut.mode,
-                );
-
-                let mut tool_summary = ToolExecutionSummary::default();
-                let initial_request = graph_bridge::GraphBridgeRequest {
-                    tool_name: shortcut.tool_name.clone(),
-                    arguments,
-                };
-
-                let out = match self.execute_graph_bridge(initial_request).await {
-                    Ok(result) => {
-                        tool_summary.record_result(result.is_error);
-                        result.output
-                    }
-                    Err(initial_error) => {
-                        tool_summary.record_transport_failure();
-                        match omega::resolve_shortcut_fallback(&decision, 0) {
-                            ShortcutFallbackAction::RetryBridgeWithoutMetadata => {
-                                self.record_shortcut_fallback(
-                                    session_id,
-                                    &decision,
-                                    shortcut.mode,
-                                    &shortcut.tool_name,
-                                    ShortcutFallbackAction::RetryBridgeWithoutMetadata,
-                                    &initial_error,
-                                );
-                                match self
-                                    .execute_graph_bridge(graph_bridge::GraphBridgeRequest {
-                                        tool_name: shortcut.tool_name.clone(),
-                                        arguments: shortcut.arguments.clone(),
-                                    })
-                                    .await
-                                {
-                                    Ok(result) => {
-                                        tool_summary.record_result(result.is_error);
-                                        result.output
-                                    }
-                                    Err(retry_error) => {
-                                        tool_summary.record_transport_failure();
-                                        let error_text = retry_error.to_string();
-                                        let _ = self
-                                            .update_recall_feedback(
-                                                session_id,
-                                                user_message_owned.as_str(),
-                                                &error_text,
-                                                Some(&tool_summary),
-                                            )
-                                            .await;
-                                        self.reflect_turn_and_update_policy_hint(
-                                            session_id,
-                                            turn_id,
-                                            decision.route,
-                                            user_message_owned.as_str(),
-                                            &error_text,
-                                            "error",
-                                            tool_summary.attempted,
-                                        )
-                                        .await;
-                                        return Err(retry_error);
-                                    }
-                                }
-                            }
-                            ShortcutFallbackAction::RouteToReact => {
-                                self.record_shortcut_fallback(
-                                    session_id,
-                                    &decision,
-                                    shortcut.mode,
-                                    &shortcut.tool_name,
-                                    ShortcutFallbackAction::RouteToReact,
-                                    &initial_error,
-                                );
-                                force_react = true;
-                                format!(
-                                    "Execute this task with ReAct because workflow bridge failed: {}",
-                                    user_message_owned
-                                )
-                            }
-                            ShortcutFallbackAction::Abort => {
-                                self.record_shortcut_fallback(
-                                    session_id,
-                                    &decision,
-                                    shortcut.mode,
-                                    &shortcut.tool_name,
-                                    ShortcutFallbackAction::Abort,
-                                    &initial_error,
-                                );
-                                let error_text = initial_error.to_string();
-                                let _ = self
-                                    .update_recall_feedback(
-                                        session_id,
-                                        user_message_owned.as_str(),
-                                        &error_text,
-                                        Some(&tool_summary),
-                                    )
-                                    .await;
-                                self.reflect_turn_and_update_policy_hint(
-                                    session_id,
-                                    turn_id,
-                                    decision.route,
-                                    user_message_owned.as_str(),
-                                    &error_text,
-                                    "error",
-                                    tool_summary.attempted,
-                                )
-                                .await;
-                                return Err(initial_error);
-                            }
-                        }
-                    }
-                };
-
-                if !force_react {
-                    let _ = self
-                        .update_recall_feedback(
-                            session_id,
-                            user_message_owned.as_str(),
-                            &out,
-                            Some(&tool_summary),
-                        )
-                        .await;
-                    self.append_turn_to_session(session_id, user_message_owned.as_str(), &out, 1)
-                        .await?;
-                    self.reflect_turn_and_update_policy_hint(
-                        session_id,
-                        turn_id,
-                        decision.route,
-                        user_message_owned.as_str(),
-                        &out,
-                        "completed",
-                        tool_summary.attempted,
-                    )
-                    .await;
-                    return Ok(out);
-                }
-                user_message_owned = out;
-            } else {
-                force_react = true;
-            }
-        }
-
-        let user_message = user_message_owned.as_str();
-
-        if !force_react && let Some(shortcut) = parse_crawl_shortcut(user_message) {
-            let mut tool_summary = ToolExecutionSummary::default();
-            let out = match self
-                .call_mcp_tool_with_diagnostics(CRAWL_TOOL_NAME, Some(shortcut.to_arguments()))
-                .await
-            {
-                Ok(output) => {
-                    tool_summary.record_result(output.is_error);
-                    output.text
-                }
-                Err(error) => {
-                    tool_summary.record_transport_failure();
-                    let error_text = error.to_string();
-                    let _ = self
-                        .update_recall_feedback(
-                            session_id,
-                            user_message,
-                            &error_text,
-                            Some(&tool_summary),
-                        )
-                        .await;
-                    self.reflect_turn_and_update_policy_hint(
-                        session_id,
-                        turn_id,
-                        OmegaRoute::React,
-                        user_message,
-                        &error_text,
-                        "error",
-                        tool_summary.attempted,
-                    )
-                    .await;
-                    return Err(error);
-                }
-            };
-            let _ = self
-                .update_recall_feedback(session_id, user_message, &out, Some(&tool_summary))
-                .await;
-            self.append_turn_to_session(session_id, user_message, &out, 1)
-                .await?;
-            self.reflect_turn_and_update_policy_hint(
+            let turn = TurnScope {
                 session_id,
                 turn_id,
-                OmegaRoute::React,
-                user_message,
-                &out,
-                "completed",
-                tool_summary.attempted,
-            )
-            .await;
-            return Ok(out);
-        }
-
-        let policy_hint = self.take_reflection_policy_hint(session_id).await;
-        if let Some(hint) = policy_hint.as_ref() {
-            tracing::debug!(
-                event = SessionEvent::ReflectionPolicyHintApplied.as_str(),
-                session_id,
-                source_turn_id = hint.source_turn_id,
-                preferred_route = hint.preferred_route.as_str(),
-                risk_floor = hint.risk_floor.as_str(),
-                fallback_override = hint.fallback_override.map(OmegaFallbackPolicy::as_str),
-                tool_trust_class = hint.tool_trust_class.as_str(),
-                reason = %hint.reason,
-                "reflection policy hint applied to route decision"
-            );
-        }
-        let decision = omega::apply_policy_hint(
-            omega::decide_for_standard_turn(force_react),
-            policy_hint.as_ref(),
-        );
-        self.record_omega_decision(session_id, &decision, None, None);
-
-        let mut summary_segments: Vec<SessionSummarySegment> = Vec::new();
-        let mut messages: Vec<ChatMessage> = if let Some(ref w) = self.bounded_session {
-            let limit = self.config.window_max_turns.unwrap_or(512);
-            summary_segments = w
-                .get_recent_summary_segments(session_id, self.config.summary_max_segments)
-                .await?;
-            w.get_recent_messages(session_id, limit).await?
-        } else {
-            self.session.get(session_id).await?
-        };
-
-        if !summary_segments.is_empty() {
-            let segment_count = summary_segments.len();
-            let summary_messages = summary_segments
-                .iter()
-                .enumerate()
-                .map(|(index, segment)| ChatMessage {
-                    role: "system".to_string(),
-                    content: Some(format!(
-                        "Compressed conversation history from older turns (segment {}/{}): {} (turns={}, tools={})",
-                        index + 1,
-                        segment_count,
-                        segment.summary,
-                        segment.turn_count,
-                        segment.tool_calls
-                    )),
-                    tool_calls: None,
-                    tool_call_id: None,
-                    name: Some(context_budget::SESSION_SUMMARY_MESSAGE_NAME.to_string()),
-                })
-                .collect::<Vec<_>>();
-            messages.splice(0..0, summary_messages);
-        }
-
-        if let Some(snapshot) = self
-            .inspect_session_system_prompt_injection(session_id)
-            .await
-        {
-            messages.insert(
-                0,
-                ChatMessage {
-                    role: "system".to_string(),
-                    content: Some(snapshot.xml),
-                    tool_calls: None,
-                    tool_call_id: None,
-                    name: Some(SYSTEM_PROMPT_INJECTION_CONTEXT_MESSAGE_NAME.to_string()),
-                },
-            );
-        }
-
-        messages.push(ChatMessage {
-            role: "user".to_string(),
-            content: Some(user_message.to_string()),
-            tool_calls: None,
-            tool_call_id: None,
-            name: None,
-        });
-
-        let mut recall_credit_candidates: Vec<RecalledEpisodeCandidate> = Vec::new();
-
-        if let (Some(ref store), Some(ref mem_cfg)) =
-            (self.memory_store.as_ref(), self.config.memory.as_ref())
-        {
-            let recall_started = Instant::now();
-            let active_turns_estimate = messages
-                .iter()
-                .filter(|message| message.role == "user" || message.role == "assistant")
-                .count()
-                / 2;
-            let query_tokens = count_tokens(user_message);
-            let recall_plan = plan_memory_recall(MemoryRecallInput {
-                base_k1: mem_cfg.recall_k1,
-                base_k2: mem_cfg.recall_k2,
-                base_lambda: mem_cfg.recall_lambda,
-                context_budget_tokens: self.config.context_budget_tokens,
-                context_budget_reserve_tokens: self.config.context_budget_reserve_tokens,
-                context_tokens_before_recall: estimate_messages_tokens(&messages),
-                active_turns_estimate,
-                window_max_turns: self.config.window_max_turns,
-                summary_segment_count: summary_segments.len(),
-            });
-            let recall_feedback_bias = self.recall_feedback_bias(session_id).await;
-            let recall_plan = apply_feedback_to_plan(recall_plan, recall_feedback_bias);
-            tracing::debug!(
-                event = SessionEvent::MemoryRecallPlanned.as_str(),
-                session_id,
-                memory_scope = session_id,
-                k1 = recall_plan.k1,
-                k2 = recall_plan.k2,
-                lambda = recall_plan.lambda,
-                min_score = recall_plan.min_score,
-                max_context_chars = recall_plan.max_context_chars,
-                budget_pressure = recall_plan.budget_pressure,
-                window_pressure = recall_plan.window_pressure,
-                effective_budget_tokens = ?recall_plan.effective_budget_tokens,
-                active_turns_estimate,
-                summary_segment_count = summary_segments.len(),
-                recall_feedback_bias,
-                "memory recall plan selected"
-            );
-            self.record_memory_recall_plan_metrics().await;
-
-            let (query_embedding, embedding_source) = self
-                .embedding_or_hash_with_source(user_message, store, mem_cfg.embedding_dim)
-                .await;
-            let recalled = store.two_phase_recall_with_embedding_for_scope(
-                session_id,
-                &query_embedding,
-                recall_plan.k1,
-                recall_plan.k2,
-                recall_plan.lambda,
-            );
-            let recalled_count = recalled.len();
-            let recalled = filter_recalled_episodes(recalled, &recall_plan);
-            if let Some(system_content) =
-                build_memory_context_message(&recalled, recall_plan.max_context_chars)
-            {
-                if mem_cfg.recall_credit_enabled {
-                    recall_credit_candidates = select_recall_credit_candidates(
-                        &recalled,
-                        mem_cfg.recall_credit_max_candidates,
-                    );
+                user_message: user_message_owned.as_str(),
+            };
+            match self.run_workflow_bridge_shortcut(turn, &shortcut).await? {
+                WorkflowBridgeOutcome::Completed(out) => return Ok(out),
+                WorkflowBridgeOutcome::React(message) => {
+                    force_react = true;
+                    user_message_owned = message;
                 }
-                let injected_count = recalled.len();
-                let context_chars_injected = system_content.chars().count();
-                let pipeline_duration_ms = recall_started.elapsed().as_millis() as u64;
-                let best_score = recalled
-                    .first()
-                    .map(|(_, score)| *score)
-                    .unwrap_or_default();
-                let weakest_score = recalled.last().map(|(_, score)| *score).unwrap_or_default();
-                messages.insert(
-                    0,
-                    ChatMessage {
-                        role: "system".to_string(),
-                        content: Some(system_content),
-                        tool_calls: None,
-                        tool_call_id: None,
-                        name: Some(MEMORY_RECALL_MESSAGE_NAME.to_string()),
-                    },
-                );
-                tracing::debug!(
-                    event = SessionEvent::MemoryRecallInjected.as_str(),
-                    session_id,
-                    query_tokens,
-                    embedding_source,
-                    recalled_total = recalled_count,
-                    recalled_selected = recalled.len(),
-                    recalled_injected = injected_count,
-                    context_chars_injected,
-                    pipeline_duration_ms,
-                    best_score,
-                    weakest_score,
-                    "memory recall context injected"
-                );
-                self.record_memory_recall_result_metrics(
-                    memory_recall_state::SessionMemoryRecallDecision::Injected,
-                    recalled.len(),
-                    injected_count,
-                    context_chars_injected,
-                    pipeline_duration_ms,
-                )
-                .await;
-                self.record_memory_recall_snapshot(
-                    session_id,
-                    memory_recall_state::SessionMemoryRecallSnapshot::from_plan(
-                        recall_plan,
-                        active_turns_estimate,
-                        summary_segments.len(),
-                        query_tokens,
-                        recall_feedback_bias,
-                        embedding_source,
-                        recalled_count,
-                        recalled.len(),
-                        injected_count,
-                        context_chars_injected,
-                        Some(best_score),
-                        Some(weakest_score),
-                        pipeline_duration_ms,
-                        memory_recall_state::SessionMemoryRecallDecision::Injected,
-                    ),
-                )
-                .await;
-            } else {
-                let pipeline_duration_ms = recall_started.elapsed().as_millis() as u64;
-                let best_score = recalled
-                    .first()
-                    .map(|(_, score)| *score)
-                    .unwrap_or_default();
-                tracing::debug!(
-                    event = SessionEvent::MemoryRecallSkipped.as_str(),
-                    session_id,
-                    query_tokens,
-                    embedding_source,
-                    recalled_total = recalled_count,
-                    recalled_selected = recalled.len(),
-                    pipeline_duration_ms,
-                    best_score,
-                    "memory recall skipped after scoring/compaction filters"
-                );
-                self.record_memory_recall_result_metrics(
-                    memory_recall_state::SessionMemoryRecallDecision::Skipped,
-                    recalled.len(),
-                    0,
-                    0,
-                    pipeline_duration_ms,
-                )
-                .await;
-                self.record_memory_recall_snapshot(
-                    session_id,
-                    memory_recall_state::SessionMemoryRecallSnapshot::from_plan(
-                        recall_plan,
-                        active_turns_estimate,
-                        summary_segments.len(),
-                        query_tokens,
-                        recall_feedback_bias,
-                        embedding_source,
-                        recalled_count,
-                        recalled.len(),
-                        0,
-                        0,
-                        recalled.first().map(|(_, score)| *score),
-                        recalled.last().map(|(_, score)| *score),
-                        pipeline_duration_ms,
-                        memory_recall_state::SessionMemoryRecallDecision::Skipped,
-                    ),
-                )
-                .await;
             }
         }
 
-        let raw_messages = messages;
-        match injection::normalize_messages_with_snapshot(
+        let turn = TurnScope {
             session_id,
             turn_id,
-            raw_messages.clone(),
-            InjectionPolicy::default(),
-        ) {
-            Ok(normalized) => {
-                if let Some(snapshot) = normalized.snapshot.as_ref() {
-                    self.record_injection_snapshot(session_id, snapshot);
-                }
-                messages = normalized.messages;
-            }
-            Err(error) => {
-                tracing::warn!(
-                    session_id,
-                    error = %error,
-                    "failed to normalize injection snapshot; context messages unchanged"
-                );
-                messages = raw_messages;
-            }
-        }
-
-        if let Some(context_budget_tokens) = self.config.context_budget_tokens
-            && context_budget_tokens > 0
-        {
-            let result = context_budget::prune_messages_for_token_budget_with_strategy(
-                messages,
-                context_budget_tokens,
-                self.config.context_budget_reserve_tokens,
-                self.config.context_budget_strategy,
-            );
-            messages = result.messages;
-            let report = result.report;
-            self.record_context_budget_snapshot(session_id, &report)
-                .await;
-            tracing::debug!(
-                session_id,
-                strategy = report.strategy.as_str(),
-                budget_tokens = report.budget_tokens,
-                reserve_tokens = report.reserve_tokens,
-                effective_budget_tokens = report.effective_budget_tokens,
-                pre_messages = report.pre_messages,
-                post_messages = report.post_messages,
-                pre_tokens = report.pre_tokens,
-                post_tokens = report.post_tokens,
-                dropped_messages = report.pre_messages.saturating_sub(report.post_messages),
-                dropped_tokens = report.pre_tokens.saturating_sub(report.post_tokens),
-                non_system_pre_messages = report.non_system.input_messages,
-                non_system_kept_messages = report.non_system.kept_messages,
-                non_system_dropped_messages = report.non_system.dropped_messages(),
-                non_system_pre_tokens = report.non_system.input_tokens,
-                non_system_kept_tokens = report.non_system.kept_tokens,
-                non_system_dropped_tokens = report.non_system.dropped_tokens(),
-                non_system_truncated_messages = report.non_system.truncated_messages,
-                non_system_truncated_tokens = report.non_system.truncated_tokens,
-                regular_system_pre_messages = report.regular_system.input_messages,
-                regular_system_kept_messages = report.regular_system.kept_messages,
-                regular_system_dropped_messages = report.regular_system.dropped_messages(),
-                regular_system_pre_tokens = report.regular_system.input_tokens,
-                regular_system_kept_tokens = report.regular_system.kept_tokens,
-                regular_system_dropped_tokens = report.regular_system.dropped_tokens(),
-                regular_system_truncated_messages = report.regular_system.truncated_messages,
-                regular_system_truncated_tokens = report.regular_system.truncated_tokens,
-                summary_pre_messages = report.summary_system.input_messages,
-                summary_kept_messages = report.summary_system.kept_messages,
-                summary_dropped_messages = report.summary_system.dropped_messages(),
-                summary_pre_tokens = report.summary_system.input_tokens,
-                summary_kept_tokens = report.summary_system.kept_tokens,
-                summary_dropped_tokens = report.summary_system.dropped_tokens(),
-                summary_truncated_messages = report.summary_system.truncated_messages,
-                summary_truncated_tokens = report.summary_system.truncated_tokens,
-                "applied token-budget context packing"
-            );
-        }
-
-        let tools_json = if self.mcp.is_some() {
-            self.mcp_tools_for_llm().await?
-        } else {
-            None
+            user_message: user_message_owned.as_str(),
         };
 
-        let mut round = 0;
-        let mut total_tool_calls_this_turn: u32 = 0;
-        let mut last_tool_names: Vec<String> = Vec::new();
-        let mut tool_summary = ToolExecutionSummary::default();
-        loop {
-            if round >= self.config.max_tool_rounds {
-                let hint = format!(
-                    "max_tool_rounds ({}) exceeded after {} rounds ({} tool calls). \\
-                    Try again with a fresh message (rounds reset per message), or increase \\
-                    OMNI_AGENT_MAX_TOOL_ROUNDS / telegram.max_tool_rounds. \\
-                    Last tools: {:?}",
-                    self.config.max_tool_rounds, round, total_tool_calls_this_turn, last_tool_names
-                );
-                tracing::warn!("{}", hint);
-                let outcome = self
-                    .update_recall_feedback(session_id, user_message, &hint, Some(&tool_summary))
-                    .await;
-                self.apply_memory_recall_credit(session_id, &recall_credit_candidates, outcome);
-                self.reflect_turn_and_update_policy_hint(
-                    session_id,
-                    turn_id,
-                    decision.route,
-                    user_message,
-                    &hint,
-                    "error",
-                    total_tool_calls_this_turn,
-                )
-                .await;
-                return Err(anyhow::anyhow!("{}", hint));
-            }
-            round += 1;
-
-            let resp = self.llm.chat(messages.clone(), tools_json.clone()).await?;
-
-            if let Some(ref tool_calls) = resp.tool_calls {
-                if tool_calls.is_empty() {
-                    let out = resp.content.unwrap_or_default();
-                    let outcome = self
-                        .update_recall_feedback(session_id, user_message, &out, Some(&tool_summary))
-                        .await;
-                    self.apply_memory_recall_credit(session_id, &recall_credit_candidates, outcome);
-                    self.append_turn_to_session(
-                        session_id,
-                        user_message,
-                        &out,
-                        total_tool_calls_this_turn,
-                    )
-                    .await?;
-                    self.reflect_turn_and_update_policy_hint(
-                        session_id,
-                        turn_id,
-                        decision.route,
-                        user_message,
-                        &out,
-                        "completed",
-                        total_tool_calls_this_turn,
-                    )
-                    .await;
-                    return Ok(out);
-                }
-                total_tool_calls_this_turn += tool_calls.len() as u32;
-                last_tool_names = tool_calls
-                    .iter()
-                    .map(|tc| tc.function.name.clone())
-                    .collect();
-                // Append assistant message with tool_calls.
-                messages.push(ChatMessage {
-                    role: "assistant".to_string(),
-                    content: resp.content.clone(),
-                    tool_calls: Some(tool_calls.clone()),
-                    tool_call_id: None,
-                    name: None,
-                });
-                // Call each tool and append tool results.
-                for tc in tool_calls.iter() {
-                    let name = tc.function.name.clone();
-                    let args_str = tc.function.arguments.clone();
-                    let args = if args_str.is_empty() {
-                        None
-                    } else {
-                        serde_json::from_str(&args_str).ok()
-                    };
-                    let result = match self.call_mcp_tool_with_diagnostics(&name, args).await {
-                        Ok(output) => {
-                            tool_summary.record_result(output.is_error);
-                            output.text
-                        }
-                        Err(error) => {
-                            tool_summary.record_transport_failure();
-                            let error_text = format!("tool `{name}` call failed: {error}");
-                            let outcome = self
-                                .update_recall_feedback(
-                                    session_id,
-                                    user_message,
-                                    &error_text,
-                                    Some(&tool_summary),
-                                )
-                                .await;
-                            self.apply_memory_recall_credit(
-                                session_id,
-                                &recall_credit_candidates,
-                                outcome,
-                            );
-                            self.reflect_turn_and_update_policy_hint(
-                                session_id,
-                                turn_id,
-                                decision.route,
-                                user_message,
-                                &error_text,
-                                "error",
-                                total_tool_calls_this_turn,
-                            )
-                            .await;
-                            return Err(error);
-                        }
-                    };
-                    messages.push(ChatMessage {
-                        role: "tool".to_string(),
-                        content: Some(result),
-                        tool_calls: None,
-                        tool_call_id: Some(tc.id.clone()),
-                        name: Some(name),
-                    });
-                }
-                continue;
-            }
+        if !force_react && let Some(shortcut) = parse_crawl_shortcut(turn.user_message) {
+            return self.run_crawl_shortcut(turn, &shortcut).await;
+        }
 
-            let out = resp.content.unwrap_or_default();
-            let outcome = self
-                .update_recall_feedback(session_id, user_message, &out, Some(&tool_summary))
-                .await;
-            self.apply_memory_recall_credit(session_id, &recall_credit_candidates, outcome);
-            self.append_turn_to_session(session_id, user_message, &out, total_tool_calls_this_turn)
-                .await?;
-            self.reflect_turn_and_update_policy_hint(
-                session_id,
-                turn_id,
-                decision.route,
-                user_message,
-                &out,
-                "completed",
-                total_tool_calls_this_turn,
-            )
+        let decision = self
+            .decide_standard_turn_route(session_id, turn.user_message, force_react)
             .await;
-            return Ok(out);
-        }
+        let (messages, recall_credit_candidates) = self.build_turn_messages(turn).await?;
+        let react_turn = ReactTurn {
+            scope: turn,
+            route: decision.route,
+            recall_credit_candidates: &recall_credit_candidates,
+            progress,
+        };
+        self.run_react_loop(&react_turn, messages).await
     }
 
     /// Clear session history for a session.
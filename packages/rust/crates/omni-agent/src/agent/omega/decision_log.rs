@@ -0,0 +1,167 @@
+use std::fs::OpenOptions;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::contracts::OmegaDecision;
+
+/// Env var pointing the agent at an Omega decision log file (JSON lines).
+pub const OMEGA_DECISION_LOG_ENV: &str = "OMNI_AGENT_OMEGA_DECISION_LOG";
+
+const INPUT_SUMMARY_MAX_CHARS: usize = 160;
+
+/// One audited Omega routing decision (one JSON line in the log).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OmegaDecisionLogEntry {
+    /// Unix epoch milliseconds when the decision was recorded.
+    pub recorded_at_ms: u64,
+    /// Logical session that triggered the decision.
+    pub session_id: String,
+    /// Whitespace-collapsed, truncated user input that was routed.
+    pub input_summary: String,
+    /// Full decision (route, risk, fallback policy, trust class, rationale).
+    pub decision: OmegaDecision,
+}
+
+/// Append-only JSON-lines log of Omega decisions, for audit and replay.
+#[derive(Debug)]
+pub struct OmegaDecisionLog {
+    path: PathBuf,
+    write_lock: Mutex<()>,
+}
+
+impl OmegaDecisionLog {
+    /// Log backed by `path`; the file is created on first append.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Log configured through [`OMEGA_DECISION_LOG_ENV`], if set and non-empty.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        std::env::var(OMEGA_DECISION_LOG_ENV)
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(Self::new)
+    }
+
+    /// Backing file path.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append one decision as a JSON line.
+    ///
+    /// # Errors
+    /// Returns an error when the log file cannot be opened or written.
+    pub fn append(
+        &self,
+        session_id: &str,
+        input: &str,
+        decision: &OmegaDecision,
+    ) -> io::Result<OmegaDecisionLogEntry> {
+        let entry = OmegaDecisionLogEntry {
+            recorded_at_ms: now_unix_ms(),
+            session_id: session_id.to_string(),
+            input_summary: summarize_input(input),
+            decision: decision.clone(),
+        };
+        let mut line = serde_json::to_string(&entry).map_err(io::Error::other)?;
+        line.push('\n');
+
+        let _guard = self
+            .write_lock
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(parent) = self.path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(line.as_bytes())?;
+        Ok(entry)
+    }
+
+    /// Read every logged entry in append order; a missing file yields no entries.
+    ///
+    /// # Errors
+    /// Returns an error when the file cannot be read or a line is not a valid entry.
+    pub fn entries(&self) -> io::Result<Vec<OmegaDecisionLogEntry>> {
+        match std::fs::File::open(&self.path) {
+            Ok(file) => read_entries(BufReader::new(file)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Reconstruct the logged decisions in append order.
+    ///
+    /// # Errors
+    /// Same as [`OmegaDecisionLog::entries`].
+    pub fn replay(&self) -> io::Result<Vec<OmegaDecision>> {
+        Ok(self
+            .entries()?
+            .into_iter()
+            .map(|entry| entry.decision)
+            .collect())
+    }
+}
+
+/// Parse decision-log JSON lines from any reader; blank lines are skipped.
+///
+/// # Errors
+/// Returns `InvalidData` naming the 1-based line number of the first malformed entry.
+pub fn read_entries(reader: impl BufRead) -> io::Result<Vec<OmegaDecisionLogEntry>> {
+    let mut entries = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = serde_json::from_str(&line).map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "invalid omega decision log entry at line {}: {error}",
+                    index + 1
+                ),
+            )
+        })?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+fn summarize_input(input: &str) -> String {
+    let collapsed = input.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= INPUT_SUMMARY_MAX_CHARS {
+        return collapsed;
+    }
+    let mut summary: String = collapsed.chars().take(INPUT_SUMMARY_MAX_CHARS).collect();
+    summary.push('…');
+    summary
+}
+
+fn now_unix_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| {
+            u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+        })
+}
+
+#[cfg(test)]
+#[path = "../../../tests/agent/omega_decision_log.rs"]
+mod tests;
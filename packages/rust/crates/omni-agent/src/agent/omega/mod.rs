@@ -1,5 +1,11 @@
 mod decision;
+mod decision_log;
 mod fallback;
+mod tool_gate;
 
 pub(crate) use decision::{apply_policy_hint, decide_for_shortcut, decide_for_standard_turn};
+pub use decision_log::{
+    OMEGA_DECISION_LOG_ENV, OmegaDecisionLog, OmegaDecisionLogEntry, read_entries,
+};
 pub(crate) use fallback::{ShortcutFallbackAction, resolve_shortcut_fallback};
+pub use tool_gate::omega_tool_gate;
@@ -0,0 +1,33 @@
+use crate::contracts::{OmegaToolGateAction, OmegaToolGateDecision, OmegaToolGatePolicy};
+
+/// Gate one tool call by the trust class `policy` assigns to that tool.
+#[must_use]
+pub fn omega_tool_gate(tool_name: &str, policy: &OmegaToolGatePolicy) -> OmegaToolGateDecision {
+    let trust_class = policy.trust_class_for(tool_name);
+    let action = if policy
+        .block_below
+        .is_some_and(|floor| trust_class.rank() < floor.rank())
+    {
+        OmegaToolGateAction::Block
+    } else if trust_class.rank() < policy.auto_execute_min.rank() {
+        OmegaToolGateAction::RequireConfirmation
+    } else {
+        OmegaToolGateAction::AutoExecute
+    };
+    OmegaToolGateDecision {
+        tool_name: tool_name.to_string(),
+        action,
+        tool_trust_class: trust_class,
+        reason: format!(
+            "tool `{tool_name}` trust class `{}` vs auto-execute threshold `{}`: {}",
+            trust_class.as_str(),
+            policy.auto_execute_min.as_str(),
+            action.as_str()
+        ),
+        policy_id: format!("omega.tool_gate.{}.v1", action.as_str()),
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/agent/omega_tool_gate.rs"]
+mod tests;
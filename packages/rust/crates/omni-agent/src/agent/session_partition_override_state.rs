@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+
+use super::Agent;
+use crate::session::ChatMessage;
+
+const SESSION_PARTITION_OVERRIDE_SESSION_PREFIX: &str = "__session_partition_overrides__:";
+const SESSION_PARTITION_OVERRIDE_STORAGE_MESSAGE_NAME: &str = "agent.session.partition_overrides";
+
+fn storage_session_id(channel: &str) -> String {
+    format!("{SESSION_PARTITION_OVERRIDE_SESSION_PREFIX}{channel}")
+}
+
+fn overrides_to_message(overrides: &BTreeMap<String, String>) -> Result<ChatMessage> {
+    let payload = serde_json::to_string(overrides)
+        .context("failed to serialize session partition overrides")?;
+    Ok(ChatMessage {
+        role: "system".to_string(),
+        content: Some(payload),
+        tool_calls: None,
+        tool_call_id: None,
+        name: Some(SESSION_PARTITION_OVERRIDE_STORAGE_MESSAGE_NAME.to_string()),
+    })
+}
+
+fn message_to_overrides(message: &ChatMessage) -> Option<BTreeMap<String, String>> {
+    if message.name.as_deref() != Some(SESSION_PARTITION_OVERRIDE_STORAGE_MESSAGE_NAME) {
+        return None;
+    }
+    serde_json::from_str(message.content.as_deref()?).ok()
+}
+
+impl Agent {
+    /// Recipient session partition overrides persisted for `channel` (recipient -> mode).
+    pub async fn recipient_session_partitions(
+        &self,
+        channel: &str,
+    ) -> Result<BTreeMap<String, String>> {
+        let storage_id = storage_session_id(channel);
+        let messages =
+            self.session.get(&storage_id).await.with_context(|| {
+                format!("failed to load session partition overrides: {storage_id}")
+            })?;
+        Ok(messages
+            .iter()
+            .rev()
+            .find_map(message_to_overrides)
+            .unwrap_or_default())
+    }
+
+    /// Persist one recipient's session partition override for `channel`; `None` clears it.
+    pub async fn upsert_recipient_session_partition(
+        &self,
+        channel: &str,
+        recipient: &str,
+        mode: Option<&str>,
+    ) -> Result<()> {
+        let mut overrides = self.recipient_session_partitions(channel).await?;
+        let recipient = recipient.trim().to_string();
+        let changed = match mode {
+            Some(mode) => overrides.insert(recipient, mode.to_string()).as_deref() != Some(mode),
+            None => overrides.remove(&recipient).is_some(),
+        };
+        if !changed {
+            return Ok(());
+        }
+
+        let storage_id = storage_session_id(channel);
+        if overrides.is_empty() {
+            return self.session.clear(&storage_id).await.with_context(|| {
+                format!("failed to clear session partition overrides: {storage_id}")
+            });
+        }
+        self.session
+            .replace(&storage_id, vec![overrides_to_message(&overrides)?])
+            .await
+            .with_context(|| format!("failed to persist session partition overrides: {storage_id}"))
+    }
+}
@@ -0,0 +1,65 @@
+use super::Agent;
+
+/// Per-session tool gate confirmation bookkeeping.
+#[derive(Debug, Default)]
+pub(super) struct SessionToolConfirmations {
+    /// Tools the gate withheld pending confirmation, oldest first.
+    pending: Vec<String>,
+    /// Tools approved for exactly one more call.
+    approved: Vec<String>,
+}
+
+impl Agent {
+    /// Tool calls in `session_id` withheld by the tool gate until confirmed.
+    pub async fn pending_tool_confirmations(&self, session_id: &str) -> Vec<String> {
+        self.tool_confirmations
+            .read()
+            .await
+            .get(session_id)
+            .map(|state| state.pending.clone())
+            .unwrap_or_default()
+    }
+
+    /// Approve the next call of `tool_name` in `session_id` after the tool gate held it
+    /// for confirmation. Returns false, changing nothing, when no such call is pending;
+    /// blocked tools stay blocked.
+    pub async fn approve_tool_call(&self, session_id: &str, tool_name: &str) -> bool {
+        let mut confirmations = self.tool_confirmations.write().await;
+        let Some(state) = confirmations.get_mut(session_id) else {
+            return false;
+        };
+        let Some(index) = state
+            .pending
+            .iter()
+            .position(|pending| pending == tool_name)
+        else {
+            return false;
+        };
+        state.pending.remove(index);
+        state.approved.push(tool_name.to_string());
+        true
+    }
+
+    pub(super) async fn take_tool_call_approval(&self, session_id: &str, tool_name: &str) -> bool {
+        let mut confirmations = self.tool_confirmations.write().await;
+        let Some(state) = confirmations.get_mut(session_id) else {
+            return false;
+        };
+        let Some(index) = state.approved.iter().position(|name| name == tool_name) else {
+            return false;
+        };
+        state.approved.remove(index);
+        if state.pending.is_empty() && state.approved.is_empty() {
+            confirmations.remove(session_id);
+        }
+        true
+    }
+
+    pub(super) async fn record_pending_tool_confirmation(&self, session_id: &str, tool_name: &str) {
+        let mut confirmations = self.tool_confirmations.write().await;
+        let state = confirmations.entry(session_id.to_string()).or_default();
+        if !state.pending.iter().any(|pending| pending == tool_name) {
+            state.pending.push(tool_name.to_string());
+        }
+    }
+}
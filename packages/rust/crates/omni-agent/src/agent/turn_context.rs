@@ -0,0 +1,447 @@
+//! Route decision and context assembly for a standard turn.
+
+use anyhow::Result;
+use omni_memory::Episode;
+use omni_tokenizer::count_tokens;
+use std::time::Instant;
+use xiuxian_qianhuan::InjectionPolicy;
+
+use super::memory::{RecalledEpisodeCandidate, select_recall_credit_candidates};
+use super::memory_recall::{
+    MEMORY_RECALL_MESSAGE_NAME, MemoryRecallInput, MemoryRecallPlan, build_memory_context_message,
+    estimate_messages_tokens, filter_recalled_episodes, plan_memory_recall,
+};
+use super::memory_recall_feedback::apply_feedback_to_plan;
+use super::memory_recall_state::{SessionMemoryRecallDecision, SessionMemoryRecallSnapshot};
+use super::system_prompt_injection_state::SYSTEM_PROMPT_INJECTION_CONTEXT_MESSAGE_NAME;
+use super::{Agent, TurnScope, context_budget, injection, omega};
+use crate::contracts::{OmegaDecision, OmegaFallbackPolicy};
+use crate::observability::SessionEvent;
+use crate::session::{ChatMessage, SessionSummarySegment};
+
+/// Inputs of one memory recall pass, kept for the injected/skipped diagnostics.
+struct MemoryRecallTrace {
+    plan: MemoryRecallPlan,
+    active_turns_estimate: usize,
+    summary_segment_count: usize,
+    query_tokens: usize,
+    recall_feedback_bias: f32,
+    embedding_source: &'static str,
+    recalled_total: usize,
+    started: Instant,
+}
+
+impl Agent {
+    /// Decide the route of a standard turn, applying any pending reflection policy hint.
+    pub(super) async fn decide_standard_turn_route(
+        &self,
+        session_id: &str,
+        user_message: &str,
+        force_react: bool,
+    ) -> OmegaDecision {
+        let policy_hint = self.take_reflection_policy_hint(session_id).await;
+        if let Some(hint) = policy_hint.as_ref() {
+            tracing::debug!(
+                event = SessionEvent::ReflectionPolicyHintApplied.as_str(),
+                session_id,
+                source_turn_id = hint.source_turn_id,
+                preferred_route = hint.preferred_route.as_str(),
+                risk_floor = hint.risk_floor.as_str(),
+                fallback_override = hint.fallback_override.map(OmegaFallbackPolicy::as_str),
+                tool_trust_class = hint.tool_trust_class.as_str(),
+                reason = %hint.reason,
+                "reflection policy hint applied to route decision"
+            );
+        }
+        let decision = omega::apply_policy_hint(
+            omega::decide_for_standard_turn(force_react),
+            policy_hint.as_ref(),
+        );
+        self.record_omega_decision(session_id, user_message, &decision, None, None);
+        decision
+    }
+
+    /// Build the LLM messages for a turn: session history, injected context, memory recall
+    /// and the user message, normalized and packed to the context budget.
+    ///
+    /// Also returns the recalled episodes eligible for recall credit.
+    pub(super) async fn build_turn_messages(
+        &self,
+        turn: TurnScope<'_>,
+    ) -> Result<(Vec<ChatMessage>, Vec<RecalledEpisodeCandidate>)> {
+        let session_id = turn.session_id;
+        let mut summary_segments: Vec<SessionSummarySegment> = Vec::new();
+        let mut messages: Vec<ChatMessage> = if let Some(ref w) = self.bounded_session {
+            let limit = self.config.window_max_turns.unwrap_or(512);
+            summary_segments = w
+                .get_recent_summary_segments(session_id, self.config.summary_max_segments)
+                .await?;
+            w.get_recent_messages(session_id, limit).await?
+        } else {
+            self.session.get(session_id).await?
+        };
+
+        if !summary_segments.is_empty() {
+            let segment_count = summary_segments.len();
+            let summary_messages = summary_segments
+                .iter()
+                .enumerate()
+                .map(|(index, segment)| ChatMessage {
+                    role: "system".to_string(),
+                    content: Some(format!(
+                        "Compressed conversation history from older turns (segment {}/{}): {} (turns={}, tools={})",
+                        index + 1,
+                        segment_count,
+                        segment.summary,
+                        segment.turn_count,
+                        segment.tool_calls
+                    )),
+                    tool_calls: None,
+                    tool_call_id: None,
+                    name: Some(context_budget::SESSION_SUMMARY_MESSAGE_NAME.to_string()),
+                })
+                .collect::<Vec<_>>();
+            messages.splice(0..0, summary_messages);
+        }
+
+        if let Some(snapshot) = self
+            .inspect_session_system_prompt_injection(session_id)
+            .await
+        {
+            messages.insert(
+                0,
+                ChatMessage {
+                    role: "system".to_string(),
+                    content: Some(snapshot.xml),
+                    tool_calls: None,
+                    tool_call_id: None,
+                    name: Some(SYSTEM_PROMPT_INJECTION_CONTEXT_MESSAGE_NAME.to_string()),
+                },
+            );
+        }
+
+        messages.push(ChatMessage {
+            role: "user".to_string(),
+            content: Some(turn.user_message.to_string()),
+            tool_calls: None,
+            tool_call_id: None,
+            name: None,
+        });
+
+        let recall_credit_candidates = self
+            .inject_memory_recall(
+                session_id,
+                turn.user_message,
+                &mut messages,
+                summary_segments.len(),
+            )
+            .await;
+
+        let messages = self.normalize_turn_messages(turn, messages);
+        let messages = self.apply_context_budget(session_id, messages).await;
+        Ok((messages, recall_credit_candidates))
+    }
+
+    /// Recall episodes relevant to `user_message` and prepend them as a system message.
+    async fn inject_memory_recall(
+        &self,
+        session_id: &str,
+        user_message: &str,
+        messages: &mut Vec<ChatMessage>,
+        summary_segment_count: usize,
+    ) -> Vec<RecalledEpisodeCandidate> {
+        let (Some(store), Some(mem_cfg)) =
+            (self.memory_store.as_ref(), self.config.memory.as_ref())
+        else {
+            return Vec::new();
+        };
+
+        let started = Instant::now();
+        let active_turns_estimate = messages
+            .iter()
+            .filter(|message| message.role == "user" || message.role == "assistant")
+            .count()
+            / 2;
+        let query_tokens = count_tokens(user_message);
+        let recall_plan = plan_memory_recall(MemoryRecallInput {
+            base_k1: mem_cfg.recall_k1,
+            base_k2: mem_cfg.recall_k2,
+            base_lambda: mem_cfg.recall_lambda,
+            context_budget_tokens: self.config.context_budget_tokens,
+            context_budget_reserve_tokens: self.config.context_budget_reserve_tokens,
+            context_tokens_before_recall: estimate_messages_tokens(messages),
+            active_turns_estimate,
+            window_max_turns: self.config.window_max_turns,
+            summary_segment_count,
+        });
+        let recall_feedback_bias = self.recall_feedback_bias(session_id).await;
+        let recall_plan = apply_feedback_to_plan(recall_plan, recall_feedback_bias);
+        tracing::debug!(
+            event = SessionEvent::MemoryRecallPlanned.as_str(),
+            session_id,
+            memory_scope = session_id,
+            k1 = recall_plan.k1,
+            k2 = recall_plan.k2,
+            lambda = recall_plan.lambda,
+            min_score = recall_plan.min_score,
+            max_context_chars = recall_plan.max_context_chars,
+            budget_pressure = recall_plan.budget_pressure,
+            window_pressure = recall_plan.window_pressure,
+            effective_budget_tokens = ?recall_plan.effective_budget_tokens,
+            active_turns_estimate,
+            summary_segment_count,
+            recall_feedback_bias,
+            "memory recall plan selected"
+        );
+        self.record_memory_recall_plan_metrics().await;
+
+        let (query_embedding, embedding_source) = self
+            .embedding_or_hash_with_source(user_message, store, mem_cfg.embedding_dim)
+            .await;
+        let recalled = store.two_phase_recall_with_embedding_for_scope(
+            session_id,
+            &query_embedding,
+            recall_plan.k1,
+            recall_plan.k2,
+            recall_plan.lambda,
+        );
+        let trace = MemoryRecallTrace {
+            plan: recall_plan,
+            active_turns_estimate,
+            summary_segment_count,
+            query_tokens,
+            recall_feedback_bias,
+            embedding_source,
+            recalled_total: recalled.len(),
+            started,
+        };
+        let recalled = filter_recalled_episodes(recalled, &recall_plan);
+        let Some(system_content) =
+            build_memory_context_message(&recalled, recall_plan.max_context_chars)
+        else {
+            self.record_memory_recall_skipped(session_id, &trace, &recalled)
+                .await;
+            return Vec::new();
+        };
+
+        let recall_credit_candidates = if mem_cfg.recall_credit_enabled {
+            select_recall_credit_candidates(&recalled, mem_cfg.recall_credit_max_candidates)
+        } else {
+            Vec::new()
+        };
+        let context_chars_injected = system_content.chars().count();
+        messages.insert(
+            0,
+            ChatMessage {
+                role: "system".to_string(),
+                content: Some(system_content),
+                tool_calls: None,
+                tool_call_id: None,
+                name: Some(MEMORY_RECALL_MESSAGE_NAME.to_string()),
+            },
+        );
+        self.record_memory_recall_injected(session_id, &trace, &recalled, context_chars_injected)
+            .await;
+        recall_credit_candidates
+    }
+
+    async fn record_memory_recall_injected(
+        &self,
+        session_id: &str,
+        trace: &MemoryRecallTrace,
+        recalled: &[(Episode, f32)],
+        context_chars_injected: usize,
+    ) {
+        let injected_count = recalled.len();
+        let pipeline_duration_ms = trace.started.elapsed().as_millis() as u64;
+        let best_score = recalled
+            .first()
+            .map(|(_, score)| *score)
+            .unwrap_or_default();
+        let weakest_score = recalled.last().map(|(_, score)| *score).unwrap_or_default();
+        tracing::debug!(
+            event = SessionEvent::MemoryRecallInjected.as_str(),
+            session_id,
+            query_tokens = trace.query_tokens,
+            embedding_source = trace.embedding_source,
+            recalled_total = trace.recalled_total,
+            recalled_selected = recalled.len(),
+            recalled_injected = injected_count,
+            context_chars_injected,
+            pipeline_duration_ms,
+            best_score,
+            weakest_score,
+            "memory recall context injected"
+        );
+        self.record_memory_recall_result_metrics(
+            SessionMemoryRecallDecision::Injected,
+            recalled.len(),
+            injected_count,
+            context_chars_injected,
+            pipeline_duration_ms,
+        )
+        .await;
+        self.record_memory_recall_snapshot(
+            session_id,
+            SessionMemoryRecallSnapshot::from_plan(
+                trace.plan,
+                trace.active_turns_estimate,
+                trace.summary_segment_count,
+                trace.query_tokens,
+                trace.recall_feedback_bias,
+                trace.embedding_source,
+                trace.recalled_total,
+                recalled.len(),
+                injected_count,
+                context_chars_injected,
+                Some(best_score),
+                Some(weakest_score),
+                pipeline_duration_ms,
+                SessionMemoryRecallDecision::Injected,
+            ),
+        )
+        .await;
+    }
+
+    async fn record_memory_recall_skipped(
+        &self,
+        session_id: &str,
+        trace: &MemoryRecallTrace,
+        recalled: &[(Episode, f32)],
+    ) {
+        let pipeline_duration_ms = trace.started.elapsed().as_millis() as u64;
+        let best_score = recalled
+            .first()
+            .map(|(_, score)| *score)
+            .unwrap_or_default();
+        tracing::debug!(
+            event = SessionEvent::MemoryRecallSkipped.as_str(),
+            session_id,
+            query_tokens = trace.query_tokens,
+            embedding_source = trace.embedding_source,
+            recalled_total = trace.recalled_total,
+            recalled_selected = recalled.len(),
+            pipeline_duration_ms,
+            best_score,
+            "memory recall skipped after scoring/compaction filters"
+        );
+        self.record_memory_recall_result_metrics(
+            SessionMemoryRecallDecision::Skipped,
+            recalled.len(),
+            0,
+            0,
+            pipeline_duration_ms,
+        )
+        .await;
+        self.record_memory_recall_snapshot(
+            session_id,
+            SessionMemoryRecallSnapshot::from_plan(
+                trace.plan,
+                trace.active_turns_estimate,
+                trace.summary_segment_count,
+                trace.query_tokens,
+                trace.recall_feedback_bias,
+                trace.embedding_source,
+                trace.recalled_total,
+                recalled.len(),
+                0,
+                0,
+                recalled.first().map(|(_, score)| *score),
+                recalled.last().map(|(_, score)| *score),
+                pipeline_duration_ms,
+                SessionMemoryRecallDecision::Skipped,
+            ),
+        )
+        .await;
+    }
+
+    fn normalize_turn_messages(
+        &self,
+        turn: TurnScope<'_>,
+        raw_messages: Vec<ChatMessage>,
+    ) -> Vec<ChatMessage> {
+        match injection::normalize_messages_with_snapshot(
+            turn.session_id,
+            turn.turn_id,
+            raw_messages.clone(),
+            InjectionPolicy::default(),
+        ) {
+            Ok(normalized) => {
+                if let Some(snapshot) = normalized.snapshot.as_ref() {
+                    self.record_injection_snapshot(turn.session_id, snapshot);
+                }
+                normalized.messages
+            }
+            Err(error) => {
+                tracing::warn!(
+                    session_id = turn.session_id,
+                    error = %error,
+                    "failed to normalize injection snapshot; context messages unchanged"
+                );
+                raw_messages
+            }
+        }
+    }
+
+    async fn apply_context_budget(
+        &self,
+        session_id: &str,
+        messages: Vec<ChatMessage>,
+    ) -> Vec<ChatMessage> {
+        let Some(context_budget_tokens) = self
+            .config
+            .context_budget_tokens
+            .filter(|tokens| *tokens > 0)
+        else {
+            return messages;
+        };
+        let result = context_budget::prune_messages_for_token_budget_with_strategy(
+            messages,
+            context_budget_tokens,
+            self.config.context_budget_reserve_tokens,
+            self.config.context_budget_strategy,
+        );
+        let report = result.report;
+        self.record_context_budget_snapshot(session_id, &report)
+            .await;
+        tracing::debug!(
+            session_id,
+            strategy = report.strategy.as_str(),
+            budget_tokens = report.budget_tokens,
+            reserve_tokens = report.reserve_tokens,
+            effective_budget_tokens = report.effective_budget_tokens,
+            pre_messages = report.pre_messages,
+            post_messages = report.post_messages,
+            pre_tokens = report.pre_tokens,
+            post_tokens = report.post_tokens,
+            dropped_messages = report.pre_messages.saturating_sub(report.post_messages),
+            dropped_tokens = report.pre_tokens.saturating_sub(report.post_tokens),
+            non_system_pre_messages = report.non_system.input_messages,
+            non_system_kept_messages = report.non_system.kept_messages,
+            non_system_dropped_messages = report.non_system.dropped_messages(),
+            non_system_pre_tokens = report.non_system.input_tokens,
+            non_system_kept_tokens = report.non_system.kept_tokens,
+            non_system_dropped_tokens = report.non_system.dropped_tokens(),
+            non_system_truncated_messages = report.non_system.truncated_messages,
+            non_system_truncated_tokens = report.non_system.truncated_tokens,
+            regular_system_pre_messages = report.regular_system.input_messages,
+            regular_system_kept_messages = report.regular_system.kept_messages,
+            regular_system_dropped_messages = report.regular_system.dropped_messages(),
+            regular_system_pre_tokens = report.regular_system.input_tokens,
+            regular_system_kept_tokens = report.regular_system.kept_tokens,
+            regular_system_dropped_tokens = report.regular_system.dropped_tokens(),
+            regular_system_truncated_messages = report.regular_system.truncated_messages,
+            regular_system_truncated_tokens = report.regular_system.truncated_tokens,
+            summary_pre_messages = report.summary_system.input_messages,
+            summary_kept_messages = report.summary_system.kept_messages,
+            summary_dropped_messages = report.summary_system.dropped_messages(),
+            summary_pre_tokens = report.summary_system.input_tokens,
+            summary_kept_tokens = report.summary_system.kept_tokens,
+            summary_dropped_tokens = report.summary_system.dropped_tokens(),
+            summary_truncated_messages = report.summary_system.truncated_messages,
+            summary_truncated_tokens = report.summary_system.truncated_tokens,
+            "applied token-budget context packing"
+        );
+        result.messages
+    }
+}
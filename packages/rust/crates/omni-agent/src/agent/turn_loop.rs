@@ -0,0 +1,220 @@
+//! LLM/tool loop of a standard turn.
+
+use anyhow::Result;
+
+use super::memory::RecalledEpisodeCandidate;
+use super::memory_recall_feedback::ToolExecutionSummary;
+use super::turn_progress::{self, TurnProgressEvent, TurnProgressSink};
+use super::{Agent, TurnScope};
+use crate::contracts::OmegaRoute;
+use crate::session::{ChatMessage, ToolCallOut};
+
+/// Per-turn inputs of the tool loop.
+pub(super) struct ReactTurn<'a> {
+    pub scope: TurnScope<'a>,
+    pub route: OmegaRoute,
+    pub recall_credit_candidates: &'a [RecalledEpisodeCandidate],
+    pub progress: Option<&'a TurnProgressSink>,
+}
+
+/// Tool activity accumulated across the rounds of one turn.
+#[derive(Default)]
+struct ReactTally {
+    total_tool_calls: u32,
+    last_tool_names: Vec<String>,
+    tool_summary: ToolExecutionSummary,
+}
+
+impl Agent {
+    /// Alternate LLM calls and MCP tool calls until the LLM answers without tool calls.
+    pub(super) async fn run_react_loop(
+        &self,
+        turn: &ReactTurn<'_>,
+        mut messages: Vec<ChatMessage>,
+    ) -> Result<String> {
+        let tools_json = if self.mcp.is_some() {
+            self.mcp_tools_for_llm().await?
+        } else {
+            None
+        };
+
+        let mut round = 0;
+        let mut tally = ReactTally::default();
+        loop {
+            if round >= self.config.max_tool_rounds {
+                let hint = format!(
+                    "max_tool_rounds ({}) exceeded after {} rounds ({} tool calls). \\
+                    Try again with a fresh message (rounds reset per message), or increase \\
+                    OMNI_AGENT_MAX_TOOL_ROUNDS / telegram.max_tool_rounds. \\
+                    Last tools: {:?}",
+                    self.config.max_tool_rounds,
+                    round,
+                    tally.total_tool_calls,
+                    tally.last_tool_names
+                );
+                tracing::warn!("{}", hint);
+                self.fail_react_turn(turn, &hint, &tally).await;
+                return Err(anyhow::anyhow!("{hint}"));
+            }
+            round += 1;
+
+            let resp = self.llm.chat(messages.clone(), tools_json.clone()).await?;
+            let Some(tool_calls) = resp.tool_calls.filter(|calls| !calls.is_empty()) else {
+                let out = resp.content.unwrap_or_default();
+                self.finish_react_turn(turn, &out, &tally).await?;
+                return Ok(out);
+            };
+
+            tally.total_tool_calls += tool_calls.len() as u32;
+            tally.last_tool_names = tool_calls
+                .iter()
+                .map(|tc| tc.function.name.clone())
+                .collect();
+            if let Some(content) = resp.content.as_ref().filter(|text| !text.trim().is_empty()) {
+                turn_progress::emit(
+                    turn.progress,
+                    TurnProgressEvent::PartialAssistant {
+                        content: content.clone(),
+                    },
+                );
+            }
+            // Append assistant message with tool_calls.
+            messages.push(ChatMessage {
+                role: "assistant".to_string(),
+                content: resp.content,
+                tool_calls: Some(tool_calls.clone()),
+                tool_call_id: None,
+                name: None,
+            });
+            self.execute_tool_calls(turn, &tool_calls, &mut messages, &mut tally)
+                .await?;
+        }
+    }
+
+    /// Call each tool and append its result (or the gate's withheld notice) to `messages`.
+    async fn execute_tool_calls(
+        &self,
+        turn: &ReactTurn<'_>,
+        tool_calls: &[ToolCallOut],
+        messages: &mut Vec<ChatMessage>,
+        tally: &mut ReactTally,
+    ) -> Result<()> {
+        for tc in tool_calls {
+            let name = tc.function.name.clone();
+            let args_str = tc.function.arguments.clone();
+            let args = if args_str.is_empty() {
+                None
+            } else {
+                serde_json::from_str(&args_str).ok()
+            };
+            if let Some((withheld, event)) = self
+                .withhold_gated_tool_call(turn.scope.session_id, &name)
+                .await
+            {
+                turn_progress::emit(turn.progress, event);
+                messages.push(ChatMessage {
+                    role: "tool".to_string(),
+                    content: Some(withheld),
+                    tool_calls: None,
+                    tool_call_id: Some(tc.id.clone()),
+                    name: Some(name),
+                });
+                continue;
+            }
+            turn_progress::emit(
+                turn.progress,
+                TurnProgressEvent::ToolCallStarted {
+                    name: name.clone(),
+                    arguments: args_str,
+                },
+            );
+            let result = match self.call_mcp_tool_with_diagnostics(&name, args).await {
+                Ok(output) => {
+                    tally.tool_summary.record_result(output.is_error);
+                    turn_progress::emit(
+                        turn.progress,
+                        TurnProgressEvent::ToolResult {
+                            name: name.clone(),
+                            is_error: output.is_error,
+                            output: output.text.clone(),
+                        },
+                    );
+                    output.text
+                }
+                Err(error) => {
+                    tally.tool_summary.record_transport_failure();
+                    let error_text = format!("tool `{name}` call failed: {error}");
+                    self.fail_react_turn(turn, &error_text, tally).await;
+                    return Err(error);
+                }
+            };
+            messages.push(ChatMessage {
+                role: "tool".to_string(),
+                content: Some(result),
+                tool_calls: None,
+                tool_call_id: Some(tc.id.clone()),
+                name: Some(name),
+            });
+        }
+        Ok(())
+    }
+
+    /// Record the LLM's final answer `out` as the turn's result.
+    async fn finish_react_turn(
+        &self,
+        turn: &ReactTurn<'_>,
+        out: &str,
+        tally: &ReactTally,
+    ) -> Result<()> {
+        let TurnScope {
+            session_id,
+            turn_id,
+            user_message,
+        } = turn.scope;
+        let outcome = self
+            .update_recall_feedback(session_id, user_message, out, Some(&tally.tool_summary))
+            .await;
+        self.apply_memory_recall_credit(session_id, turn.recall_credit_candidates, outcome);
+        self.append_turn_to_session(session_id, user_message, out, tally.total_tool_calls)
+            .await?;
+        self.reflect_turn_and_update_policy_hint(
+            session_id,
+            turn_id,
+            turn.route,
+            user_message,
+            out,
+            "completed",
+            tally.total_tool_calls,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// Record recall feedback, credit and reflection for a turn that failed with `error_text`.
+    async fn fail_react_turn(&self, turn: &ReactTurn<'_>, error_text: &str, tally: &ReactTally) {
+        let TurnScope {
+            session_id,
+            turn_id,
+            user_message,
+        } = turn.scope;
+        let outcome = self
+            .update_recall_feedback(
+                session_id,
+                user_message,
+                error_text,
+                Some(&tally.tool_summary),
+            )
+            .await;
+        self.apply_memory_recall_credit(session_id, turn.recall_credit_candidates, outcome);
+        self.reflect_turn_and_update_policy_hint(
+            session_id,
+            turn_id,
+            turn.route,
+            user_message,
+            error_text,
+            "error",
+            tally.total_tool_calls,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,60 @@
+//! Step-by-step progress events emitted while a turn runs (consumed by streaming gateways).
+
+use serde::Serialize;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// One observable step of an agent turn.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TurnProgressEvent {
+    /// Assistant text produced before the turn finished (e.g. alongside tool calls).
+    PartialAssistant {
+        /// Assistant text for this round.
+        content: String,
+    },
+    /// A tool call is about to be executed.
+    ToolCallStarted {
+        /// Tool name.
+        name: String,
+        /// Raw JSON arguments as produced by the LLM.
+        arguments: String,
+    },
+    /// A tool call returned.
+    ToolResult {
+        /// Tool name.
+        name: String,
+        /// Whether the tool reported an error result.
+        is_error: bool,
+        /// Tool output text.
+        output: String,
+    },
+    /// The tool gate is holding a tool call until the user approves it.
+    ToolConfirmationRequired {
+        /// Tool name.
+        name: String,
+        /// Gate rationale.
+        reason: String,
+    },
+}
+
+impl TurnProgressEvent {
+    /// Stable event name (matches the serialized `event` tag).
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::PartialAssistant { .. } => "partial_assistant",
+            Self::ToolCallStarted { .. } => "tool_call_started",
+            Self::ToolResult { .. } => "tool_result",
+            Self::ToolConfirmationRequired { .. } => "tool_confirmation_required",
+        }
+    }
+}
+
+/// Receiver side is dropped freely; sends to a closed sink are ignored.
+pub type TurnProgressSink = UnboundedSender<TurnProgressEvent>;
+
+pub(super) fn emit(progress: Option<&TurnProgressSink>, event: TurnProgressEvent) {
+    if let Some(sink) = progress {
+        let _ = sink.send(event);
+    }
+}
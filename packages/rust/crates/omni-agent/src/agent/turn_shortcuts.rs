@@ -0,0 +1,230 @@
+//! Workflow-bridge and crawl shortcuts that answer a turn without the tool loop.
+
+use anyhow::Result;
+
+use super::graph_bridge::GraphBridgeRequest;
+use super::injection;
+use super::memory_recall_feedback::ToolExecutionSummary;
+use super::omega::{self, ShortcutFallbackAction};
+use super::{Agent, TurnScope};
+use crate::contracts::{OmegaDecision, OmegaRoute};
+use crate::shortcuts::{CRAWL_TOOL_NAME, CrawlShortcut, GraphBridgeShortcut};
+
+/// How a workflow-bridge shortcut left the turn.
+pub(super) enum WorkflowBridgeOutcome {
+    /// The bridge answered the turn; the output was recorded in the session.
+    Completed(String),
+    /// Continue with the standard tool loop on this message.
+    React(String),
+}
+
+impl Agent {
+    /// Run a workflow-bridge shortcut parsed from `turn.user_message`.
+    pub(super) async fn run_workflow_bridge_shortcut(
+        &self,
+        turn: TurnScope<'_>,
+        shortcut: &GraphBridgeShortcut,
+    ) -> Result<WorkflowBridgeOutcome> {
+        let decision =
+            omega::decide_for_shortcut(shortcut.mode, turn.user_message, &shortcut.tool_name);
+        self.record_omega_decision(
+            turn.session_id,
+            turn.user_message,
+            &decision,
+            Some(shortcut.mode),
+            Some(shortcut.tool_name.as_str()),
+        );
+        if decision.route != OmegaRoute::Graph {
+            return Ok(WorkflowBridgeOutcome::React(turn.user_message.to_string()));
+        }
+
+        let shortcut_snapshot = self
+            .build_shortcut_injection_snapshot(turn.session_id, turn.turn_id, turn.user_message)
+            .await?;
+        if let Some(snapshot) = &shortcut_snapshot {
+            self.record_injection_snapshot(turn.session_id, snapshot);
+        }
+        let arguments = injection::augment_shortcut_arguments(
+            shortcut.arguments.clone(),
+            shortcut_snapshot.as_ref(),
+            &decision,
+            shortcut.mode,
+        );
+
+        let mut tool_summary = ToolExecutionSummary::default();
+        let initial_request = GraphBridgeRequest {
+            tool_name: shortcut.tool_name.clone(),
+            arguments,
+        };
+        let out = match self.execute_graph_bridge(initial_request).await {
+            Ok(result) => {
+                tool_summary.record_result(result.is_error);
+                result.output
+            }
+            Err(initial_error) => {
+                tool_summary.record_transport_failure();
+                let fallback = self
+                    .apply_workflow_bridge_fallback(
+                        turn,
+                        &decision,
+                        shortcut,
+                        &mut tool_summary,
+                        initial_error,
+                    )
+                    .await?;
+                let Some(out) = fallback else {
+                    return Ok(WorkflowBridgeOutcome::React(format!(
+                        "Execute this task with ReAct because workflow bridge failed: {}",
+                        turn.user_message
+                    )));
+                };
+                out
+            }
+        };
+
+        self.complete_shortcut_turn(turn, decision.route, &out, &tool_summary)
+            .await?;
+        Ok(WorkflowBridgeOutcome::Completed(out))
+    }
+
+    /// Apply the Omega fallback after the initial bridge call failed.
+    ///
+    /// Returns the retried bridge output, or `None` when the turn should be handed to the tool loop.
+    async fn apply_workflow_bridge_fallback(
+        &self,
+        turn: TurnScope<'_>,
+        decision: &OmegaDecision,
+        shortcut: &GraphBridgeShortcut,
+        tool_summary: &mut ToolExecutionSummary,
+        initial_error: anyhow::Error,
+    ) -> Result<Option<String>> {
+        let action = omega::resolve_shortcut_fallback(decision, 0);
+        self.record_shortcut_fallback(
+            turn.session_id,
+            decision,
+            shortcut.mode,
+            &shortcut.tool_name,
+            action,
+            &initial_error,
+        );
+        match action {
+            ShortcutFallbackAction::RetryBridgeWithoutMetadata => {
+                let retry = self
+                    .execute_graph_bridge(GraphBridgeRequest {
+                        tool_name: shortcut.tool_name.clone(),
+                        arguments: shortcut.arguments.clone(),
+                    })
+                    .await;
+                match retry {
+                    Ok(result) => {
+                        tool_summary.record_result(result.is_error);
+                        Ok(Some(result.output))
+                    }
+                    Err(retry_error) => {
+                        tool_summary.record_transport_failure();
+                        self.fail_shortcut_turn(
+                            turn,
+                            decision.route,
+                            &retry_error.to_string(),
+                            tool_summary,
+                        )
+                        .await;
+                        Err(retry_error)
+                    }
+                }
+            }
+            ShortcutFallbackAction::RouteToReact => Ok(None),
+            ShortcutFallbackAction::Abort => {
+                self.fail_shortcut_turn(
+                    turn,
+                    decision.route,
+                    &initial_error.to_string(),
+                    tool_summary,
+                )
+                .await;
+                Err(initial_error)
+            }
+        }
+    }
+
+    /// Run a crawl shortcut parsed from `turn.user_message`.
+    pub(super) async fn run_crawl_shortcut(
+        &self,
+        turn: TurnScope<'_>,
+        shortcut: &CrawlShortcut,
+    ) -> Result<String> {
+        let mut tool_summary = ToolExecutionSummary::default();
+        let out = match self
+            .call_mcp_tool_with_diagnostics(CRAWL_TOOL_NAME, Some(shortcut.to_arguments()))
+            .await
+        {
+            Ok(output) => {
+                tool_summary.record_result(output.is_error);
+                output.text
+            }
+            Err(error) => {
+                tool_summary.record_transport_failure();
+                self.fail_shortcut_turn(turn, OmegaRoute::React, &error.to_string(), &tool_summary)
+                    .await;
+                return Err(error);
+            }
+        };
+        self.complete_shortcut_turn(turn, OmegaRoute::React, &out, &tool_summary)
+            .await?;
+        Ok(out)
+    }
+
+    /// Record a shortcut's output as the turn's answer.
+    async fn complete_shortcut_turn(
+        &self,
+        turn: TurnScope<'_>,
+        route: OmegaRoute,
+        out: &str,
+        tool_summary: &ToolExecutionSummary,
+    ) -> Result<()> {
+        let _ = self
+            .update_recall_feedback(turn.session_id, turn.user_message, out, Some(tool_summary))
+            .await;
+        self.append_turn_to_session(turn.session_id, turn.user_message, out, 1)
+            .await?;
+        self.reflect_turn_and_update_policy_hint(
+            turn.session_id,
+            turn.turn_id,
+            route,
+            turn.user_message,
+            out,
+            "completed",
+            tool_summary.attempted,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// Record recall feedback and reflection for a shortcut that failed with `error_text`.
+    async fn fail_shortcut_turn(
+        &self,
+        turn: TurnScope<'_>,
+        route: OmegaRoute,
+        error_text: &str,
+        tool_summary: &ToolExecutionSummary,
+    ) {
+        let _ = self
+            .update_recall_feedback(
+                turn.session_id,
+                turn.user_message,
+                error_text,
+                Some(tool_summary),
+            )
+            .await;
+        self.reflect_turn_and_update_policy_hint(
+            turn.session_id,
+            turn.turn_id,
+            route,
+            turn.user_message,
+            error_text,
+            "error",
+            tool_summary.attempted,
+        )
+        .await;
+    }
+}
@@ -1,9 +1,10 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use anyhow::{Result, anyhow};
 use omni_agent::{
-    Agent, AgentConfig, ContextBudgetStrategy, LITELLM_DEFAULT_URL, MemoryConfig, RuntimeSettings,
-    load_mcp_config,
+    Agent, AgentConfig, ContextBudgetStrategy, LITELLM_DEFAULT_URL, MemoryConfig,
+    OmegaToolGatePolicy, OmegaToolTrustClass, RuntimeSettings, load_mcp_config,
 };
 
 use crate::resolve::{
@@ -48,6 +49,50 @@ fn resolve_context_budget_strategy(
     Ok(ContextBudgetStrategy::RecentFirst)
 }
 
+fn parse_tool_trust_class_env(name: &str) -> Result<Option<OmegaToolTrustClass>> {
+    non_empty_env(name)
+        .map(|raw| {
+            raw.parse::<OmegaToolTrustClass>().map_err(|_| {
+                anyhow!("invalid {name}: '{raw}' (expected one of: other, verification, evidence)")
+            })
+        })
+        .transpose()
+}
+
+fn resolve_tool_gate_policy() -> Result<OmegaToolGatePolicy> {
+    let mut policy = OmegaToolGatePolicy::default();
+    if let Some(threshold) = parse_tool_trust_class_env("OMNI_AGENT_TOOL_GATE_AUTO_EXECUTE_MIN")? {
+        policy.auto_execute_min = threshold;
+    }
+    policy.block_below = parse_tool_trust_class_env("OMNI_AGENT_TOOL_GATE_BLOCK_BELOW")?;
+    if let Some(default_class) =
+        parse_tool_trust_class_env("OMNI_AGENT_TOOL_GATE_DEFAULT_TRUST_CLASS")?
+    {
+        policy.default_trust_class = default_class;
+    }
+    if let Some(raw) = non_empty_env("OMNI_AGENT_TOOL_GATE_TOOL_TRUST_CLASSES") {
+        policy.tool_trust_classes = parse_tool_trust_classes(&raw)?;
+    }
+    Ok(policy)
+}
+
+/// Parse `tool=class` pairs separated by commas.
+fn parse_tool_trust_classes(raw: &str) -> Result<HashMap<String, OmegaToolTrustClass>> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (tool, class) = entry.split_once('=').ok_or_else(|| {
+                anyhow!("invalid OMNI_AGENT_TOOL_GATE_TOOL_TRUST_CLASSES entry: '{entry}' (expected tool=class)")
+            })?;
+            let class = class.trim().parse::<OmegaToolTrustClass>().map_err(|_| {
+                anyhow!("invalid trust class for tool '{}': '{}' (expected one of: other, verification, evidence)", tool.trim(), class.trim())
+            })?;
+            Ok((tool.trim().to_string(), class))
+        })
+        .collect()
+}
+
 fn normalize_unit_f32(value: f32, source: &str) -> Option<f32> {
     if (0.0..=1.0).contains(&value) {
         return Some(value);
@@ -166,6 +211,7 @@ pub(crate) async fn build_agent(
                 .filter(|v| *v > 0))
             .unwrap_or(512);
     let context_budget_strategy = resolve_context_budget_strategy(runtime_settings)?;
+    let tool_gate = resolve_tool_gate_policy()?;
     let summary_max_segments = parse_positive_usize_from_env("OMNI_AGENT_SUMMARY_MAX_SEGMENTS")
         .or(runtime_settings
             .session
@@ -545,6 +591,7 @@ pub(crate) async fn build_agent(
         context_budget_strategy,
         summary_max_segments,
         summary_max_chars,
+        tool_gate,
     };
     Agent::from_config(config).await
 }
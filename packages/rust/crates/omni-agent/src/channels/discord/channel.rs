@@ -1,5 +1,6 @@
 //! Discord channel skeleton with shared control-command authorization policy.
 
+use std::collections::HashMap;
 use std::sync::{PoisonError, RwLock};
 
 use async_trait::async_trait;
@@ -19,6 +20,7 @@ use crate::channels::traits::{Channel, ChannelMessage};
 
 use super::client::build_discord_http_client;
 use super::constants::DISCORD_DEFAULT_API_BASE;
+use super::reactions::DiscordReactionAcknowledgement;
 use super::session_partition::DiscordSessionPartition;
 
 type DiscordCommandAdminRule = CommandSelectorAuthRule;
@@ -119,6 +121,8 @@ pub struct DiscordChannel {
     control_command_policy: ControlCommandPolicy<DiscordCommandAdminRule>,
     slash_command_policy: ControlCommandPolicy<DiscordSlashCommandRule>,
     session_partition: RwLock<DiscordSessionPartition>,
+    recipient_session_partitions: RwLock<HashMap<String, DiscordSessionPartition>>,
+    reaction_acknowledgement: RwLock<Option<DiscordReactionAcknowledgement>>,
     pub(super) client: reqwest::Client,
 }
 
@@ -295,6 +299,8 @@ impl DiscordChannel {
             control_command_policy,
             slash_command_policy,
             session_partition: RwLock::new(session_partition),
+            recipient_session_partitions: RwLock::new(HashMap::new()),
+            reaction_acknowledgement: RwLock::new(None),
             client,
         }
     }
@@ -315,6 +321,54 @@ impl DiscordChannel {
             .unwrap_or_else(PoisonError::into_inner) = mode;
     }
 
+    /// Override the session partition for one recipient channel; `None` clears it.
+    pub fn set_recipient_partition(
+        &self,
+        recipient: &str,
+        partition: Option<DiscordSessionPartition>,
+    ) {
+        let key = recipient.trim().to_string();
+        let mut overrides = self
+            .recipient_session_partitions
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        match partition {
+            Some(partition) => {
+                overrides.insert(key, partition);
+            }
+            None => {
+                overrides.remove(&key);
+            }
+        }
+    }
+
+    /// Session partition used for messages in `channel_id`.
+    pub fn recipient_partition(&self, channel_id: &str) -> DiscordSessionPartition {
+        self.recipient_session_partitions
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(channel_id)
+            .copied()
+            .unwrap_or_else(|| self.session_partition())
+    }
+
+    /// Reaction acknowledgement settings; `None` disables reactions.
+    #[must_use]
+    pub fn reaction_acknowledgement(&self) -> Option<DiscordReactionAcknowledgement> {
+        self.reaction_acknowledgement
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Enable (`Some`) or disable (`None`) reaction acknowledgement at runtime.
+    pub fn set_reaction_acknowledgement(&self, config: Option<DiscordReactionAcknowledgement>) {
+        *self
+            .reaction_acknowledgement
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = config;
+    }
+
     pub(super) fn normalize_identity(&self, identity: &str) -> String {
         normalize_discord_identity(identity)
     }
@@ -511,6 +565,21 @@ impl Channel for DiscordChannel {
         Ok(())
     }
 
+    fn set_recipient_session_partition_mode(
+        &self,
+        recipient: &str,
+        mode: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let parsed = mode
+            .map(|mode| {
+                mode.parse::<DiscordSessionPartition>()
+                    .map_err(|_| anyhow::anyhow!("invalid discord session partition mode: {mode}"))
+            })
+            .transpose()?;
+        self.set_recipient_partition(recipient, parsed);
+        Ok(())
+    }
+
     fn is_admin_user(&self, identity: &str) -> bool {
         let normalized = normalize_discord_identity(identity);
         self.control_command_policy
@@ -539,6 +608,18 @@ impl Channel for DiscordChannel {
         self.start_typing_indicator(recipient).await
     }
 
+    async fn acknowledge_message_received(&self, message: &ChannelMessage) -> anyhow::Result<()> {
+        self.acknowledge_received(message).await
+    }
+
+    async fn acknowledge_message_completed(
+        &self,
+        message: &ChannelMessage,
+        succeeded: bool,
+    ) -> anyhow::Result<()> {
+        self.acknowledge_completed(message, succeeded).await
+    }
+
     async fn health_check(&self) -> bool {
         false
     }
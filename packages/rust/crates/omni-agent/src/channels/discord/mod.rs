@@ -4,12 +4,14 @@ mod channel;
 mod client;
 mod constants;
 mod parsing;
+mod reactions;
 mod runtime;
 mod send;
 mod session_partition;
 
 pub use channel::{DiscordChannel, DiscordControlCommandPolicy, DiscordSlashCommandPolicy};
 pub use constants::DISCORD_MAX_MESSAGE_LENGTH;
+pub use reactions::DiscordReactionAcknowledgement;
 pub use runtime::{
     DiscordIngressApp, DiscordRuntimeConfig, build_discord_ingress_app,
     build_discord_ingress_app_with_control_command_policy,
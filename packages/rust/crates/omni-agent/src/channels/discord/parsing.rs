@@ -27,7 +27,7 @@ impl DiscordChannel {
     }
 
     fn build_session_key(&self, scope: &str, channel_id: &str, user_identity: &str) -> String {
-        self.session_partition()
+        self.recipient_partition(channel_id)
             .build_session_key(scope, channel_id, user_identity)
     }
 
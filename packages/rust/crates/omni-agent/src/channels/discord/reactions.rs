@@ -0,0 +1,177 @@
+//! Reaction-based acknowledgement for inbound Discord messages.
+
+use std::fmt::Write as _;
+
+use anyhow::{Context, Result};
+
+use super::channel::DiscordChannel;
+use crate::channels::traits::ChannelMessage;
+
+/// Emojis used to acknowledge the triggering message of a foreground turn.
+///
+/// The `working` reaction is added on receipt and replaced by `done` or `error`
+/// once the turn completes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscordReactionAcknowledgement {
+    pub working: String,
+    pub done: String,
+    pub error: String,
+}
+
+impl Default for DiscordReactionAcknowledgement {
+    fn default() -> Self {
+        Self {
+            working: "⏳".to_string(),
+            done: "✅".to_string(),
+            error: "❌".to_string(),
+        }
+    }
+}
+
+impl DiscordReactionAcknowledgement {
+    /// Read acknowledgement settings from `OMNI_AGENT_DISCORD_REACTION_ACK*` env vars.
+    ///
+    /// Returns `None` unless `OMNI_AGENT_DISCORD_REACTION_ACK` is truthy.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("OMNI_AGENT_DISCORD_REACTION_ACK")
+            .ok()
+            .is_some_and(|raw| {
+                matches!(
+                    raw.trim().to_ascii_lowercase().as_str(),
+                    "1" | "true" | "yes" | "on"
+                )
+            });
+        if !enabled {
+            return None;
+        }
+        let defaults = Self::default();
+        let read = |key: &str, fallback: String| {
+            std::env::var(key)
+                .ok()
+                .map(|raw| raw.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .unwrap_or(fallback)
+        };
+        Some(Self {
+            working: read(
+                "OMNI_AGENT_DISCORD_REACTION_WORKING_EMOJI",
+                defaults.working,
+            ),
+            done: read("OMNI_AGENT_DISCORD_REACTION_DONE_EMOJI", defaults.done),
+            error: read("OMNI_AGENT_DISCORD_REACTION_ERROR_EMOJI", defaults.error),
+        })
+    }
+}
+
+impl DiscordChannel {
+    pub(super) async fn acknowledge_received(&self, message: &ChannelMessage) -> Result<()> {
+        let Some(config) = self.reaction_acknowledgement() else {
+            return Ok(());
+        };
+        let (channel_id, message_id) = reaction_target(message)?;
+        self.put_reaction(channel_id, message_id, &config.working)
+            .await
+    }
+
+    pub(super) async fn acknowledge_completed(
+        &self,
+        message: &ChannelMessage,
+        succeeded: bool,
+    ) -> Result<()> {
+        let Some(config) = self.reaction_acknowledgement() else {
+            return Ok(());
+        };
+        let (channel_id, message_id) = reaction_target(message)?;
+        self.delete_own_reaction(channel_id, message_id, &config.working)
+            .await?;
+        let emoji = if succeeded {
+            &config.done
+        } else {
+            &config.error
+        };
+        self.put_reaction(channel_id, message_id, emoji).await
+    }
+
+    async fn put_reaction(&self, channel_id: &str, message_id: &str, emoji: &str) -> Result<()> {
+        let url = self.reaction_url(channel_id, message_id, emoji);
+        let response = self
+            .client
+            .put(url)
+            .header("Authorization", format!("Bot {}", self.bot_token))
+            .header("Content-Length", "0")
+            .send()
+            .await
+            .context("discord add reaction request failed")?;
+        if response.status().is_success() {
+            return Ok(());
+        }
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        let preview = body.chars().take(256).collect::<String>();
+        anyhow::bail!("discord add reaction failed: status={status} body={preview}");
+    }
+
+    async fn delete_own_reaction(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        emoji: &str,
+    ) -> Result<()> {
+        let url = self.reaction_url(channel_id, message_id, emoji);
+        let response = self
+            .client
+            .delete(url)
+            .header("Authorization", format!("Bot {}", self.bot_token))
+            .send()
+            .await
+            .context("discord remove reaction request failed")?;
+        if response.status().is_success() {
+            return Ok(());
+        }
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        let preview = body.chars().take(256).collect::<String>();
+        anyhow::bail!("discord remove reaction failed: status={status} body={preview}");
+    }
+
+    fn reaction_url(&self, channel_id: &str, message_id: &str, emoji: &str) -> String {
+        self.api_url(&format!(
+            "channels/{channel_id}/messages/{message_id}/reactions/{}/@me",
+            encode_reaction_emoji(emoji)
+        ))
+    }
+}
+
+/// Resolve `(channel_id, message_id)` from a `discord_{channel_id}_{message_id}` message id.
+fn reaction_target(message: &ChannelMessage) -> Result<(&str, &str)> {
+    let channel_id = message.recipient.trim();
+    let message_id = message
+        .id
+        .strip_prefix("discord_")
+        .and_then(|rest| rest.strip_prefix(channel_id))
+        .and_then(|rest| rest.strip_prefix('_'))
+        .filter(|id| !id.is_empty() && !channel_id.is_empty())
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "discord message id `{}` does not identify a message in channel `{channel_id}`",
+                message.id
+            )
+        })?;
+    Ok((channel_id, message_id))
+}
+
+/// Percent-encode an emoji for the reactions path (`name:id` custom emojis keep their colon).
+fn encode_reaction_emoji(emoji: &str) -> String {
+    let mut encoded = String::with_capacity(emoji.len() * 3);
+    for byte in emoji.trim().bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~' | b':') {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+    encoded
+}
@@ -1,3 +1,4 @@
+use super::super::reactions::DiscordReactionAcknowledgement;
 use super::super::session_partition::DiscordSessionPartition;
 
 const DISCORD_DEFAULT_INBOUND_QUEUE_CAPACITY: usize = 512;
@@ -9,6 +10,8 @@ pub struct DiscordRuntimeConfig {
     pub session_partition: DiscordSessionPartition,
     pub inbound_queue_capacity: usize,
     pub turn_timeout_secs: u64,
+    /// Optional working/done/error reactions on the triggering message.
+    pub reaction_acknowledgement: Option<DiscordReactionAcknowledgement>,
 }
 
 impl DiscordRuntimeConfig {
@@ -33,6 +36,7 @@ impl Default for DiscordRuntimeConfig {
             session_partition: DiscordSessionPartition::from_env(),
             inbound_queue_capacity,
             turn_timeout_secs,
+            reaction_acknowledgement: DiscordReactionAcknowledgement::from_env(),
         }
     }
 }
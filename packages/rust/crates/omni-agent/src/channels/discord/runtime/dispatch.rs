@@ -44,6 +44,9 @@ pub(super) async fn process_discord_message(
         preview = log_preview(&msg.content)
     );
 
+    if let Err(error) = channel.acknowledge_message_received(&msg).await {
+        tracing::debug!("discord: failed to add working reaction: {error}");
+    }
     if let Err(error) = channel.start_typing(&msg.recipient).await {
         tracing::debug!("discord: failed to start typing: {error}");
     }
@@ -61,6 +64,7 @@ pub(super) async fn process_discord_message(
         tracing::debug!("discord: failed to stop typing: {error}");
     }
 
+    let turn_succeeded = matches!(result, ForegroundTurnOutcome::Succeeded(_));
     let reply = match result {
         ForegroundTurnOutcome::Succeeded(output) => output,
         ForegroundTurnOutcome::Failed {
@@ -94,12 +98,25 @@ pub(super) async fn process_discord_message(
         }
     };
 
-    match channel.send(&reply, &msg.recipient).await {
-        Ok(()) => tracing::info!(
-            r#"discord → Bot: "{preview}""#,
-            preview = log_preview(&reply)
-        ),
-        Err(error) => tracing::warn!("discord: failed to send reply: {error}"),
+    let sent = match channel.send(&reply, &msg.recipient).await {
+        Ok(()) => {
+            tracing::info!(
+                r#"discord → Bot: "{preview}""#,
+                preview = log_preview(&reply)
+            );
+            true
+        }
+        Err(error) => {
+            tracing::warn!("discord: failed to send reply: {error}");
+            false
+        }
+    };
+
+    if let Err(error) = channel
+        .acknowledge_message_completed(&msg, turn_succeeded && sent)
+        .await
+    {
+        tracing::debug!("discord: failed to update completion reaction: {error}");
     }
 }
 
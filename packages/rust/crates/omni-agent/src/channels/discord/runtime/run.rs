@@ -12,6 +12,7 @@ use super::ingress::{
 };
 use super::managed::push_background_completion;
 use crate::agent::Agent;
+use crate::channels::managed_runtime::session_partition_override::restore_recipient_session_partitions;
 use crate::channels::traits::{Channel, ChannelMessage};
 use crate::jobs::{JobManager, JobManagerConfig, TurnRunner};
 
@@ -44,7 +45,9 @@ pub async fn run_discord_ingress(
         tx,
     )?;
     let DiscordIngressApp { app, channel, path } = ingress;
+    channel.set_reaction_acknowledgement(runtime_config.reaction_acknowledgement);
     let channel_for_send: Arc<dyn Channel> = channel.clone();
+    restore_recipient_session_partitions(&agent, channel_for_send.as_ref()).await;
     let listener = TcpListener::bind(bind_addr).await?;
 
     let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
@@ -4,8 +4,9 @@
 //! handled outside generic LLM conversation flow.
 
 use crate::channels::managed_runtime::parsing::{
+    is_session_family_command, parse_command_input,
     parse_session_partition_command as parse_session_partition_shared,
-    parse_session_partition_mode_token,
+    parse_session_partition_mode_token, split_command_input,
 };
 
 pub(crate) const SLASH_SCOPE_SESSION_STATUS: &str = "session.status";
@@ -78,8 +79,14 @@ impl ManagedControlCommand {
 
 /// Detect managed non-privileged slash commands that are ACL-scoped by `Channel::is_authorized_for_slash_command`.
 pub(crate) fn detect_managed_slash_command(input: &str) -> Option<ManagedSlashCommand> {
-    let normalized = normalize_command_input(input);
-    let tokens: Vec<&str> = normalized.split_whitespace().collect();
+    // Background prompts are free text, so classify them before tokenizing quotes.
+    if let Some((command, prompt)) = split_command_input(input)
+        && matches!(command.as_str(), "bg" | "research")
+    {
+        return (!prompt.is_empty()).then_some(ManagedSlashCommand::BackgroundSubmit);
+    }
+    let parsed = parse_command_input(input)?;
+    let tokens: Vec<&str> = parsed.tokens().collect();
     let command = *tokens.first()?;
 
     if is_session_family_command(command) {
@@ -94,17 +101,14 @@ pub(crate) fn detect_managed_slash_command(input: &str) -> Option<ManagedSlashCo
     if command.eq_ignore_ascii_case("jobs") {
         return detect_jobs_summary_command(&tokens);
     }
-    if command.eq_ignore_ascii_case("bg") || command.eq_ignore_ascii_case("research") {
-        return detect_background_submit_command(&tokens);
-    }
     None
 }
 
 /// Detect privileged managed control commands that are ACL-scoped by
 /// `Channel::is_authorized_for_control_command`.
 pub(crate) fn detect_managed_control_command(input: &str) -> Option<ManagedControlCommand> {
-    let normalized = normalize_command_input(input);
-    let tokens: Vec<&str> = normalized.split_whitespace().collect();
+    let parsed = parse_command_input(input)?;
+    let tokens: Vec<&str> = parsed.tokens().collect();
     let command = *tokens.first()?;
 
     if (command.eq_ignore_ascii_case("reset") || command.eq_ignore_ascii_case("clear"))
@@ -130,35 +134,16 @@ pub(crate) fn detect_managed_control_command(input: &str) -> Option<ManagedContr
         };
     }
 
-    if is_session_partition_control_command(normalized) {
+    if is_session_partition_control_command(input) {
         return Some(ManagedControlCommand::SessionPartition);
     }
-    if is_session_admin_control_command(normalized) {
+    if is_session_admin_control_command(&tokens) {
         return Some(ManagedControlCommand::SessionAdmin);
     }
 
     None
 }
 
-fn normalize_command_input(input: &str) -> &str {
-    let mut normalized = input.trim();
-    if normalized.starts_with('[')
-        && let Some(end) = normalized.find(']')
-    {
-        let tag = &normalized[1..end];
-        if tag.to_ascii_lowercase().starts_with("bbx-") {
-            normalized = normalized[end + 1..].trim_start();
-        }
-    }
-    normalized.trim_start_matches('/')
-}
-
-fn is_session_family_command(command: &str) -> bool {
-    command.eq_ignore_ascii_case("session")
-        || command.eq_ignore_ascii_case("window")
-        || command.eq_ignore_ascii_case("context")
-}
-
 fn is_json_token(token: &str) -> bool {
     token.eq_ignore_ascii_case("json")
 }
@@ -191,21 +176,15 @@ fn is_session_partition_control_command(input: &str) -> bool {
     parse_session_partition_shared(input, parse_session_partition_mode_token).is_some()
 }
 
-fn is_session_admin_control_command(input: &str) -> bool {
-    let tokens: Vec<&str> = input.split_whitespace().collect();
-    match tokens.as_slice() {
+fn is_session_admin_control_command(tokens: &[&str]) -> bool {
+    match tokens {
         [scope, admin]
-            if (scope.eq_ignore_ascii_case("session")
-                || scope.eq_ignore_ascii_case("window")
-                || scope.eq_ignore_ascii_case("context"))
-                && admin.eq_ignore_ascii_case("admin") =>
+            if is_session_family_command(scope) && admin.eq_ignore_ascii_case("admin") =>
         {
             true
         }
         [scope, admin, third]
-            if (scope.eq_ignore_ascii_case("session")
-                || scope.eq_ignore_ascii_case("window")
-                || scope.eq_ignore_ascii_case("context"))
+            if is_session_family_command(scope)
                 && admin.eq_ignore_ascii_case("admin")
                 && (third.eq_ignore_ascii_case("json")
                     || third.eq_ignore_ascii_case("list")
@@ -214,9 +193,7 @@ fn is_session_admin_control_command(input: &str) -> bool {
             true
         }
         [scope, admin, action, ..]
-            if (scope.eq_ignore_ascii_case("session")
-                || scope.eq_ignore_ascii_case("window")
-                || scope.eq_ignore_ascii_case("context"))
+            if is_session_family_command(scope)
                 && admin.eq_ignore_ascii_case("admin")
                 && (action.eq_ignore_ascii_case("set")
                     || action.eq_ignore_ascii_case("add")
@@ -295,11 +272,3 @@ fn detect_jobs_summary_command(tokens: &[&str]) -> Option<ManagedSlashCommand> {
         _ => None,
     }
 }
-
-fn detect_background_submit_command(tokens: &[&str]) -> Option<ManagedSlashCommand> {
-    if tokens.len() >= 2 {
-        Some(ManagedSlashCommand::BackgroundSubmit)
-    } else {
-        None
-    }
-}
@@ -1,6 +1,7 @@
 pub(crate) mod parsing;
 pub(crate) mod replies;
 pub(crate) mod session_partition;
+pub(crate) mod session_partition_override;
 #[cfg(test)]
 mod tests;
 pub(crate) mod turn;
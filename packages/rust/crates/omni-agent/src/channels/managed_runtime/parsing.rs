@@ -1,3 +1,5 @@
+use super::super::slash_command::{ParsedSlashCommand, parse_slash_command, split_slash_command};
+
 macro_rules! eq_any_ignore_ascii {
     ($value:expr; $($candidate:literal),+ $(,)?) => {{
         let value = $value;
@@ -133,7 +135,7 @@ struct SessionContextCommand {
     format: OutputFormat,
 }
 
-pub(crate) fn normalize_command_input(input: &str) -> &str {
+fn normalize_command_input(input: &str) -> &str {
     let mut normalized = input.trim();
     if normalized.starts_with('[')
         && let Some(end) = normalized.find(']')
@@ -146,20 +148,24 @@ pub(crate) fn normalize_command_input(input: &str) -> &str {
     normalized.trim_start_matches('/')
 }
 
-pub(crate) fn slice_original_command_suffix<'a>(
-    normalized: &'a str,
-    lowered_suffix: &str,
-) -> Option<&'a str> {
-    let start = normalized.len().checked_sub(lowered_suffix.len())?;
-    normalized
-        .get(start..)
-        .map(str::trim)
-        .filter(|s| !s.is_empty())
+/// Tokenize a managed command through the shared slash-command parser.
+///
+/// The leading `/` is optional here so bare `session json` keeps working.
+pub(crate) fn parse_command_input(input: &str) -> Option<ParsedSlashCommand> {
+    parse_slash_command(&format!("/{}", normalize_command_input(input))).ok()
+}
+
+/// Split a managed command into its name and untokenized free-text remainder.
+pub(crate) fn split_command_input(input: &str) -> Option<(String, String)> {
+    let command = format!("/{}", normalize_command_input(input));
+    split_slash_command(&command)
+        .ok()
+        .map(|(name, rest)| (name, rest.to_string()))
 }
 
 pub(crate) fn parse_help_command(input: &str) -> Option<OutputFormat> {
-    let normalized = normalize_command_input(input);
-    let mut parts = normalized.split_whitespace();
+    let parsed = parse_command_input(input)?;
+    let mut parts = parsed.tokens();
     let command = parts.next()?;
     let arg1 = parts.next();
     let arg2 = parts.next();
@@ -187,21 +193,20 @@ pub(crate) fn parse_help_command(input: &str) -> Option<OutputFormat> {
 }
 
 pub(crate) fn parse_background_prompt(input: &str) -> Option<String> {
-    let normalized = normalize_command_input(input);
-    let lower = normalized.to_ascii_lowercase();
-    if let Some(rest) = lower.strip_prefix("bg ") {
-        return slice_original_command_suffix(normalized, rest).map(ToString::to_string);
+    let (command, rest) = split_command_input(input)?;
+    if rest.is_empty() {
+        return None;
     }
-    if let Some(rest) = lower.strip_prefix("research ") {
-        let original = slice_original_command_suffix(normalized, rest)?;
-        return Some(format!("research {}", original.trim()));
+    match command.as_str() {
+        "bg" => Some(rest),
+        "research" => Some(format!("research {rest}")),
+        _ => None,
     }
-    None
 }
 
 pub(crate) fn parse_job_status_command(input: &str) -> Option<JobStatusCommand> {
-    let normalized = normalize_command_input(input);
-    let mut parts = normalized.split_whitespace();
+    let parsed = parse_command_input(input)?;
+    let mut parts = parsed.tokens();
     let cmd = parts.next()?;
     if !eq_any_ignore_ascii!(cmd; "job") {
         return None;
@@ -226,8 +231,8 @@ pub(crate) fn parse_job_status_command(input: &str) -> Option<JobStatusCommand>
 }
 
 pub(crate) fn parse_jobs_summary_command(input: &str) -> Option<OutputFormat> {
-    let normalized = normalize_command_input(input);
-    let mut parts = normalized.split_whitespace();
+    let parsed = parse_command_input(input)?;
+    let mut parts = parsed.tokens();
     let cmd = parts.next()?;
     if !eq_any_ignore_ascii!(cmd; "jobs") {
         return None;
@@ -269,8 +274,8 @@ pub(crate) fn parse_session_context_memory_command(input: &str) -> Option<Output
 }
 
 pub(crate) fn parse_session_feedback_command(input: &str) -> Option<SessionFeedbackCommand> {
-    let normalized = normalize_command_input(input);
-    let mut parts = normalized.split_whitespace();
+    let parsed = parse_command_input(input)?;
+    let mut parts = parsed.tokens();
     let command = parts.next()?;
 
     let (direction_raw, format_raw) = if eq_any_ignore_ascii!(command; "feedback") {
@@ -305,8 +310,8 @@ pub(crate) fn parse_session_partition_command<Mode, F>(
 where
     F: Fn(&str) -> Option<Mode>,
 {
-    let normalized = normalize_command_input(input);
-    let mut parts = normalized.split_whitespace();
+    let parsed = parse_command_input(input)?;
+    let mut parts = parsed.tokens();
     let command = parts.next()?;
     if !is_session_family_command(command) {
         return None;
@@ -349,13 +354,14 @@ where
 }
 
 pub(crate) fn is_reset_context_command(input: &str) -> bool {
-    let normalized = normalize_command_input(input);
-    eq_any_ignore_ascii!(normalized; "reset", "clear")
+    parse_command_input(input).is_some_and(|command| {
+        command.args.is_empty() && eq_any_ignore_ascii!(command.name.as_str(); "reset", "clear")
+    })
 }
 
 pub(crate) fn parse_resume_context_command(input: &str) -> Option<ResumeCommand> {
-    let normalized = normalize_command_input(input);
-    let mut parts = normalized.split_whitespace();
+    let parsed = parse_command_input(input)?;
+    let mut parts = parsed.tokens();
     let cmd = parts.next()?;
     if !eq_any_ignore_ascii!(cmd; "resume") {
         return None;
@@ -373,8 +379,8 @@ pub(crate) fn parse_resume_context_command(input: &str) -> Option<ResumeCommand>
 }
 
 fn parse_session_context_command(input: &str) -> Option<SessionContextCommand> {
-    let normalized = normalize_command_input(input);
-    let mut parts = normalized.split_whitespace();
+    let parsed = parse_command_input(input)?;
+    let mut parts = parsed.tokens();
     let command = parts.next()?;
     if !is_session_family_command(command) {
         return None;
@@ -434,7 +440,7 @@ fn parse_session_context_command(input: &str) -> Option<SessionContextCommand> {
     }
 }
 
-fn is_session_family_command(command: &str) -> bool {
+pub(crate) fn is_session_family_command(command: &str) -> bool {
     eq_any_ignore_ascii!(command; "session", "window", "context")
 }
 
@@ -0,0 +1,51 @@
+use crate::agent::Agent;
+use crate::channels::traits::Channel;
+
+/// Override `recipient`'s session partition on `channel` and persist it in the agent
+/// session store so the override survives restarts; `None` clears it.
+///
+/// # Errors
+/// Returns an error when the channel rejects `mode` or the session store write fails.
+pub async fn set_recipient_session_partition(
+    agent: &Agent,
+    channel: &dyn Channel,
+    recipient: &str,
+    mode: Option<&str>,
+) -> anyhow::Result<()> {
+    channel.set_recipient_session_partition_mode(recipient, mode)?;
+    agent
+        .upsert_recipient_session_partition(channel.name(), recipient, mode)
+        .await
+}
+
+/// Re-apply persisted recipient session partition overrides to `channel`.
+///
+/// Returns how many overrides were applied; unreadable state or invalid modes are logged
+/// and skipped so startup is never blocked.
+pub async fn restore_recipient_session_partitions(agent: &Agent, channel: &dyn Channel) -> usize {
+    let overrides = match agent.recipient_session_partitions(channel.name()).await {
+        Ok(overrides) => overrides,
+        Err(error) => {
+            tracing::warn!(
+                channel = channel.name(),
+                error = %error,
+                "failed to load persisted recipient session partitions"
+            );
+            return 0;
+        }
+    };
+    let mut applied = 0;
+    for (recipient, mode) in &overrides {
+        match channel.set_recipient_session_partition_mode(recipient, Some(mode)) {
+            Ok(()) => applied += 1,
+            Err(error) => tracing::warn!(
+                channel = channel.name(),
+                recipient = %recipient,
+                mode = %mode,
+                error = %error,
+                "skipping persisted recipient session partition"
+            ),
+        }
+    }
+    applied
+}
@@ -5,16 +5,25 @@ mod control_command_rule_specs;
 mod discord;
 pub(crate) mod managed_commands;
 mod managed_runtime;
+/// Typed slash-command tokenizer shared by Discord and Telegram.
+///
+/// Splits `/name arg1 "quoted arg" 'another'` into a command name plus arguments,
+/// so handlers can validate arguments without re-implementing ad hoc splitting.
+pub(crate) mod slash_command;
 pub(crate) mod telegram;
 mod traits;
 
 pub use discord::{
     DISCORD_MAX_MESSAGE_LENGTH, DiscordChannel, DiscordControlCommandPolicy, DiscordIngressApp,
-    DiscordRuntimeConfig, DiscordSessionPartition, DiscordSlashCommandPolicy,
-    build_discord_ingress_app, build_discord_ingress_app_with_control_command_policy,
+    DiscordReactionAcknowledgement, DiscordRuntimeConfig, DiscordSessionPartition,
+    DiscordSlashCommandPolicy, build_discord_ingress_app,
+    build_discord_ingress_app_with_control_command_policy,
     build_discord_ingress_app_with_partition_and_control_command_policy, run_discord_ingress,
     split_message_for_discord,
 };
+pub use managed_runtime::session_partition_override::{
+    restore_recipient_session_partitions, set_recipient_session_partition,
+};
 pub use telegram::{
     DEFAULT_REDIS_KEY_PREFIX, SessionGate, TELEGRAM_MAX_MESSAGE_LENGTH, TelegramChannel,
     TelegramControlCommandPolicy, TelegramRuntimeConfig, TelegramSessionPartition,
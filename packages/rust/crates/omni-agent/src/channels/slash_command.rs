@@ -0,0 +1,167 @@
+use std::fmt;
+
+/// A slash command split into its normalized name and raw arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedSlashCommand {
+    /// Lowercased command name without the leading `/` or any `@bot` suffix.
+    pub name: String,
+    /// Arguments in order, with surrounding quotes removed.
+    pub args: Vec<String>,
+}
+
+impl ParsedSlashCommand {
+    /// Argument at `index`, if present.
+    pub fn arg(&self, index: usize) -> Option<&str> {
+        self.args.get(index).map(String::as_str)
+    }
+
+    /// Command name followed by every argument, in order.
+    pub fn tokens(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(self.args.iter().map(String::as_str))
+    }
+}
+
+/// Structured error for malformed slash-command input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlashCommandParseError {
+    /// Input was empty or whitespace only.
+    Empty,
+    /// Input does not start with `/`.
+    MissingSlash,
+    /// Command name is empty or contains characters outside `[A-Za-z0-9_-]`.
+    InvalidName(String),
+    /// A quote opened at byte `position` was never closed.
+    UnterminatedQuote { quote: char, position: usize },
+    /// Input ended with a dangling `\` escape.
+    TrailingEscape,
+}
+
+impl fmt::Display for SlashCommandParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "slash command is empty"),
+            Self::MissingSlash => write!(f, "slash command must start with `/`"),
+            Self::InvalidName(name) => write!(f, "invalid slash command name: `{name}`"),
+            Self::UnterminatedQuote { quote, position } => {
+                write!(f, "unterminated {quote} quote starting at byte {position}")
+            }
+            Self::TrailingEscape => write!(f, "slash command ends with a dangling `\\`"),
+        }
+    }
+}
+
+impl std::error::Error for SlashCommandParseError {}
+
+/// Parse `text` into a [`ParsedSlashCommand`].
+///
+/// Arguments are split on whitespace; single or double quotes group words and
+/// `\` escapes the next character outside single quotes.
+///
+/// # Errors
+///
+/// Returns [`SlashCommandParseError`] for empty input, a missing `/`, an invalid
+/// command name, an unterminated quote, or a dangling escape.
+pub fn parse_slash_command(text: &str) -> Result<ParsedSlashCommand, SlashCommandParseError> {
+    let (name, rest, offset) = split_head(text)?;
+    Ok(ParsedSlashCommand {
+        name,
+        args: tokenize(rest, offset)?,
+    })
+}
+
+/// Split `text` into its normalized command name and the untokenized remainder.
+///
+/// For commands whose argument is free text (background prompts, injected XML),
+/// where quotes and backslashes belong to the payload.
+///
+/// # Errors
+///
+/// Returns [`SlashCommandParseError`] for empty input, a missing `/`, or an
+/// invalid command name.
+pub fn split_slash_command(text: &str) -> Result<(String, &str), SlashCommandParseError> {
+    split_head(text).map(|(name, rest, _)| (name, rest))
+}
+
+fn split_head(text: &str) -> Result<(String, &str, usize), SlashCommandParseError> {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return Err(SlashCommandParseError::Empty);
+    }
+    let Some(body) = trimmed.strip_prefix('/') else {
+        return Err(SlashCommandParseError::MissingSlash);
+    };
+    let name_end = body.find(char::is_whitespace).unwrap_or(body.len());
+    let raw_name = &body[..name_end];
+    let name = raw_name
+        .split_once('@')
+        .map_or(raw_name, |(name, _bot)| name)
+        .to_ascii_lowercase();
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-')
+    {
+        return Err(SlashCommandParseError::InvalidName(raw_name.to_string()));
+    }
+
+    let after_name = &body[name_end..];
+    let rest = after_name.trim_start();
+    let leading = text.len() - text.trim_start().len();
+    let offset = leading + 1 + name_end + (after_name.len() - rest.len());
+    Ok((name, rest, offset))
+}
+
+fn tokenize(input: &str, offset: usize) -> Result<Vec<String>, SlashCommandParseError> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut chars = input.char_indices();
+
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '"' | '\'' => {
+                in_token = true;
+                let quote = ch;
+                let mut closed = false;
+                while let Some((_, inner)) = chars.next() {
+                    if inner == quote {
+                        closed = true;
+                        break;
+                    }
+                    if inner == '\\' && quote == '"' {
+                        let (_, escaped) =
+                            chars.next().ok_or(SlashCommandParseError::TrailingEscape)?;
+                        current.push(escaped);
+                    } else {
+                        current.push(inner);
+                    }
+                }
+                if !closed {
+                    return Err(SlashCommandParseError::UnterminatedQuote {
+                        quote,
+                        position: offset + index,
+                    });
+                }
+            }
+            '\\' => {
+                in_token = true;
+                let (_, escaped) = chars.next().ok_or(SlashCommandParseError::TrailingEscape)?;
+                current.push(escaped);
+            }
+            ch if ch.is_whitespace() => {
+                if in_token {
+                    tokens.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            ch => {
+                in_token = true;
+                current.push(ch);
+            }
+        }
+    }
+    if in_token {
+        tokens.push(current);
+    }
+    Ok(tokens)
+}
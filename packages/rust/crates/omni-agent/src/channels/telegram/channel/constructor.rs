@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::{PoisonError, RwLock};
 
 use crate::channels::control_command_authorization::ControlCommandPolicy;
@@ -277,6 +278,7 @@ impl TelegramChannel {
             group_policy_config: RwLock::new(TelegramGroupPolicyConfig::default()),
             session_admin_persist: RwLock::new(false),
             session_partition: RwLock::new(session_partition),
+            recipient_session_partitions: RwLock::new(HashMap::new()),
             acl_reload_state: RwLock::new(TelegramAclReloadState::new(
                 system_settings_path,
                 user_settings_path,
@@ -320,4 +322,44 @@ impl TelegramChannel {
             .write()
             .unwrap_or_else(PoisonError::into_inner) = mode;
     }
+
+    /// Override the session partition for one recipient (`chat_id` or `chat_id:thread_id`).
+    ///
+    /// A topic-level override takes precedence over a chat-level one; `None` clears it.
+    pub fn set_recipient_partition(
+        &self,
+        recipient: &str,
+        partition: Option<TelegramSessionPartition>,
+    ) {
+        let key = recipient.trim().to_string();
+        let mut overrides = self
+            .recipient_session_partitions
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        match partition {
+            Some(partition) => {
+                overrides.insert(key, partition);
+            }
+            None => {
+                overrides.remove(&key);
+            }
+        }
+    }
+
+    /// Session partition used for messages addressed to `chat_id` (and optional topic).
+    pub fn recipient_partition(
+        &self,
+        chat_id: &str,
+        message_thread_id: Option<i64>,
+    ) -> TelegramSessionPartition {
+        let overrides = self
+            .recipient_session_partitions
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        message_thread_id
+            .and_then(|thread_id| overrides.get(&format!("{chat_id}:{thread_id}")))
+            .or_else(|| overrides.get(chat_id))
+            .copied()
+            .unwrap_or_else(|| self.session_partition())
+    }
 }
@@ -1,5 +1,7 @@
 use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
 
+use super::markdown_table::MarkdownTable;
+
 pub fn markdown_to_telegram_markdown_v2(markdown: &str) -> String {
     let mut options = Options::empty();
     options.insert(Options::ENABLE_STRIKETHROUGH);
@@ -12,10 +14,23 @@ pub fn markdown_to_telegram_markdown_v2(markdown: &str) -> String {
     let mut list_is_ordered_stack: Vec<bool> = Vec::new();
     let mut link_stack: Vec<String> = Vec::new();
     let mut in_code_block = false;
+    let mut table: Option<MarkdownTable> = None;
 
     for event in parser {
+        if let Some(active) = table.as_mut() {
+            if matches!(event, Event::End(TagEnd::Table)) {
+                rendered.push_str("```\n");
+                rendered.push_str(&escape_markdown_v2_code(&active.render()));
+                rendered.push_str("\n```\n\n");
+                table = None;
+            } else {
+                active.consume(&event);
+            }
+            continue;
+        }
         match event {
             Event::Start(tag) => match tag {
+                Tag::Table(alignments) => table = Some(MarkdownTable::new(alignments)),
                 Tag::Strong => rendered.push('*'),
                 Tag::Emphasis => rendered.push('_'),
                 Tag::Strikethrough => rendered.push('~'),
@@ -138,10 +153,23 @@ pub fn markdown_to_telegram_html(markdown: &str) -> String {
     let mut rendered = String::new();
     let mut ordered_list_stack: Vec<usize> = Vec::new();
     let mut list_is_ordered_stack: Vec<bool> = Vec::new();
+    let mut table: Option<MarkdownTable> = None;
 
     for event in parser {
+        if let Some(active) = table.as_mut() {
+            if matches!(event, Event::End(TagEnd::Table)) {
+                rendered.push_str("<pre>");
+                rendered.push_str(&escape_html_text(&active.render()));
+                rendered.push_str("</pre>\n\n");
+                table = None;
+            } else {
+                active.consume(&event);
+            }
+            continue;
+        }
         match event {
             Event::Start(tag) => match tag {
+                Tag::Table(alignments) => table = Some(MarkdownTable::new(alignments)),
                 Tag::Strong => rendered.push_str("<b>"),
                 Tag::Emphasis => rendered.push_str("<i>"),
                 Tag::Strikethrough => rendered.push_str("<s>"),
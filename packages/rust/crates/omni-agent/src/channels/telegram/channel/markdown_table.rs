@@ -0,0 +1,120 @@
+use pulldown_cmark::{Alignment, Event, Tag, TagEnd};
+
+/// Buffered GitHub-style table, rendered as space-aligned monospace text.
+///
+/// Telegram has no native table markup, so cells are collected as plain text while the
+/// parser walks the table and laid out once the table ends.
+pub(super) struct MarkdownTable {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<String>>,
+    header_rows: usize,
+    in_head: bool,
+}
+
+impl MarkdownTable {
+    pub(super) fn new(alignments: Vec<Alignment>) -> Self {
+        Self {
+            alignments,
+            rows: Vec::new(),
+            header_rows: 0,
+            in_head: false,
+        }
+    }
+
+    /// Feed one parser event that occurs inside the table; inline formatting is dropped.
+    pub(super) fn consume(&mut self, event: &Event<'_>) {
+        match event {
+            Event::Start(Tag::TableHead) => self.start_head(),
+            Event::End(TagEnd::TableHead) => self.end_head(),
+            Event::Start(Tag::TableRow) => self.start_row(),
+            Event::Start(Tag::TableCell) => self.start_cell(),
+            Event::Text(text) | Event::Code(text) => self.push_text(text.as_ref()),
+            Event::SoftBreak | Event::HardBreak => self.push_text(" "),
+            _ => {}
+        }
+    }
+
+    fn start_head(&mut self) {
+        self.in_head = true;
+        self.rows.push(Vec::new());
+    }
+
+    fn end_head(&mut self) {
+        self.in_head = false;
+        self.header_rows = self.rows.len();
+    }
+
+    fn start_row(&mut self) {
+        if !self.in_head {
+            self.rows.push(Vec::new());
+        }
+    }
+
+    fn start_cell(&mut self) {
+        if self.rows.is_empty() {
+            self.rows.push(Vec::new());
+        }
+        if let Some(row) = self.rows.last_mut() {
+            row.push(String::new());
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if let Some(cell) = self.rows.last_mut().and_then(|row| row.last_mut()) {
+            cell.push_str(text);
+        }
+    }
+
+    /// Render the table as unescaped, newline-separated lines without a trailing newline.
+    pub(super) fn render(&self) -> String {
+        let columns = self
+            .rows
+            .iter()
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0)
+            .max(self.alignments.len());
+        let mut widths = vec![0usize; columns];
+        for row in &self.rows {
+            for (index, cell) in row.iter().enumerate() {
+                widths[index] = widths[index].max(cell.trim().chars().count());
+            }
+        }
+
+        let mut lines = Vec::with_capacity(self.rows.len() + 1);
+        for (row_index, row) in self.rows.iter().enumerate() {
+            let cells = widths
+                .iter()
+                .enumerate()
+                .map(|(index, width)| {
+                    let cell = row.get(index).map_or("", |cell| cell.trim());
+                    let alignment = self
+                        .alignments
+                        .get(index)
+                        .copied()
+                        .unwrap_or(Alignment::None);
+                    pad_cell(cell, *width, alignment)
+                })
+                .collect::<Vec<_>>();
+            lines.push(cells.join(" | ").trim_end().to_string());
+            if row_index + 1 == self.header_rows {
+                let separator = widths
+                    .iter()
+                    .map(|width| "-".repeat(*width))
+                    .collect::<Vec<_>>();
+                lines.push(separator.join("-+-"));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+fn pad_cell(cell: &str, width: usize, alignment: Alignment) -> String {
+    let padding = width.saturating_sub(cell.chars().count());
+    let (left, right) = match alignment {
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+        Alignment::None | Alignment::Left => (0, padding),
+    };
+    format!("{}{cell}{}", " ".repeat(left), " ".repeat(right))
+}
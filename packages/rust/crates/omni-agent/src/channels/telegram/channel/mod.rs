@@ -13,6 +13,7 @@ mod group_policy;
 mod identity;
 mod listen;
 mod markdown;
+mod markdown_table;
 mod media;
 mod parsing;
 mod policy;
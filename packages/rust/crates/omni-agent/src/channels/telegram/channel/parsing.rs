@@ -40,7 +40,7 @@ impl TelegramChannel {
         user_identity: &str,
         message_thread_id: Option<i64>,
     ) -> String {
-        self.session_partition()
+        self.recipient_partition(chat_id, message_thread_id)
             .build_session_key(chat_id, user_identity, message_thread_id)
     }
 
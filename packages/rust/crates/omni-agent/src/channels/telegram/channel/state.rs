@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::RwLock;
 use std::time::Duration;
 
@@ -24,6 +25,7 @@ pub struct TelegramChannel {
     pub(super) group_policy_config: RwLock<TelegramGroupPolicyConfig>,
     pub(super) session_admin_persist: RwLock<bool>,
     pub(super) session_partition: RwLock<TelegramSessionPartition>,
+    pub(super) recipient_session_partitions: RwLock<HashMap<String, TelegramSessionPartition>>,
     pub(super) acl_reload_state: RwLock<TelegramAclReloadState>,
     pub(super) send_rate_limit_gate: tokio::sync::Mutex<TelegramSendRateLimitGateState>,
     pub(super) send_rate_limit_backend: TelegramSendRateLimitBackend,
@@ -23,6 +23,21 @@ impl Channel for TelegramChannel {
         Ok(())
     }
 
+    fn set_recipient_session_partition_mode(
+        &self,
+        recipient: &str,
+        mode: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let parsed = mode
+            .map(|mode| {
+                mode.parse::<TelegramSessionPartition>()
+                    .map_err(|_| anyhow::anyhow!("invalid session partition mode: {mode}"))
+            })
+            .transpose()?;
+        self.set_recipient_partition(recipient, parsed);
+        Ok(())
+    }
+
     fn is_admin_user(&self, identity: &str) -> bool {
         self.is_admin_identity(identity)
     }
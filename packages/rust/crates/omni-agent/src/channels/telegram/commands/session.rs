@@ -1,13 +1,13 @@
 use super::shared::{
     SessionPartitionModeToken, is_reset_context_command as is_reset_context_command_shared,
-    normalize_command_input, parse_resume_context_command as parse_resume_shared,
+    is_session_family_command, parse_command_input,
+    parse_resume_context_command as parse_resume_shared,
     parse_session_context_budget_command as parse_session_budget_shared,
     parse_session_context_memory_command as parse_session_memory_shared,
     parse_session_context_status_command as parse_session_status_shared,
     parse_session_feedback_command as parse_session_feedback_shared,
     parse_session_partition_command as parse_session_partition_shared,
-    parse_session_partition_mode_token as parse_partition_mode_token,
-    slice_original_command_suffix,
+    parse_session_partition_mode_token as parse_partition_mode_token, split_command_input,
 };
 
 pub type ResumeContextCommand = super::shared::ResumeCommand;
@@ -103,28 +103,16 @@ pub fn parse_session_feedback_command(input: &str) -> Option<SessionFeedbackComm
 /// - `/session inject set <xml>`
 /// - `/session inject <xml>`
 pub fn parse_session_injection_command(input: &str) -> Option<SessionInjectionCommand> {
-    let normalized = normalize_command_input(input);
-    let lowered = normalized.to_ascii_lowercase();
-    let prefixes = [
-        "session inject",
-        "window inject",
-        "context inject",
-        "session injection",
-        "window injection",
-        "context injection",
-    ];
-
-    let rest = prefixes.iter().find_map(|prefix| {
-        lowered.strip_prefix(prefix).and_then(|suffix| {
-            if suffix.trim().is_empty() {
-                Some(String::new())
-            } else {
-                slice_original_command_suffix(normalized, suffix).map(ToString::to_string)
-            }
-        })
-    })?;
-
-    let tail = rest.trim();
+    let (root, rest) = split_command_input(input)?;
+    if !is_session_family_command(&root) {
+        return None;
+    }
+    let lowered = rest.to_ascii_lowercase();
+    let sub_len = lowered.find(char::is_whitespace).unwrap_or(lowered.len());
+    if !matches!(&lowered[..sub_len], "inject" | "injection") {
+        return None;
+    }
+    let tail = rest[sub_len..].trim();
     if tail.is_empty() {
         return Some(SessionInjectionCommand {
             action: SessionInjectionAction::Status,
@@ -190,13 +178,10 @@ pub fn parse_session_injection_command(input: &str) -> Option<SessionInjectionCo
 /// - `/session admin remove <user_ids...> [json]`
 /// - `/session admin clear [json]`
 pub fn parse_session_admin_command(input: &str) -> Option<SessionAdminCommand> {
-    let normalized = normalize_command_input(input);
-    let mut parts = normalized.split_whitespace();
+    let parsed = parse_command_input(input)?;
+    let mut parts = parsed.tokens();
     let root = parts.next()?;
-    if !root.eq_ignore_ascii_case("session")
-        && !root.eq_ignore_ascii_case("window")
-        && !root.eq_ignore_ascii_case("context")
-    {
+    if !is_session_family_command(root) {
         return None;
     }
     let Some(sub) = parts.next() else {
@@ -7,6 +7,7 @@ use super::super::dispatch::start_telegram_runtime;
 use super::channel_listener;
 use super::loop_control;
 use crate::agent::Agent;
+use crate::channels::managed_runtime::session_partition_override::restore_recipient_session_partitions;
 use crate::channels::telegram::TelegramControlCommandPolicy;
 use crate::channels::telegram::runtime_config::TelegramRuntimeConfig;
 
@@ -63,6 +64,7 @@ pub async fn run_telegram_with_control_command_policy(
         Arc::clone(&channel_for_send),
         runtime_config,
     )?;
+    restore_recipient_session_partitions(&agent, channel_for_send.as_ref()).await;
 
     println!("Telegram channel listening... (polling, Ctrl+C to stop)");
     println!(
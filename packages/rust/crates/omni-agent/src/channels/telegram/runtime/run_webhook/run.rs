@@ -9,6 +9,7 @@ use super::loop_control;
 use super::secret;
 use super::server;
 use crate::agent::Agent;
+use crate::channels::managed_runtime::session_partition_override::restore_recipient_session_partitions;
 use crate::channels::telegram::TelegramControlCommandPolicy;
 use crate::channels::telegram::idempotency::WebhookDedupConfig;
 use crate::channels::telegram::runtime_config::TelegramRuntimeConfig;
@@ -90,6 +91,7 @@ pub async fn run_telegram_webhook_with_control_command_policy(
         Arc::clone(&channel_for_send),
         runtime_config,
     )?;
+    restore_recipient_session_partitions(&agent, channel_for_send.as_ref()).await;
 
     println!(
         "Telegram webhook listening on {}{} (Ctrl+C to stop)",
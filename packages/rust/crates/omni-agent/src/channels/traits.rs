@@ -49,6 +49,20 @@ pub trait Channel: Send + Sync {
         ))
     }
 
+    /// Optional per-recipient session partition override (`None` clears the override).
+    ///
+    /// # Errors
+    /// Returns an error when the channel does not support overrides or `mode` is invalid.
+    fn set_recipient_session_partition_mode(
+        &self,
+        _recipient: &str,
+        _mode: Option<&str>,
+    ) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!(
+            "recipient session partition override is not supported for this channel"
+        ))
+    }
+
     /// Whether this sender identity is allowed to run privileged control commands.
     fn is_admin_user(&self, _identity: &str) -> bool {
         false
@@ -141,4 +155,18 @@ pub trait Channel: Send + Sync {
     async fn stop_typing(&self, _recipient: &str) -> anyhow::Result<()> {
         Ok(())
     }
+
+    /// Acknowledge receipt of the message that triggered a turn (e.g. a "working" reaction).
+    async fn acknowledge_message_received(&self, _message: &ChannelMessage) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Mark the triggering message as completed, replacing any receipt acknowledgement.
+    async fn acknowledge_message_completed(
+        &self,
+        _message: &ChannelMessage,
+        _succeeded: bool,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
@@ -50,6 +50,10 @@ pub(crate) enum Command {
         #[arg(long)]
         max_concurrent: Option<usize>,
 
+        /// Max message size in bytes; larger requests get 400 (default: 65536)
+        #[arg(long)]
+        max_message_bytes: Option<usize>,
+
         /// Path to mcp.json (default: .mcp.json)
         #[arg(long, default_value = ".mcp.json")]
         mcp_config: PathBuf,
@@ -3,6 +3,8 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+use crate::contracts::OmegaToolGatePolicy;
+
 /// One MCP server entry (e.g. SSE URL or stdio command).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McpServerEntry {
@@ -323,6 +325,9 @@ pub struct AgentConfig {
     /// Maximum chars kept per compacted summary segment.
     #[serde(default = "default_summary_max_chars")]
     pub summary_max_chars: usize,
+    /// Trust-class gate applied to every MCP tool call in the agent tool loop.
+    #[serde(default)]
+    pub tool_gate: OmegaToolGatePolicy,
 }
 
 fn default_max_tool_rounds() -> u32 {
@@ -417,6 +422,7 @@ impl Default for AgentConfig {
             context_budget_strategy: ContextBudgetStrategy::default(),
             summary_max_segments: default_summary_max_segments(),
             summary_max_chars: default_summary_max_chars(),
+            tool_gate: OmegaToolGatePolicy::default(),
         }
     }
 }
@@ -452,6 +458,7 @@ impl AgentConfig {
             context_budget_strategy: ContextBudgetStrategy::default(),
             summary_max_segments: default_summary_max_segments(),
             summary_max_chars: default_summary_max_chars(),
+            tool_gate: OmegaToolGatePolicy::default(),
         }
     }
 
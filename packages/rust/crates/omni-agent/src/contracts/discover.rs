@@ -28,4 +28,34 @@ pub struct DiscoverMatch {
     pub input_schema_digest: String,
     /// Optional path to supporting documentation.
     pub documentation_path: Option<String>,
+    /// Component scores behind `score`, when the ranker reported them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<DiscoverExplanation>,
+}
+
+/// Per-component breakdown of a discover ranking score.
+///
+/// Same shape as the `explanation` column omni-vector's hybrid tool search emits:
+/// `vector_contribution + keyword_contribution + boost` equals the raw `score`, and a
+/// side's raw score is `None` exactly when that side did not contribute.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiscoverExplanation {
+    /// Raw semantic similarity before fusion, when the tool was a vector hit.
+    pub vector_score: Option<f32>,
+    /// Raw keyword (BM25) score before fusion, when the tool was a keyword hit.
+    pub keyword_score: Option<f32>,
+    /// Fused contribution from the vector ranking.
+    pub vector_contribution: f32,
+    /// Fused contribution from the keyword ranking.
+    pub keyword_contribution: f32,
+    /// Name/metadata boost applied after fusion.
+    pub boost: f32,
+}
+
+impl DiscoverExplanation {
+    /// Sum of all components; equals the raw ranking score of the explained match.
+    #[must_use]
+    pub fn total(&self) -> f32 {
+        self.vector_contribution + self.keyword_contribution + self.boost
+    }
 }
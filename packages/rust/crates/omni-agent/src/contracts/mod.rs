@@ -2,8 +2,9 @@ mod discover;
 mod memory_gate;
 mod omega;
 
-pub use discover::{DiscoverConfidence, DiscoverMatch};
+pub use discover::{DiscoverConfidence, DiscoverExplanation, DiscoverMatch};
 pub use memory_gate::{MemoryGateDecision, MemoryGateVerdict};
 pub use omega::{
-    OmegaDecision, OmegaFallbackPolicy, OmegaRiskLevel, OmegaRoute, OmegaToolTrustClass,
+    OmegaDecision, OmegaFallbackPolicy, OmegaRiskLevel, OmegaRoute, OmegaToolGateAction,
+    OmegaToolGateDecision, OmegaToolGatePolicy, OmegaToolTrustClass,
 };
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Route selected by Omega governance.
@@ -78,6 +80,101 @@ impl OmegaToolTrustClass {
             Self::Other => "other",
         }
     }
+
+    /// Trust ordering used by the tool gate: `other` < `verification` < `evidence`.
+    #[must_use]
+    pub const fn rank(self) -> u8 {
+        match self {
+            Self::Other => 0,
+            Self::Verification => 1,
+            Self::Evidence => 2,
+        }
+    }
+}
+
+impl std::str::FromStr for OmegaToolTrustClass {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "evidence" => Ok(Self::Evidence),
+            "verification" => Ok(Self::Verification),
+            "other" => Ok(Self::Other),
+            other => Err(format!("unknown omega tool trust class: {other}")),
+        }
+    }
+}
+
+/// Outcome of the Omega tool gate for one tool call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OmegaToolGateAction {
+    AutoExecute,
+    RequireConfirmation,
+    Block,
+}
+
+impl OmegaToolGateAction {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::AutoExecute => "auto_execute",
+            Self::RequireConfirmation => "require_confirmation",
+            Self::Block => "block",
+        }
+    }
+}
+
+/// Trust thresholds deciding whether a tool may run without confirmation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OmegaToolGatePolicy {
+    /// Tools at or above this trust class execute without confirmation.
+    pub auto_execute_min: OmegaToolTrustClass,
+    /// Tools below this trust class are blocked outright (`None`: never block).
+    pub block_below: Option<OmegaToolTrustClass>,
+    /// Trust class for tools without an entry in `tool_trust_classes`.
+    pub default_trust_class: OmegaToolTrustClass,
+    /// Per-tool trust classes, keyed by tool name.
+    pub tool_trust_classes: HashMap<String, OmegaToolTrustClass>,
+}
+
+impl OmegaToolGatePolicy {
+    /// Trust class of `tool_name` under this policy.
+    #[must_use]
+    pub fn trust_class_for(&self, tool_name: &str) -> OmegaToolTrustClass {
+        self.tool_trust_classes
+            .get(tool_name)
+            .copied()
+            .unwrap_or(self.default_trust_class)
+    }
+}
+
+impl Default for OmegaToolGatePolicy {
+    /// Every tool auto-executes (the pre-gate runtime behavior).
+    fn default() -> Self {
+        Self {
+            auto_execute_min: OmegaToolTrustClass::Other,
+            block_below: None,
+            default_trust_class: OmegaToolTrustClass::Other,
+            tool_trust_classes: HashMap::new(),
+        }
+    }
+}
+
+/// Tool gate verdict for one tool call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OmegaToolGateDecision {
+    /// Gated tool name.
+    pub tool_name: String,
+    /// Whether the call runs, waits for confirmation, or is blocked.
+    pub action: OmegaToolGateAction,
+    /// Trust class the policy assigned to this tool.
+    pub tool_trust_class: OmegaToolTrustClass,
+    /// Human/audit-readable rationale.
+    pub reason: String,
+    /// Policy profile identifier.
+    pub policy_id: String,
 }
 
 /// Decision envelope emitted by Omega.
@@ -1,13 +1,19 @@
 //! HTTP gateway: POST /message → agent turn → JSON response.
 //!
-//! Request validation (400 for empty session_id or message), 500 on agent error.
+//! Request validation (structured 400 for empty session_id/message or a message over the byte
+//! limit), 500 on agent error.
 //! Each request is limited by a timeout to avoid stuck connections.
+//! POST /message/stream runs the same turn but answers with Server-Sent Events (progress + final).
+//! Running turns are listed by GET /turns and aborted by POST /turns/{id}/cancel (409 on the
+//! cancelled /message request).
+//! GET /health pings each pooled MCP client and reports `degraded` when any ping fails.
 
 use anyhow::Result;
 use axum::{
     Json, Router,
-    extract::State,
+    extract::{Path, State},
     http::StatusCode,
+    response::{IntoResponse, Response},
     routing::{get, post},
 };
 use serde::{Deserialize, Serialize};
@@ -16,12 +22,18 @@ use std::time::Duration;
 use tokio::net::TcpListener;
 use tokio::sync::Semaphore;
 
-use crate::agent::Agent;
-use crate::mcp_pool::McpToolsListCacheStatsSnapshot;
+use crate::agent::{Agent, TurnProgressSink};
+use crate::mcp_pool::{McpClientHealth, McpToolsListCacheStatsSnapshot};
+
+use super::stream::handle_message_stream;
+use super::turns::{GatewayTurnCancelResponse, GatewayTurnListResponse, GatewayTurnRegistry};
 
 /// Default timeout for one agent turn (LLM + tools); avoids stuck connections.
 const TURN_TIMEOUT_SECS: u64 = 300;
 
+/// Default upper bound for `MessageRequest::message`, in bytes.
+pub const DEFAULT_MAX_MESSAGE_BYTES: usize = 64 * 1024;
+
 /// Request body for POST /message.
 #[derive(Debug, Deserialize)]
 pub struct MessageRequest {
@@ -48,6 +60,10 @@ pub struct GatewayState {
     /// When Some, limits concurrent agent turns; excess requests wait for a slot.
     pub concurrency_semaphore: Option<Arc<Semaphore>>,
     pub max_concurrent_turns: Option<usize>,
+    /// Requests whose message exceeds this many bytes are rejected with 400.
+    pub max_message_bytes: usize,
+    /// Running turns, for listing and cancellation.
+    pub turns: Arc<GatewayTurnRegistry>,
 }
 
 /// MCP section in gateway health response.
@@ -56,6 +72,9 @@ pub struct GatewayMcpHealthResponse {
     pub enabled: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tools_list_cache: Option<McpToolsListCacheStatsSnapshot>,
+    /// Per-client ping results for the MCP pool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clients: Option<Vec<McpClientHealth>>,
 }
 
 /// Response body for gateway health endpoint.
@@ -70,22 +89,78 @@ pub struct GatewayHealthResponse {
     pub mcp: GatewayMcpHealthResponse,
 }
 
-/// Validate request body; returns error for empty session_id or message.
+/// Structured body of a 400 response for an invalid `MessageRequest`.
+#[derive(Debug, Serialize)]
+pub struct GatewayValidationError {
+    /// Machine-readable reason: `empty_session_id`, `empty_message` or `message_too_large`.
+    pub error: &'static str,
+    /// Human-readable description.
+    pub message: String,
+    /// Configured byte limit (only for `message_too_large`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_message_bytes: Option<usize>,
+    /// Received message size in bytes (only for `message_too_large`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_bytes: Option<usize>,
+}
+
+impl GatewayValidationError {
+    fn rejection(error: &'static str, message: &str) -> (StatusCode, Json<Self>) {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(Self {
+                error,
+                message: message.to_string(),
+                max_message_bytes: None,
+                message_bytes: None,
+            }),
+        )
+    }
+}
+
+/// Validate request body with the default message byte limit.
+///
+/// # Errors
+/// Returns a 400 status with the human-readable description of the rejection
+/// described by [`validate_message_request_with_limit`].
 pub fn validate_message_request(
     body: &MessageRequest,
 ) -> Result<(String, String), (StatusCode, String)> {
+    validate_message_request_with_limit(body, DEFAULT_MAX_MESSAGE_BYTES)
+        .map_err(|(status, Json(error))| (status, error.message))
+}
+
+/// Validate request body and return the trimmed `(session_id, message)` pair.
+///
+/// # Errors
+/// Returns a 400 rejection with a [`GatewayValidationError`] body for an empty `session_id`,
+/// an empty message, or a message longer than `max_message_bytes`.
+pub fn validate_message_request_with_limit(
+    body: &MessageRequest,
+    max_message_bytes: usize,
+) -> Result<(String, String), (StatusCode, Json<GatewayValidationError>)> {
     let session_id = body.session_id.trim().to_string();
-    let message = body.message.trim().to_string();
     if session_id.is_empty() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "session_id must be non-empty".to_string(),
+        return Err(GatewayValidationError::rejection(
+            "empty_session_id",
+            "session_id must be non-empty",
         ));
     }
+    let message_bytes = body.message.len();
+    if message_bytes > max_message_bytes {
+        let (status, Json(mut error)) = GatewayValidationError::rejection(
+            "message_too_large",
+            &format!("message is {message_bytes} bytes; limit is {max_message_bytes}"),
+        );
+        error.max_message_bytes = Some(max_message_bytes);
+        error.message_bytes = Some(message_bytes);
+        return Err((status, Json(error)));
+    }
+    let message = body.message.trim().to_string();
     if message.is_empty() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            "message must be non-empty".to_string(),
+        return Err(GatewayValidationError::rejection(
+            "empty_message",
+            "message must be non-empty",
         ));
     }
     Ok((session_id, message))
@@ -94,8 +169,31 @@ pub fn validate_message_request(
 async fn handle_message(
     State(state): State<GatewayState>,
     Json(body): Json<MessageRequest>,
+) -> Response {
+    match validate_message_request_with_limit(&body, state.max_message_bytes) {
+        Ok((session_id, message)) => run_message_turn(state, session_id, message)
+            .await
+            .into_response(),
+        Err(rejection) => rejection.into_response(),
+    }
+}
+
+async fn run_message_turn(
+    state: GatewayState,
+    session_id: String,
+    message: String,
 ) -> Result<Json<MessageResponse>, (StatusCode, String)> {
-    let (session_id, message) = validate_message_request(&body)?;
+    let output = execute_turn(&state, &session_id, &message, None).await?;
+    Ok(Json(MessageResponse { output, session_id }))
+}
+
+/// Run one registered, cancellable, time-limited turn under the concurrency limit.
+pub(super) async fn execute_turn(
+    state: &GatewayState,
+    session_id: &str,
+    message: &str,
+    progress: Option<&TurnProgressSink>,
+) -> Result<String, (StatusCode, String)> {
     let _permit = if let Some(ref sem) = state.concurrency_semaphore {
         Some(sem.acquire().await.map_err(|_| {
             (
@@ -107,26 +205,61 @@ async fn handle_message(
         None
     };
     let timeout_secs = state.turn_timeout_secs;
-    let output = match tokio::time::timeout(
+    let (turn, cancelled) = state.turns.register(session_id);
+    let run = tokio::time::timeout(
         Duration::from_secs(timeout_secs),
-        state.agent.run_turn(&session_id, &message),
-    )
-    .await
-    {
-        Ok(Ok(out)) => out,
-        Ok(Err(e)) => return Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
-        Err(_) => {
-            return Err((
-                StatusCode::GATEWAY_TIMEOUT,
-                format!("agent turn timed out after {}s", timeout_secs),
-            ));
-        }
+        state
+            .agent
+            .run_turn_with_progress(session_id, message, progress),
+    );
+    let result = tokio::select! {
+        result = run => Some(result),
+        _ = cancelled => None,
     };
-    Ok(Json(MessageResponse { output, session_id }))
+    let turn_id = turn.turn_id().to_string();
+    drop(turn);
+    match result {
+        Some(Ok(Ok(out))) => Ok(out),
+        Some(Ok(Err(e))) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+        Some(Err(_)) => Err((
+            StatusCode::GATEWAY_TIMEOUT,
+            format!("agent turn timed out after {}s", timeout_secs),
+        )),
+        None => Err((
+            StatusCode::CONFLICT,
+            format!("agent turn {turn_id} was cancelled"),
+        )),
+    }
+}
+
+async fn handle_list_turns(State(state): State<GatewayState>) -> Json<GatewayTurnListResponse> {
+    Json(GatewayTurnListResponse {
+        turns: state.turns.list(),
+    })
+}
+
+async fn handle_cancel_turn(
+    State(state): State<GatewayState>,
+    Path(turn_id): Path<String>,
+) -> Result<Json<GatewayTurnCancelResponse>, (StatusCode, String)> {
+    if !state.turns.cancel(&turn_id) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("no in-flight turn with id {turn_id}"),
+        ));
+    }
+    Ok(Json(GatewayTurnCancelResponse {
+        turn_id,
+        status: "cancelled",
+    }))
 }
 
 async fn handle_health(State(state): State<GatewayState>) -> Json<GatewayHealthResponse> {
     let mcp_cache = state.agent.inspect_mcp_tools_list_cache_stats();
+    let mcp_clients = state.agent.probe_mcp_health().await;
+    let mcp_degraded = mcp_clients
+        .as_ref()
+        .is_some_and(|clients| clients.iter().any(|client| !client.alive));
     let in_flight_turns = state.max_concurrent_turns.and_then(|max| {
         state
             .concurrency_semaphore
@@ -134,29 +267,51 @@ async fn handle_health(State(state): State<GatewayState>) -> Json<GatewayHealthR
             .map(|sem| max.saturating_sub(sem.available_permits()))
     });
     Json(GatewayHealthResponse {
-        status: "healthy",
+        status: if mcp_degraded { "degraded" } else { "healthy" },
         turn_timeout_secs: state.turn_timeout_secs,
         max_concurrent_turns: state.max_concurrent_turns,
         in_flight_turns,
         mcp: GatewayMcpHealthResponse {
             enabled: mcp_cache.is_some(),
             tools_list_cache: mcp_cache,
+            clients: mcp_clients,
         },
     })
 }
 
-/// Build the gateway router (POST /message).
+/// Build the gateway router (POST /message, POST /message/stream, GET /turns,
+/// POST /turns/{id}/cancel).
 pub fn router(agent: Agent, turn_timeout_secs: u64, max_concurrent_turns: Option<usize>) -> Router {
+    router_with_limits(
+        agent,
+        turn_timeout_secs,
+        max_concurrent_turns,
+        DEFAULT_MAX_MESSAGE_BYTES,
+    )
+}
+
+/// Build the gateway router with an explicit message byte limit.
+pub fn router_with_limits(
+    agent: Agent,
+    turn_timeout_secs: u64,
+    max_concurrent_turns: Option<usize>,
+    max_message_bytes: usize,
+) -> Router {
     let concurrency_semaphore = max_concurrent_turns.map(|n| Arc::new(Semaphore::new(n)));
     let state = GatewayState {
         agent: Arc::new(agent),
         turn_timeout_secs,
         concurrency_semaphore,
         max_concurrent_turns,
+        max_message_bytes,
+        turns: Arc::new(GatewayTurnRegistry::default()),
     };
     Router::new()
         .route("/health", get(handle_health))
         .route("/message", post(handle_message))
+        .route("/message/stream", post(handle_message_stream))
+        .route("/turns", get(handle_list_turns))
+        .route("/turns/{id}/cancel", post(handle_cancel_turn))
         .with_state(state)
 }
 
@@ -164,23 +319,27 @@ pub fn router(agent: Agent, turn_timeout_secs: u64, max_concurrent_turns: Option
 /// Graceful shutdown on Ctrl+C (SIGINT) and SIGTERM (Unix); in-flight requests complete before exit.
 /// `turn_timeout_secs`: per-turn timeout (default 300 when None).
 /// `max_concurrent_turns`: limit concurrent agent turns (None = no limit; Some(4) default from CLI).
+/// `max_message_bytes`: per-request message size limit (default 64 KiB when None).
 pub async fn run_http(
     agent: Agent,
     bind_addr: &str,
     turn_timeout_secs: Option<u64>,
     max_concurrent_turns: Option<usize>,
+    max_message_bytes: Option<usize>,
 ) -> Result<()> {
     let timeout = turn_timeout_secs.unwrap_or(TURN_TIMEOUT_SECS);
-    let app = router(agent, timeout, max_concurrent_turns);
+    let max_message_bytes = max_message_bytes.unwrap_or(DEFAULT_MAX_MESSAGE_BYTES);
+    let app = router_with_limits(agent, timeout, max_concurrent_turns, max_message_bytes);
     let listener = TcpListener::bind(bind_addr).await?;
     let max_str = max_concurrent_turns
         .map(|n| n.to_string())
         .unwrap_or_else(|| "unlimited".to_string());
     tracing::info!(
-        "gateway listening on {} (turn_timeout={}s, max_concurrent={}, Ctrl+C/SIGTERM to stop)",
+        "gateway listening on {} (turn_timeout={}s, max_concurrent={}, max_message_bytes={}, Ctrl+C/SIGTERM to stop)",
         bind_addr,
         timeout,
-        max_str
+        max_str,
+        max_message_bytes
     );
     axum::serve(listener, app)
         .with_graceful_shutdown(shutdown_signal())
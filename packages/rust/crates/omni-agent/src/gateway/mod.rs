@@ -2,9 +2,16 @@
 
 mod http;
 mod stdio;
+mod stream;
+mod turns;
 
 pub use http::{
-    GatewayHealthResponse, GatewayMcpHealthResponse, GatewayState, MessageRequest, MessageResponse,
-    router, run_http, validate_message_request,
+    DEFAULT_MAX_MESSAGE_BYTES, GatewayHealthResponse, GatewayMcpHealthResponse, GatewayState,
+    GatewayValidationError, MessageRequest, MessageResponse, router, router_with_limits, run_http,
+    validate_message_request, validate_message_request_with_limit,
 };
 pub use stdio::{DEFAULT_STDIO_SESSION_ID, run_stdio};
+pub use turns::{
+    GatewayTurnCancelResponse, GatewayTurnGuard, GatewayTurnListResponse, GatewayTurnRegistry,
+    GatewayTurnSummary,
+};
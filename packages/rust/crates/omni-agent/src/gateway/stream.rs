@@ -0,0 +1,92 @@
+//! POST /message/stream: run a turn and relay its progress as Server-Sent Events.
+//!
+//! Events: `tool_call_started`, `tool_result`, `partial_assistant` while the turn runs, then
+//! exactly one terminal `final` (a `MessageResponse`) or `error` (`{status, message}`) event.
+
+use axum::{
+    Json,
+    extract::State,
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use serde::Serialize;
+use std::convert::Infallible;
+use tokio::sync::mpsc;
+
+use super::http::{
+    GatewayState, MessageRequest, MessageResponse, execute_turn,
+    validate_message_request_with_limit,
+};
+use crate::agent::TurnProgressEvent;
+
+/// Payload of the terminal `error` event.
+#[derive(Debug, Serialize)]
+struct StreamErrorPayload {
+    status: u16,
+    message: String,
+}
+
+/// Aborts the streamed turn when the SSE response (and so this guard) is dropped,
+/// e.g. because the client disconnected.
+struct AbortTurnOnDrop(tokio::task::AbortHandle);
+
+impl Drop for AbortTurnOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+fn sse_event<T: Serialize>(name: &str, payload: &T) -> Event {
+    Event::default()
+        .event(name)
+        .data(serde_json::to_string(payload).unwrap_or_default())
+}
+
+pub(super) async fn handle_message_stream(
+    State(state): State<GatewayState>,
+    Json(body): Json<MessageRequest>,
+) -> Response {
+    let (session_id, message) =
+        match validate_message_request_with_limit(&body, state.max_message_bytes) {
+            Ok(validated) => validated,
+            Err(rejection) => return rejection.into_response(),
+        };
+
+    let (event_tx, event_rx) = mpsc::unbounded_channel::<Event>();
+    let turn = tokio::spawn(async move {
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel::<TurnProgressEvent>();
+        let forward_tx = event_tx.clone();
+        let forwarder = tokio::spawn(async move {
+            while let Some(progress) = progress_rx.recv().await {
+                let _ = forward_tx.send(sse_event(progress.name(), &progress));
+            }
+        });
+        let result = execute_turn(&state, &session_id, &message, Some(&progress_tx)).await;
+        drop(progress_tx);
+        let _ = forwarder.await;
+        let terminal = match result {
+            Ok(output) => sse_event("final", &MessageResponse { output, session_id }),
+            Err((status, message)) => sse_event(
+                "error",
+                &StreamErrorPayload {
+                    status: status.as_u16(),
+                    message,
+                },
+            ),
+        };
+        let _ = event_tx.send(terminal);
+    });
+
+    let guard = AbortTurnOnDrop(turn.abort_handle());
+    let stream = futures::stream::unfold((event_rx, guard), |(mut event_rx, guard)| async move {
+        event_rx
+            .recv()
+            .await
+            .map(|event| (Ok::<_, Infallible>(event), (event_rx, guard)))
+    });
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
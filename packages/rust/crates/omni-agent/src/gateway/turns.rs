@@ -0,0 +1,142 @@
+//! In-flight turn registry for the HTTP gateway: list running turns and cancel them by id.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::Instant;
+use tokio::sync::oneshot;
+
+/// One running gateway turn as reported by `GET /turns`.
+#[derive(Debug, Clone, Serialize)]
+pub struct GatewayTurnSummary {
+    /// Gateway-assigned turn identifier.
+    pub turn_id: String,
+    /// Session the turn belongs to.
+    pub session_id: String,
+    /// Milliseconds since the turn started.
+    pub elapsed_ms: u64,
+}
+
+/// Response body for `GET /turns`.
+#[derive(Debug, Serialize)]
+pub struct GatewayTurnListResponse {
+    /// Active turns, oldest first.
+    pub turns: Vec<GatewayTurnSummary>,
+}
+
+/// Response body for `POST /turns/{id}/cancel`.
+#[derive(Debug, Serialize)]
+pub struct GatewayTurnCancelResponse {
+    /// Cancelled turn identifier.
+    pub turn_id: String,
+    /// Always `cancelled`.
+    pub status: &'static str,
+}
+
+struct ActiveTurn {
+    session_id: String,
+    started_at: Instant,
+    cancel: oneshot::Sender<()>,
+}
+
+/// Tracks gateway turns while they run so they can be observed and cancelled.
+#[derive(Default)]
+pub struct GatewayTurnRegistry {
+    next_id: AtomicU64,
+    active: Mutex<HashMap<String, ActiveTurn>>,
+}
+
+impl GatewayTurnRegistry {
+    /// Register a new turn; the returned receiver fires when the turn is cancelled.
+    ///
+    /// The turn is removed when the guard drops, including when the request future is
+    /// dropped because the client disconnected.
+    pub fn register(&self, session_id: &str) -> (GatewayTurnGuard<'_>, oneshot::Receiver<()>) {
+        let turn_id = format!("turn-{}", self.next_id.fetch_add(1, Ordering::Relaxed) + 1);
+        let (cancel, cancelled) = oneshot::channel();
+        self.active
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(
+                turn_id.clone(),
+                ActiveTurn {
+                    session_id: session_id.to_string(),
+                    started_at: Instant::now(),
+                    cancel,
+                },
+            );
+        (
+            GatewayTurnGuard {
+                registry: self,
+                turn_id,
+            },
+            cancelled,
+        )
+    }
+
+    /// Drop a finished turn from the registry.
+    pub fn finish(&self, turn_id: &str) {
+        self.active
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(turn_id);
+    }
+
+    /// Signal cancellation to a running turn; returns `false` when the id is unknown.
+    pub fn cancel(&self, turn_id: &str) -> bool {
+        let removed = self
+            .active
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(turn_id);
+        match removed {
+            Some(turn) => {
+                let _ = turn.cancel.send(());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Snapshot of running turns, oldest first.
+    pub fn list(&self) -> Vec<GatewayTurnSummary> {
+        let active = self.active.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut turns: Vec<(Instant, GatewayTurnSummary)> = active
+            .iter()
+            .map(|(turn_id, turn)| {
+                (
+                    turn.started_at,
+                    GatewayTurnSummary {
+                        turn_id: turn_id.clone(),
+                        session_id: turn.session_id.clone(),
+                        elapsed_ms: u64::try_from(turn.started_at.elapsed().as_millis())
+                            .unwrap_or(u64::MAX),
+                    },
+                )
+            })
+            .collect();
+        turns.sort_by_key(|(started_at, _)| *started_at);
+        turns.into_iter().map(|(_, summary)| summary).collect()
+    }
+}
+
+/// Keeps a turn listed in its [`GatewayTurnRegistry`] until dropped.
+pub struct GatewayTurnGuard<'a> {
+    registry: &'a GatewayTurnRegistry,
+    turn_id: String,
+}
+
+impl GatewayTurnGuard<'_> {
+    /// Gateway-assigned turn identifier.
+    #[must_use]
+    pub fn turn_id(&self) -> &str {
+        &self.turn_id
+    }
+}
+
+impl Drop for GatewayTurnGuard<'_> {
+    fn drop(&mut self) {
+        self.registry.finish(&self.turn_id);
+    }
+}
@@ -23,26 +23,28 @@ mod tools;
 
 pub use agent::{
     Agent, GraphBridgeRequest, GraphBridgeResult, MemoryRecallLatencyBucketsSnapshot,
-    MemoryRecallMetricsSnapshot, SessionContextBudgetClassSnapshot, SessionContextBudgetSnapshot,
-    SessionContextMode, SessionContextSnapshotInfo, SessionContextStats, SessionContextWindowInfo,
-    SessionMemoryRecallDecision, SessionMemoryRecallSnapshot, prune_messages_for_token_budget,
+    MemoryRecallMetricsSnapshot, OMEGA_DECISION_LOG_ENV, OmegaDecisionLog, OmegaDecisionLogEntry,
+    SessionContextBudgetClassSnapshot, SessionContextBudgetSnapshot, SessionContextMode,
+    SessionContextSnapshotInfo, SessionContextStats, SessionContextWindowInfo,
+    SessionMemoryRecallDecision, SessionMemoryRecallSnapshot, TurnProgressEvent, TurnProgressSink,
+    omega_tool_gate, prune_messages_for_token_budget, read_omega_decision_log_entries,
     summarise_drained_turns, validate_graph_bridge_request,
 };
 pub use channels::{
     Channel, ChannelMessage, DEFAULT_REDIS_KEY_PREFIX, DISCORD_MAX_MESSAGE_LENGTH, DiscordChannel,
-    DiscordControlCommandPolicy, DiscordIngressApp, DiscordRuntimeConfig, DiscordSessionPartition,
-    DiscordSlashCommandPolicy, RecipientCommandAdminUsersMutation, SessionGate,
-    TELEGRAM_MAX_MESSAGE_LENGTH, TelegramChannel, TelegramControlCommandPolicy,
-    TelegramRuntimeConfig, TelegramSessionPartition, TelegramSlashCommandPolicy,
-    TelegramWebhookApp, WebhookDedupBackend, WebhookDedupConfig, build_discord_ingress_app,
-    build_discord_ingress_app_with_control_command_policy,
+    DiscordControlCommandPolicy, DiscordIngressApp, DiscordReactionAcknowledgement,
+    DiscordRuntimeConfig, DiscordSessionPartition, DiscordSlashCommandPolicy,
+    RecipientCommandAdminUsersMutation, SessionGate, TELEGRAM_MAX_MESSAGE_LENGTH, TelegramChannel,
+    TelegramControlCommandPolicy, TelegramRuntimeConfig, TelegramSessionPartition,
+    TelegramSlashCommandPolicy, TelegramWebhookApp, WebhookDedupBackend, WebhookDedupConfig,
+    build_discord_ingress_app, build_discord_ingress_app_with_control_command_policy,
     build_discord_ingress_app_with_partition_and_control_command_policy,
     build_telegram_webhook_app, build_telegram_webhook_app_with_control_command_policy,
     build_telegram_webhook_app_with_partition, chunk_marker_reserve_chars,
     decorate_chunk_for_telegram, markdown_to_telegram_html, markdown_to_telegram_markdown_v2,
-    run_discord_ingress, run_telegram, run_telegram_webhook,
+    restore_recipient_session_partitions, run_discord_ingress, run_telegram, run_telegram_webhook,
     run_telegram_webhook_with_control_command_policy, run_telegram_with_control_command_policy,
-    split_message_for_discord, split_message_for_telegram,
+    set_recipient_session_partition, split_message_for_discord, split_message_for_telegram,
 };
 pub use config::{
     AgentConfig, ContextBudgetStrategy, DiscordSettings, EmbeddingSettings, LITELLM_DEFAULT_URL,
@@ -51,13 +53,17 @@ pub use config::{
     load_runtime_settings_from_paths, set_config_home_override,
 };
 pub use contracts::{
-    DiscoverConfidence, DiscoverMatch, MemoryGateDecision, MemoryGateVerdict, OmegaDecision,
-    OmegaFallbackPolicy, OmegaRiskLevel, OmegaRoute, OmegaToolTrustClass,
+    DiscoverConfidence, DiscoverExplanation, DiscoverMatch, MemoryGateDecision, MemoryGateVerdict,
+    OmegaDecision, OmegaFallbackPolicy, OmegaRiskLevel, OmegaRoute, OmegaToolGateAction,
+    OmegaToolGateDecision, OmegaToolGatePolicy, OmegaToolTrustClass,
 };
 pub use embedding::EmbeddingClient;
 pub use gateway::{
-    DEFAULT_STDIO_SESSION_ID, GatewayHealthResponse, GatewayMcpHealthResponse, GatewayState,
-    MessageRequest, MessageResponse, router, run_http, run_stdio, validate_message_request,
+    DEFAULT_MAX_MESSAGE_BYTES, DEFAULT_STDIO_SESSION_ID, GatewayHealthResponse,
+    GatewayMcpHealthResponse, GatewayState, GatewayTurnCancelResponse, GatewayTurnGuard,
+    GatewayTurnListResponse, GatewayTurnRegistry, GatewayTurnSummary, GatewayValidationError,
+    MessageRequest, MessageResponse, router, router_with_limits, run_http, run_stdio,
+    validate_message_request, validate_message_request_with_limit,
 };
 pub use jobs::{
     HeartbeatProbeState, JobCompletion, JobCompletionKind, JobHealthState, JobManager,
@@ -66,7 +72,7 @@ pub use jobs::{
     run_recurring_schedule,
 };
 pub use mcp_pool::{
-    McpClientPool, McpDiscoverCacheStatsSnapshot, McpPoolConnectConfig,
+    McpClientHealth, McpClientPool, McpDiscoverCacheStatsSnapshot, McpPoolConnectConfig,
     McpToolsListCacheStatsSnapshot, connect_pool,
 };
 pub use session::{
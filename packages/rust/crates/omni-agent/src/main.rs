@@ -47,12 +47,14 @@ async fn main() -> anyhow::Result<()> {
             bind,
             turn_timeout,
             max_concurrent,
+            max_message_bytes,
             mcp_config,
         } => {
             run_gateway_mode(
                 bind,
                 turn_timeout,
                 max_concurrent,
+                max_message_bytes,
                 mcp_config,
                 &runtime_settings,
             )
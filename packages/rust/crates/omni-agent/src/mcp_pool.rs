@@ -26,6 +26,7 @@ const DEFAULT_CONNECT_RETRY_BACKOFF_MS: u64 = 1_000;
 const DEFAULT_TOOL_TIMEOUT_SECS: u64 = 180;
 const DEFAULT_HEALTH_PROBE_TIMEOUT_MS: u64 = 1_500;
 const DEFAULT_HEALTH_READY_POLL_MS: u64 = 200;
+const DEFAULT_PING_TIMEOUT_MS: u64 = 3_000;
 const DEFAULT_INFLIGHT_LOG_INTERVAL_SECS: u64 = 5;
 const DEFAULT_SLOW_CALL_WARN_MS: u128 = 2_000;
 const DEFAULT_LIST_TOOLS_CACHE_TTL_MS: u64 = 1_000;
@@ -66,6 +67,19 @@ pub struct McpDiscoverCacheStatsSnapshot {
     pub hit_rate_pct: f64,
 }
 
+/// Liveness of one pooled MCP client, as reported by [`McpClientPool::ping_clients`].
+#[derive(Debug, Clone, Serialize)]
+pub struct McpClientHealth {
+    /// Position of the client in the pool.
+    pub client_index: usize,
+    /// Whether the client answered the ping before the deadline.
+    pub alive: bool,
+    /// Round-trip time of a successful ping.
+    pub latency_ms: Option<u64>,
+    /// Why the ping failed or timed out; `None` when alive.
+    pub error: Option<String>,
+}
+
 /// MCP pool connection settings.
 #[derive(Debug, Clone, Copy)]
 pub struct McpPoolConnectConfig {
@@ -435,6 +449,52 @@ impl McpClientPool {
         }
     }
 
+    /// Ping every pooled client (MCP `ping`) so dead connections can be told apart from idle ones.
+    ///
+    /// Clients are pinged concurrently under one shared deadline, so a probe takes at most
+    /// the ping timeout regardless of pool size; failures are reported per client and do not
+    /// reconnect.
+    pub async fn ping_clients(&self) -> Vec<McpClientHealth> {
+        let clients = self.clients.read().await.clone();
+        let timeout = Duration::from_millis(DEFAULT_PING_TIMEOUT_MS);
+        let deadline = tokio::time::Instant::now() + timeout;
+        let pings = clients.iter().map(|client| async move {
+            match tokio::time::timeout_at(deadline, client.ping()).await {
+                Ok(Ok(latency)) => Ok(latency),
+                Ok(Err(error)) => Err(format!("{error:#}")),
+                Err(_) => Err(format!("ping timed out after {}ms", timeout.as_millis())),
+            }
+        });
+        let outcomes = futures::future::join_all(pings).await;
+        outcomes
+            .into_iter()
+            .enumerate()
+            .map(|(client_index, outcome)| match outcome {
+                Ok(latency) => McpClientHealth {
+                    client_index,
+                    alive: true,
+                    latency_ms: Some(u64::try_from(latency.as_millis()).unwrap_or(u64::MAX)),
+                    error: None,
+                },
+                Err(error) => {
+                    tracing::warn!(
+                        event = "mcp.pool.ping.failed",
+                        url = %self.server_url,
+                        client_index,
+                        error = %error,
+                        "mcp pool client ping failed"
+                    );
+                    McpClientHealth {
+                        client_index,
+                        alive: false,
+                        latency_ms: None,
+                        error: Some(error),
+                    }
+                }
+            })
+            .collect()
+    }
+
     /// Return discover cache stats when discover read-through cache is enabled.
     pub fn discover_cache_stats_snapshot(&self) -> Option<McpDiscoverCacheStatsSnapshot> {
         let cache = self.discover_cache.as_ref()?;
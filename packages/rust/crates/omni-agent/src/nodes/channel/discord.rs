@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
 use omni_agent::{
-    DiscordControlCommandPolicy, DiscordRuntimeConfig, DiscordSessionPartition,
-    DiscordSlashCommandPolicy, RuntimeSettings, run_discord_ingress,
+    DiscordControlCommandPolicy, DiscordReactionAcknowledgement, DiscordRuntimeConfig,
+    DiscordSessionPartition, DiscordSlashCommandPolicy, RuntimeSettings, run_discord_ingress,
 };
 
 use crate::agent_builder::build_agent;
@@ -287,6 +287,7 @@ async fn run_discord_channel_mode(
             session_partition,
             inbound_queue_capacity,
             turn_timeout_secs,
+            reaction_acknowledgement: DiscordReactionAcknowledgement::from_env(),
         },
     )
     .await
@@ -8,9 +8,17 @@ pub(crate) async fn run_gateway_mode(
     bind_addr: String,
     turn_timeout: Option<u64>,
     max_concurrent: Option<usize>,
+    max_message_bytes: Option<usize>,
     mcp_config_path: PathBuf,
     runtime_settings: &RuntimeSettings,
 ) -> anyhow::Result<()> {
     let agent = build_agent(&mcp_config_path, runtime_settings).await?;
-    run_http(agent, &bind_addr, turn_timeout, max_concurrent).await
+    run_http(
+        agent,
+        &bind_addr,
+        turn_timeout,
+        max_concurrent,
+        max_message_bytes,
+    )
+    .await
 }
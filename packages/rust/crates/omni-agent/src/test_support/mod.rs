@@ -7,6 +7,9 @@ mod managed_parser;
 mod telegram_parser;
 mod types;
 
+pub use crate::channels::slash_command::{
+    ParsedSlashCommand, SlashCommandParseError, parse_slash_command,
+};
 pub use managed_parser::{detect_managed_control_command, detect_managed_slash_command};
 pub use telegram_parser::{
     is_reset_context_command, parse_background_prompt, parse_help_command,
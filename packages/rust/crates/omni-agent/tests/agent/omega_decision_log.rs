@@ -0,0 +1,55 @@
+use std::io::Cursor;
+
+use crate::contracts::{OmegaRiskLevel, OmegaRoute};
+use crate::shortcuts::WorkflowBridgeMode;
+
+use super::super::{decide_for_shortcut, decide_for_standard_turn};
+use super::{OmegaDecisionLog, read_entries};
+
+#[test]
+fn logged_decisions_replay_in_order() {
+    let temp_dir = tempfile::tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+    let log = OmegaDecisionLog::new(temp_dir.path().join("audit").join("omega.jsonl"));
+
+    let standard = decide_for_standard_turn(false);
+    let shortcut = decide_for_shortcut(
+        WorkflowBridgeMode::Omega,
+        "run the release flow",
+        "release.run",
+    );
+    log.append("s1", "  hello\n   world  ", &standard)
+        .unwrap_or_else(|error| panic!("append standard: {error}"));
+    log.append("s1", "run the release flow", &shortcut)
+        .unwrap_or_else(|error| panic!("append shortcut: {error}"));
+
+    let replayed = log
+        .replay()
+        .unwrap_or_else(|error| panic!("replay: {error}"));
+    assert_eq!(replayed, vec![standard, shortcut]);
+    assert_eq!(replayed[0].route, OmegaRoute::React);
+    assert_eq!(replayed[1].route, OmegaRoute::Graph);
+    assert_eq!(replayed[1].risk_level, OmegaRiskLevel::Medium);
+
+    let entries = log
+        .entries()
+        .unwrap_or_else(|error| panic!("entries: {error}"));
+    assert_eq!(entries[0].input_summary, "hello world");
+    assert_eq!(entries[1].session_id, "s1");
+}
+
+#[test]
+fn missing_log_replays_empty() {
+    let temp_dir = tempfile::tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+    let log = OmegaDecisionLog::new(temp_dir.path().join("absent.jsonl"));
+    let replayed = log
+        .replay()
+        .unwrap_or_else(|error| panic!("replay: {error}"));
+    assert!(replayed.is_empty());
+}
+
+#[test]
+fn malformed_line_reports_line_number() {
+    let error = read_entries(Cursor::new("\n{\"not\":\"an entry\"}\n"))
+        .expect_err("malformed entry should fail");
+    assert!(error.to_string().contains("line 2"));
+}
@@ -0,0 +1,68 @@
+use crate::contracts::{OmegaToolGateAction, OmegaToolGatePolicy, OmegaToolTrustClass};
+
+use super::omega_tool_gate;
+
+fn strict_policy() -> OmegaToolGatePolicy {
+    let mut policy = OmegaToolGatePolicy {
+        auto_execute_min: OmegaToolTrustClass::Evidence,
+        ..OmegaToolGatePolicy::default()
+    };
+    policy.tool_trust_classes.insert(
+        "knowledge.search".to_string(),
+        OmegaToolTrustClass::Evidence,
+    );
+    policy
+}
+
+#[test]
+fn low_trust_tool_requires_confirmation() {
+    let decision = omega_tool_gate("shell.exec", &strict_policy());
+    assert_eq!(decision.action, OmegaToolGateAction::RequireConfirmation);
+    assert_eq!(decision.tool_name, "shell.exec");
+    assert_eq!(decision.tool_trust_class, OmegaToolTrustClass::Other);
+    assert_eq!(
+        decision.policy_id,
+        "omega.tool_gate.require_confirmation.v1"
+    );
+}
+
+#[test]
+fn high_trust_tool_auto_executes() {
+    let decision = omega_tool_gate("knowledge.search", &strict_policy());
+    assert_eq!(decision.action, OmegaToolGateAction::AutoExecute);
+    assert_eq!(decision.tool_trust_class, OmegaToolTrustClass::Evidence);
+}
+
+#[test]
+fn each_tool_is_gated_by_its_own_trust_class() {
+    let mut policy = strict_policy();
+    policy.block_below = Some(OmegaToolTrustClass::Verification);
+    policy
+        .tool_trust_classes
+        .insert("tests.run".to_string(), OmegaToolTrustClass::Verification);
+
+    assert_eq!(
+        omega_tool_gate("shell.exec", &policy).action,
+        OmegaToolGateAction::Block
+    );
+    assert_eq!(
+        omega_tool_gate("tests.run", &policy).action,
+        OmegaToolGateAction::RequireConfirmation
+    );
+    assert_eq!(
+        omega_tool_gate("knowledge.search", &policy).action,
+        OmegaToolGateAction::AutoExecute
+    );
+
+    policy.default_trust_class = OmegaToolTrustClass::Evidence;
+    assert_eq!(
+        omega_tool_gate("shell.exec", &policy).action,
+        OmegaToolGateAction::AutoExecute
+    );
+}
+
+#[test]
+fn default_policy_auto_executes_everything() {
+    let decision = omega_tool_gate("any.tool", &OmegaToolGatePolicy::default());
+    assert_eq!(decision.action, OmegaToolGateAction::AutoExecute);
+}
@@ -297,3 +297,21 @@ fn parse_session_commands_rejects_invalid_shape() {
     assert!(parse_resume_context_command("/clear").is_none());
     assert!(parse_resume_context_command("/resume now").is_none());
 }
+
+#[test]
+fn parse_commands_share_slash_command_tokenizer() {
+    let help = parse_help_command("/Help@omni_bot json").expect("expected /help@bot parse");
+    assert!(help.is_json());
+
+    let quoted = parse_job_status_command(r#"/job "job-123" json"#).expect("expected quoted job");
+    assert_eq!(quoted.job_id, "job-123");
+    assert!(quoted.format.is_json());
+
+    assert_eq!(
+        parse_background_prompt(r#"/bg don't "quote" this"#).as_deref(),
+        Some(r#"don't "quote" this"#)
+    );
+    assert!(parse_job_status_command(r#"/job "job-123"#).is_none());
+    assert!(is_reset_context_command("/reset@omni_bot"));
+    assert!(!is_reset_context_command("/reset now"));
+}
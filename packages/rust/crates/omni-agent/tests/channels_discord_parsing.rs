@@ -180,6 +180,40 @@ fn discord_session_partition_runtime_toggle_changes_strategy() {
     assert_eq!(parsed_a_shared.session_key, parsed_b_shared.session_key);
 }
 
+#[test]
+fn discord_recipient_partition_override_routes_to_new_session() {
+    let channel = DiscordChannel::new_with_partition(
+        "fake-token".to_string(),
+        vec!["*".to_string()],
+        vec![],
+        DiscordSessionPartition::GuildChannelUser,
+    );
+    let overridden = discord_event("1", "hello", "2001", Some("3001"), "1001", Some("alice"));
+    let untouched = discord_event("2", "hello", "2002", Some("3001"), "1001", Some("alice"));
+
+    channel
+        .set_recipient_session_partition_mode("2001", Some("channel"))
+        .expect("override should be accepted");
+
+    let parsed = channel
+        .parse_gateway_message(&overridden)
+        .expect("overridden message should parse");
+    assert_eq!(parsed.session_key, "3001:2001");
+    let other = channel
+        .parse_gateway_message(&untouched)
+        .expect("untouched message should parse");
+    assert_eq!(other.session_key, "3001:2002:1001");
+
+    let error = channel
+        .set_recipient_session_partition_mode("2001", Some("invalid"))
+        .expect_err("invalid override should fail");
+    assert!(
+        error
+            .to_string()
+            .contains("invalid discord session partition mode")
+    );
+}
+
 #[test]
 fn discord_session_partition_mode_rejects_invalid_value() {
     let channel = DiscordChannel::new("fake-token".to_string(), vec!["*".to_string()], vec![]);
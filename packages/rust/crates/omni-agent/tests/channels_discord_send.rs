@@ -8,15 +8,19 @@ use axum::{
     Json, Router,
     extract::{Path, State},
     http::StatusCode,
-    routing::post,
+    routing::{post, put},
+};
+use omni_agent::{
+    Channel, ChannelMessage, DISCORD_MAX_MESSAGE_LENGTH, DiscordChannel,
+    DiscordReactionAcknowledgement, split_message_for_discord,
 };
-use omni_agent::{Channel, DISCORD_MAX_MESSAGE_LENGTH, DiscordChannel, split_message_for_discord};
 use tokio::sync::Mutex;
 
 #[derive(Clone, Default)]
 struct MockDiscordState {
     sent: Arc<Mutex<Vec<(String, String)>>>,
     typing: Arc<Mutex<Vec<String>>>,
+    reactions: Arc<Mutex<Vec<(String, String, String, String)>>>,
 }
 
 async fn handle_send_message(
@@ -44,12 +48,40 @@ async fn handle_typing(
     StatusCode::NO_CONTENT
 }
 
+async fn handle_add_reaction(
+    State(state): State<MockDiscordState>,
+    Path((channel_id, message_id, emoji)): Path<(String, String, String)>,
+) -> StatusCode {
+    state
+        .reactions
+        .lock()
+        .await
+        .push(("PUT".to_string(), channel_id, message_id, emoji));
+    StatusCode::NO_CONTENT
+}
+
+async fn handle_remove_reaction(
+    State(state): State<MockDiscordState>,
+    Path((channel_id, message_id, emoji)): Path<(String, String, String)>,
+) -> StatusCode {
+    state
+        .reactions
+        .lock()
+        .await
+        .push(("DELETE".to_string(), channel_id, message_id, emoji));
+    StatusCode::NO_CONTENT
+}
+
 async fn spawn_mock_discord_api()
 -> Result<Option<(String, MockDiscordState, tokio::task::JoinHandle<()>)>> {
     let state = MockDiscordState::default();
     let app = Router::new()
         .route("/channels/{channel_id}/messages", post(handle_send_message))
         .route("/channels/{channel_id}/typing", post(handle_typing))
+        .route(
+            "/channels/{channel_id}/messages/{message_id}/reactions/{emoji}/@me",
+            put(handle_add_reaction).delete(handle_remove_reaction),
+        )
         .with_state(state.clone());
 
     let listener = match tokio::net::TcpListener::bind("127.0.0.1:0").await {
@@ -148,6 +180,103 @@ async fn discord_start_typing_calls_typing_endpoint() -> Result<()> {
     Ok(())
 }
 
+fn inbound_message(channel_id: &str, message_id: &str) -> ChannelMessage {
+    ChannelMessage {
+        id: format!("discord_{channel_id}_{message_id}"),
+        sender: "1001".to_string(),
+        recipient: channel_id.to_string(),
+        session_key: format!("3001:{channel_id}:1001"),
+        content: "hello".to_string(),
+        channel: "discord".to_string(),
+        timestamp: 0,
+    }
+}
+
+#[tokio::test]
+async fn discord_reaction_acknowledgement_swaps_working_for_done() -> Result<()> {
+    let Some((api_base, state, handle)) = spawn_mock_discord_api().await? else {
+        return Ok(());
+    };
+
+    let channel = DiscordChannel::new_with_base_url(
+        "fake-token".to_string(),
+        vec!["*".to_string()],
+        vec![],
+        api_base,
+    );
+    let message = inbound_message("2001", "9001");
+
+    // Disabled by default: no reactions are sent.
+    channel.acknowledge_message_received(&message).await?;
+    assert!(state.reactions.lock().await.is_empty());
+
+    channel.set_reaction_acknowledgement(Some(DiscordReactionAcknowledgement::default()));
+    channel.acknowledge_message_received(&message).await?;
+    let reaction = |method: &str, emoji: &str| {
+        (
+            method.to_string(),
+            "2001".to_string(),
+            "9001".to_string(),
+            emoji.to_string(),
+        )
+    };
+    assert_eq!(
+        state.reactions.lock().await.clone(),
+        vec![reaction("PUT", "⏳")]
+    );
+
+    channel
+        .acknowledge_message_completed(&message, true)
+        .await?;
+    assert_eq!(
+        state.reactions.lock().await.clone(),
+        vec![
+            reaction("PUT", "⏳"),
+            reaction("DELETE", "⏳"),
+            reaction("PUT", "✅"),
+        ]
+    );
+
+    handle.abort();
+    Ok(())
+}
+
+#[tokio::test]
+async fn discord_reaction_acknowledgement_marks_error_with_custom_emoji() -> Result<()> {
+    let Some((api_base, state, handle)) = spawn_mock_discord_api().await? else {
+        return Ok(());
+    };
+
+    let channel = DiscordChannel::new_with_base_url(
+        "fake-token".to_string(),
+        vec!["*".to_string()],
+        vec![],
+        api_base,
+    );
+    channel.set_reaction_acknowledgement(Some(DiscordReactionAcknowledgement {
+        working: "spin:123".to_string(),
+        ..DiscordReactionAcknowledgement::default()
+    }));
+    let message = inbound_message("2001", "9002");
+
+    channel.acknowledge_message_received(&message).await?;
+    channel
+        .acknowledge_message_completed(&message, false)
+        .await?;
+
+    let emojis = state
+        .reactions
+        .lock()
+        .await
+        .iter()
+        .map(|(method, _, _, emoji)| format!("{method} {emoji}"))
+        .collect::<Vec<_>>();
+    assert_eq!(emojis, vec!["PUT spin:123", "DELETE spin:123", "PUT ❌"]);
+
+    handle.abort();
+    Ok(())
+}
+
 #[test]
 fn split_message_for_discord_handles_zero_limit() {
     assert!(split_message_for_discord("abc", 0).is_empty());
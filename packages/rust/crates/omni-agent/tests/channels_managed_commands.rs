@@ -2,8 +2,8 @@
 #![allow(dead_code)]
 
 use omni_agent::test_support::{
-    ManagedControlCommand, ManagedSlashCommand, detect_managed_control_command,
-    detect_managed_slash_command,
+    ManagedControlCommand, ManagedSlashCommand, SlashCommandParseError,
+    detect_managed_control_command, detect_managed_slash_command, parse_slash_command,
 };
 
 #[test]
@@ -139,3 +139,92 @@ fn detect_managed_control_commands_rejects_invalid_shapes() {
         None
     );
 }
+
+#[test]
+fn parse_slash_command_splits_name_and_arguments() {
+    let parsed = parse_slash_command("/budget set 8000")
+        .unwrap_or_else(|error| panic!("parse slash command: {error}"));
+    assert_eq!(parsed.name, "budget");
+    assert_eq!(parsed.args, vec!["set".to_string(), "8000".to_string()]);
+    assert_eq!(parsed.arg(1), Some("8000"));
+
+    let telegram = parse_slash_command("  /Session@omni_bot  memory json ")
+        .unwrap_or_else(|error| panic!("parse slash command: {error}"));
+    assert_eq!(telegram.name, "session");
+    assert_eq!(
+        telegram.args,
+        vec!["memory".to_string(), "json".to_string()]
+    );
+}
+
+#[test]
+fn parse_slash_command_groups_quoted_arguments() {
+    let parsed = parse_slash_command(r#"/bg "collect build logs" 'from ci' say\ \"hi\""#)
+        .unwrap_or_else(|error| panic!("parse slash command: {error}"));
+    assert_eq!(parsed.name, "bg");
+    assert_eq!(
+        parsed.args,
+        vec![
+            "collect build logs".to_string(),
+            "from ci".to_string(),
+            "say \"hi\"".to_string(),
+        ]
+    );
+    assert_eq!(
+        parse_slash_command("/note \"\"")
+            .unwrap_or_else(|error| panic!("parse slash command: {error}"))
+            .args,
+        vec![String::new()]
+    );
+}
+
+#[test]
+fn parse_slash_command_reports_malformed_input() {
+    assert_eq!(
+        parse_slash_command("/bg \"unterminated prompt"),
+        Err(SlashCommandParseError::UnterminatedQuote {
+            quote: '"',
+            position: 4,
+        })
+    );
+    assert_eq!(
+        parse_slash_command("   "),
+        Err(SlashCommandParseError::Empty)
+    );
+    assert_eq!(
+        parse_slash_command("budget set"),
+        Err(SlashCommandParseError::MissingSlash)
+    );
+    assert_eq!(
+        parse_slash_command("/ set"),
+        Err(SlashCommandParseError::InvalidName(String::new()))
+    );
+    assert_eq!(
+        parse_slash_command("/bud$get"),
+        Err(SlashCommandParseError::InvalidName("bud$get".to_string()))
+    );
+    assert_eq!(
+        parse_slash_command("/bg trailing\\"),
+        Err(SlashCommandParseError::TrailingEscape)
+    );
+    assert!(
+        SlashCommandParseError::UnterminatedQuote {
+            quote: '"',
+            position: 4
+        }
+        .to_string()
+        .contains("byte 4")
+    );
+}
+
+#[test]
+fn detect_managed_slash_command_keeps_background_prompts_untokenized() {
+    assert_eq!(
+        detect_managed_slash_command("/bg don't stop \"here"),
+        Some(ManagedSlashCommand::BackgroundSubmit)
+    );
+    assert_eq!(
+        detect_managed_slash_command("/session@omni_bot memory json"),
+        Some(ManagedSlashCommand::SessionMemory)
+    );
+}
@@ -1,6 +1,13 @@
 #![allow(missing_docs)]
 #![allow(dead_code)]
 
+mod slash_command {
+    include!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/channels/slash_command.rs"
+    ));
+}
+
 mod managed_runtime {
     pub mod parsing {
         include!(concat!(
@@ -6,8 +6,9 @@ use std::time::{Duration, Instant};
 use anyhow::Result;
 use axum::{Json, Router, extract::State, http::StatusCode, routing::post};
 use omni_agent::{
-    Channel, TELEGRAM_MAX_MESSAGE_LENGTH, TelegramChannel, TelegramSessionPartition,
-    decorate_chunk_for_telegram, split_message_for_telegram,
+    Agent, AgentConfig, Channel, TELEGRAM_MAX_MESSAGE_LENGTH, TelegramChannel,
+    TelegramSessionPartition, decorate_chunk_for_telegram, restore_recipient_session_partitions,
+    set_recipient_session_partition, split_message_for_telegram,
 };
 use tokio::sync::Mutex;
 
@@ -668,6 +669,105 @@ fn telegram_parse_update_partition_runtime_toggle_changes_session_key_strategy()
     assert_eq!(msg_a_shared.session_key, msg_b_shared.session_key);
 }
 
+#[test]
+fn telegram_parse_update_recipient_partition_override_routes_to_new_session() {
+    let ch = TelegramChannel::new_with_partition(
+        "t".into(),
+        vec!["*".into()],
+        vec![],
+        TelegramSessionPartition::ChatUser,
+    );
+    let update_for = |update_id: i64, chat_id: i64| {
+        serde_json::json!({
+            "update_id": update_id,
+            "message": {
+                "message_id": update_id,
+                "text": "hello",
+                "chat": {"id": chat_id},
+                "from": {"id": 1001, "username": "alice"}
+            }
+        })
+    };
+    let overridden = update_for(10010, -200111);
+    let untouched = update_for(10011, -200222);
+
+    ch.set_recipient_partition("-200111", Some(TelegramSessionPartition::UserOnly));
+
+    let msg = ch.parse_update_message(&overridden).expect("overridden");
+    assert_eq!(msg.session_key, "1001");
+    let other = ch.parse_update_message(&untouched).expect("untouched");
+    assert_eq!(other.session_key, "-200222:1001");
+
+    ch.set_recipient_session_partition_mode("-200111", None)
+        .expect("clearing override should succeed");
+    let restored = ch.parse_update_message(&overridden).expect("restored");
+    assert_eq!(restored.session_key, "-200111:1001");
+}
+
+#[tokio::test]
+async fn telegram_recipient_partition_override_is_restored_from_session_state() -> Result<()> {
+    let mut config = AgentConfig::default();
+    config.inference_url = "http://127.0.0.1:4000/v1/chat/completions".to_string();
+    config.memory = None;
+    config.window_max_turns = None;
+    config.consolidation_threshold_turns = None;
+    let agent = Agent::from_config(config).await?;
+    let new_channel = || {
+        TelegramChannel::new_with_partition(
+            "t".into(),
+            vec!["*".into()],
+            vec![],
+            TelegramSessionPartition::ChatUser,
+        )
+    };
+    let update = serde_json::json!({
+        "update_id": 10020,
+        "message": {
+            "message_id": 10020,
+            "text": "hello",
+            "chat": {"id": -200333},
+            "from": {"id": 1001, "username": "alice"}
+        }
+    });
+
+    let before_restart = new_channel();
+    set_recipient_session_partition(&agent, &before_restart, "-200333", Some("user")).await?;
+    assert!(
+        set_recipient_session_partition(&agent, &before_restart, "-200333", Some("bogus"))
+            .await
+            .is_err()
+    );
+
+    let after_restart = new_channel();
+    assert_eq!(
+        after_restart
+            .parse_update_message(&update)
+            .expect("message should parse")
+            .session_key,
+        "-200333:1001"
+    );
+    assert_eq!(
+        restore_recipient_session_partitions(&agent, &after_restart).await,
+        1
+    );
+    assert_eq!(
+        after_restart
+            .parse_update_message(&update)
+            .expect("message should parse")
+            .session_key,
+        "1001"
+    );
+
+    set_recipient_session_partition(&agent, &after_restart, "-200333", None).await?;
+    assert!(
+        agent
+            .recipient_session_partitions("telegram")
+            .await?
+            .is_empty()
+    );
+    Ok(())
+}
+
 #[test]
 fn telegram_session_partition_parse_aliases() {
     assert_eq!(
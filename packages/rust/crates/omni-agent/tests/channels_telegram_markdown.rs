@@ -150,3 +150,24 @@ fn html_renderer_keeps_cjk_fullwidth_punctuation_in_code_block() {
     assert!(rendered.contains("<pre><code>"));
     assert!(rendered.contains("</code></pre>"));
 }
+
+#[test]
+fn html_renderer_converts_table_to_aligned_preformatted_block() {
+    let rendered = markdown_to_telegram_html(
+        "| Name | Qty |\n| --- | ---: |\n| apple | 3 |\n| kiwi & lime | 12 |",
+    );
+    let rendered = normalize(&rendered);
+
+    assert_eq!(
+        rendered,
+        "<pre>Name        | Qty\n------------+----\napple       |   3\nkiwi &amp; lime |  12</pre>"
+    );
+}
+
+#[test]
+fn markdown_renderer_converts_table_to_code_block() {
+    let rendered = markdown_to_telegram_markdown_v2("| a | b |\n|---|---|\n| 1 | 2 |");
+    let rendered = normalize(&rendered);
+
+    assert_eq!(rendered, "```\na | b\n--+--\n1 | 2\n```");
+}
@@ -1,6 +1,6 @@
 use omni_agent::{
-    DiscoverConfidence, DiscoverMatch, MemoryGateDecision, MemoryGateVerdict, OmegaDecision,
-    OmegaFallbackPolicy, OmegaRiskLevel, OmegaRoute, OmegaToolTrustClass,
+    DiscoverConfidence, DiscoverExplanation, DiscoverMatch, MemoryGateDecision, MemoryGateVerdict,
+    OmegaDecision, OmegaFallbackPolicy, OmegaRiskLevel, OmegaRoute, OmegaToolTrustClass,
 };
 
 #[test]
@@ -60,6 +60,7 @@ fn discover_match_contract_carries_confidence_and_digest() {
         ranking_reason: "Strong intent overlap + schema compatibility.".to_string(),
         input_schema_digest: "sha256:abc123".to_string(),
         documentation_path: Some("/tmp/SKILL.md".to_string()),
+        explanation: None,
     };
 
     let raw = serde_json::to_value(&row).unwrap_or_else(|error| {
@@ -73,3 +74,50 @@ fn discover_match_contract_carries_confidence_and_digest() {
         .unwrap_or_else(|| panic!("missing final_score number in serialized payload"));
     assert!((final_score - 0.84).abs() < 1e-6);
 }
+
+#[test]
+fn discover_match_decodes_ranking_explanation() {
+    let raw = serde_json::json!({
+        "tool": "git.commit",
+        "usage": "@omni(\"git.commit\", {})",
+        "score": 0.3273,
+        "final_score": 0.82,
+        "confidence": "high",
+        "ranking_reason": "vector=0.810 | keyword=7.400 | confidence=high",
+        "input_schema_digest": "sha256:fixture",
+        "documentation_path": null,
+        "explanation": {
+            "vector_score": 0.81,
+            "keyword_score": 7.4,
+            "vector_contribution": 0.0909,
+            "keyword_contribution": 0.1364,
+            "boost": 0.1,
+        },
+    });
+
+    let decoded: DiscoverMatch = serde_json::from_value(raw).unwrap_or_else(|error| {
+        panic!("failed to deserialize discover match: {error}");
+    });
+    let explanation = decoded
+        .explanation
+        .as_ref()
+        .unwrap_or_else(|| panic!("missing explanation"));
+    assert!((explanation.total() - decoded.score).abs() < 1e-6);
+    assert_eq!(explanation.keyword_score, Some(7.4));
+
+    let vector_only = DiscoverMatch {
+        explanation: Some(DiscoverExplanation {
+            vector_score: Some(0.62),
+            keyword_score: None,
+            vector_contribution: 0.1818,
+            keyword_contribution: 0.0,
+            boost: 0.0,
+        }),
+        ..decoded.clone()
+    };
+    let raw = serde_json::to_value(&vector_only).unwrap_or_else(|error| {
+        panic!("failed to serialize discover match: {error}");
+    });
+    assert!(raw["explanation"]["keyword_score"].is_null());
+    assert_eq!(raw["explanation"]["keyword_contribution"], 0.0);
+}
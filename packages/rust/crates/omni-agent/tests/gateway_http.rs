@@ -4,7 +4,7 @@
 use axum::body::Body;
 use axum::body::to_bytes;
 use axum::http::{Request, StatusCode};
-use omni_agent::{Agent, AgentConfig, router};
+use omni_agent::{Agent, AgentConfig, router, router_with_limits};
 use serde_json::Value;
 use tower::ServiceExt;
 
@@ -56,6 +56,32 @@ async fn gateway_returns_400_for_empty_message() {
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
 
+#[tokio::test]
+async fn gateway_returns_structured_400_for_oversized_message() {
+    let config = minimal_agent_config();
+    let agent = Agent::from_config(config).await.expect("agent");
+    let app = router_with_limits(agent, 300, None, 8);
+
+    let response = app
+        .oneshot(
+            Request::post("/message")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    r#"{"session_id":"s1","message":"longer than eight"}"#,
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let payload: Value = serde_json::from_slice(&bytes).expect("json body");
+    assert_eq!(payload["error"], "message_too_large");
+    assert_eq!(payload["max_message_bytes"], 8);
+    assert_eq!(payload["message_bytes"], 17);
+}
+
 #[tokio::test]
 async fn gateway_returns_404_for_unknown_route() {
     let config = minimal_agent_config();
@@ -115,4 +141,220 @@ async fn gateway_health_returns_structured_summary_without_mcp() {
         tools_list_cache.is_none() || tools_list_cache.is_some_and(Value::is_null),
         "tools_list_cache should be omitted or null when MCP is disabled"
     );
+    assert!(
+        payload["mcp"].get("clients").is_none(),
+        "clients should be omitted when MCP is disabled"
+    );
+}
+
+async fn spawn_stalled_inference_server() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind mock inference server");
+    let addr = listener.local_addr().expect("mock server addr");
+    let app = axum::Router::new().route(
+        "/v1/chat/completions",
+        axum::routing::post(|| async {
+            tokio::time::sleep(std::time::Duration::from_secs(600)).await;
+            StatusCode::OK
+        }),
+    );
+    tokio::spawn(async move {
+        let _ = axum::serve(listener, app).await;
+    });
+    format!("http://{addr}/v1/chat/completions")
+}
+
+async fn json_body(response: axum::response::Response) -> Value {
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&bytes).expect("json body")
+}
+
+#[tokio::test]
+async fn gateway_lists_and_cancels_in_flight_turn() {
+    let config = AgentConfig {
+        inference_url: spawn_stalled_inference_server().await,
+        ..minimal_agent_config()
+    };
+    let agent = Agent::from_config(config).await.expect("agent");
+    let app = router(agent, 300, None);
+
+    let message_app = app.clone();
+    let turn = tokio::spawn(async move {
+        message_app
+            .oneshot(
+                Request::post("/message")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"session_id":"slow","message":"hi"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    });
+
+    let mut listed = Value::Null;
+    for _ in 0..100 {
+        let response = app
+            .clone()
+            .oneshot(Request::get("/turns").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        listed = json_body(response).await;
+        if listed["turns"]
+            .as_array()
+            .is_some_and(|turns| !turns.is_empty())
+        {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    let turns = listed["turns"].as_array().expect("turns array");
+    assert_eq!(turns.len(), 1);
+    assert_eq!(turns[0]["session_id"], "slow");
+    assert!(turns[0]["elapsed_ms"].is_u64());
+    let turn_id = turns[0]["turn_id"].as_str().expect("turn id").to_string();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::post(format!("/turns/{turn_id}/cancel"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let cancelled = json_body(response).await;
+    assert_eq!(cancelled["turn_id"], turn_id.as_str());
+    assert_eq!(cancelled["status"], "cancelled");
+
+    let response = tokio::time::timeout(std::time::Duration::from_secs(5), turn)
+        .await
+        .expect("cancelled turn should finish promptly")
+        .expect("turn task");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = app
+        .clone()
+        .oneshot(Request::get("/turns").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(json_body(response).await["turns"], Value::Array(Vec::new()));
+
+    let response = app
+        .oneshot(
+            Request::post(format!("/turns/{turn_id}/cancel"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn gateway_drops_turn_when_client_disconnects() {
+    let config = AgentConfig {
+        inference_url: spawn_stalled_inference_server().await,
+        ..minimal_agent_config()
+    };
+    let agent = Agent::from_config(config).await.expect("agent");
+    let app = router(agent, 300, None);
+
+    let message_app = app.clone();
+    let turn = tokio::spawn(async move {
+        message_app
+            .oneshot(
+                Request::post("/message")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"session_id":"gone","message":"hi"}"#))
+                    .unwrap(),
+            )
+            .await
+    });
+
+    let mut registered = false;
+    for _ in 0..100 {
+        let response = app
+            .clone()
+            .oneshot(Request::get("/turns").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        if json_body(response).await["turns"]
+            .as_array()
+            .is_some_and(|turns| !turns.is_empty())
+        {
+            registered = true;
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert!(registered, "turn should be listed while running");
+
+    turn.abort();
+    let _ = turn.await;
+
+    let response = app
+        .oneshot(Request::get("/turns").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(json_body(response).await["turns"], Value::Array(Vec::new()));
+}
+
+#[tokio::test]
+async fn gateway_stream_aborts_turn_when_client_disconnects() {
+    let config = AgentConfig {
+        inference_url: spawn_stalled_inference_server().await,
+        ..minimal_agent_config()
+    };
+    let agent = Agent::from_config(config).await.expect("agent");
+    let app = router(agent, 300, None);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::post("/message/stream")
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"session_id":"gone","message":"hi"}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let mut registered = false;
+    for _ in 0..100 {
+        let listed = app
+            .clone()
+            .oneshot(Request::get("/turns").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        if json_body(listed).await["turns"]
+            .as_array()
+            .is_some_and(|turns| !turns.is_empty())
+        {
+            registered = true;
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert!(registered, "streamed turn should be listed while running");
+
+    drop(response);
+
+    let mut released = false;
+    for _ in 0..100 {
+        let listed = app
+            .clone()
+            .oneshot(Request::get("/turns").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        if json_body(listed).await["turns"] == Value::Array(Vec::new()) {
+            released = true;
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert!(released, "dropping the stream should abort the turn");
 }
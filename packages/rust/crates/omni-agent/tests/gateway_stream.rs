@@ -0,0 +1,339 @@
+//! HTTP gateway SSE streaming: POST /message/stream relays tool progress and a final event.
+
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum::body::{Body, to_bytes};
+use axum::http::{Request, StatusCode};
+use axum::{Json, Router};
+use omni_agent::{
+    Agent, AgentConfig, McpServerEntry, OmegaToolGatePolicy, OmegaToolTrustClass, router,
+};
+use rmcp::ServerHandler;
+use rmcp::model::{
+    CallToolRequestParams, CallToolResult, Content, ErrorData, ListToolsResult,
+    PaginatedRequestParams, ServerCapabilities, ServerInfo, Tool,
+};
+use rmcp::service::{RequestContext, RoleServer};
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use rmcp::transport::streamable_http_server::{StreamableHttpServerConfig, StreamableHttpService};
+use serde_json::Value;
+use tower::ServiceExt;
+
+#[derive(Clone)]
+struct EchoToolServer;
+
+impl ServerHandler for EchoToolServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..Default::default()
+        }
+    }
+
+    fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> impl std::future::Future<Output = Result<ListToolsResult, ErrorData>> + Send + '_ {
+        let schema = serde_json::json!({"type": "object", "additionalProperties": true});
+        let tool = Tool {
+            name: "mock.echo".into(),
+            title: None,
+            description: Some("Echo arguments".into()),
+            input_schema: Arc::new(schema.as_object().cloned().unwrap_or_default()),
+            output_schema: None,
+            annotations: None,
+            execution: None,
+            icons: None,
+            meta: None,
+        };
+        std::future::ready(Ok(ListToolsResult::with_all_items(vec![tool])))
+    }
+
+    fn call_tool(
+        &self,
+        request: CallToolRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> impl std::future::Future<Output = Result<CallToolResult, ErrorData>> + Send + '_ {
+        let args = request.arguments.map(Value::Object).unwrap_or_default();
+        std::future::ready(Ok(CallToolResult::success(vec![Content::text(format!(
+            "echo:{args}"
+        ))])))
+    }
+}
+
+async fn spawn_mcp_server() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind mock mcp listener");
+    let addr = listener.local_addr().expect("mock mcp addr");
+    let service: StreamableHttpService<EchoToolServer, LocalSessionManager> =
+        StreamableHttpService::new(
+            || Ok(EchoToolServer),
+            Arc::new(LocalSessionManager::default()),
+            StreamableHttpServerConfig {
+                stateful_mode: true,
+                sse_keep_alive: None,
+                ..Default::default()
+            },
+        );
+    let app = Router::new().nest_service("/sse", service);
+    tokio::spawn(async move {
+        let _ = axum::serve(listener, app).await;
+    });
+    format!("http://{addr}/sse")
+}
+
+/// Scripted LLM: each turn first asks for `mock.echo`, then answers.
+async fn spawn_scripted_llm() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind mock llm listener");
+    let addr = listener.local_addr().expect("mock llm addr");
+    let calls = Arc::new(AtomicUsize::new(0));
+    let app = Router::new().route(
+        "/v1/chat/completions",
+        axum::routing::post(move || {
+            let calls = Arc::clone(&calls);
+            async move {
+                let message = if calls.fetch_add(1, Ordering::SeqCst) % 2 == 0 {
+                    serde_json::json!({
+                        "content": "checking the echo tool",
+                        "tool_calls": [{
+                            "id": "call-1",
+                            "type": "function",
+                            "function": {"name": "mock.echo", "arguments": "{\"q\":\"ping\"}"}
+                        }]
+                    })
+                } else {
+                    serde_json::json!({"content": "all done"})
+                };
+                Json(serde_json::json!({"choices": [{"message": message}]}))
+            }
+        }),
+    );
+    tokio::spawn(async move {
+        let _ = axum::serve(listener, app).await;
+    });
+    format!("http://{addr}/v1/chat/completions")
+}
+
+fn parse_sse(body: &str) -> Vec<(String, Value)> {
+    body.split("\n\n")
+        .filter_map(|frame| {
+            let mut name = None;
+            let mut data = None;
+            for line in frame.lines() {
+                if let Some(value) = line.strip_prefix("event:") {
+                    name = Some(value.trim().to_string());
+                } else if let Some(value) = line.strip_prefix("data:") {
+                    data = serde_json::from_str(value.trim()).ok();
+                }
+            }
+            Some((name?, data?))
+        })
+        .collect()
+}
+
+async fn scripted_agent_config() -> AgentConfig {
+    AgentConfig {
+        inference_url: spawn_scripted_llm().await,
+        model: "test-model".to_string(),
+        mcp_servers: vec![McpServerEntry {
+            name: "mock".to_string(),
+            url: Some(spawn_mcp_server().await),
+            command: None,
+            args: None,
+        }],
+        mcp_handshake_timeout_secs: 2,
+        mcp_connect_retries: 2,
+        mcp_connect_retry_backoff_ms: 50,
+        max_tool_rounds: 3,
+        ..AgentConfig::default()
+    }
+}
+
+async fn stream_events(config: AgentConfig) -> Vec<(String, Value)> {
+    let agent = Agent::from_config(config).await.expect("agent");
+    let app = router(agent, 30, None);
+
+    let response = app
+        .oneshot(
+            Request::post("/message/stream")
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"session_id":"stream","message":"ping it"}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response
+            .headers()
+            .get("content-type")
+            .and_then(|value| value.to_str().ok()),
+        Some("text/event-stream")
+    );
+
+    let bytes = tokio::time::timeout(
+        std::time::Duration::from_secs(20),
+        to_bytes(response.into_body(), usize::MAX),
+    )
+    .await
+    .expect("stream should terminate")
+    .unwrap();
+    parse_sse(&String::from_utf8_lossy(&bytes))
+}
+
+#[tokio::test]
+async fn gateway_stream_emits_tool_progress_then_final_event() {
+    let events = stream_events(scripted_agent_config().await).await;
+    let names: Vec<&str> = events.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "partial_assistant",
+            "tool_call_started",
+            "tool_result",
+            "final"
+        ]
+    );
+
+    assert_eq!(events[0].1["content"], "checking the echo tool");
+    assert_eq!(events[1].1["name"], "mock.echo");
+    assert_eq!(events[1].1["arguments"], r#"{"q":"ping"}"#);
+    assert_eq!(events[2].1["is_error"], false);
+    assert_eq!(events[2].1["output"], r#"echo:{"q":"ping"}"#);
+    assert_eq!(events[3].1["output"], "all done");
+    assert_eq!(events[3].1["session_id"], "stream");
+}
+
+fn confirm_low_trust_policy() -> OmegaToolGatePolicy {
+    OmegaToolGatePolicy {
+        auto_execute_min: OmegaToolTrustClass::Evidence,
+        ..OmegaToolGatePolicy::default()
+    }
+}
+
+#[tokio::test]
+async fn tool_gate_holds_low_trust_tool_call_for_confirmation() {
+    let mut config = scripted_agent_config().await;
+    config.tool_gate = confirm_low_trust_policy();
+    let events = stream_events(config).await;
+    let names: Vec<&str> = events.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["partial_assistant", "tool_confirmation_required", "final"]
+    );
+    assert_eq!(events[1].1["name"], "mock.echo");
+    assert!(
+        events[1].1["reason"]
+            .as_str()
+            .is_some_and(|reason| reason.contains("require_confirmation"))
+    );
+}
+
+#[tokio::test]
+async fn tool_gate_blocks_tool_below_block_floor() {
+    let mut config = scripted_agent_config().await;
+    config.tool_gate = OmegaToolGatePolicy {
+        block_below: Some(OmegaToolTrustClass::Verification),
+        ..confirm_low_trust_policy()
+    };
+    let events = stream_events(config).await;
+    let names: Vec<&str> = events.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["partial_assistant", "tool_result", "final"]);
+    assert_eq!(events[1].1["is_error"], true);
+}
+
+#[tokio::test]
+async fn approved_tool_call_executes_on_next_turn() {
+    let mut config = scripted_agent_config().await;
+    config.tool_gate = confirm_low_trust_policy();
+    let agent = Agent::from_config(config).await.expect("agent");
+    let run = |message: &'static str| {
+        let agent = &agent;
+        async move {
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            agent
+                .run_turn_with_progress("confirm", message, Some(&tx))
+                .await
+                .expect("turn");
+            drop(tx);
+            let mut events = Vec::new();
+            while let Some(event) = rx.recv().await {
+                events.push(event.name());
+            }
+            events
+        }
+    };
+
+    // Nothing is pending yet, so a pre-approval is refused and does not bypass the gate.
+    assert!(!agent.approve_tool_call("confirm", "mock.echo").await);
+
+    let held = run("ping it").await;
+    assert!(held.contains(&"tool_confirmation_required"));
+    assert!(!held.contains(&"tool_call_started"));
+    assert_eq!(
+        agent.pending_tool_confirmations("confirm").await,
+        ["mock.echo"]
+    );
+
+    assert!(agent.approve_tool_call("confirm", "mock.echo").await);
+    assert!(!agent.approve_tool_call("confirm", "mock.echo").await);
+    assert!(agent.pending_tool_confirmations("confirm").await.is_empty());
+
+    let approved = run("ping it again").await;
+    assert!(approved.contains(&"tool_call_started"));
+    assert!(!approved.contains(&"tool_confirmation_required"));
+}
+
+#[tokio::test]
+async fn gateway_health_pings_mcp_clients() {
+    let agent = Agent::from_config(scripted_agent_config().await)
+        .await
+        .expect("agent");
+    let app = router(agent, 30, None);
+
+    let response = app
+        .oneshot(Request::get("/health").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let payload: Value = serde_json::from_slice(&bytes).expect("json body");
+
+    assert_eq!(payload["status"], "healthy");
+    let clients = payload["mcp"]["clients"]
+        .as_array()
+        .expect("mcp clients should be reported");
+    assert!(!clients.is_empty());
+    for client in clients {
+        assert_eq!(client["alive"], true);
+        assert!(client["latency_ms"].is_u64());
+    }
+}
+
+#[tokio::test]
+async fn gateway_stream_rejects_invalid_request_before_streaming() {
+    let config = AgentConfig {
+        inference_url: "http://127.0.0.1:1/v1/chat/completions".to_string(),
+        ..AgentConfig::default()
+    };
+    let agent = Agent::from_config(config).await.expect("agent");
+    let app = router(agent, 30, None);
+
+    let response = app
+        .oneshot(
+            Request::post("/message/stream")
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"session_id":"stream","message":" "}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
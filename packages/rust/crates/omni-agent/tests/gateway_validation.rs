@@ -1,7 +1,10 @@
 #![allow(missing_docs)]
 
 use axum::http::StatusCode;
-use omni_agent::{MessageRequest, validate_message_request};
+use omni_agent::{
+    DEFAULT_MAX_MESSAGE_BYTES, MessageRequest, validate_message_request,
+    validate_message_request_with_limit,
+};
 
 #[test]
 fn validate_rejects_empty_session_id() {
@@ -35,3 +38,49 @@ fn validate_accepts_trimmed_values() {
     assert_eq!(session_id, "s1");
     assert_eq!(message, "hello");
 }
+
+#[test]
+fn validate_reports_structured_error_for_empty_message() {
+    let body = MessageRequest {
+        session_id: "s1".to_string(),
+        message: "\n\t ".to_string(),
+    };
+    let (status, error) = validate_message_request_with_limit(&body, 16).expect_err("err");
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error.error, "empty_message");
+    assert_eq!(error.max_message_bytes, None);
+}
+
+#[test]
+fn validate_rejects_message_over_byte_limit() {
+    let body = MessageRequest {
+        session_id: "s1".to_string(),
+        message: "é".repeat(9),
+    };
+    let (status, error) = validate_message_request_with_limit(&body, 16).expect_err("err");
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error.error, "message_too_large");
+    assert_eq!(error.max_message_bytes, Some(16));
+    assert_eq!(error.message_bytes, Some(18));
+}
+
+#[test]
+fn validate_accepts_message_at_byte_limit() {
+    let body = MessageRequest {
+        session_id: "s1".to_string(),
+        message: "a".repeat(16),
+    };
+    let (_, message) = validate_message_request_with_limit(&body, 16).expect("ok");
+    assert_eq!(message.len(), 16);
+}
+
+#[test]
+fn validate_default_limit_rejects_oversized_message() {
+    let body = MessageRequest {
+        session_id: "s1".to_string(),
+        message: "a".repeat(DEFAULT_MAX_MESSAGE_BYTES + 1),
+    };
+    let (status, message) = validate_message_request(&body).expect_err("err");
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(message.contains(&format!("limit is {DEFAULT_MAX_MESSAGE_BYTES}")));
+}
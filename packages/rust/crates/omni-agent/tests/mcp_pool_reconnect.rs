@@ -19,6 +19,7 @@ use rmcp::transport::streamable_http_server::{StreamableHttpServerConfig, Stream
 struct MockMcpServer {
     list_failures_remaining: Arc<AtomicUsize>,
     list_calls_total: Arc<AtomicUsize>,
+    ping_delay: Duration,
 }
 
 impl MockMcpServer {
@@ -50,6 +51,17 @@ impl ServerHandler for MockMcpServer {
         }
     }
 
+    fn ping(
+        &self,
+        _context: RequestContext<RoleServer>,
+    ) -> impl std::future::Future<Output = Result<(), ErrorData>> + Send + '_ {
+        let delay = self.ping_delay;
+        async move {
+            tokio::time::sleep(delay).await;
+            Ok(())
+        }
+    }
+
     fn list_tools(
         &self,
         _request: Option<PaginatedRequestParams>,
@@ -99,20 +111,21 @@ async fn spawn_mock_server_with_list_counter(
     addr: std::net::SocketAddr,
     initial_list_failures: usize,
 ) -> (tokio::task::JoinHandle<()>, Arc<AtomicUsize>) {
-    let list_failures_remaining = Arc::new(AtomicUsize::new(initial_list_failures));
-    let list_calls_total = Arc::new(AtomicUsize::new(0));
+    let server = MockMcpServer {
+        list_failures_remaining: Arc::new(AtomicUsize::new(initial_list_failures)),
+        ..MockMcpServer::default()
+    };
+    let list_calls_total = server.list_calls_total.clone();
+    (serve_mock_server(addr, server).await, list_calls_total)
+}
+
+async fn serve_mock_server(
+    addr: std::net::SocketAddr,
+    server: MockMcpServer,
+) -> tokio::task::JoinHandle<()> {
     let service: StreamableHttpService<MockMcpServer, LocalSessionManager> =
         StreamableHttpService::new(
-            {
-                let list_failures_remaining = list_failures_remaining.clone();
-                let list_calls_total = list_calls_total.clone();
-                move || {
-                    Ok(MockMcpServer {
-                        list_failures_remaining: list_failures_remaining.clone(),
-                        list_calls_total: list_calls_total.clone(),
-                    })
-                }
-            },
+            move || Ok(server.clone()),
             Arc::new(LocalSessionManager::default()),
             StreamableHttpServerConfig {
                 stateful_mode: true,
@@ -124,12 +137,9 @@ async fn spawn_mock_server_with_list_counter(
     let listener = tokio::net::TcpListener::bind(addr)
         .await
         .expect("bind mock mcp listener");
-    (
-        tokio::spawn(async move {
-            let _ = axum::serve(listener, router).await;
-        }),
-        list_calls_total,
-    )
+    tokio::spawn(async move {
+        let _ = axum::serve(listener, router).await;
+    })
 }
 
 fn reconnect_test_config(pool_size: usize) -> McpPoolConnectConfig {
@@ -282,3 +292,68 @@ async fn mcp_pool_list_tools_falls_back_to_next_client_on_non_transport_error()
     handle.abort();
     let _ = handle.await;
 }
+
+#[tokio::test]
+async fn mcp_pool_ping_clients_reports_latency_then_dead_connections() {
+    let addr = reserve_local_addr().await;
+    let handle = spawn_mock_server(addr, 0).await;
+    let url = format!("http://{addr}/sse");
+    let pool = connect_pool(&url, reconnect_test_config(2))
+        .await
+        .expect("connect pool");
+
+    let healthy = pool.ping_clients().await;
+    assert_eq!(healthy.len(), 2);
+    for health in &healthy {
+        assert!(
+            health.alive,
+            "client {} should be alive",
+            health.client_index
+        );
+        assert!(health.latency_ms.is_some());
+        assert!(health.error.is_none());
+    }
+
+    handle.abort();
+    let _ = handle.await;
+
+    let dead = pool.ping_clients().await;
+    assert_eq!(dead.len(), 2);
+    for health in &dead {
+        assert!(
+            !health.alive,
+            "client {} should be dead",
+            health.client_index
+        );
+        assert!(health.latency_ms.is_none());
+        assert!(health.error.is_some());
+    }
+}
+
+#[tokio::test]
+async fn mcp_pool_ping_clients_share_one_deadline() {
+    let addr = reserve_local_addr().await;
+    let handle = serve_mock_server(
+        addr,
+        MockMcpServer {
+            ping_delay: Duration::from_secs(30),
+            ..MockMcpServer::default()
+        },
+    )
+    .await;
+    let url = format!("http://{addr}/sse");
+    let pool = connect_pool(&url, reconnect_test_config(3))
+        .await
+        .expect("connect pool");
+
+    let started = std::time::Instant::now();
+    let stalled = pool.ping_clients().await;
+    let elapsed = started.elapsed();
+    assert_eq!(stalled.len(), 3);
+    assert!(stalled.iter().all(|health| !health.alive));
+    assert!(
+        elapsed < Duration::from_secs(6),
+        "stalled pings should time out together, took {elapsed:?}"
+    );
+    handle.abort();
+}
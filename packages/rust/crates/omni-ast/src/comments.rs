@@ -0,0 +1,77 @@
+//! Comment and docstring location.
+//!
+//! Used to tell prose (comments, Python docstrings) apart from code, e.g. so
+//! secret scanning can skip placeholder keys in example comments.
+
+use std::ops::Range;
+
+use anyhow::{Context, Result};
+
+use crate::lang::Lang;
+use crate::re_exports::{LanguageExt, SupportLang};
+
+/// Byte ranges of all comments in `content`, plus docstrings for Python.
+///
+/// A Python docstring is any string literal that forms a statement on its own.
+/// Ranges are sorted and non-overlapping.
+///
+/// # Errors
+/// Returns an error when the language is not supported by ast-grep.
+pub fn comment_ranges(content: &str, lang: Lang) -> Result<Vec<Range<usize>>> {
+    let lang_str = lang.as_str();
+    let support_lang: SupportLang = lang_str
+        .parse()
+        .with_context(|| format!("Failed to parse language: {lang_str}"))?;
+    let grep_result = support_lang.ast_grep(content);
+    let root_node = grep_result.root();
+
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for node in root_node.dfs() {
+        let kind = node.kind();
+        let is_comment = kind.contains("comment");
+        let is_docstring = lang == Lang::Python
+            && kind == "string"
+            && node.parent().is_some_and(|parent| {
+                parent.kind() == "expression_statement"
+                    && parent
+                        .children()
+                        .filter(ast_grep_core::Node::is_named)
+                        .count()
+                        == 1
+            });
+        if is_comment || is_docstring {
+            ranges.push(node.range());
+        }
+    }
+
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_python_comments_and_docstrings() {
+        let content = "# top\ndef f():\n    \"\"\"Doc.\"\"\"\n    x = \"value\"  # trailing\n";
+        let ranges = comment_ranges(content, Lang::Python).unwrap();
+        let texts: Vec<&str> = ranges.iter().map(|r| &content[r.clone()]).collect();
+        assert_eq!(texts, vec!["# top", "\"\"\"Doc.\"\"\"", "# trailing"]);
+    }
+
+    #[test]
+    fn test_rust_line_and_block_comments() {
+        let content = "// line\nfn main() { /* block */ let s = \"text\"; }\n";
+        let ranges = comment_ranges(content, Lang::Rust).unwrap();
+        let texts: Vec<&str> = ranges.iter().map(|r| &content[r.clone()]).collect();
+        assert_eq!(texts, vec!["// line", "/* block */"]);
+    }
+}
@@ -90,16 +90,20 @@ impl Lang {
     }
 
     /// Try to detect language from file extension
+    ///
+    /// Accepts anything path-like, so `Lang::from_path("a.rs")` works directly.
     #[must_use]
-    pub fn from_path(path: &Path) -> Option<Self> {
-        let ext = path.extension()?.to_str()?.to_lowercase();
-        Self::from_extension(&ext)
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let ext = path.as_ref().extension()?.to_str()?;
+        Self::from_extension(ext)
     }
 
     /// Try to detect language from extension string
+    ///
+    /// Matching is case-insensitive and tolerates a leading dot (`".PY"`).
     #[must_use]
     pub fn from_extension(ext: &str) -> Option<Self> {
-        match ext {
+        match ext.trim_start_matches('.').to_lowercase().as_str() {
             "py" => Some(Self::Python),
             "rs" => Some(Self::Rust),
             "js" | "mjs" => Some(Self::JavaScript),
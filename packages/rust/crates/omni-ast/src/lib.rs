@@ -29,6 +29,7 @@
 // ============================================================================
 
 mod chunk;
+mod comments;
 mod extract;
 mod item;
 mod lang;
@@ -52,7 +53,10 @@ pub use lang::Lang;
 pub use item::Match;
 
 // Scan functions (both direct and re-exported from scan module)
-pub use scan::{extract, pattern, scan, scan_with_lang};
+pub use scan::{
+    BoundedScan, ScanLimits, extract, pattern, scan, scan_bounded, scan_with_captures,
+    scan_with_lang,
+};
 
 // Python utilities
 pub use python::{
@@ -70,6 +74,9 @@ pub use extract::{ExtractResult, extract_items, extract_skeleton, get_skeleton_p
 // Code chunking utilities
 pub use chunk::{CodeChunk, chunk_code};
 
+// Comment and docstring ranges
+pub use comments::comment_ranges;
+
 // Tree-sitter based Python parser for robust decorator extraction
 pub use python_tree_sitter::{
     DecoratedFunction, DecoratorArguments, DecoratorInfo, ParameterInfo, TreeSitterPythonParser,
@@ -2,11 +2,15 @@
 //!
 //! Provides high-level functions for creating patterns and scanning content.
 
+use std::collections::HashMap;
+
 use anyhow::{Context, Result};
 
 use crate::item::Match;
 use crate::lang::Lang;
-use crate::re_exports::{LanguageExt, MatcherExt, MetaVariable, Pattern, SupportLang};
+use crate::re_exports::{
+    LanguageExt, MatcherExt, MetaVariable, NodeMatch, Pattern, StrDoc, SupportLang,
+};
 
 /// Create a search pattern for a language
 ///
@@ -21,6 +25,59 @@ pub fn pattern(pattern: &str, lang: Lang) -> Result<Pattern> {
         .with_context(|| format!("Failed to parse pattern: {pattern}"))
 }
 
+/// Traversal limits for [`scan_bounded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanLimits {
+    /// Maximum number of AST nodes visited before traversal stops
+    pub max_nodes: usize,
+    /// Maximum number of matches collected before traversal stops
+    pub max_matches: usize,
+}
+
+impl Default for ScanLimits {
+    fn default() -> Self {
+        Self {
+            max_nodes: 1_000_000,
+            max_matches: 10_000,
+        }
+    }
+}
+
+/// Result of a [`scan_bounded`] call
+#[derive(Debug, Clone)]
+pub struct BoundedScan {
+    /// Matches found before any limit was reached
+    pub matches: Vec<Match>,
+    /// Number of AST nodes visited
+    pub nodes_visited: usize,
+    /// Whether nodes or matches beyond a limit were left unscanned or dropped
+    pub truncated: bool,
+}
+
+/// Build a `Match` from an ast-grep node match, extracting named captures.
+fn to_match(m: &NodeMatch<'_, StrDoc<SupportLang>>) -> Match {
+    let env = m.get_env();
+
+    // Extract captures using MetaVariable API
+    let mut captures = Vec::new();
+    for mv in env.get_matched_variables() {
+        let name = match &mv {
+            MetaVariable::Capture(name, _) | MetaVariable::MultiCapture(name) => name.as_str(),
+            _ => continue,
+        };
+        if let Some(captured) = env.get_match(name) {
+            captures.push((name.to_string(), captured.text().to_string()));
+        }
+    }
+
+    Match {
+        text: m.text().to_string(),
+        start: m.range().start,
+        end: m.range().end,
+        captures,
+    }
+}
+
 /// Scan content and find all matches for a pattern
 ///
 /// # Errors
@@ -40,34 +97,86 @@ pub fn scan(content: &str, pat: &str, lang: Lang) -> Result<Vec<Match>> {
 
     for node in root_node.dfs() {
         if let Some(m) = search_pattern.match_node(node.clone()) {
-            let env = m.get_env();
-
-            // Extract captures using MetaVariable API
-            let mut captures = Vec::new();
-            for mv in env.get_matched_variables() {
-                let name = match &mv {
-                    MetaVariable::Capture(name, _) | MetaVariable::MultiCapture(name) => {
-                        name.as_str()
-                    }
-                    _ => continue,
-                };
-                if let Some(captured) = env.get_match(name) {
-                    captures.push((name.to_string(), captured.text().to_string()));
-                }
-            }
-
-            matches.push(Match {
-                text: m.text().to_string(),
-                start: m.range().start,
-                end: m.range().end,
-                captures,
-            });
+            matches.push(to_match(&m));
         }
     }
 
     Ok(matches)
 }
 
+/// Scan content like [`scan`], stopping once `limits` are reached
+///
+/// Guards against pathologically large or deeply nested inputs: traversal
+/// halts after `max_nodes` nodes or `max_matches` matches, and the result
+/// reports whether it was truncated.
+///
+/// # Errors
+/// Returns an error when the language or pattern cannot be parsed.
+pub fn scan_bounded(
+    content: &str,
+    pat: &str,
+    lang: Lang,
+    limits: ScanLimits,
+) -> Result<BoundedScan> {
+    let lang_str = lang.as_str();
+    let support_lang: SupportLang = lang_str
+        .parse()
+        .with_context(|| format!("Failed to parse language: {lang_str}"))?;
+    let grep_result = support_lang.ast_grep(content);
+    let root_node = grep_result.root();
+
+    let search_pattern = Pattern::try_new(pat, support_lang)
+        .with_context(|| format!("Failed to parse pattern: {pat}"))?;
+
+    let mut matches = Vec::new();
+    let mut nodes_visited = 0;
+    let mut truncated = false;
+
+    for node in root_node.dfs() {
+        if nodes_visited >= limits.max_nodes {
+            truncated = true;
+            break;
+        }
+        nodes_visited += 1;
+        if let Some(m) = search_pattern.match_node(node) {
+            // Only a match past the cap means results were dropped.
+            if matches.len() >= limits.max_matches {
+                truncated = true;
+                break;
+            }
+            matches.push(to_match(&m));
+        }
+    }
+
+    Ok(BoundedScan {
+        matches,
+        nodes_visited,
+        truncated,
+    })
+}
+
+/// Scan content and pair each match with its named captures
+///
+/// Captures are keyed by metavariable name (without the `$`), e.g. `NAME`
+/// for `def $NAME($$$)`.
+///
+/// # Errors
+/// Returns an error when the language or pattern cannot be parsed.
+pub fn scan_with_captures(
+    content: &str,
+    pat: &str,
+    lang: Lang,
+) -> Result<Vec<(Match, HashMap<String, String>)>> {
+    let matches = scan(content, pat, lang)?;
+    Ok(matches
+        .into_iter()
+        .map(|m| {
+            let captures = m.captures.iter().cloned().collect();
+            (m, captures)
+        })
+        .collect())
+}
+
 /// Extract a single capture value from pattern matches
 #[must_use]
 pub fn extract(content: &str, pattern: &str, var: &str, lang: Lang) -> Option<String> {
@@ -97,27 +206,7 @@ pub fn scan_with_lang(content: &str, pat: &str, support_lang: SupportLang) -> Re
 
     for node in root_node.dfs() {
         if let Some(m) = search_pattern.match_node(node.clone()) {
-            let env = m.get_env();
-
-            let mut captures = Vec::new();
-            for mv in env.get_matched_variables() {
-                let name = match &mv {
-                    MetaVariable::Capture(name, _) | MetaVariable::MultiCapture(name) => {
-                        name.as_str()
-                    }
-                    _ => continue,
-                };
-                if let Some(captured) = env.get_match(name) {
-                    captures.push((name.to_string(), captured.text().to_string()));
-                }
-            }
-
-            matches.push(Match {
-                text: m.text().to_string(),
-                start: m.range().start,
-                end: m.range().end,
-                captures,
-            });
+            matches.push(to_match(&m));
         }
     }
 
@@ -157,6 +246,61 @@ def goodbye():
         let name = extract(content, "def $NAME($ARGS)", "NAME", Lang::Python);
         assert_eq!(name, Some("hello".to_string()));
     }
+
+    #[test]
+    fn test_scan_with_captures() {
+        let content = "def alpha(a, b):\n    pass\n\ndef beta():\n    pass\n";
+        let results = scan_with_captures(content, "def $NAME($$$)", Lang::Python).unwrap();
+        let names: Vec<&str> = results
+            .iter()
+            .map(|(_, captures)| captures.get("NAME").map_or("", String::as_str))
+            .collect();
+        assert_eq!(names, vec!["alpha", "beta"]);
+        assert!(results[0].0.text.starts_with("def alpha"));
+    }
+
+    #[test]
+    fn test_scan_bounded_stops_at_node_limit() {
+        let content: String = (0..2_000).map(|i| format!("x{i} = call({i})\n")).collect();
+        let limits = ScanLimits {
+            max_nodes: 500,
+            max_matches: usize::MAX,
+        };
+
+        let bounded = scan_bounded(&content, "call($A)", Lang::Python, limits).unwrap();
+        assert!(bounded.truncated);
+        assert_eq!(bounded.nodes_visited, 500);
+        assert!(!bounded.matches.is_empty());
+        assert!(bounded.matches.len() < 2_000);
+
+        let full = scan_bounded(&content, "call($A)", Lang::Python, ScanLimits::default()).unwrap();
+        assert!(!full.truncated);
+        assert_eq!(full.matches.len(), 2_000);
+    }
+
+    #[test]
+    fn test_scan_bounded_stops_at_match_limit() {
+        let content = "call(1)\ncall(2)\ncall(3)\n";
+        let limits = ScanLimits {
+            max_nodes: usize::MAX,
+            max_matches: 2,
+        };
+        let bounded = scan_bounded(content, "call($A)", Lang::Python, limits).unwrap();
+        assert!(bounded.truncated);
+        assert_eq!(bounded.matches.len(), 2);
+    }
+
+    #[test]
+    fn test_scan_bounded_exact_match_cap_is_not_truncated() {
+        let content = "call(1)\ncall(2)\nx = 3\n";
+        let limits = ScanLimits {
+            max_nodes: usize::MAX,
+            max_matches: 2,
+        };
+        let bounded = scan_bounded(content, "call($A)", Lang::Python, limits).unwrap();
+        assert!(!bounded.truncated);
+        assert_eq!(bounded.matches.len(), 2);
+    }
 }
 
 #[cfg(test)]
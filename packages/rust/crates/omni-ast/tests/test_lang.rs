@@ -23,3 +23,16 @@ fn test_extensions() {
     assert_eq!(Lang::Python.extensions(), vec!["py"]);
     assert!(Lang::JavaScript.extensions().contains(&"js"));
 }
+
+#[test]
+fn test_from_path() {
+    assert_eq!(Lang::from_path("a.rs"), Some(Lang::Rust));
+    assert_eq!(Lang::from_path("src/main.py"), Some(Lang::Python));
+    assert_eq!(Lang::from_path("web/app.ts"), Some(Lang::TypeScript));
+    assert_eq!(Lang::from_path("cmd/main.go"), Some(Lang::Go));
+    assert_eq!(Lang::from_path("Main.JAVA"), Some(Lang::Java));
+    assert_eq!(Lang::from_path("index.js"), Some(Lang::JavaScript));
+    assert_eq!(Lang::from_path("notes.xyz"), None);
+    assert_eq!(Lang::from_path("Makefile"), None);
+    assert_eq!(Lang::from_extension(".rs"), Some(Lang::Rust));
+}
@@ -7,6 +7,7 @@
 //! processes thousands of files concurrently.
 
 use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
 use std::path::Path;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
@@ -29,6 +30,8 @@ pub struct BatchRefactorStats {
     pub errors: HashMap<String, String>,
     /// List of modified files
     pub modified_files: Vec<String>,
+    /// Replacement count per modified file (path -> count)
+    pub file_replacements: HashMap<String, usize>,
 }
 
 impl BatchRefactorStats {
@@ -41,8 +44,45 @@ impl BatchRefactorStats {
             replacements: 0,
             errors: HashMap::new(),
             modified_files: Vec::new(),
+            file_replacements: HashMap::new(),
         }
     }
+
+    /// Build a machine-readable report of this run.
+    ///
+    /// Per-file counts and errors are sorted by path so reports from
+    /// identical runs are byte-for-byte comparable.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut files: Vec<_> = self.file_replacements.iter().collect();
+        files.sort();
+        let mut errors: Vec<_> = self.errors.iter().collect();
+        errors.sort();
+
+        serde_json::json!({
+            "files_scanned": self.files_scanned,
+            "files_changed": self.files_changed,
+            "replacements": self.replacements,
+            "files": files
+                .into_iter()
+                .map(|(path, count)| serde_json::json!({ "path": path, "replacements": count }))
+                .collect::<Vec<_>>(),
+            "errors": errors
+                .into_iter()
+                .map(|(path, message)| serde_json::json!({ "path": path, "error": message }))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    /// Write the JSON report from [`Self::to_json`] to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the report cannot be serialized or written.
+    pub fn write_report<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let report = serde_json::to_string_pretty(&self.to_json())?;
+        std::fs::write(path, report)
+    }
 }
 
 /// Configuration for batch refactoring.
@@ -142,39 +182,26 @@ impl StructuralEditor {
             })
             .collect();
 
-        // Process files in parallel
+        // Process files in parallel. Each file is isolated: a read, parse or
+        // write failure (or a panic inside the parser) is recorded against
+        // that file and the rest of the batch keeps going.
         files.into_par_iter().for_each(|path| {
             files_scanned.fetch_add(1, Ordering::Relaxed);
 
-            let content = match std::fs::read_to_string(&path) {
-                Ok(c) => c,
-                Err(e) => {
-                    errors.insert(path.display().to_string(), format!("Read error: {e}"));
-                    return;
-                }
-            };
-
-            let lang = detect_language(&path);
-
-            match StructuralEditor::replace(&content, search_pattern, rewrite_pattern, &lang) {
-                Ok(result) => {
-                    if result.count > 0 {
-                        files_changed.fetch_add(1, Ordering::Relaxed);
-                        total_replacements.fetch_add(result.count, Ordering::Relaxed);
-                        modified_files.insert(path.display().to_string(), result.count);
-
-                        if !config.dry_run {
-                            if let Err(e) = std::fs::write(&path, &result.modified) {
-                                errors.insert(
-                                    path.display().to_string(),
-                                    format!("Write error: {e}"),
-                                );
-                            }
-                        }
-                    }
+            let outcome = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                process_file(&path, search_pattern, rewrite_pattern, config.dry_run)
+            }))
+            .unwrap_or_else(|panic| Err(format!("Panic: {}", panic_message(panic.as_ref()))));
+
+            match outcome {
+                Ok(0) => {}
+                Ok(count) => {
+                    files_changed.fetch_add(1, Ordering::Relaxed);
+                    total_replacements.fetch_add(count, Ordering::Relaxed);
+                    modified_files.insert(path.display().to_string(), count);
                 }
-                Err(e) => {
-                    errors.insert(path.display().to_string(), format!("Edit error: {e}"));
+                Err(message) => {
+                    errors.insert(path.display().to_string(), message);
                 }
             }
         });
@@ -183,13 +210,44 @@ impl StructuralEditor {
         stats.files_scanned = files_scanned.load(Ordering::Relaxed);
         stats.files_changed = files_changed.load(Ordering::Relaxed);
         stats.replacements = total_replacements.load(Ordering::Relaxed);
-        stats.modified_files = modified_files.into_iter().map(|(k, _)| k).collect();
+        stats.file_replacements = modified_files.into_iter().collect();
+        stats.modified_files = stats.file_replacements.keys().cloned().collect();
         stats.errors = errors.into_iter().collect();
 
         stats
     }
 }
 
+/// Refactor a single file, returning the number of replacements made.
+fn process_file(
+    path: &Path,
+    search_pattern: &str,
+    rewrite_pattern: &str,
+    dry_run: bool,
+) -> Result<usize, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| format!("Read error: {e}"))?;
+    let lang = detect_language(path);
+
+    let result = StructuralEditor::replace(&content, search_pattern, rewrite_pattern, &lang)
+        .map_err(|e| format!("Edit error: {e}"))?;
+
+    if result.count > 0 && !dry_run {
+        std::fs::write(path, &result.modified).map_err(|e| format!("Write error: {e}"))?;
+    }
+    Ok(result.count)
+}
+
+/// Extract a readable message from a caught panic payload.
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
 /// Check if a path matches a glob pattern (simplified implementation).
 fn matches_glob(path: &Path, pattern: &str) -> bool {
     let path_str = path.to_string_lossy();
@@ -17,10 +17,39 @@ use omni_ast::{Doc, MetaVariable};
 ///
 /// # Returns
 /// The replacement text with all captures substituted.
+#[must_use]
 pub fn substitute_captures<D: Doc>(
     replacement: &str,
     env: &omni_ast::MetaVarEnv<D>,
     original_text: &str,
+) -> String {
+    substitute_with_source(replacement, env, original_text, None)
+}
+
+/// Substitute captured variables, taking variadic captures from `source`.
+///
+/// Like [`substitute_captures`], but a named `$$$NAME` capture spans the source
+/// from its first to its last captured node, and multi-line captures (e.g. a
+/// `$$$BODY` block) are re-indented to the indentation of the line holding the
+/// placeholder in `replacement`.
+///
+/// # Arguments
+/// * `source` - Full source the match was taken from
+#[must_use]
+pub fn substitute_captures_in_source<D: Doc>(
+    replacement: &str,
+    env: &omni_ast::MetaVarEnv<D>,
+    original_text: &str,
+    source: &str,
+) -> String {
+    substitute_with_source(replacement, env, original_text, Some(source))
+}
+
+fn substitute_with_source<D: Doc>(
+    replacement: &str,
+    env: &omni_ast::MetaVarEnv<D>,
+    original_text: &str,
+    source: Option<&str>,
 ) -> String {
     let mut new_text = replacement.to_string();
 
@@ -33,6 +62,25 @@ pub fn substitute_captures<D: Doc>(
         };
 
         if let Some(name) = capture_name {
+            if let (true, Some(source)) = (is_multi, source) {
+                let nodes = env.get_multiple_matches(&name);
+                if let (Some(first), Some(last)) = (nodes.first(), nodes.last()) {
+                    let start = first.range().start;
+                    let captured_text = &source[start..last.range().end];
+                    let base_col = start - source[..start].rfind('\n').map_or(0, |i| i + 1);
+
+                    let multi_placeholder = format!("$$${}", name);
+                    if new_text.contains(&multi_placeholder) {
+                        new_text = replace_reindented(
+                            &new_text,
+                            &multi_placeholder,
+                            captured_text,
+                            base_col,
+                        );
+                    }
+                    continue;
+                }
+            }
             if let Some(captured) = env.get_match(&name) {
                 let captured_text = captured.text().to_string();
 
@@ -62,6 +110,43 @@ pub fn substitute_captures<D: Doc>(
     new_text
 }
 
+/// Replace every `placeholder` in `template` with `text`, re-indenting the
+/// continuation lines of `text` from `base_col` to the placeholder's line indent.
+fn replace_reindented(template: &str, placeholder: &str, text: &str, base_col: usize) -> String {
+    let mut out = String::with_capacity(template.len() + text.len());
+    let mut rest = template;
+    while let Some(idx) = rest.find(placeholder) {
+        let before = &rest[..idx];
+        out.push_str(before);
+
+        let line_prefix = out.rsplit('\n').next().unwrap_or("");
+        let indent_len = line_prefix.len() - line_prefix.trim_start().len();
+        let indent = line_prefix[..indent_len].to_string();
+
+        for (i, line) in text.split('\n').enumerate() {
+            if i == 0 {
+                out.push_str(line);
+                continue;
+            }
+            out.push('\n');
+            // Strip up to `base_col` bytes of leading whitespace, never splitting a character.
+            let cut = line
+                .char_indices()
+                .find(|&(i, c)| !c.is_whitespace() || i + c.len_utf8() > base_col)
+                .map_or(line.len(), |(i, _)| i);
+            let dedented = &line[cut..];
+            if !dedented.is_empty() {
+                out.push_str(&indent);
+                out.push_str(dedented);
+            }
+        }
+
+        rest = &rest[idx + placeholder.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
 /// Fallback extraction for variadic patterns from original text.
 ///
 /// Extracts content between parentheses to substitute remaining $$$ patterns.
@@ -3,15 +3,17 @@
 //! Provides AST-based code modification using ast-grep patterns.
 //! Part of The Surgeon.
 
+use std::fmt::Write as _;
 use std::path::Path;
 use std::str::FromStr;
 
 // Use omni-ast for unified ast-grep (re-exports Pattern, SupportLang, LanguageExt)
 use omni_ast::{AstLanguage, LanguageExt, MatcherExt, Pattern, SupportLang};
 
-use crate::capture::substitute_captures;
+use crate::capture::substitute_captures_in_source;
 use crate::diff::generate_unified_diff;
 use crate::error::EditError;
+use crate::language::{language_name, supported_languages};
 use crate::types::{EditConfig, EditLocation, EditResult};
 
 /// StructuralEditor - AST-based code modification engine.
@@ -70,7 +72,12 @@ impl StructuralEditor {
                 let end_byte = m.range().end;
                 let original_text = m.text().to_string();
 
-                let new_text = substitute_captures(replacement, m.get_env(), &original_text);
+                let new_text = substitute_captures_in_source(
+                    replacement,
+                    m.get_env(),
+                    &original_text,
+                    content,
+                );
                 matches.push((start_byte, end_byte, original_text, new_text));
             }
         }
@@ -82,11 +89,25 @@ impl StructuralEditor {
                 count: 0,
                 diff: String::new(),
                 edits: Vec::new(),
+                language: language_name(lang),
+                language_detected: false,
             });
         }
 
-        // Sort by position in reverse order
-        matches.sort_by(|a, b| b.0.cmp(&a.0));
+        // Keep the outermost of overlapping or nested matches: the outer rewrite
+        // already carries the inner text through its captures.
+        matches.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+        let mut kept_end = 0;
+        matches.retain(|(start, end, _, _)| {
+            let keep = *start >= kept_end;
+            if keep {
+                kept_end = *end;
+            }
+            keep
+        });
+
+        // Apply in reverse order so earlier byte offsets stay valid
+        matches.reverse();
 
         // Apply replacements
         let mut modified = content.to_string();
@@ -116,6 +137,8 @@ impl StructuralEditor {
             count: edits.len(),
             diff,
             edits,
+            language: language_name(lang),
+            language_detected: false,
         })
     }
 
@@ -140,22 +163,17 @@ impl StructuralEditor {
         let path = path.as_ref();
         let content = omni_io::read_text_safe(path, config.max_file_size)?;
 
-        let lang_str = match language {
-            Some(l) => l.to_string(),
-            None => {
-                if let Some(lang) = SupportLang::from_path(path) {
-                    format!("{lang:?}").to_lowercase()
-                } else {
-                    let ext = path
-                        .extension()
-                        .map(|e| e.to_string_lossy().to_string())
-                        .unwrap_or_else(|| "unknown".to_string());
-                    return Err(EditError::UnsupportedLanguage(ext));
-                }
-            }
+        // An explicit language always wins over path-based detection.
+        let (lang_str, detected) = match language {
+            Some(l) => (l.to_string(), false),
+            None => match SupportLang::from_path(path) {
+                Some(lang) => (language_name(lang), true),
+                None => return Err(EditError::LanguageDetection(path.display().to_string())),
+            },
         };
 
-        let result = Self::replace(&content, pattern, replacement, &lang_str)?;
+        let mut result = Self::replace(&content, pattern, replacement, &lang_str)?;
+        result.language_detected = detected;
 
         if !config.preview_only && result.count > 0 {
             std::fs::write(path, &result.modified)
@@ -165,6 +183,12 @@ impl StructuralEditor {
         Ok(result)
     }
 
+    /// Lowercase names of every language accepted by the editor.
+    #[must_use]
+    pub fn supported_languages() -> Vec<String> {
+        supported_languages()
+    }
+
     /// Preview structural replace (no file modification).
     ///
     /// Convenience method that always previews without modifying files.
@@ -214,9 +238,15 @@ impl StructuralEditor {
         let mut output = String::new();
 
         if let Some(p) = path {
-            output.push_str(&format!("// EDIT: {}\n", p));
+            let _ = writeln!(output, "// EDIT: {p}");
         }
-        output.push_str(&format!("// Replacements: {}\n", result.count));
+        let _ = writeln!(output, "// Replacements: {}", result.count);
+        let detected = if result.language_detected {
+            " (auto-detected)"
+        } else {
+            ""
+        };
+        let _ = writeln!(output, "// Language: {}{}", result.language, detected);
 
         if result.count == 0 {
             output.push_str("[No matches found]\n");
@@ -225,10 +255,11 @@ impl StructuralEditor {
 
         output.push_str("\n// Changes:\n");
         for edit in &result.edits {
-            output.push_str(&format!(
-                "L{}: \"{}\" -> \"{}\"\n",
+            let _ = writeln!(
+                output,
+                "L{}: \"{}\" -> \"{}\"",
                 edit.line, edit.original_text, edit.new_text
-            ));
+            );
         }
 
         output.push_str("\n// Diff:\n");
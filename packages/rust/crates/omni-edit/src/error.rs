@@ -5,6 +5,8 @@
 use omni_io::IoError;
 use thiserror::Error;
 
+use crate::language::supported_languages;
+
 /// Error types for edit operations.
 ///
 /// Each variant represents a specific failure mode in the editing pipeline.
@@ -23,9 +25,19 @@ pub enum EditError {
     Pattern(String),
 
     /// Language not supported by ast-grep.
-    #[error("Unsupported language: {0}")]
+    #[error(
+        "Unsupported language: {0} (supported: {supported})",
+        supported = supported_languages().join(", ")
+    )]
     UnsupportedLanguage(String),
 
+    /// Language could not be auto-detected from the file path.
+    #[error(
+        "Cannot detect language for {0}; pass a language explicitly (supported: {supported})",
+        supported = supported_languages().join(", ")
+    )]
+    LanguageDetection(String),
+
     /// Replacement operation failed.
     #[error("Replacement error: {0}")]
     Replacement(String),
@@ -0,0 +1,16 @@
+//! Language names shared by the editor and its error messages.
+
+use omni_ast::SupportLang;
+
+/// Lowercase display name of a language (e.g. `python`).
+pub(crate) fn language_name(lang: SupportLang) -> String {
+    format!("{lang:?}").to_lowercase()
+}
+
+/// Lowercase names of every language accepted by the editor.
+pub(crate) fn supported_languages() -> Vec<String> {
+    SupportLang::all_langs()
+        .iter()
+        .map(|lang| language_name(*lang))
+        .collect()
+}
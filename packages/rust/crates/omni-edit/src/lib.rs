@@ -21,6 +21,7 @@
 //! ├── types.rs    # EditResult, EditLocation, EditConfig
 //! ├── diff.rs     # Diff generation utilities
 //! ├── capture.rs  # Variable capture substitution
+//! ├── language.rs # Language names for results and errors
 //! ├── editor.rs   # StructuralEditor implementation
 //! └── batch.rs    # Batch refactoring with rayon (The Ouroboros)
 //! ```
@@ -60,12 +61,14 @@ mod capture;
 pub mod diff;
 mod editor;
 mod error;
+mod language;
 mod types; // Heavy-duty batch refactoring
 
 // ============================================================================
 // Public Re-exports
 // ============================================================================
 
+pub use capture::{substitute_captures, substitute_captures_in_source};
 pub use editor::StructuralEditor;
 pub use error::EditError;
 pub use types::{EditConfig, EditLocation, EditResult};
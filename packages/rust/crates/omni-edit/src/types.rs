@@ -19,6 +19,10 @@ pub struct EditResult {
     pub diff: String,
     /// Individual edit locations.
     pub edits: Vec<EditLocation>,
+    /// Language the content was parsed as (lowercase, e.g. `python`).
+    pub language: String,
+    /// Whether `language` was auto-detected from the file path.
+    pub language_detected: bool,
 }
 
 /// Location of an individual edit within a file.
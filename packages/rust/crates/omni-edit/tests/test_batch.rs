@@ -123,3 +123,99 @@ fn matches_glob(path: &Path, pattern: &str) -> bool {
         path_str == pattern
     }
 }
+
+#[test]
+fn test_batch_report_json() {
+    let dir = TempDir::new().expect("Create temp dir");
+    File::create(dir.path().join("a.py"))
+        .expect("Create a.py")
+        .write_all(b"old_func(a)\nold_func(b)\n")
+        .expect("Write a.py");
+    File::create(dir.path().join("b.py"))
+        .expect("Create b.py")
+        .write_all(b"other(a)\n")
+        .expect("Write b.py");
+
+    let stats = StructuralEditor::batch_replace(
+        dir.path(),
+        "old_func($$$ARGS)",
+        "new_func($$$ARGS)",
+        &BatchConfig {
+            file_pattern: "**/*.py".to_string(),
+            dry_run: true,
+            ..Default::default()
+        },
+    );
+
+    let report_path = dir.path().join("report.json");
+    stats.write_report(&report_path).expect("Write report");
+    let report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).expect("Read report"))
+            .expect("Parse report");
+
+    assert_eq!(report["files_scanned"], 2);
+    assert_eq!(report["files_changed"], 1);
+    assert_eq!(report["replacements"], 2);
+    let files = report["files"].as_array().expect("files array");
+    assert_eq!(files.len(), 1);
+    assert!(
+        files[0]["path"]
+            .as_str()
+            .is_some_and(|p| p.ends_with("a.py"))
+    );
+    assert_eq!(files[0]["replacements"], 2);
+    assert_eq!(report["errors"], serde_json::json!([]));
+    assert_eq!(stats.to_json(), report);
+}
+
+#[test]
+fn test_batch_replace_continues_past_bad_file() {
+    let dir = TempDir::new().expect("Create temp dir");
+    let good1 = dir.path().join("good1.py");
+    let good2 = dir.path().join("good2.py");
+    let bad = dir.path().join("bad.py");
+
+    File::create(&good1)
+        .expect("Create good1")
+        .write_all(b"old_func(a)\n")
+        .expect("Write good1");
+    File::create(&good2)
+        .expect("Create good2")
+        .write_all(b"old_func(b)\n")
+        .expect("Write good2");
+    // Invalid UTF-8 cannot be read as source text.
+    File::create(&bad)
+        .expect("Create bad")
+        .write_all(b"old_func(\xff)\n")
+        .expect("Write bad");
+
+    let stats = StructuralEditor::batch_replace(
+        dir.path(),
+        "old_func($$$ARGS)",
+        "f($$$ARGS)",
+        &BatchConfig {
+            file_pattern: "**/*.py".to_string(),
+            dry_run: false,
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(stats.files_scanned, 3);
+    assert_eq!(stats.files_changed, 2);
+    assert_eq!(stats.errors.len(), 1);
+    assert!(
+        stats.errors[&bad.display().to_string()].starts_with("Read error: "),
+        "bad file should fail to read: {:?}",
+        stats.errors
+    );
+    assert_eq!(std::fs::read(&bad).expect("Read bad"), b"old_func(\xff)\n");
+
+    assert_eq!(
+        std::fs::read_to_string(&good1).expect("Read good1"),
+        "f(a)\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(&good2).expect("Read good2"),
+        "f(b)\n"
+    );
+}
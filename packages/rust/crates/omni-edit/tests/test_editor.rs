@@ -4,7 +4,7 @@ use std::fs::File;
 use std::io::Write;
 use tempfile::TempDir;
 
-use omni_edit::StructuralEditor;
+use omni_edit::{EditError, StructuralEditor};
 
 #[test]
 fn test_simple_replace() {
@@ -42,6 +42,18 @@ def foo():
     assert_eq!(result.modified.matches("safe_connect").count(), 3);
 }
 
+#[test]
+fn test_nested_matches_keep_outer_rewrite() {
+    let content = "old_func(old_func(a))\nold_func(b)\n";
+    let result = StructuralEditor::replace(content, "old_func($$$ARGS)", "f($$$ARGS)", "python")
+        .expect("Should replace nested matches");
+
+    assert_eq!(result.count, 2);
+    assert_eq!(result.modified, "f(old_func(a))\nf(b)\n");
+    assert_eq!(result.edits[0].line, 1);
+    assert_eq!(result.edits[1].line, 2);
+}
+
 #[test]
 fn test_no_matches() {
     let content = "x = 1 + 2";
@@ -140,3 +152,92 @@ fn test_format_result() {
     assert!(formatted.contains("Replacements: 1"));
     assert!(formatted.contains("Diff:"));
 }
+
+#[test]
+fn test_multiline_body_capture_is_reindented() {
+    let content = r#"def load(path):
+    data = read(path)
+    if data:
+        return parse(data)
+"#;
+    let result = StructuralEditor::replace(
+        content,
+        "def $NAME($$$PARAMS):\n    $$$BODY",
+        "def $NAME($$$PARAMS):\n    try:\n        $$$BODY\n    except Exception:\n        raise",
+        "python",
+    )
+    .expect("Should wrap body");
+
+    assert_eq!(result.count, 1);
+    assert_eq!(
+        result.modified,
+        r#"def load(path):
+    try:
+        data = read(path)
+        if data:
+            return parse(data)
+    except Exception:
+        raise
+"#
+    );
+}
+
+#[test]
+fn test_multiline_capture_with_multibyte_indent_is_reindented() {
+    // The string continuation line is indented with two U+3000 (3 bytes each),
+    // so the 4-byte body column falls inside the second one.
+    let content = "def load(path):\n    data = \"\"\"a\n\u{3000}\u{3000}b\"\"\"\n    return data\n";
+    let result = StructuralEditor::replace(
+        content,
+        "def $NAME($$$PARAMS):\n    $$$BODY",
+        "def $NAME($$$PARAMS):\n    try:\n        $$$BODY\n    except Exception:\n        raise",
+        "python",
+    )
+    .expect("Should wrap body");
+
+    assert_eq!(result.count, 1);
+    assert_eq!(
+        result.modified,
+        "def load(path):\n    try:\n        data = \"\"\"a\n        \u{3000}b\"\"\"\n        return data\n    except Exception:\n        raise\n"
+    );
+}
+
+#[test]
+fn test_preview_reports_detected_language() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("module.py");
+    File::create(&path)
+        .unwrap()
+        .write_all(b"x = connect(a)\n")
+        .unwrap();
+
+    let result = StructuralEditor::preview(&path, "connect($$$ARGS)", "open($$$ARGS)", None)
+        .expect("Should preview");
+
+    assert_eq!(result.language, "python");
+    assert!(result.language_detected);
+}
+
+#[test]
+fn test_preview_ambiguous_extension_needs_explicit_language() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("module.pyx");
+    File::create(&path)
+        .unwrap()
+        .write_all(b"x = connect(a)\n")
+        .unwrap();
+
+    let err = StructuralEditor::preview(&path, "connect($$$ARGS)", "open($$$ARGS)", None)
+        .expect_err("Detection should fail for .pyx");
+    assert!(matches!(err, EditError::LanguageDetection(_)));
+    let message = err.to_string();
+    assert!(message.contains("module.pyx"), "{message}");
+    assert!(message.contains("python"), "{message}");
+
+    let result =
+        StructuralEditor::preview(&path, "connect($$$ARGS)", "open($$$ARGS)", Some("python"))
+            .expect("Explicit language should win");
+    assert_eq!(result.count, 1);
+    assert_eq!(result.language, "python");
+    assert!(!result.language_detected);
+}
@@ -0,0 +1,39 @@
+//! Time-windowed deduplication for [`crate::EventBus::emit_deduped`].
+//!
+//! Editors often save the same file several times in quick succession; the
+//! cache remembers each recently emitted (source, topic, payload) and reports
+//! repeats until the window expires.
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Identity of an event: source, topic and serialized payload.
+type EventKey = (String, String, String);
+
+/// Expiry times keyed by full event identity.
+#[derive(Debug, Default)]
+pub(crate) struct DedupCache {
+    expires: HashMap<EventKey, Instant>,
+}
+
+impl DedupCache {
+    /// Record the event and return `true` if it was not already seen within its window.
+    pub(crate) fn admit(
+        &mut self,
+        source: &str,
+        topic: &str,
+        payload: &Value,
+        window: Duration,
+    ) -> bool {
+        let now = Instant::now();
+        self.expires.retain(|_, expires_at| *expires_at > now);
+
+        let key = (source.to_string(), topic.to_string(), payload.to_string());
+        if self.expires.contains_key(&key) {
+            return false;
+        }
+        self.expires.insert(key, now + window);
+        true
+    }
+}
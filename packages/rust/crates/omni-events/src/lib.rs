@@ -18,10 +18,21 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
-use std::sync::{Arc, LazyLock};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+use std::time::Duration;
 use tokio::sync::broadcast;
 use uuid::Uuid;
 
+mod dedup;
+mod monitor;
+mod payloads;
+
+use dedup::DedupCache;
+use monitor::BusCounters;
+pub use monitor::{BusMetrics, MonitoredReceiver};
+pub use payloads::{AgentActionPayload, AgentThoughtPayload};
+
 /// Core event model
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OmniEvent {
@@ -87,6 +98,10 @@ pub struct EventBus {
     tx: broadcast::Sender<OmniEvent>,
     /// Bus capacity for backpressure handling
     capacity: usize,
+    /// Publish and lag counters shared by all clones
+    counters: Arc<BusCounters>,
+    /// Recently emitted events for `emit_deduped`
+    dedup: Arc<Mutex<DedupCache>>,
 }
 
 impl EventBus {
@@ -94,7 +109,12 @@ impl EventBus {
     #[must_use]
     pub fn new(capacity: usize) -> Self {
         let (tx, _) = broadcast::channel(capacity);
-        Self { tx, capacity }
+        Self {
+            tx,
+            capacity,
+            counters: Arc::new(BusCounters::default()),
+            dedup: Arc::new(Mutex::new(DedupCache::default())),
+        }
     }
 
     /// Get the bus capacity
@@ -109,6 +129,7 @@ impl EventBus {
     /// Returns 0 if there are no subscribers (not an error).
     #[must_use]
     pub fn publish(&self, event: OmniEvent) -> usize {
+        self.counters.published.fetch_add(1, Ordering::Relaxed);
         self.tx.send(event).unwrap_or(0)
     }
 
@@ -118,6 +139,27 @@ impl EventBus {
         self.publish(OmniEvent::new(source, topic, payload))
     }
 
+    /// Publish unless an identical event was emitted within `window`
+    ///
+    /// Events are identical when source, topic and payload all match. The
+    /// window starts at the first emitted occurrence; repeats inside it are
+    /// suppressed and return `None`. Otherwise returns the subscriber count.
+    #[must_use]
+    pub fn emit_deduped(
+        &self,
+        source: &str,
+        topic: &str,
+        payload: Value,
+        window: Duration,
+    ) -> Option<usize> {
+        let admitted = self
+            .dedup
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .admit(source, topic, &payload, window);
+        admitted.then(|| self.emit(source, topic, payload))
+    }
+
     /// Subscribe to the event bus
     ///
     /// Returns a receiver that will receive all future events.
@@ -127,6 +169,21 @@ impl EventBus {
         self.tx.subscribe()
     }
 
+    /// Subscribe with lag monitoring
+    ///
+    /// The returned receiver logs and counts `Lagged` errors instead of
+    /// returning them; see [`EventBus::metrics`] for the aggregated counts.
+    #[must_use]
+    pub fn subscribe_monitored(&self) -> MonitoredReceiver {
+        MonitoredReceiver::new(self.tx.subscribe(), Arc::clone(&self.counters))
+    }
+
+    /// Snapshot of publish and backpressure counters
+    #[must_use]
+    pub fn metrics(&self) -> BusMetrics {
+        self.counters.snapshot()
+    }
+
     /// Get current subscriber count
     #[must_use]
     pub fn subscriber_count(&self) -> usize {
@@ -234,4 +291,116 @@ mod tests {
         let _rx2 = bus.subscribe();
         assert_eq!(bus.subscriber_count(), 2);
     }
+
+    #[test]
+    fn test_agent_action_payload_roundtrip() {
+        let payload = AgentActionPayload {
+            action: "git.commit".to_string(),
+            args: json!({ "message": "fix typo" }),
+            reason: Some("changes are staged".to_string()),
+        };
+        let event = OmniEvent::agent_action(&payload);
+        assert_eq!(event.source, sources::AGENT);
+        assert_eq!(event.topic, topics::AGENT_ACTION);
+        assert_eq!(event.payload["action"], "git.commit");
+
+        assert_eq!(event.as_agent_action(), Some(payload));
+        assert_eq!(event.as_agent_thought(), None);
+
+        let thought = OmniEvent::agent_thought(&AgentThoughtPayload {
+            thought: "check the diff first".to_string(),
+            step: None,
+        });
+        assert_eq!(
+            thought.payload,
+            json!({ "thought": "check the diff first" })
+        );
+        assert_eq!(
+            thought.as_agent_thought().map(|p| p.thought),
+            Some("check the diff first".to_string())
+        );
+
+        let malformed = OmniEvent::new("agent", topics::AGENT_ACTION, json!({ "args": {} }));
+        assert_eq!(malformed.as_agent_action(), None);
+    }
+
+    #[tokio::test]
+    async fn test_emit_deduped_suppresses_repeats_within_window() {
+        let bus = EventBus::new(16);
+        let mut rx = bus.subscribe();
+        let window = Duration::from_secs(30);
+        let payload = json!({ "path": "src/main.rs", "is_dir": false });
+
+        let results: Vec<Option<usize>> = (0..3)
+            .map(|_| bus.emit_deduped("watcher", "file/changed", payload.clone(), window))
+            .collect();
+        assert_eq!(results, vec![Some(1), None, None]);
+
+        // A different payload is not a duplicate.
+        let other = json!({ "path": "src/lib.rs", "is_dir": false });
+        assert_eq!(
+            bus.emit_deduped("watcher", "file/changed", other, window),
+            Some(1)
+        );
+        // Neither is the same payload under another topic.
+        assert_eq!(
+            bus.emit_deduped("watcher", "file/created", payload, window),
+            Some(1)
+        );
+
+        let mut paths = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            paths.push(event.payload["path"].clone());
+        }
+        assert_eq!(
+            paths,
+            vec![
+                json!("src/main.rs"),
+                json!("src/lib.rs"),
+                json!("src/main.rs")
+            ]
+        );
+
+        // Once the window has passed the event goes through again.
+        let expired = bus.emit_deduped("kernel", "tick", json!({}), Duration::ZERO);
+        assert_eq!(expired, Some(1));
+        assert_eq!(
+            bus.emit_deduped("kernel", "tick", json!({}), Duration::ZERO),
+            Some(1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_monitored_subscriber_counts_lag() {
+        let bus = EventBus::new(4);
+        let mut slow = bus.subscribe_monitored();
+        assert_eq!(bus.metrics().monitored_subscribers, 1);
+
+        for i in 0..10 {
+            let _ = bus.emit("test", "topic", json!({ "seq": i }));
+        }
+
+        // The 6 oldest events were overwritten; reception resumes at seq 6.
+        let Some(first) = slow.recv().await else {
+            panic!("expected an event after lag");
+        };
+        assert_eq!(first.payload["seq"], 6);
+        assert_eq!(slow.lag_count(), 1);
+        assert_eq!(slow.dropped_count(), 6);
+        for seq in 7..10 {
+            let Some(event) = slow.try_recv() else {
+                panic!("expected queued event {seq}");
+            };
+            assert_eq!(event.payload["seq"], seq);
+        }
+        assert!(slow.try_recv().is_none());
+
+        let metrics = bus.metrics();
+        assert_eq!(metrics.published, 10);
+        assert_eq!(metrics.lag_events, 1);
+        assert_eq!(metrics.dropped_events, 6);
+
+        drop(slow);
+        assert_eq!(bus.metrics().monitored_subscribers, 0);
+    }
 }
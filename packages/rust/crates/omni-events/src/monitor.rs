@@ -0,0 +1,128 @@
+//! Backpressure metrics for the event bus.
+//!
+//! A broadcast receiver that falls more than `capacity` events behind gets
+//! `RecvError::Lagged` and silently skips the overwritten events.
+//! [`MonitoredReceiver`] logs and counts those lags, and the counts are
+//! aggregated in [`BusMetrics`].
+
+use serde::Serialize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use tokio::sync::broadcast::{self, error::RecvError, error::TryRecvError};
+
+use crate::OmniEvent;
+
+/// Snapshot of event bus counters, from [`crate::EventBus::metrics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct BusMetrics {
+    /// Events published on the bus (including ones with no subscribers).
+    pub published: u64,
+    /// Number of times a monitored subscriber lagged behind.
+    pub lag_events: u64,
+    /// Events skipped by monitored subscribers because of lag.
+    pub dropped_events: u64,
+    /// Currently live monitored subscribers.
+    pub monitored_subscribers: usize,
+}
+
+/// Shared counters behind [`BusMetrics`].
+#[derive(Debug, Default)]
+pub(crate) struct BusCounters {
+    pub(crate) published: AtomicU64,
+    lag_events: AtomicU64,
+    dropped_events: AtomicU64,
+    monitored_subscribers: AtomicUsize,
+}
+
+impl BusCounters {
+    pub(crate) fn snapshot(&self) -> BusMetrics {
+        BusMetrics {
+            published: self.published.load(Ordering::Relaxed),
+            lag_events: self.lag_events.load(Ordering::Relaxed),
+            dropped_events: self.dropped_events.load(Ordering::Relaxed),
+            monitored_subscribers: self.monitored_subscribers.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Receiver wrapper that records lag instead of surfacing `RecvError::Lagged`.
+///
+/// Created by [`crate::EventBus::subscribe_monitored`]. Lags are logged, counted
+/// on this receiver and in the bus-wide [`BusMetrics`], then reception resumes
+/// from the oldest retained event.
+pub struct MonitoredReceiver {
+    rx: broadcast::Receiver<OmniEvent>,
+    counters: Arc<BusCounters>,
+    lag_count: u64,
+    dropped_count: u64,
+}
+
+impl MonitoredReceiver {
+    pub(crate) fn new(rx: broadcast::Receiver<OmniEvent>, counters: Arc<BusCounters>) -> Self {
+        counters
+            .monitored_subscribers
+            .fetch_add(1, Ordering::Relaxed);
+        Self {
+            rx,
+            counters,
+            lag_count: 0,
+            dropped_count: 0,
+        }
+    }
+
+    /// Receive the next event, skipping past lag.
+    ///
+    /// Returns `None` once the bus is closed.
+    pub async fn recv(&mut self) -> Option<OmniEvent> {
+        loop {
+            match self.rx.recv().await {
+                Ok(event) => return Some(event),
+                Err(RecvError::Lagged(skipped)) => self.record_lag(skipped),
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Receive an already-queued event without waiting, skipping past lag.
+    ///
+    /// Returns `None` when no event is queued or the bus is closed.
+    pub fn try_recv(&mut self) -> Option<OmniEvent> {
+        loop {
+            match self.rx.try_recv() {
+                Ok(event) => return Some(event),
+                Err(TryRecvError::Lagged(skipped)) => self.record_lag(skipped),
+                Err(TryRecvError::Empty | TryRecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Number of times this receiver lagged behind.
+    #[must_use]
+    pub fn lag_count(&self) -> u64 {
+        self.lag_count
+    }
+
+    /// Events this receiver skipped because of lag.
+    #[must_use]
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped_count
+    }
+
+    fn record_lag(&mut self, skipped: u64) {
+        log::warn!("event bus subscriber lagged, {skipped} events dropped");
+        self.lag_count += 1;
+        self.dropped_count += skipped;
+        self.counters.lag_events.fetch_add(1, Ordering::Relaxed);
+        self.counters
+            .dropped_events
+            .fetch_add(skipped, Ordering::Relaxed);
+    }
+}
+
+impl Drop for MonitoredReceiver {
+    fn drop(&mut self) {
+        self.counters
+            .monitored_subscribers
+            .fetch_sub(1, Ordering::Relaxed);
+    }
+}
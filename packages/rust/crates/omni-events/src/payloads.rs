@@ -0,0 +1,76 @@
+//! Typed payloads for agent-topic events.
+//!
+//! Producers build correctly-shaped events with [`OmniEvent::agent_thought`] and
+//! [`OmniEvent::agent_action`]; consumers parse them back with
+//! [`OmniEvent::as_agent_thought`] and [`OmniEvent::as_agent_action`].
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{OmniEvent, sources, topics};
+
+/// Payload of an `agent/think` event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentThoughtPayload {
+    /// Reasoning text
+    pub thought: String,
+    /// Reasoning step within the current task, if tracked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub step: Option<u32>,
+}
+
+/// Payload of an `agent/action` event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentActionPayload {
+    /// Tool or command being invoked (e.g. "git.commit")
+    pub action: String,
+    /// Arguments passed to the action
+    #[serde(default)]
+    pub args: Value,
+    /// Why the agent chose this action
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl OmniEvent {
+    /// Create an `agent/think` event from the agent source
+    #[must_use]
+    pub fn agent_thought(payload: &AgentThoughtPayload) -> Self {
+        Self::new(sources::AGENT, topics::AGENT_THINK, to_value(payload))
+    }
+
+    /// Create an `agent/action` event from the agent source
+    #[must_use]
+    pub fn agent_action(payload: &AgentActionPayload) -> Self {
+        Self::new(sources::AGENT, topics::AGENT_ACTION, to_value(payload))
+    }
+
+    /// Parse the payload of an `agent/think` event
+    ///
+    /// Returns `None` for other topics or malformed payloads.
+    #[must_use]
+    pub fn as_agent_thought(&self) -> Option<AgentThoughtPayload> {
+        self.typed_payload(topics::AGENT_THINK)
+    }
+
+    /// Parse the payload of an `agent/action` event
+    ///
+    /// Returns `None` for other topics or malformed payloads.
+    #[must_use]
+    pub fn as_agent_action(&self) -> Option<AgentActionPayload> {
+        self.typed_payload(topics::AGENT_ACTION)
+    }
+
+    fn typed_payload<T: DeserializeOwned>(&self, topic: &str) -> Option<T> {
+        if self.topic != topic {
+            return None;
+        }
+        T::deserialize(&self.payload).ok()
+    }
+}
+
+fn to_value<T: Serialize>(payload: &T) -> Value {
+    // Plain structs with string keys always serialize.
+    serde_json::to_value(payload).unwrap_or(Value::Null)
+}
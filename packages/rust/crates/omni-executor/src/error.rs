@@ -19,6 +19,10 @@ pub enum ExecutorError {
     #[error("Shell error (exit {0}): {1}")]
     ShellError(i32, String),
 
+    /// Command succeeded but stdout was not valid JSON (exit code, raw stdout, truncated).
+    #[error("Non-JSON output (exit {0}): {1}")]
+    NonJsonOutput(i32, String),
+
     /// JSON serialization/deserialization failed.
     #[error("Serialization error: {0}")]
     SerializationError(String),
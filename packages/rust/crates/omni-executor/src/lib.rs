@@ -16,5 +16,5 @@ pub use ast_analyzer::{
     AstCommandAnalyzer, CommandAnalysis, SecurityViolation, VariableInfo, ViolationSeverity,
 };
 pub use error::{ExecutorError, Result};
-pub use nu_bridge::{ActionType, NuConfig, NuSystemBridge};
-pub use query::{QueryAction, QueryBuilder};
+pub use nu_bridge::{ActionType, NON_JSON_OUTPUT_MAX_CHARS, NuConfig, NuSystemBridge};
+pub use query::{QueryAction, QueryBuilder, escape_nu_string};
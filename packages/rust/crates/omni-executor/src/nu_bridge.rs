@@ -9,6 +9,9 @@ use std::time::Duration;
 
 use crate::error::{ExecutorError, Result};
 
+/// Maximum number of stdout chars carried in [`ExecutorError::NonJsonOutput`].
+pub const NON_JSON_OUTPUT_MAX_CHARS: usize = 2_000;
+
 /// Configuration for the Nushell bridge.
 #[derive(Debug, Clone)]
 pub struct NuConfig {
@@ -200,7 +203,18 @@ impl NuSystemBridge {
 
         let stdout = String::from_utf8_lossy(&output.stdout);
 
-        // 5. Handle empty output (e.g., cp, mv operations)
+        // 5-6. Verify and parse the forced JSON output
+        Self::parse_json_output(&stdout, output.status.code().unwrap_or(-1))
+    }
+
+    /// Verify that command stdout is JSON and parse it.
+    ///
+    /// Empty output (e.g. `cp`, `mv`) maps to a success status object.
+    ///
+    /// # Errors
+    /// Returns [`ExecutorError::NonJsonOutput`] with the exit code and raw stdout when the
+    /// output is not valid JSON. Stdout longer than [`NON_JSON_OUTPUT_MAX_CHARS`] is truncated.
+    pub fn parse_json_output(stdout: &str, exit_code: i32) -> Result<Value> {
         if stdout.trim().is_empty() {
             return Ok(serde_json::json!({
                 "status": "success",
@@ -208,14 +222,8 @@ impl NuSystemBridge {
             }));
         }
 
-        // 6. Parse JSON output
-        serde_json::from_str(&stdout).map_err(|e| {
-            ExecutorError::SerializationError(format!(
-                "Nu output wasn't valid JSON: {} (raw: {:?})",
-                e,
-                &stdout[..stdout.len().min(200)]
-            ))
-        })
+        serde_json::from_str(stdout)
+            .map_err(|_| ExecutorError::NonJsonOutput(exit_code, truncate_output(stdout)))
     }
 
     /// Execute with timeout.
@@ -354,3 +362,13 @@ pub enum ActionType {
     /// Side-effect operation (rm, cp, mv, save)
     Mutate,
 }
+
+/// Cap raw output at [`NON_JSON_OUTPUT_MAX_CHARS`], noting the original length.
+fn truncate_output(output: &str) -> String {
+    let total_chars = output.chars().count();
+    if total_chars <= NON_JSON_OUTPUT_MAX_CHARS {
+        return output.to_string();
+    }
+    let head: String = output.chars().take(NON_JSON_OUTPUT_MAX_CHARS).collect();
+    format!("{head}... [truncated, {total_chars} chars total]")
+}
@@ -3,6 +3,11 @@
 //! Prevents injection attacks by using a builder pattern instead of string concatenation.
 //! Automatically optimizes queries by composing efficient Nushell pipelines.
 
+use std::fmt::Write as _;
+
+use crate::ast_analyzer::{AstCommandAnalyzer, ViolationSeverity};
+use crate::error::{ExecutorError, Result};
+
 /// Action type for semantic classification.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum QueryAction {
@@ -23,6 +28,7 @@ pub struct QueryBuilder {
     sort_descending: bool,
     limit: Option<u32>,
     action_type: QueryAction,
+    severity_threshold: ViolationSeverity,
 }
 
 impl Default for QueryBuilder {
@@ -46,6 +52,7 @@ impl QueryBuilder {
             sort_descending: false,
             limit: None,
             action_type: QueryAction::Observe,
+            severity_threshold: ViolationSeverity::Blocked,
         }
     }
 
@@ -56,6 +63,25 @@ impl QueryBuilder {
         self
     }
 
+    /// Add a literal string argument to the source command (escaped, never globbed).
+    #[must_use]
+    pub fn source_literal(mut self, value: &str) -> Self {
+        self.source_args.push(escape_nu_string(value));
+        self
+    }
+
+    /// Add a `where <column> == "<value>"` filter with the value escaped as a string literal.
+    ///
+    /// Filters on columns that are not plain cell paths are ignored.
+    #[must_use]
+    pub fn where_eq(mut self, column: &str, value: &str) -> Self {
+        if Self::is_safe_column(column) {
+            self.filters
+                .push(format!("where {column} == {}", escape_nu_string(value)));
+        }
+        self
+    }
+
     /// Add a where clause for filtering.
     ///
     /// # Safety
@@ -119,6 +145,50 @@ impl QueryBuilder {
         self
     }
 
+    /// Set the minimum violation severity that rejects a query in [`Self::build_validated`].
+    ///
+    /// Defaults to [`ViolationSeverity::Blocked`].
+    #[must_use]
+    pub fn with_severity_threshold(mut self, severity: ViolationSeverity) -> Self {
+        self.severity_threshold = severity;
+        self
+    }
+
+    /// Build the final Nushell command string after AST-based validation.
+    ///
+    /// The pipeline is analyzed with [`AstCommandAnalyzer`] before the JSON suffix is added.
+    /// Mutating commands are only accepted when the action type is [`QueryAction::Mutate`].
+    ///
+    /// # Errors
+    /// Returns [`ExecutorError::SecurityViolation`] when the analysis reports a violation at or
+    /// above the configured severity threshold, or a mutation in an observe-only query.
+    pub fn build_validated(self) -> Result<String> {
+        let analysis = AstCommandAnalyzer::new().analyze(&self.build_base());
+
+        let threshold = severity_rank(&self.severity_threshold);
+        let rejected: Vec<&str> = analysis
+            .violations
+            .iter()
+            .filter(|violation| severity_rank(&violation.severity) >= threshold)
+            .map(|violation| violation.rule.as_str())
+            .collect();
+        if !rejected.is_empty() {
+            return Err(ExecutorError::SecurityViolation(format!(
+                "Query rejected by AST analysis: {}",
+                rejected.join(", ")
+            )));
+        }
+
+        if analysis.is_mutation && self.action_type == QueryAction::Observe {
+            return Err(ExecutorError::SecurityViolation(format!(
+                "Mutating command '{}' requires QueryAction::Mutate",
+                analysis.command_name.unwrap_or_default()
+            )));
+        }
+
+        Ok(self.build())
+    }
+
     /// Build the final Nushell command string.
     ///
     /// Automatically composes the pipeline with `| to json --raw` for structured output.
@@ -187,6 +257,14 @@ impl QueryBuilder {
         cmd
     }
 
+    /// Check if a column name is a plain cell path (e.g. `name`, `meta.size`).
+    fn is_safe_column(column: &str) -> bool {
+        !column.is_empty()
+            && column
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    }
+
     /// Check if a predicate is safe (no injection patterns).
     fn is_safe_predicate(predicate: &str) -> bool {
         let p = predicate.to_lowercase();
@@ -215,6 +293,40 @@ impl QueryBuilder {
     }
 }
 
+/// Escape a value as a double-quoted Nushell string literal.
+///
+/// Backslashes, quotes, and control characters are escaped. Plain double-quoted strings are
+/// never interpolated by Nushell, so `$name` and `$(...)` stay literal text.
+#[must_use]
+pub fn escape_nu_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for ch in value.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{{{:x}}}", u32::from(c));
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Rank severities so that higher values are more severe.
+fn severity_rank(severity: &ViolationSeverity) -> u8 {
+    match severity {
+        ViolationSeverity::Info => 0,
+        ViolationSeverity::Warning => 1,
+        ViolationSeverity::Blocked => 2,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -287,4 +399,86 @@ mod tests {
         assert!(query.contains("where size > 1kb"));
         assert!(!query.contains("to json"));
     }
+
+    #[test]
+    fn test_build_validated_accepts_observe_query() {
+        let query = QueryBuilder::new("ls")
+            .source(".")
+            .sort_by("name")
+            .build_validated();
+
+        assert!(matches!(query, Ok(ref cmd) if cmd.ends_with("to json --raw")));
+    }
+
+    #[test]
+    fn test_build_validated_rejects_unallowed_mutation() {
+        let rejected = QueryBuilder::new("rm")
+            .source("build.log")
+            .build_validated();
+        assert!(matches!(rejected, Err(ExecutorError::SecurityViolation(_))));
+
+        let allowed = QueryBuilder::new("rm")
+            .source("build.log")
+            .with_action_type(QueryAction::Mutate)
+            .build_validated();
+        assert!(matches!(allowed, Ok(ref cmd) if cmd == "rm build.log"));
+    }
+
+    #[test]
+    fn test_build_validated_rejects_blocked_violation_even_when_mutation_allowed() {
+        let query = QueryBuilder::new("rm")
+            .source("-rf /")
+            .with_action_type(QueryAction::Mutate)
+            .build_validated();
+
+        assert!(
+            matches!(query, Err(ExecutorError::SecurityViolation(msg)) if msg.contains("RM_RF_ROOT"))
+        );
+    }
+
+    #[test]
+    fn test_escape_nu_string_quotes_and_backslashes() {
+        assert_eq!(escape_nu_string("plain"), "\"plain\"");
+        assert_eq!(escape_nu_string(r#"a"b\c"#), r#""a\"b\\c""#);
+        assert_eq!(escape_nu_string("line\nbreak"), r#""line\nbreak""#);
+    }
+
+    #[test]
+    fn test_where_eq_escapes_interpolated_value() {
+        let value = r#"it's "quoted" $(rm -rf ~) \ $env.HOME"#;
+        let query = QueryBuilder::new("ls").where_eq("name", value).build();
+
+        let literal = query
+            .strip_prefix("ls | where name == ")
+            .and_then(|rest| rest.strip_suffix(" | to json --raw"))
+            .unwrap_or_default();
+        assert!(literal.starts_with('"') && literal.ends_with('"'));
+        assert!(
+            !literal.starts_with("$\""),
+            "must not be an interpolated string"
+        );
+
+        // Nushell double-quoted escapes are JSON-compatible: the literal must decode back
+        // to exactly the original value, proving no quote terminates it early.
+        let decoded: String = serde_json::from_str(literal).unwrap_or_default();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_where_eq_rejects_unsafe_column() {
+        let query = QueryBuilder::new("ls")
+            .where_eq("name; rm -rf /", "x")
+            .build();
+
+        assert_eq!(query, "ls | to json --raw");
+    }
+
+    #[test]
+    fn test_source_literal_is_quoted() {
+        let query = QueryBuilder::new("open")
+            .source_literal("my \"file\".json")
+            .build_raw();
+
+        assert_eq!(query, r#"open "my \"file\".json""#);
+    }
 }
@@ -1,6 +1,8 @@
 //! Integration tests for Nushell system bridge.
 
-use omni_executor::{ActionType, NuConfig, NuSystemBridge};
+use omni_executor::{
+    ActionType, ExecutorError, NON_JSON_OUTPUT_MAX_CHARS, NuConfig, NuSystemBridge,
+};
 
 #[test]
 fn test_new_bridge_has_default_config() {
@@ -145,3 +147,55 @@ fn test_action_type_variants() {
     assert_eq!(ActionType::Mutate, ActionType::Mutate);
     assert_ne!(ActionType::Observe, ActionType::Mutate);
 }
+
+#[test]
+fn test_parse_json_output_accepts_json_and_empty() {
+    let value = NuSystemBridge::parse_json_output(r#"[{"name":"a"}]"#, 0)
+        .unwrap_or_else(|error| panic!("json output should parse: {error}"));
+    assert_eq!(value[0]["name"], "a");
+
+    let empty = NuSystemBridge::parse_json_output("  \n", 0)
+        .unwrap_or_else(|error| panic!("empty output should map to success: {error}"));
+    assert_eq!(empty["status"], "success");
+}
+
+#[test]
+fn test_parse_json_output_truncates_large_non_json_output() {
+    let stdout = "x".repeat(NON_JSON_OUTPUT_MAX_CHARS * 3);
+    match NuSystemBridge::parse_json_output(&stdout, 0) {
+        Err(ExecutorError::NonJsonOutput(_, raw)) => {
+            assert!(raw.starts_with(&"x".repeat(NON_JSON_OUTPUT_MAX_CHARS)));
+            assert!(raw.len() < stdout.len());
+            assert!(raw.ends_with(&format!("[truncated, {} chars total]", stdout.len())));
+        }
+        other => panic!("expected NonJsonOutput, got {other:?}"),
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_execute_plain_text_output_returns_non_json_error() {
+    use std::os::unix::fs::PermissionsExt;
+
+    // Stand-in for `nu` that ignores its arguments and prints plain text.
+    let dir = tempfile::tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+    let fake_nu = dir.path().join("fake-nu");
+    std::fs::write(&fake_nu, "#!/bin/sh\necho 'plain text, not json'\n")
+        .unwrap_or_else(|error| panic!("write fake nu: {error}"));
+    std::fs::set_permissions(&fake_nu, std::fs::Permissions::from_mode(0o755))
+        .unwrap_or_else(|error| panic!("chmod fake nu: {error}"));
+
+    let bridge = NuSystemBridge::with_config(NuConfig {
+        nu_path: fake_nu.to_string_lossy().into_owned(),
+        enable_shellcheck: false,
+        ..Default::default()
+    });
+
+    match bridge.execute("ls", true) {
+        Err(ExecutorError::NonJsonOutput(exit_code, raw)) => {
+            assert_eq!(exit_code, 0);
+            assert_eq!(raw.trim(), "plain text, not json");
+        }
+        other => panic!("expected NonJsonOutput, got {other:?}"),
+    }
+}
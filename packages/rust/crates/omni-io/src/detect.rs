@@ -6,16 +6,61 @@ use memchr::memchr;
 
 use crate::error::IoError;
 
+/// Bytes inspected by [`is_binary`] by default.
+pub const DEFAULT_BINARY_SCAN_LEN: usize = 8192;
+
+/// Options for [`crate::read_text_safe_opts`].
+///
+/// The default matches [`crate::read_text_safe`] with a 1MB limit: any NULL
+/// byte in the first 8KB marks the file as binary.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReadOptions {
+    /// Maximum file size in bytes (default: 1MB)
+    pub max_bytes: u64,
+    /// Leading bytes inspected for NULL bytes (default: 8KB)
+    pub binary_scan_len: usize,
+    /// Largest fraction of NULL bytes in the inspected prefix still treated as
+    /// text (default: 0.0, i.e. any NULL byte means binary)
+    pub max_nul_ratio: f64,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self {
+            max_bytes: 1024 * 1024, // 1MB
+            binary_scan_len: DEFAULT_BINARY_SCAN_LEN,
+            max_nul_ratio: 0.0,
+        }
+    }
+}
+
 /// Quick binary detection - checks first 8KB for NULL bytes.
 ///
 /// Files containing NULL bytes in the first 8KB are considered binary.
 /// This is a fast heuristic that works well for most text files.
 #[must_use]
 pub fn is_binary(buffer: &[u8]) -> bool {
-    let check_len = std::cmp::min(buffer.len(), 8192);
+    let check_len = std::cmp::min(buffer.len(), DEFAULT_BINARY_SCAN_LEN);
     memchr(0, &buffer[..check_len]).is_some()
 }
 
+/// Binary detection with a configurable scan length and NULL-byte ratio.
+///
+/// The buffer is binary when the fraction of NULL bytes in its first
+/// `options.binary_scan_len` bytes exceeds `options.max_nul_ratio`.
+#[must_use]
+pub fn is_binary_with(buffer: &[u8], options: &ReadOptions) -> bool {
+    let check_len = std::cmp::min(buffer.len(), options.binary_scan_len);
+    let prefix = &buffer[..check_len];
+    if memchr(0, prefix).is_none() {
+        return false;
+    }
+    let nul_count = memchr::memchr_iter(0, prefix).count();
+    #[allow(clippy::cast_precision_loss)]
+    let ratio = nul_count as f64 / check_len as f64;
+    ratio > options.max_nul_ratio
+}
+
 /// Decode bytes to String with lossy fallback.
 ///
 /// First checks for binary content, then attempts UTF-8 decoding.
@@ -28,11 +73,27 @@ pub fn decode_buffer(buffer: Vec<u8>) -> Result<String, IoError> {
         return Err(IoError::BinaryFile);
     }
 
+    Ok(decode_utf8_lossy(buffer))
+}
+
+/// Decode bytes to String like [`decode_buffer`], using [`is_binary_with`].
+///
+/// # Errors
+/// Returns `IoError::BinaryFile` when binary content is detected.
+pub fn decode_buffer_with(buffer: Vec<u8>, options: &ReadOptions) -> Result<String, IoError> {
+    if is_binary_with(&buffer, options) {
+        return Err(IoError::BinaryFile);
+    }
+
+    Ok(decode_utf8_lossy(buffer))
+}
+
+fn decode_utf8_lossy(buffer: Vec<u8>) -> String {
     match String::from_utf8(buffer) {
-        Ok(s) => Ok(s),
+        Ok(s) => s,
         Err(e) => {
             // Standard library lossy conversion: replaces errors with U+FFFD
-            Ok(String::from_utf8_lossy(&e.into_bytes()).into_owned())
+            String::from_utf8_lossy(&e.into_bytes()).into_owned()
         }
     }
 }
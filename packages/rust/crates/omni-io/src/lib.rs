@@ -17,6 +17,7 @@
 //! ├── error.rs    # IoError enum
 //! ├── detect.rs   # Binary detection & decoding
 //! ├── sync.rs     # Synchronous API
+//! ├── mmap.rs     # Memory-mapped reads for large files
 //! ├── async_io.rs # Asynchronous API (Tokio)
 //! └── assembler.rs # Context Assembly (optional, requires "assembler" feature)
 //! ```
@@ -42,6 +43,7 @@ mod detect;
 mod dirs;
 mod discover;
 mod error;
+mod mmap;
 mod sync;
 
 // Conditionally compiled assembler module
@@ -62,10 +64,14 @@ pub use discover::{
     DiscoverOptions, count_files_in_dir, discover_files, discover_files_in_dir, should_skip_path,
 };
 pub use error::{IoError, Result};
-pub use sync::read_text_safe;
+pub use mmap::read_text_mmap;
+pub use sync::{read_dir_texts, read_text_safe, read_text_safe_opts};
 
 // Re-export detection utilities for advanced use
-pub use detect::{decode_buffer, is_binary};
+pub use detect::{
+    DEFAULT_BINARY_SCAN_LEN, ReadOptions, decode_buffer, decode_buffer_with, is_binary,
+    is_binary_with,
+};
 
 // Conditionally export assembler
 #[cfg(feature = "assembler")]
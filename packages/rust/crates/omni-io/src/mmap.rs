@@ -0,0 +1,64 @@
+//! Memory-mapped file reads.
+//!
+//! Avoids copying large read-only files into an intermediate heap buffer.
+
+use std::fs as std_fs;
+use std::path::Path;
+
+use memmap2::Mmap;
+
+use crate::detect::is_binary;
+use crate::error::IoError;
+
+/// Read text from a file through a memory map, with size and binary checks.
+///
+/// Same checks and result as [`crate::read_text_safe`]: the file must not
+/// exceed `max_bytes`, must not look binary, and is decoded as UTF-8 with
+/// invalid sequences replaced by U+FFFD. The mapped bytes are decoded straight
+/// into the returned `String`, skipping the intermediate read buffer.
+///
+/// # Safety caveats
+///
+/// The file must not be truncated or modified while it is being read. Other
+/// writers can change the mapped bytes mid-decode (yielding inconsistent text),
+/// and truncation can raise `SIGBUS`. Use this only for read-only files such as
+/// build outputs, vendored sources or indexes; use [`crate::read_text_safe`]
+/// for files other processes may be writing.
+///
+/// # Errors
+/// Returns `IoError` when the file does not exist, exceeds `max_bytes`,
+/// cannot be mapped, or is detected as binary.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use omni_io::read_text_mmap;
+///
+/// let content = read_text_mmap("target/index.json", 64 * 1024 * 1024)?;
+/// ```
+pub fn read_text_mmap<P: AsRef<Path>>(path: P, max_bytes: u64) -> Result<String, IoError> {
+    let path = path.as_ref();
+
+    let metadata = std_fs::metadata(path)
+        .map_err(|_| IoError::NotFound(path.to_string_lossy().to_string()))?;
+
+    if metadata.len() > max_bytes {
+        return Err(IoError::TooLarge(metadata.len(), max_bytes));
+    }
+    // Zero-length mappings are rejected by the OS.
+    if metadata.len() == 0 {
+        return Ok(String::new());
+    }
+
+    let file = std_fs::File::open(path)?;
+    // SAFETY: the map is read-only and dropped before returning; callers are
+    // documented to only use this for files that are not mutated concurrently.
+    #[allow(unsafe_code)]
+    let mmap = unsafe { Mmap::map(&file)? };
+
+    if is_binary(&mmap) {
+        return Err(IoError::BinaryFile);
+    }
+
+    Ok(String::from_utf8_lossy(&mmap).into_owned())
+}
@@ -4,9 +4,11 @@
 
 use std::fs as std_fs;
 use std::io::Read;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use crate::detect::decode_buffer;
+use rayon::prelude::*;
+
+use crate::detect::{ReadOptions, decode_buffer, decode_buffer_with};
 use crate::error::IoError;
 
 /// Read text from a file with size and binary checks (synchronous).
@@ -30,8 +32,62 @@ use crate::error::IoError;
 /// let content = read_text_safe("README.md", 1024 * 1024)?;
 /// ```
 pub fn read_text_safe<P: AsRef<Path>>(path: P, max_bytes: u64) -> Result<String, IoError> {
-    let path = path.as_ref();
+    read_bytes_limited(path.as_ref(), max_bytes).and_then(decode_buffer)
+}
+
+/// Read text from a file with configurable size and binary checks (synchronous).
+///
+/// Like [`read_text_safe`], but the size limit, binary scan length and tolerated
+/// NULL-byte ratio come from `options`. `ReadOptions::default()` keeps the
+/// strict behaviour of [`read_text_safe`].
+///
+/// # Errors
+/// Returns `IoError` when the file does not exist, exceeds `options.max_bytes`,
+/// cannot be read, or is detected as binary.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use omni_io::{ReadOptions, read_text_safe_opts};
+///
+/// let lenient = ReadOptions { max_nul_ratio: 0.01, ..ReadOptions::default() };
+/// let content = read_text_safe_opts("dump.log", &lenient)?;
+/// ```
+pub fn read_text_safe_opts<P: AsRef<Path>>(
+    path: P,
+    options: &ReadOptions,
+) -> Result<String, IoError> {
+    let buffer = read_bytes_limited(path.as_ref(), options.max_bytes)?;
+    decode_buffer_with(buffer, options)
+}
+
+/// Read many files in parallel with [`read_text_safe`] (synchronous).
+///
+/// Results are returned in the order of `paths`, one per path. A failed read
+/// (binary, too large, missing) only affects its own slot.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use omni_io::read_dir_texts;
+///
+/// for (path, result) in read_dir_texts(&paths, 1024 * 1024) {
+///     if let Ok(text) = result { /* ... */ }
+/// }
+/// ```
+#[must_use]
+pub fn read_dir_texts(
+    paths: &[PathBuf],
+    max_bytes: u64,
+) -> Vec<(PathBuf, Result<String, IoError>)> {
+    paths
+        .par_iter()
+        .map(|path| (path.clone(), read_text_safe(path, max_bytes)))
+        .collect()
+}
 
+/// Read a whole file after checking it against `max_bytes`.
+fn read_bytes_limited(path: &Path, max_bytes: u64) -> Result<Vec<u8>, IoError> {
     let metadata = std_fs::metadata(path)
         .map_err(|_| IoError::NotFound(path.to_string_lossy().to_string()))?;
 
@@ -45,5 +101,5 @@ pub fn read_text_safe<P: AsRef<Path>>(path: P, max_bytes: u64) -> Result<String,
     let mut buffer = Vec::with_capacity(capacity);
     file.read_to_end(&mut buffer)?;
 
-    decode_buffer(buffer)
+    Ok(buffer)
 }
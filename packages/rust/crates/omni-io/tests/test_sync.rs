@@ -3,7 +3,9 @@
 use std::io::Write;
 use tempfile::TempDir;
 
-use omni_io::{IoError, read_text_safe};
+use omni_io::{
+    IoError, ReadOptions, read_dir_texts, read_text_mmap, read_text_safe, read_text_safe_opts,
+};
 
 #[test]
 fn test_sync_read() -> Result<(), Box<dyn std::error::Error>> {
@@ -41,3 +43,91 @@ fn test_file_not_found() {
     let result = read_text_safe("/nonexistent/file.txt", 1024);
     assert!(matches!(result, Err(IoError::NotFound(_))));
 }
+
+#[test]
+fn test_stray_nul_with_lenient_threshold() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = TempDir::new()?;
+    let p = dir.path().join("stray_nul.log");
+    let mut content = "log line with a stray byte: ".repeat(4).into_bytes();
+    content.push(0);
+    content.extend_from_slice(b"\nmore text\n");
+    std::fs::write(&p, &content)?;
+
+    let strict = ReadOptions::default();
+    assert!(matches!(
+        read_text_safe_opts(&p, &strict),
+        Err(IoError::BinaryFile)
+    ));
+    assert!(matches!(read_text_safe(&p, 1024), Err(IoError::BinaryFile)));
+
+    let lenient = ReadOptions {
+        max_nul_ratio: 0.01,
+        ..ReadOptions::default()
+    };
+    let text = read_text_safe_opts(&p, &lenient)?;
+    assert!(text.ends_with("more text\n"));
+
+    // A scan window that stops before the NUL also accepts the file.
+    let short_scan = ReadOptions {
+        binary_scan_len: 16,
+        ..ReadOptions::default()
+    };
+    assert!(read_text_safe_opts(&p, &short_scan).is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_read_dir_texts_preserves_order() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = TempDir::new()?;
+    let mut paths = Vec::new();
+    for i in 0..8 {
+        let p = dir.path().join(format!("file_{i}"));
+        if i % 3 == 1 {
+            std::fs::write(&p, b"\x00\x01binary")?;
+        } else {
+            std::fs::write(&p, format!("text {i}"))?;
+        }
+        paths.push(p);
+    }
+
+    let results = read_dir_texts(&paths, 1024);
+    assert_eq!(results.len(), paths.len());
+    for (i, (path, result)) in results.iter().enumerate() {
+        assert_eq!(path, &paths[i]);
+        if i % 3 == 1 {
+            assert!(matches!(result, Err(IoError::BinaryFile)));
+        } else {
+            assert_eq!(result.as_deref().ok(), Some(format!("text {i}").as_str()));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_read_text_mmap_matches_buffered_read() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = TempDir::new()?;
+    let p = dir.path().join("large.txt");
+    let line = "mmap line with unicode: 日本語 ✓\n";
+    let content = line.repeat(64 * 1024);
+    std::fs::write(&p, &content)?;
+
+    let max_bytes = 16 * 1024 * 1024;
+    let mapped = read_text_mmap(&p, max_bytes)?;
+    assert_eq!(mapped, read_text_safe(&p, max_bytes)?);
+    assert_eq!(mapped, content);
+
+    assert!(matches!(
+        read_text_mmap(&p, 1024),
+        Err(IoError::TooLarge(_, _))
+    ));
+    let bin = dir.path().join("binary.bin");
+    std::fs::write(&bin, b"\x00\x01\x02\x03")?;
+    assert!(matches!(
+        read_text_mmap(&bin, 1024),
+        Err(IoError::BinaryFile)
+    ));
+    let empty = dir.path().join("empty.txt");
+    std::fs::write(&empty, "")?;
+    assert_eq!(read_text_mmap(&empty, 1024)?, "");
+    Ok(())
+}
@@ -181,6 +181,18 @@ pub fn extract_optional_string(array: Option<&StringArray>, index: usize) -> Opt
     })
 }
 
+/// Scale `v` in place to unit L2 norm.
+///
+/// Zero vectors (and vectors with a non-finite norm) are left unchanged.
+pub fn normalize_l2(v: &mut [f32]) {
+    let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 && norm.is_finite() {
+        for x in v.iter_mut() {
+            *x /= norm;
+        }
+    }
+}
+
 fn dimension_to_i32(dimension: usize) -> Result<i32, ArrowError> {
     i32::try_from(dimension).map_err(|_| {
         ArrowError::InvalidArgumentError(format!(
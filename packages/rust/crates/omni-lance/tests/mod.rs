@@ -3,7 +3,7 @@
 use lance::deps::arrow_array::RecordBatchReader;
 use omni_lance::{
     CONTENT_COLUMN, ID_COLUMN, METADATA_COLUMN, VECTOR_COLUMN, VectorRecordBatchReader,
-    extract_optional_string, extract_string,
+    extract_optional_string, extract_string, normalize_l2,
 };
 
 #[test]
@@ -80,3 +80,15 @@ fn test_extract_optional_string_array_none() {
     let result = extract_optional_string(None, 0);
     assert_eq!(result, None);
 }
+
+#[test]
+fn test_normalize_l2() {
+    let mut v = vec![3.0_f32, 4.0];
+    normalize_l2(&mut v);
+    assert!((v[0] - 0.6).abs() < 1e-6);
+    assert!((v[1] - 0.8).abs() < 1e-6);
+
+    let mut zero = vec![0.0_f32; 3];
+    normalize_l2(&mut zero);
+    assert_eq!(zero, vec![0.0; 3]);
+}
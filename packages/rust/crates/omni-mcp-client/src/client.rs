@@ -1,23 +1,25 @@
 //! MCP client: full protocol handshake and tool calls.
 //!
 //! **Protocol (MCP spec, same as codex-rs):**
-//! 1. Build transport (Streamable HTTP or stdio via `rmcp`).
+//! 1. Build transport (Streamable HTTP, optionally with custom headers, or stdio via `rmcp`).
 //! 2. `serve_client(init_params, transport)` runs the handshake:
 //!    - Client sends `initialize` request (JSON-RPC) with protocolVersion, capabilities, clientInfo.
 //!    - Server responds with 200 + JSON `InitializeResult` and `Mcp-Session-Id` header.
 //!    - Client sends `notifications/initialized` (no id); server must respond **202 Accepted**.
-//! 3. After handshake, use `list_tools` and `call_tool` on the running service.
+//! 3. After handshake, use `list_tools` and `call_tool` on the running service (`ping` for liveness).
 //!
 //! Reference: [MCP Streamable HTTP](https://spec.modelcontextprotocol.io/specification/2024-11-05/server/streamableHTTP/),
 //! codex-rs `rmcp-client` (`serve_client` + `RunningService`).
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use rmcp::model::{
-    CallToolRequestParams, ClientCapabilities, InitializeRequestParams, PaginatedRequestParams,
-    ProtocolVersion,
+    CallToolRequestParams, ClientCapabilities, ClientRequest, InitializeRequestParams,
+    PaginatedRequestParams, PingRequest, ProtocolVersion,
 };
 use rmcp::service::{RoleClient, serve_client};
 use rmcp::transport::StreamableHttpClientTransport;
@@ -30,13 +32,35 @@ use crate::config::McpServerTransportConfig;
 
 /// Build init params for the omni Python MCP server (protocol 2024-11-05).
 /// Use this when connecting to `omni mcp --transport sse` so protocol version matches server support.
+///
+/// Default-args wrapper over [`init_params_with`]: build-env client info, default capabilities.
 #[must_use]
 pub fn init_params_omni_server() -> InitializeRequestParams {
+    let client_info = rmcp::model::Implementation::from_build_env();
+    init_params_with(
+        &client_info.name,
+        &client_info.version,
+        ClientCapabilities::default(),
+    )
+}
+
+/// Build init params (protocol 2024-11-05) advertising a custom client name, version and
+/// capabilities, for third-party servers that gate features on client info.
+#[must_use]
+pub fn init_params_with(
+    client_name: &str,
+    version: &str,
+    capabilities: ClientCapabilities,
+) -> InitializeRequestParams {
     InitializeRequestParams {
         meta: None,
         protocol_version: ProtocolVersion::V_2024_11_05,
-        capabilities: ClientCapabilities::default(),
-        client_info: rmcp::model::Implementation::from_build_env(),
+        capabilities,
+        client_info: rmcp::model::Implementation {
+            name: client_name.to_string(),
+            version: version.to_string(),
+            ..rmcp::model::Implementation::from_build_env()
+        },
     }
 }
 
@@ -63,6 +87,55 @@ impl OmniMcpClient {
         }
     }
 
+    /// Connect using a transport config: HTTP variants use Streamable HTTP, `Stdio` spawns a process.
+    ///
+    /// For both HTTP variants, a set `bearer_token_env_var` is resolved from the environment and
+    /// sent as a bearer token (for `Http`, in place of any `Authorization` header); an unset
+    /// variable connects without one.
+    ///
+    /// # Errors
+    /// Same as [`Self::connect_http`] or [`Self::connect_stdio`] for the selected transport.
+    pub async fn connect(
+        transport: &McpServerTransportConfig,
+        init_params: InitializeRequestParams,
+        timeout: Option<Duration>,
+    ) -> Result<Self> {
+        match transport {
+            McpServerTransportConfig::Http {
+                url,
+                headers,
+                bearer_token_env_var,
+            } => {
+                let Some(token) = bearer_token_from_env(bearer_token_env_var.as_deref()) else {
+                    return Self::connect_http(url, headers, init_params, timeout).await;
+                };
+                let mut headers = headers.clone();
+                headers.retain(|name, _| !name.eq_ignore_ascii_case("authorization"));
+                headers.insert("authorization".to_string(), format!("Bearer {token}"));
+                Self::connect_http(url, &headers, init_params, timeout).await
+            }
+            McpServerTransportConfig::StreamableHttp {
+                url,
+                bearer_token_env_var,
+            } => {
+                let mut http_config = StreamableHttpClientTransportConfig::with_uri(url.clone());
+                if let Some(token) = bearer_token_from_env(bearer_token_env_var.as_deref()) {
+                    http_config = http_config.auth_header(token);
+                }
+                Self::connect_http_transport(
+                    reqwest::Client::builder(),
+                    http_config,
+                    init_params,
+                    timeout,
+                )
+                .await
+            }
+            McpServerTransportConfig::Stdio { command, args } => {
+                Self::connect_stdio(command, args, init_params, timeout).await
+            }
+        }
+    }
+
     /// Connect via Streamable HTTP (e.g. `http://127.0.0.1:3000` for our Python MCP SSE).
     ///
     /// # Errors
@@ -73,8 +146,44 @@ impl OmniMcpClient {
         init_params: InitializeRequestParams,
         timeout: Option<Duration>,
     ) -> Result<Self> {
-        let http_config = StreamableHttpClientTransportConfig::with_uri(url.to_string());
-        let http_client = reqwest::Client::builder()
+        Self::connect_http(url, &BTreeMap::new(), init_params, timeout).await
+    }
+
+    /// Connect via Streamable HTTP, sending `headers` with every MCP request.
+    ///
+    /// # Errors
+    /// Returns an error if a header name or value is invalid, the HTTP client cannot be built,
+    /// the MCP handshake times out, or the server rejects initialization.
+    pub async fn connect_http(
+        url: &str,
+        headers: &BTreeMap<String, String>,
+        init_params: InitializeRequestParams,
+        timeout: Option<Duration>,
+    ) -> Result<Self> {
+        let mut header_map = HeaderMap::new();
+        for (name, value) in headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| anyhow::anyhow!("invalid MCP header name {name:?}: {e}"))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|e| anyhow::anyhow!("invalid MCP header value for {name}: {e}"))?;
+            header_map.insert(name, value);
+        }
+        Self::connect_http_transport(
+            reqwest::Client::builder().default_headers(header_map),
+            StreamableHttpClientTransportConfig::with_uri(url.to_string()),
+            init_params,
+            timeout,
+        )
+        .await
+    }
+
+    async fn connect_http_transport(
+        client_builder: reqwest::ClientBuilder,
+        http_config: StreamableHttpClientTransportConfig,
+        init_params: InitializeRequestParams,
+        timeout: Option<Duration>,
+    ) -> Result<Self> {
+        let http_client = client_builder
             .build()
             .map_err(|e| anyhow::anyhow!("reqwest client: {e}"))?;
         let transport = StreamableHttpClientTransport::with_client(http_client, http_config);
@@ -128,6 +237,20 @@ impl OmniMcpClient {
         })
     }
 
+    /// Liveness check: send an MCP `ping` and return the round-trip latency.
+    ///
+    /// # Errors
+    /// Returns an error if the client has not connected yet or the server does not answer.
+    pub async fn ping(&self) -> Result<Duration> {
+        let service = self.ready_service().await?;
+        let started = Instant::now();
+        service
+            .send_request(ClientRequest::PingRequest(PingRequest::default()))
+            .await
+            .map_err(|e| anyhow::anyhow!("ping: {e}"))?;
+        Ok(started.elapsed())
+    }
+
     /// List tools from the MCP server.
     ///
     /// # Errors
@@ -136,15 +259,7 @@ impl OmniMcpClient {
         &self,
         params: Option<PaginatedRequestParams>,
     ) -> Result<rmcp::model::ListToolsResult> {
-        let service = {
-            let guard = self.state.lock().await;
-            match &*guard {
-                ClientState::Ready { service } => Arc::clone(service),
-                ClientState::Connecting => {
-                    return Err(anyhow::anyhow!("MCP client not initialized"));
-                }
-            }
-        };
+        let service = self.ready_service().await?;
         service
             .list_tools(params)
             .await
@@ -160,15 +275,7 @@ impl OmniMcpClient {
         name: String,
         arguments: Option<serde_json::Value>,
     ) -> Result<rmcp::model::CallToolResult> {
-        let service = {
-            let guard = self.state.lock().await;
-            match &*guard {
-                ClientState::Ready { service } => Arc::clone(service),
-                ClientState::Connecting => {
-                    return Err(anyhow::anyhow!("MCP client not initialized"));
-                }
-            }
-        };
+        let service = self.ready_service().await?;
         let args = arguments.and_then(|v| v.as_object().cloned());
         let params = CallToolRequestParams {
             meta: None,
@@ -181,4 +288,21 @@ impl OmniMcpClient {
             .await
             .map_err(|e| anyhow::anyhow!("tools/call: {e}"))
     }
+
+    async fn ready_service(
+        &self,
+    ) -> Result<Arc<rmcp::service::RunningService<RoleClient, InitializeRequestParams>>> {
+        let guard = self.state.lock().await;
+        match &*guard {
+            ClientState::Ready { service } => Ok(Arc::clone(service)),
+            ClientState::Connecting => Err(anyhow::anyhow!("MCP client not initialized")),
+        }
+    }
+}
+
+/// Read a bearer token from `env_var`; unset or blank values yield `None`.
+fn bearer_token_from_env(env_var: Option<&str>) -> Option<String> {
+    env_var
+        .and_then(|name| std::env::var(name).ok())
+        .filter(|token| !token.trim().is_empty())
 }
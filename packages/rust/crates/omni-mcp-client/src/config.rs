@@ -1,13 +1,28 @@
-//! MCP server config: transport (Streamable HTTP or stdio).
+//! MCP server config: transport (Streamable HTTP, HTTP with custom headers, or stdio).
 //!
 //! Minimal shape aligned with codex-rs `McpServerTransportConfig`.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
-/// Transport for one MCP server (Streamable HTTP or stdio).
+/// Transport for one MCP server (Streamable HTTP, HTTP with headers, or stdio).
+///
+/// Untagged: `{"url", "headers"}` selects [`Self::Http`], a bare `{"url"}` selects
+/// [`Self::StreamableHttp`], and `{"command"}` selects [`Self::Stdio`].
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(untagged, rename_all = "snake_case")]
 pub enum McpServerTransportConfig {
+    /// Streamable HTTP with static request headers (e.g. gateway auth or tenant routing).
+    Http {
+        /// MCP server URL (e.g. `http://127.0.0.1:3000/sse`).
+        url: String,
+        /// Headers sent with every MCP request; required to select this variant.
+        headers: BTreeMap<String, String>,
+        /// Optional env var name for bearer token; replaces any `Authorization` header.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        bearer_token_env_var: Option<String>,
+    },
     /// Streamable HTTP: connect to URL (e.g. our Python `omni mcp` SSE endpoint).
     StreamableHttp {
         /// MCP server URL (e.g. `http://127.0.0.1:3000`).
@@ -7,5 +7,5 @@
 mod client;
 mod config;
 
-pub use client::{OmniMcpClient, init_params_omni_server};
+pub use client::{OmniMcpClient, init_params_omni_server, init_params_with};
 pub use config::McpServerTransportConfig;
@@ -1,6 +1,9 @@
-//! Tests for `OmniMcpClient`: from_config, list_tools/call_tool before connect.
+//! Tests for `OmniMcpClient`: from_config, list_tools/call_tool before connect, init params.
 
-use omni_mcp_client::{McpServerTransportConfig, OmniMcpClient};
+use omni_mcp_client::{
+    McpServerTransportConfig, OmniMcpClient, init_params_omni_server, init_params_with,
+};
+use rmcp::model::{ClientCapabilities, ProtocolVersion};
 
 #[test]
 fn from_config_streamable_http_creates_client() {
@@ -57,3 +60,34 @@ async fn call_tool_before_connect_returns_error() {
         msg
     );
 }
+
+#[tokio::test]
+async fn ping_before_connect_returns_error() {
+    let config = McpServerTransportConfig::StreamableHttp {
+        url: "http://127.0.0.1:3000".to_string(),
+        bearer_token_env_var: None,
+    };
+    let client = OmniMcpClient::from_config(&config);
+    let err = client.ping().await.unwrap_err();
+    assert!(err.to_string().contains("not initialized"));
+}
+
+#[test]
+fn init_params_with_carries_client_info_and_capabilities() {
+    let capabilities = ClientCapabilities::builder().enable_roots().build();
+    let params = init_params_with("acme-agent", "2.1.0", capabilities.clone());
+    assert_eq!(params.client_info.name, "acme-agent");
+    assert_eq!(params.client_info.version, "2.1.0");
+    assert_eq!(params.capabilities, capabilities);
+    assert!(params.capabilities.roots.is_some());
+    assert_eq!(params.protocol_version, ProtocolVersion::V_2024_11_05);
+}
+
+#[test]
+fn init_params_omni_server_keeps_default_client_info() {
+    let params = init_params_omni_server();
+    let defaults = rmcp::model::Implementation::from_build_env();
+    assert_eq!(params.client_info.name, defaults.name);
+    assert_eq!(params.client_info.version, defaults.version);
+    assert_eq!(params.capabilities, ClientCapabilities::default());
+}
@@ -78,3 +78,44 @@ fn config_stdio_default_args() {
         _ => panic!("expected Stdio"),
     }
 }
+
+#[test]
+fn config_http_with_headers_selects_http_variant() {
+    let json = r#"{"url":"http://127.0.0.1:3000/sse","headers":{"x-omni-tenant":"acme"}}"#;
+    let config: McpServerTransportConfig = serde_json::from_str(json).expect("deserialize");
+    match &config {
+        McpServerTransportConfig::Http {
+            url,
+            headers,
+            bearer_token_env_var,
+        } => {
+            assert_eq!(url, "http://127.0.0.1:3000/sse");
+            assert!(bearer_token_env_var.is_none());
+            assert_eq!(
+                headers.get("x-omni-tenant").map(String::as_str),
+                Some("acme")
+            );
+        }
+        _ => panic!("expected Http"),
+    }
+    let out = serde_json::to_string(&config).expect("serialize");
+    let again: McpServerTransportConfig = serde_json::from_str(&out).expect("deserialize again");
+    assert!(matches!(again, McpServerTransportConfig::Http { .. }));
+}
+
+#[test]
+fn config_http_with_headers_keeps_bearer_token_env_var() {
+    let json = r#"{"url":"http://127.0.0.1:3000/sse","headers":{"x-omni-tenant":"acme"},"bearer_token_env_var":"MCP_TOKEN"}"#;
+    let config: McpServerTransportConfig = serde_json::from_str(json).expect("deserialize");
+    match &config {
+        McpServerTransportConfig::Http {
+            bearer_token_env_var,
+            ..
+        } => {
+            assert_eq!(bearer_token_env_var.as_deref(), Some("MCP_TOKEN"));
+        }
+        _ => panic!("expected Http"),
+    }
+    let out = serde_json::to_string(&config).expect("serialize");
+    assert!(out.contains("\"bearer_token_env_var\":\"MCP_TOKEN\""));
+}
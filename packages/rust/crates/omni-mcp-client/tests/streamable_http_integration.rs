@@ -130,33 +130,38 @@ mod mock {
     }
 }
 
-#[tokio::test]
-async fn test_connect_with_mock_server() {
-    use axum::Router;
+fn mock_service(
+    ct: &tokio_util::sync::CancellationToken,
+) -> rmcp::transport::streamable_http_server::StreamableHttpService<
+    mock::MockMcpServer,
+    rmcp::transport::streamable_http_server::session::local::LocalSessionManager,
+> {
     use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
     use rmcp::transport::streamable_http_server::{
         StreamableHttpServerConfig, StreamableHttpService,
     };
-    use tokio_util::sync::CancellationToken;
 
-    let ct = CancellationToken::new();
-    let service: StreamableHttpService<mock::MockMcpServer, LocalSessionManager> =
-        StreamableHttpService::new(
-            || Ok(mock::MockMcpServer),
-            Arc::new(LocalSessionManager::default()),
-            StreamableHttpServerConfig {
-                stateful_mode: true,
-                sse_keep_alive: None,
-                cancellation_token: ct.child_token(),
-                ..Default::default()
-            },
-        );
-    let router = Router::new().nest_service("/mcp", service);
+    StreamableHttpService::new(
+        || Ok(mock::MockMcpServer),
+        Arc::new(LocalSessionManager::default()),
+        StreamableHttpServerConfig {
+            stateful_mode: true,
+            sse_keep_alive: None,
+            cancellation_token: ct.child_token(),
+            ..Default::default()
+        },
+    )
+}
+
+/// Serve `router` on an ephemeral port until `ct` is cancelled; returns the bound address.
+async fn serve_mock(
+    router: axum::Router,
+    ct: &tokio_util::sync::CancellationToken,
+) -> (std::net::SocketAddr, tokio::task::JoinHandle<()>) {
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
         .await
         .expect("bind");
     let addr = listener.local_addr().expect("local_addr");
-
     let handle = tokio::spawn({
         let ct = ct.clone();
         async move {
@@ -165,6 +170,17 @@ async fn test_connect_with_mock_server() {
                 .await;
         }
     });
+    (addr, handle)
+}
+
+#[tokio::test]
+async fn test_connect_with_mock_server() {
+    use axum::Router;
+    use tokio_util::sync::CancellationToken;
+
+    let ct = CancellationToken::new();
+    let router = Router::new().nest_service("/mcp", mock_service(&ct));
+    let (addr, handle) = serve_mock(router, &ct).await;
 
     let url = format!("http://{}/mcp", addr);
     run_client_assertions(&url, 10).await;
@@ -172,3 +188,152 @@ async fn test_connect_with_mock_server() {
     ct.cancel();
     let _ = handle.await;
 }
+
+#[tokio::test]
+async fn test_http_transport_config_sends_headers() {
+    use axum::Router;
+    use axum::extract::Request;
+    use axum::http::StatusCode;
+    use axum::middleware::{self, Next};
+    use axum::response::{IntoResponse, Response};
+    use omni_mcp_client::McpServerTransportConfig;
+    use std::collections::BTreeMap;
+    use tokio_util::sync::CancellationToken;
+
+    async fn require_tenant(request: Request, next: Next) -> Response {
+        let tenant = request
+            .headers()
+            .get("x-omni-tenant")
+            .and_then(|value| value.to_str().ok());
+        if tenant == Some("acme") {
+            next.run(request).await
+        } else {
+            StatusCode::UNAUTHORIZED.into_response()
+        }
+    }
+
+    let ct = CancellationToken::new();
+    let router = Router::new()
+        .nest_service("/mcp", mock_service(&ct))
+        .layer(middleware::from_fn(require_tenant));
+    let (addr, handle) = serve_mock(router, &ct).await;
+    let url = format!("http://{}/mcp", addr);
+    let timeout = Some(Duration::from_secs(10));
+
+    let config: McpServerTransportConfig = serde_json::from_value(serde_json::json!({
+        "url": url,
+        "headers": {"x-omni-tenant": "acme"}
+    }))
+    .expect("deserialize http transport config");
+    assert!(matches!(config, McpServerTransportConfig::Http { .. }));
+    let client = OmniMcpClient::connect(&config, init_params_omni_server(), timeout)
+        .await
+        .expect("connect over http with headers");
+    let list = client.list_tools(None).await.expect("list_tools");
+    assert_eq!(list.tools.len(), 1);
+    assert_eq!(list.tools[0].name, "mock_echo");
+
+    let without_headers = McpServerTransportConfig::Http {
+        url,
+        headers: BTreeMap::new(),
+        bearer_token_env_var: None,
+    };
+    assert!(
+        OmniMcpClient::connect(&without_headers, init_params_omni_server(), timeout)
+            .await
+            .is_err(),
+        "server should reject the handshake without the tenant header"
+    );
+
+    ct.cancel();
+    let _ = handle.await;
+}
+
+#[tokio::test]
+async fn test_http_transport_config_sends_bearer_token_from_env() {
+    use axum::Router;
+    use axum::extract::Request;
+    use axum::http::StatusCode;
+    use axum::middleware::{self, Next};
+    use axum::response::{IntoResponse, Response};
+    use omni_mcp_client::McpServerTransportConfig;
+    use tokio_util::sync::CancellationToken;
+
+    async fn require_bearer(request: Request, next: Next) -> Response {
+        let auth = request
+            .headers()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok());
+        if auth == Some(concat!("Bearer ", env!("CARGO_PKG_NAME"))) {
+            next.run(request).await
+        } else {
+            StatusCode::UNAUTHORIZED.into_response()
+        }
+    }
+
+    let ct = CancellationToken::new();
+    let router = Router::new()
+        .nest_service("/mcp", mock_service(&ct))
+        .layer(middleware::from_fn(require_bearer));
+    let (addr, handle) = serve_mock(router, &ct).await;
+    let timeout = Some(Duration::from_secs(10));
+
+    // An unset token variable leaves the configured headers as they are.
+    let unset: McpServerTransportConfig = serde_json::from_value(serde_json::json!({
+        "url": format!("http://{}/mcp", addr),
+        "headers": {"Authorization": "Bearer stale"},
+        "bearer_token_env_var": "OMNI_MCP_CLIENT_TEST_UNSET_TOKEN"
+    }))
+    .expect("deserialize http transport config");
+    assert!(
+        OmniMcpClient::connect(&unset, init_params_omni_server(), timeout)
+            .await
+            .is_err(),
+        "server should reject the stale header"
+    );
+
+    // Cargo sets `CARGO_PKG_NAME` for test processes; its value replaces the stale header.
+    let config: McpServerTransportConfig = serde_json::from_value(serde_json::json!({
+        "url": format!("http://{}/mcp", addr),
+        "headers": {"Authorization": "Bearer stale"},
+        "bearer_token_env_var": "CARGO_PKG_NAME"
+    }))
+    .expect("deserialize http transport config");
+    let client = OmniMcpClient::connect(&config, init_params_omni_server(), timeout)
+        .await
+        .expect("connect over http with bearer token");
+    let list = client.list_tools(None).await.expect("list_tools");
+    assert_eq!(list.tools.len(), 1);
+
+    ct.cancel();
+    let _ = handle.await;
+}
+
+#[tokio::test]
+async fn test_ping_reports_latency_and_detects_dead_server() {
+    use axum::Router;
+    use tokio_util::sync::CancellationToken;
+
+    let ct = CancellationToken::new();
+    let router = Router::new().nest_service("/mcp", mock_service(&ct));
+    let (addr, handle) = serve_mock(router, &ct).await;
+    let client = OmniMcpClient::connect_streamable_http(
+        &format!("http://{}/mcp", addr),
+        init_params_omni_server(),
+        Some(Duration::from_secs(10)),
+    )
+    .await
+    .expect("connect to mock server");
+
+    let latency = client.ping().await.expect("ping live server");
+    assert!(latency > Duration::ZERO, "expected positive ping latency");
+
+    ct.cancel();
+    handle.abort();
+    let _ = handle.await;
+
+    let dead = tokio::time::timeout(Duration::from_secs(10), client.ping())
+        .await
+        .expect("ping against dead server should not hang");
+    assert!(dead.is_err(), "ping should fail once the server is gone");
+}
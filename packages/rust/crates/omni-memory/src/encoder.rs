@@ -4,23 +4,120 @@
 //! Uses a hash-based approach for quick encoding without external dependencies.
 
 use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
 use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
 
 /// Encode intent text into a fixed-size embedding vector.
 ///
 /// Uses a simple hash-based encoding that maps similar intents to similar vectors.
 /// For production, this would be replaced with actual embedding models.
-#[derive(Clone)]
 pub struct IntentEncoder {
     /// Dimension of the embedding vector
     dimension: usize,
+    /// Optional bounded cache of previous encodings keyed by intent text.
+    cache: Option<Mutex<EncodingCache>>,
+    /// Number of encodings actually computed (cache misses included).
+    compute_count: AtomicU64,
+}
+
+/// Bounded FIFO cache of intent encodings.
+struct EncodingCache {
+    capacity: usize,
+    entries: HashMap<String, Vec<f32>>,
+    order: VecDeque<String>,
+}
+
+impl EncodingCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn insert(&mut self, intent: &str, embedding: Vec<f32>) {
+        if self.entries.contains_key(intent) {
+            return;
+        }
+        while self.entries.len() >= self.capacity {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+        self.order.push_back(intent.to_string());
+        self.entries.insert(intent.to_string(), embedding);
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+// Manual Clone implementation - keeps cache capacity but starts with an empty cache
+impl Clone for IntentEncoder {
+    fn clone(&self) -> Self {
+        Self {
+            dimension: self.dimension,
+            cache: self
+                .cache
+                .as_ref()
+                .map(|cache| Mutex::new(EncodingCache::new(lock_cache(cache).capacity))),
+            compute_count: AtomicU64::new(0),
+        }
+    }
+}
+
+fn lock_cache(cache: &Mutex<EncodingCache>) -> std::sync::MutexGuard<'_, EncodingCache> {
+    cache.lock().unwrap_or_else(PoisonError::into_inner)
 }
 
 impl IntentEncoder {
     /// Create a new encoder with specified dimension.
     #[must_use]
     pub fn new(dimension: usize) -> Self {
-        Self { dimension }
+        Self {
+            dimension,
+            cache: None,
+            compute_count: AtomicU64::new(0),
+        }
+    }
+
+    /// Create an encoder that caches up to `capacity` encodings keyed by intent text.
+    ///
+    /// A `capacity` of zero disables caching. When full, the oldest entry is evicted.
+    #[must_use]
+    pub fn with_cache(dimension: usize, capacity: usize) -> Self {
+        Self {
+            dimension,
+            cache: (capacity > 0).then(|| Mutex::new(EncodingCache::new(capacity))),
+            compute_count: AtomicU64::new(0),
+        }
+    }
+
+    /// Drop all cached encodings.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            lock_cache(cache).clear();
+        }
+    }
+
+    /// Number of encodings currently cached.
+    #[must_use]
+    pub fn cached_len(&self) -> usize {
+        self.cache
+            .as_ref()
+            .map_or(0, |cache| lock_cache(cache).entries.len())
+    }
+
+    /// Number of encodings computed so far (cache hits are not counted).
+    #[must_use]
+    pub fn compute_count(&self) -> u64 {
+        self.compute_count.load(Ordering::Relaxed)
     }
 
     /// Encode intent text into embedding vector.
@@ -30,8 +127,23 @@ impl IntentEncoder {
     /// 2. Use hash to seed random number generator
     /// 3. Generate deterministic random vector
     /// 4. Apply position-based perturbations for uniqueness
+    ///
+    /// When caching is enabled, repeated intents are served from the cache.
     #[must_use]
     pub fn encode(&self, intent: &str) -> Vec<f32> {
+        let Some(cache) = &self.cache else {
+            return self.compute(intent);
+        };
+        if let Some(hit) = lock_cache(cache).entries.get(intent) {
+            return hit.clone();
+        }
+        let embedding = self.compute(intent);
+        lock_cache(cache).insert(intent, embedding.clone());
+        embedding
+    }
+
+    fn compute(&self, intent: &str) -> Vec<f32> {
+        self.compute_count.fetch_add(1, Ordering::Relaxed);
         let mut embedding = vec![0.0; self.dimension];
 
         // Create multiple hash variants for better distribution
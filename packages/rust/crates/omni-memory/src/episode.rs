@@ -44,6 +44,9 @@ pub struct Episode {
     /// Logical memory scope (for example, `session_id`) used for isolation.
     #[serde(default = "default_episode_scope")]
     pub scope: String,
+    /// Optional task category (for example, `debug` or `refactor`) used to filter recall.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_type: Option<String>,
 }
 
 impl Episode {
@@ -109,9 +112,17 @@ impl Episode {
             failure_count: 0,
             created_at: Utc::now().timestamp_millis(),
             scope: Self::normalize_scope(&scope),
+            task_type: None,
         }
     }
 
+    /// Set the task category of this episode.
+    #[must_use]
+    pub fn with_task_type(mut self, task_type: impl Into<String>) -> Self {
+        self.task_type = Some(task_type.into());
+        self
+    }
+
     /// Calculate the utility of this episode.
     ///
     /// Utility is computed as: `success_rate * q_value`
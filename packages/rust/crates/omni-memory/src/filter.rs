@@ -0,0 +1,71 @@
+//! Metadata predicates for scoping episode recall.
+
+use crate::episode::Episode;
+
+/// Metadata filter for [`crate::EpisodeStore::search_filtered`].
+///
+/// Every set field must match; an empty filter matches all episodes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EpisodeFilter {
+    /// Only episodes with this task type.
+    pub task_type: Option<String>,
+    /// Only episodes with this outcome (exact match).
+    pub outcome: Option<String>,
+    /// Only episodes in this logical scope.
+    pub scope: Option<String>,
+    /// Only episodes whose current Q-value is at least this value.
+    pub min_q_value: Option<f32>,
+}
+
+impl EpisodeFilter {
+    /// Create an empty filter (matches everything).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict to one task type.
+    #[must_use]
+    pub fn with_task_type(mut self, task_type: impl Into<String>) -> Self {
+        self.task_type = Some(task_type.into());
+        self
+    }
+
+    /// Restrict to one outcome.
+    #[must_use]
+    pub fn with_outcome(mut self, outcome: impl Into<String>) -> Self {
+        self.outcome = Some(outcome.into());
+        self
+    }
+
+    /// Restrict to one logical scope.
+    #[must_use]
+    pub fn with_scope(mut self, scope: &str) -> Self {
+        self.scope = Some(Episode::normalize_scope(scope));
+        self
+    }
+
+    /// Require a minimum Q-value.
+    #[must_use]
+    pub fn with_min_q_value(mut self, min_q_value: f32) -> Self {
+        self.min_q_value = Some(min_q_value);
+        self
+    }
+
+    /// Check `episode` against the filter, using `q_value` as its current Q-value.
+    #[must_use]
+    pub fn matches(&self, episode: &Episode, q_value: f32) -> bool {
+        self.task_type
+            .as_deref()
+            .is_none_or(|task_type| episode.task_type.as_deref() == Some(task_type))
+            && self
+                .outcome
+                .as_deref()
+                .is_none_or(|outcome| episode.outcome == outcome)
+            && self
+                .scope
+                .as_deref()
+                .is_none_or(|scope| episode.scope_key() == Episode::normalize_scope(scope))
+            && self.min_q_value.is_none_or(|min_q| q_value >= min_q)
+    }
+}
@@ -1,4 +1,4 @@
-//! 3-in-1 memory gate: retain / obsolete / promote.
+//! 3-in-1 memory gate: retain / obsolete / promote (plus forced-store overrides).
 //!
 //! This module provides a deterministic utility ledger and gate policy that
 //! can be replayed for audits.
@@ -17,6 +17,8 @@ pub enum MemoryGateVerdict {
     Obsolete,
     /// Promote the episode to long-term knowledge workflows.
     Promote,
+    /// Keep the episode because the caller overrode the utility check.
+    ForcedStore,
 }
 
 impl MemoryGateVerdict {
@@ -27,6 +29,7 @@ impl MemoryGateVerdict {
             Self::Retain => "retain",
             Self::Obsolete => "obsolete",
             Self::Promote => "promote",
+            Self::ForcedStore => "forced_store",
         }
     }
 }
@@ -158,6 +161,37 @@ impl MemoryUtilityLedger {
     }
 }
 
+/// One exported ledger row keyed by memory id, used for offline policy tuning.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryUtilityLedgerEntry {
+    /// Memory episode id this ledger belongs to.
+    pub memory_id: String,
+    /// Last time (Unix ms) the memory was used.
+    pub last_used: i64,
+    /// Utility ledger snapshot for the memory.
+    pub ledger: MemoryUtilityLedger,
+}
+
+impl MemoryUtilityLedger {
+    /// Serialize ledger entries to a JSON array for offline analysis.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn export(entries: &[MemoryUtilityLedgerEntry]) -> Result<String, serde_json::Error> {
+        serde_json::to_string(entries)
+    }
+
+    /// Restore ledger entries previously produced by [`Self::export`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is not a valid ledger export.
+    pub fn import(payload: &str) -> Result<Vec<MemoryUtilityLedgerEntry>, serde_json::Error> {
+        serde_json::from_str(payload)
+    }
+}
+
 /// Deterministic policy thresholds for memory gate.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct MemoryGatePolicy {
@@ -244,31 +278,62 @@ impl MemoryGatePolicy {
                 let to_obsolete = (ledger.utility_score - self.obsolete_threshold).abs();
                 (0.42 + to_promote.min(to_obsolete) * 0.6).clamp(0.0, 0.85)
             }
+            MemoryGateVerdict::ForcedStore => 1.0,
         };
 
-        let reason = format!(
-            "utility={:.3}, ttl={:.3}, uses={}, failure_rate={:.3}, react={:.3}, graph={:.3}, omega={:.3}",
-            ledger.utility_score,
-            ledger.ttl_score,
-            ledger.usage_count,
-            ledger.failure_rate,
-            ledger.react_revalidation_score,
-            ledger.graph_consistency_score,
-            ledger.omega_alignment_score
-        );
-
         MemoryGateDecision {
             verdict,
             confidence,
             react_evidence_refs,
             graph_evidence_refs,
             omega_factors,
-            reason,
+            reason: ledger_reason(ledger),
+            next_action: verdict.as_str().to_string(),
+        }
+    }
+
+    /// Record a forced-store decision that bypasses the utility thresholds.
+    ///
+    /// The ledger metrics are still captured in the reason and omega factors so
+    /// the override remains auditable alongside regular gate decisions.
+    #[must_use]
+    pub fn force_store(
+        ledger: &MemoryUtilityLedger,
+        react_evidence_refs: Vec<String>,
+        graph_evidence_refs: Vec<String>,
+        mut omega_factors: Vec<String>,
+        override_reason: &str,
+    ) -> MemoryGateDecision {
+        omega_factors.push("forced_store=true".to_string());
+        omega_factors.push(format!("utility_score={:.3}", ledger.utility_score));
+        omega_factors.push(format!("ttl_score={:.3}", ledger.ttl_score));
+
+        let verdict = MemoryGateVerdict::ForcedStore;
+        MemoryGateDecision {
+            verdict,
+            confidence: 1.0,
+            react_evidence_refs,
+            graph_evidence_refs,
+            omega_factors,
+            reason: format!("forced_store: {override_reason}; {}", ledger_reason(ledger)),
             next_action: verdict.as_str().to_string(),
         }
     }
 }
 
+fn ledger_reason(ledger: &MemoryUtilityLedger) -> String {
+    format!(
+        "utility={:.3}, ttl={:.3}, uses={}, failure_rate={:.3}, react={:.3}, graph={:.3}, omega={:.3}",
+        ledger.utility_score,
+        ledger.ttl_score,
+        ledger.usage_count,
+        ledger.failure_rate,
+        ledger.react_revalidation_score,
+        ledger.graph_consistency_score,
+        ledger.omega_alignment_score
+    )
+}
+
 /// Auditable gate-event payload aligned with `omni.memory.gate_event.v1`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MemoryGateEvent {
@@ -300,7 +365,9 @@ impl MemoryGateEvent {
     ) -> Self {
         let state_before = MemoryLifecycleState::Active;
         let state_after = match decision.verdict {
-            MemoryGateVerdict::Retain => MemoryLifecycleState::Active,
+            MemoryGateVerdict::Retain | MemoryGateVerdict::ForcedStore => {
+                MemoryLifecycleState::Active
+            }
             MemoryGateVerdict::Obsolete => MemoryLifecycleState::Purged,
             MemoryGateVerdict::Promote => MemoryLifecycleState::Promoted,
         };
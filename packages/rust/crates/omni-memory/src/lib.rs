@@ -58,6 +58,7 @@
 
 mod encoder;
 mod episode;
+mod filter;
 mod gate;
 mod persistence;
 mod q_table;
@@ -79,9 +80,10 @@ mod pymodule_impl;
 
 pub use encoder::IntentEncoder;
 pub use episode::Episode;
+pub use filter::EpisodeFilter;
 pub use gate::{
     MemoryGateDecision, MemoryGateEvent, MemoryGatePolicy, MemoryGateVerdict, MemoryLifecycleState,
-    MemoryUtilityLedger,
+    MemoryUtilityLedger, MemoryUtilityLedgerEntry,
 };
 pub use q_table::QTable;
 pub use schema::EpisodeMetadata;
@@ -89,7 +91,9 @@ pub use schema::EpisodeMetadata;
 pub use state_backend::ValkeyMemoryStateStore;
 pub use state_backend::{LocalMemoryStateStore, MemoryStateStore, default_valkey_state_key};
 pub use store::{EpisodeStore, MemoryStateSnapshot, StoreConfig};
-pub use two_phase::{TwoPhaseConfig, TwoPhaseSearch, calculate_score};
+pub use two_phase::{
+    TwoPhaseConfig, TwoPhaseConfigError, TwoPhaseResults, TwoPhaseSearch, calculate_score,
+};
 
 // Python bindings re-exports
 #[cfg(feature = "pybindings")]
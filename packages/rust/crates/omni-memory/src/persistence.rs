@@ -8,6 +8,7 @@ use std::path::Path;
 /// - ensure parent directory exists
 /// - write to a temp file in the same directory
 /// - fsync file + rename into place
+/// - fsync the parent directory (Unix) so the rename itself is durable
 pub(crate) fn atomic_write_text(path: &Path, content: &str) -> Result<()> {
     let parent = path.parent().unwrap_or_else(|| Path::new("."));
     std::fs::create_dir_all(parent).with_context(|| {
@@ -46,5 +47,10 @@ pub(crate) fn atomic_write_text(path: &Path, content: &str) -> Result<()> {
         )
     })?;
 
+    #[cfg(unix)]
+    std::fs::File::open(parent)
+        .and_then(|dir| dir.sync_all())
+        .with_context(|| format!("failed to fsync directory {}", parent.display()))?;
+
     Ok(())
 }
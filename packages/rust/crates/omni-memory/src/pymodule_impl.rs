@@ -414,20 +414,63 @@ mod pymodule_impl {
         pub k2: usize,
         #[pyo3(get, set)]
         pub lambda: f32,
+        #[pyo3(get, set)]
+        pub semantic_weight: Option<f32>,
+        #[pyo3(get, set)]
+        pub utility_weight: Option<f32>,
     }
 
     #[pymethods]
     impl PyTwoPhaseConfig {
         #[new]
-        fn new(k1: Option<usize>, k2: Option<usize>, lambda: Option<f32>) -> Self {
+        #[pyo3(signature = (k1=None, k2=None, lambda=None, semantic_weight=None, utility_weight=None))]
+        fn new(
+            k1: Option<usize>,
+            k2: Option<usize>,
+            lambda: Option<f32>,
+            semantic_weight: Option<f32>,
+            utility_weight: Option<f32>,
+        ) -> PyResult<Self> {
+            let defaults = TwoPhaseConfig::default();
+            let config = Self {
+                k1: k1.unwrap_or(defaults.k1),
+                k2: k2.unwrap_or(defaults.k2),
+                lambda: lambda.unwrap_or(defaults.lambda),
+                semantic_weight,
+                utility_weight,
+            };
+            TwoPhaseConfig::from(config.clone())
+                .validate()
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+            Ok(config)
+        }
+    }
+
+    impl From<PyTwoPhaseConfig> for TwoPhaseConfig {
+        fn from(c: PyTwoPhaseConfig) -> Self {
             Self {
-                k1: k1.unwrap_or(20),
-                k2: k2.unwrap_or(5),
-                lambda: lambda.unwrap_or(0.3),
+                k1: c.k1,
+                k2: c.k2,
+                lambda: c.lambda,
+                semantic_weight: c.semantic_weight,
+                utility_weight: c.utility_weight,
             }
         }
     }
 
+    /// Build a validated search engine; fields set from Python after construction are checked here.
+    fn new_two_phase_search(
+        q_table: &PyQTable,
+        encoder: &PyIntentEncoder,
+        config: Option<PyTwoPhaseConfig>,
+    ) -> PyResult<TwoPhaseSearch> {
+        let q_table = Arc::new(q_table.inner.clone());
+        let encoder = Arc::new(encoder.inner.clone());
+        let config = config.map(TwoPhaseConfig::from).unwrap_or_default();
+        TwoPhaseSearch::new(q_table, encoder, config)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
     /// Python wrapper for TwoPhaseSearch.
     #[pyclass]
     pub struct PyTwoPhaseSearch {
@@ -441,18 +484,10 @@ mod pymodule_impl {
             q_table: &PyQTable,
             encoder: &PyIntentEncoder,
             config: Option<PyTwoPhaseConfig>,
-        ) -> Self {
-            let q_table = Arc::new(q_table.inner.clone());
-            let encoder = Arc::new(encoder.inner.clone());
-            let config = config.map(|c| TwoPhaseConfig {
-                k1: c.k1,
-                k2: c.k2,
-                lambda: c.lambda,
-            });
-            let config = config.unwrap_or_default();
-            Self {
-                inner: TwoPhaseSearch::new(q_table, encoder, config),
-            }
+        ) -> PyResult<Self> {
+            Ok(Self {
+                inner: new_two_phase_search(q_table, encoder, config)?,
+            })
         }
 
         fn search(
@@ -542,28 +577,41 @@ mod pymodule_impl {
         }
     }
 
+    /// Create a two-phase search engine.
+    ///
+    /// # Errors
+    ///
+    /// Raises `ValueError` if `config` is invalid.
     #[pyfunction]
     pub fn create_two_phase_search(
         q_table: &PyQTable,
         encoder: &PyIntentEncoder,
         config: Option<PyTwoPhaseConfig>,
-    ) -> PyTwoPhaseSearch {
-        let q_table = Arc::new(q_table.inner.clone());
-        let encoder = Arc::new(encoder.inner.clone());
-        let config = config.map(|c| TwoPhaseConfig {
-            k1: c.k1,
-            k2: c.k2,
-            lambda: c.lambda,
-        });
-        let config = config.unwrap_or_default();
-        PyTwoPhaseSearch {
-            inner: TwoPhaseSearch::new(q_table, encoder, config),
-        }
+    ) -> PyResult<PyTwoPhaseSearch> {
+        Ok(PyTwoPhaseSearch {
+            inner: new_two_phase_search(q_table, encoder, config)?,
+        })
     }
 
+    /// Combined score; explicit weights override the ones derived from `lambda`.
     #[pyfunction]
-    pub fn calculate_score(similarity: f32, q_value: f32, lambda: f32) -> f32 {
-        calc_score(similarity, q_value, lambda)
+    #[pyo3(signature = (similarity, q_value, lambda=None, semantic_weight=None, utility_weight=None))]
+    pub fn calculate_score(
+        similarity: f32,
+        q_value: f32,
+        lambda: Option<f32>,
+        semantic_weight: Option<f32>,
+        utility_weight: Option<f32>,
+    ) -> f32 {
+        let defaults = TwoPhaseConfig::default();
+        let config = TwoPhaseConfig {
+            lambda: lambda.unwrap_or(defaults.lambda),
+            semantic_weight,
+            utility_weight,
+            ..defaults
+        };
+        let (semantic_weight, utility_weight) = config.weights();
+        calc_score(similarity, q_value, semantic_weight, utility_weight)
     }
 
     // ============================================================================
@@ -46,6 +46,9 @@ pub struct EpisodeMetadata {
     pub failure_count: u32,
     /// Creation timestamp (Unix milliseconds).
     pub created_at: i64,
+    /// Optional task category used to filter recall.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_type: Option<String>,
 }
 
 impl EpisodeMetadata {
@@ -72,9 +75,17 @@ impl EpisodeMetadata {
             success_count,
             failure_count,
             created_at,
+            task_type: None,
         })
     }
 
+    /// Set the task category.
+    #[must_use]
+    pub fn with_task_type(mut self, task_type: impl Into<String>) -> Self {
+        self.task_type = Some(task_type.into());
+        self
+    }
+
     /// Deserialize from JSON string with strict validation.
     ///
     /// # Errors
@@ -112,6 +123,7 @@ impl Default for EpisodeMetadata {
             success_count: 0,
             failure_count: 0,
             created_at: 0,
+            task_type: None,
         }
     }
 }
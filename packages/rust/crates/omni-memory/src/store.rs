@@ -4,8 +4,10 @@
 
 use crate::encoder::IntentEncoder;
 use crate::episode::Episode;
+use crate::filter::EpisodeFilter;
 use crate::persistence::atomic_write_text;
 use crate::q_table::QTable;
+use crate::two_phase::TwoPhaseConfig;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -69,12 +71,29 @@ fn default_memory_store_path() -> String {
     data_home.join("omni-memory").to_string_lossy().to_string()
 }
 
+/// Two-phase configuration scoring with a single Q-value share `lambda`.
+fn lambda_config(k1: usize, k2: usize, lambda: f32) -> TwoPhaseConfig {
+    TwoPhaseConfig {
+        k1,
+        k2,
+        lambda,
+        ..TwoPhaseConfig::default()
+    }
+}
+
 /// Episode store with `LanceDB` persistence and Q-learning.
 ///
 /// Provides:
 /// - Vector search for semantic recall
 /// - Q-value updates via Q-learning
 /// - Persistent storage
+///
+/// # Durability
+///
+/// Writes (`store`, `update_q`, ...) are synchronous but in-memory only; nothing
+/// reaches disk until [`Self::flush`] (or [`Self::save_state`]) is called. Each
+/// flush is atomic and fsynced, so after it returns a crash loses nothing written
+/// before the call.
 pub struct EpisodeStore {
     /// Q-table for episode utility tracking
     pub q_table: QTable,
@@ -155,13 +174,23 @@ impl EpisodeStore {
         scope: Option<&str>,
     ) -> Vec<(Episode, f32)> {
         let scope_key = scope.map(Episode::normalize_scope);
+        self.recall_matching(embedding, top_k, |episode| match scope_key.as_deref() {
+            Some(key) => episode.scope_key() == key,
+            None => true,
+        })
+    }
+
+    /// Vector recall over the episodes accepted by `predicate`.
+    fn recall_matching(
+        &self,
+        embedding: &[f32],
+        top_k: usize,
+        predicate: impl Fn(&Episode) -> bool,
+    ) -> Vec<(Episode, f32)> {
         let episodes = self.read_episodes();
         let mut similarities: Vec<(Episode, f32)> = episodes
             .iter()
-            .filter(|episode| match scope_key.as_deref() {
-                Some(key) => episode.scope_key() == key,
-                None => true,
-            })
+            .filter(|episode| predicate(episode))
             .map(|episode| {
                 let similarity = self
                     .encoder
@@ -177,21 +206,20 @@ impl EpisodeStore {
     fn rerank_candidates(
         &self,
         candidates: Vec<(Episode, f32)>,
-        k2: usize,
-        lambda: f32,
+        config: &TwoPhaseConfig,
     ) -> Vec<(Episode, f32)> {
         let mut scored: Vec<(Episode, f32)> = candidates
             .into_iter()
             .map(|(mut episode, similarity)| {
                 let q_value = self.q_table.get_q(&episode.id);
                 episode.q_value = q_value;
-                let score = (1.0 - lambda) * similarity + lambda * q_value;
+                let score = config.score(similarity, q_value);
                 (episode, score)
             })
             .collect();
 
         scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-        scored.into_iter().take(k2).collect()
+        scored.into_iter().take(config.k2).collect()
     }
 
     /// Store a new episode.
@@ -228,6 +256,56 @@ impl EpisodeStore {
         self.store(episode)
     }
 
+    /// Store an episode unless a near-identical one already exists.
+    ///
+    /// The most similar episode in the same scope is found by intent-embedding
+    /// cosine similarity. If that similarity is at least `similarity_threshold`,
+    /// the existing episode takes over the new experience, outcome and timestamp
+    /// (keeping its id, Q-value and feedback counts) and its id is returned.
+    /// Otherwise the episode is stored as with [`Self::store`].
+    ///
+    /// # Errors
+    ///
+    /// This currently never returns an error and is modeled as `Result` for API compatibility.
+    #[allow(clippy::unnecessary_wraps)]
+    pub fn insert_deduped(
+        &self,
+        mut episode: Episode,
+        similarity_threshold: f32,
+    ) -> Result<String> {
+        Self::normalize_episode_scope(&mut episode);
+
+        let mut episodes = self.write_episodes();
+        let best_match = episodes
+            .iter_mut()
+            .filter(|existing| existing.scope_key() == episode.scope_key())
+            .map(|existing| {
+                let similarity = self
+                    .encoder
+                    .cosine_similarity(&episode.intent_embedding, &existing.intent_embedding);
+                (existing, similarity)
+            })
+            .filter(|(_, similarity)| *similarity >= similarity_threshold)
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        if let Some((existing, similarity)) = best_match {
+            log::debug!(
+                "Merged episode {} into {} (similarity {similarity:.3})",
+                episode.id,
+                existing.id
+            );
+            existing.experience = episode.experience;
+            existing.outcome = episode.outcome;
+            existing.created_at = episode.created_at;
+            return Ok(existing.id.clone());
+        }
+
+        self.q_table.init_episode(&episode.id);
+        let id = episode.id.clone();
+        episodes.push(episode);
+        Ok(id)
+    }
+
     /// Get an episode by ID.
     #[must_use]
     pub fn get(&self, id: &str) -> Option<Episode> {
@@ -287,6 +365,32 @@ impl EpisodeStore {
         self.recall_with_embedding_internal(embedding, top_k, Some(scope))
     }
 
+    /// Recall episodes by semantic similarity among those matching `filter`.
+    ///
+    /// Metadata predicates are applied before vector ranking, so `limit`
+    /// results are returned whenever enough matching episodes exist.
+    pub fn search_filtered(
+        &self,
+        query: &str,
+        limit: usize,
+        filter: &EpisodeFilter,
+    ) -> Vec<(Episode, f32)> {
+        let embedding = self.encoder.encode(query);
+        self.search_filtered_with_embedding(&embedding, limit, filter)
+    }
+
+    /// Filtered recall with a pre-computed embedding.
+    pub fn search_filtered_with_embedding(
+        &self,
+        embedding: &[f32],
+        limit: usize,
+        filter: &EpisodeFilter,
+    ) -> Vec<(Episode, f32)> {
+        self.recall_matching(embedding, limit, |episode| {
+            filter.matches(episode, self.q_table.get_q(&episode.id))
+        })
+    }
+
     /// Recall episodes with Q-value reranking.
     ///
     /// Two-phase search:
@@ -305,8 +409,7 @@ impl EpisodeStore {
         k2: usize,
         lambda: f32,
     ) -> Vec<(Episode, f32)> {
-        let candidates = self.recall(intent, k1);
-        self.rerank_candidates(candidates, k2, lambda)
+        self.two_phase_recall_with_config(intent, &lambda_config(k1, k2, lambda))
     }
 
     /// Recall episodes with Q-value reranking inside a logical scope.
@@ -318,8 +421,7 @@ impl EpisodeStore {
         k2: usize,
         lambda: f32,
     ) -> Vec<(Episode, f32)> {
-        let candidates = self.recall_for_scope(scope, intent, k1);
-        self.rerank_candidates(candidates, k2, lambda)
+        self.two_phase_recall_for_scope_with_config(scope, intent, &lambda_config(k1, k2, lambda))
     }
 
     /// Two-phase recall with pre-computed embedding.
@@ -330,8 +432,7 @@ impl EpisodeStore {
         k2: usize,
         lambda: f32,
     ) -> Vec<(Episode, f32)> {
-        let candidates = self.recall_with_embedding(embedding, k1);
-        self.rerank_candidates(candidates, k2, lambda)
+        self.two_phase_recall_with_embedding_with_config(embedding, &lambda_config(k1, k2, lambda))
     }
 
     /// Two-phase recall with pre-computed embedding inside a logical scope.
@@ -343,8 +444,53 @@ impl EpisodeStore {
         k2: usize,
         lambda: f32,
     ) -> Vec<(Episode, f32)> {
-        let candidates = self.recall_with_embedding_for_scope(scope, embedding, k1);
-        self.rerank_candidates(candidates, k2, lambda)
+        self.two_phase_recall_with_embedding_for_scope_with_config(
+            scope,
+            embedding,
+            &lambda_config(k1, k2, lambda),
+        )
+    }
+
+    /// Two-phase recall using the recall pool sizes and score weights of `config`.
+    pub fn two_phase_recall_with_config(
+        &self,
+        intent: &str,
+        config: &TwoPhaseConfig,
+    ) -> Vec<(Episode, f32)> {
+        let candidates = self.recall(intent, config.k1);
+        self.rerank_candidates(candidates, config)
+    }
+
+    /// Two-phase recall inside a logical scope using `config`.
+    pub fn two_phase_recall_for_scope_with_config(
+        &self,
+        scope: &str,
+        intent: &str,
+        config: &TwoPhaseConfig,
+    ) -> Vec<(Episode, f32)> {
+        let candidates = self.recall_for_scope(scope, intent, config.k1);
+        self.rerank_candidates(candidates, config)
+    }
+
+    /// Two-phase recall with pre-computed embedding using `config`.
+    pub fn two_phase_recall_with_embedding_with_config(
+        &self,
+        embedding: &[f32],
+        config: &TwoPhaseConfig,
+    ) -> Vec<(Episode, f32)> {
+        let candidates = self.recall_with_embedding(embedding, config.k1);
+        self.rerank_candidates(candidates, config)
+    }
+
+    /// Two-phase recall with pre-computed embedding inside a logical scope using `config`.
+    pub fn two_phase_recall_with_embedding_for_scope_with_config(
+        &self,
+        scope: &str,
+        embedding: &[f32],
+        config: &TwoPhaseConfig,
+    ) -> Vec<(Episode, f32)> {
+        let candidates = self.recall_with_embedding_for_scope(scope, embedding, config.k1);
+        self.rerank_candidates(candidates, config)
     }
 
     /// Multi-hop reasoning: chain multiple queries together.
@@ -658,6 +804,19 @@ impl EpisodeStore {
         Ok(())
     }
 
+    /// Persistence barrier: make every write so far durable.
+    ///
+    /// Persists episodes and Q-table to the default state paths. Each file is
+    /// written to a temporary file, fsynced and atomically renamed into place,
+    /// so a crash during a flush leaves the previous state intact.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either state file cannot be persisted.
+    pub fn flush(&self) -> Result<()> {
+        self.save_state()
+    }
+
     /// Load both episodes and Q-table using default state paths.
     ///
     /// # Errors
@@ -8,16 +8,53 @@ use crate::encoder::IntentEncoder;
 use crate::episode::Episode;
 use crate::q_table::QTable;
 use std::sync::Arc;
+use thiserror::Error;
+
+/// Error when a two-phase configuration is inconsistent.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TwoPhaseConfigError {
+    /// Phase 1 recall pool is smaller than the requested final result count.
+    #[error("recall_k ({recall_k}) must be >= final_k ({final_k})")]
+    RecallSmallerThanFinal {
+        /// Phase 1 recall pool size.
+        recall_k: usize,
+        /// Phase 2 final result count.
+        final_k: usize,
+    },
+
+    /// `lambda` is outside `[0.0, 1.0]` or not finite.
+    #[error("lambda must be within [0.0, 1.0]")]
+    LambdaOutOfRange,
+
+    /// A score weight is negative or not finite.
+    #[error("{0} must be a non-negative finite number")]
+    InvalidWeight(&'static str),
+}
 
 /// Two-phase search configuration.
+///
+/// The phase 1 recall pool (`k1`, a.k.a. `recall_k`) and the phase 2 result
+/// count (`k2`, a.k.a. `final_k`) are independent, so callers can recall
+/// widely and then rerank tightly.
+///
+/// Phase 2 scores each candidate as
+/// `semantic_weight * similarity + utility_weight * q_value`. A weight left
+/// unset falls back to the one derived from `lambda`: `(1 - lambda, lambda)`.
 #[derive(Debug, Clone)]
 pub struct TwoPhaseConfig {
-    /// Number of candidates to retrieve in phase 1
+    /// Number of candidates to retrieve in phase 1 (`recall_k`)
     pub k1: usize,
-    /// Number of final results after phase 2
+    /// Number of final results after phase 2 (`final_k`)
     pub k2: usize,
     /// Lambda weight for Q-value in phase 2 (0.0 = semantic only, 1.0 = Q only)
+    ///
+    /// Deprecated: prefer `semantic_weight` and `utility_weight`. Only used
+    /// for weights that are not set explicitly.
     pub lambda: f32,
+    /// Weight of semantic similarity in the phase 2 score (default `1 - lambda`)
+    pub semantic_weight: Option<f32>,
+    /// Weight of Q-value utility in the phase 2 score (default `lambda`)
+    pub utility_weight: Option<f32>,
 }
 
 impl Default for TwoPhaseConfig {
@@ -26,10 +63,103 @@ impl Default for TwoPhaseConfig {
             k1: 20,
             k2: 5,
             lambda: 0.3, // 30% weight on Q-value, 70% on semantic similarity
+            semantic_weight: None,
+            utility_weight: None,
         }
     }
 }
 
+impl TwoPhaseConfig {
+    /// Create a validated configuration with independent recall and final pool sizes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `recall_k < final_k` or `lambda` is outside `[0.0, 1.0]`.
+    pub fn new(recall_k: usize, final_k: usize, lambda: f32) -> Result<Self, TwoPhaseConfigError> {
+        let config = Self {
+            k1: recall_k,
+            k2: final_k,
+            lambda,
+            ..Self::default()
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Set the phase 2 weights of semantic similarity and Q-value utility.
+    #[must_use]
+    pub fn with_weights(mut self, semantic_weight: f32, utility_weight: f32) -> Self {
+        self.semantic_weight = Some(semantic_weight);
+        self.utility_weight = Some(utility_weight);
+        self
+    }
+
+    /// Effective `(semantic_weight, utility_weight)` used to score phase 2.
+    #[must_use]
+    pub fn weights(&self) -> (f32, f32) {
+        (
+            self.semantic_weight.unwrap_or(1.0 - self.lambda),
+            self.utility_weight.unwrap_or(self.lambda),
+        )
+    }
+
+    /// Phase 2 score of a candidate under this configuration's weights.
+    #[must_use]
+    pub fn score(&self, similarity: f32, q_value: f32) -> f32 {
+        let (semantic_weight, utility_weight) = self.weights();
+        calculate_score(similarity, q_value, semantic_weight, utility_weight)
+    }
+
+    /// Phase 1 semantic-recall pool size.
+    #[must_use]
+    pub fn recall_k(&self) -> usize {
+        self.k1
+    }
+
+    /// Phase 2 final result count.
+    #[must_use]
+    pub fn final_k(&self) -> usize {
+        self.k2
+    }
+
+    /// Check that the recall pool can satisfy the final result count.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `recall_k < final_k`, `lambda` is outside `[0.0, 1.0]`, or a
+    /// weight is negative or not finite.
+    pub fn validate(&self) -> Result<(), TwoPhaseConfigError> {
+        if self.k1 < self.k2 {
+            return Err(TwoPhaseConfigError::RecallSmallerThanFinal {
+                recall_k: self.k1,
+                final_k: self.k2,
+            });
+        }
+        if !(0.0..=1.0).contains(&self.lambda) {
+            return Err(TwoPhaseConfigError::LambdaOutOfRange);
+        }
+        let (semantic_weight, utility_weight) = self.weights();
+        for (name, weight) in [
+            ("semantic_weight", semantic_weight),
+            ("utility_weight", utility_weight),
+        ] {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(TwoPhaseConfigError::InvalidWeight(name));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Two-phase search output with phase 1 diagnostics.
+#[derive(Debug, Clone)]
+pub struct TwoPhaseResults {
+    /// Final reranked (episode, score) tuples sorted by score.
+    pub results: Vec<(Episode, f32)>,
+    /// Number of phase 1 candidates that entered Q-value reranking.
+    pub candidates_considered: usize,
+}
+
 /// Two-phase search engine for memory recall.
 ///
 /// Combines semantic similarity with Q-value utility for optimal recall.
@@ -44,17 +174,30 @@ pub struct TwoPhaseSearch {
 
 impl TwoPhaseSearch {
     /// Create a new two-phase search engine.
-    pub fn new(q_table: Arc<QTable>, encoder: Arc<IntentEncoder>, config: TwoPhaseConfig) -> Self {
-        Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `config` fails [`TwoPhaseConfig::validate`].
+    pub fn new(
+        q_table: Arc<QTable>,
+        encoder: Arc<IntentEncoder>,
+        config: TwoPhaseConfig,
+    ) -> Result<Self, TwoPhaseConfigError> {
+        config.validate()?;
+        Ok(Self {
             q_table,
             encoder,
             config,
-        }
+        })
     }
 
     /// Create with default configuration.
     pub fn with_defaults(q_table: Arc<QTable>, encoder: Arc<IntentEncoder>) -> Self {
-        Self::new(q_table, encoder, TwoPhaseConfig::default())
+        Self {
+            q_table,
+            encoder,
+            config: TwoPhaseConfig::default(),
+        }
     }
 
     /// Execute two-phase search.
@@ -64,7 +207,7 @@ impl TwoPhaseSearch {
     /// * `intent` - Query intent
     /// * `k1` - Override for phase 1 candidate count
     /// * `k2` - Override for phase 2 result count
-    /// * `lambda` - Override for Q-value weight
+    /// * `lambda` - Override for the configured weights, as a Q-value share
     ///
     /// # Returns
     /// Vector of (episode, score) tuples sorted by score
@@ -77,9 +220,28 @@ impl TwoPhaseSearch {
         k2: Option<usize>,
         lambda: Option<f32>,
     ) -> Vec<(Episode, f32)> {
+        self.search_with_stats(episodes, intent, k1, k2, lambda)
+            .results
+    }
+
+    /// Execute two-phase search and report how many candidates were reranked.
+    ///
+    /// Arguments match [`Self::search`].
+    #[must_use]
+    pub fn search_with_stats(
+        &self,
+        episodes: &[Episode],
+        intent: &str,
+        k1: Option<usize>,
+        k2: Option<usize>,
+        lambda: Option<f32>,
+    ) -> TwoPhaseResults {
         let k1 = k1.unwrap_or(self.config.k1);
         let k2 = k2.unwrap_or(self.config.k2);
-        let lambda = lambda.unwrap_or(self.config.lambda);
+        let (semantic_weight, utility_weight) = match lambda {
+            Some(lambda) => (1.0 - lambda, lambda),
+            None => self.config.weights(),
+        };
 
         // Phase 1: Semantic recall
         let embedding = self.encoder.encode(intent);
@@ -96,14 +258,14 @@ impl TwoPhaseSearch {
         // Sort by semantic similarity and take top k1
         candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
         candidates.truncate(k1);
+        let candidates_considered = candidates.len();
 
         // Phase 2: Q-value reranking
         let mut reranked: Vec<(Episode, f32)> = candidates
             .into_iter()
             .map(|(ep, sim)| {
                 let q_value = self.q_table.get_q(&ep.id);
-                // Combined score: (1-lambda) * similarity + lambda * q_value
-                let score = (1.0 - lambda) * sim + lambda * q_value;
+                let score = calculate_score(sim, q_value, semantic_weight, utility_weight);
                 (ep, score)
             })
             .collect();
@@ -112,7 +274,10 @@ impl TwoPhaseSearch {
         reranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
         reranked.truncate(k2);
 
-        reranked
+        TwoPhaseResults {
+            results: reranked,
+            candidates_considered,
+        }
     }
 
     /// Quick search with default parameters.
@@ -128,13 +293,28 @@ impl TwoPhaseSearch {
     }
 
     /// Update configuration.
-    pub fn set_config(&mut self, config: TwoPhaseConfig) {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `config` fails [`TwoPhaseConfig::validate`]; the current
+    /// configuration is kept.
+    pub fn set_config(&mut self, config: TwoPhaseConfig) -> Result<(), TwoPhaseConfigError> {
+        config.validate()?;
         self.config = config;
+        Ok(())
     }
 }
 
 /// Calculate combined score for an episode.
+///
+/// `semantic_weight * similarity + utility_weight * q_value`; see [`TwoPhaseConfig::weights`]
+/// for the weights derived from a configuration.
 #[must_use]
-pub fn calculate_score(similarity: f32, q_value: f32, lambda: f32) -> f32 {
-    (1.0 - lambda) * similarity + lambda * q_value
+pub fn calculate_score(
+    similarity: f32,
+    q_value: f32,
+    semantic_weight: f32,
+    utility_weight: f32,
+) -> f32 {
+    semantic_weight * similarity + utility_weight * q_value
 }
@@ -49,3 +49,35 @@ fn test_encoding_normalized() {
     let norm: f32 = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
     assert!((norm - 1.0).abs() < 0.001);
 }
+
+#[test]
+fn test_encoding_cache_serves_repeated_intents() {
+    let encoder = IntentEncoder::with_cache(64, 2);
+
+    let first = encoder.encode("debug network error");
+    let second = encoder.encode("debug network error");
+    assert_eq!(first, second);
+    assert_eq!(encoder.compute_count(), 1);
+    assert_eq!(encoder.cached_len(), 1);
+
+    // Bounded: a third distinct intent evicts the oldest entry.
+    let _ = encoder.encode("fix memory leak");
+    let _ = encoder.encode("tune retry policy");
+    assert_eq!(encoder.cached_len(), 2);
+    let _ = encoder.encode("debug network error");
+    assert_eq!(encoder.compute_count(), 4);
+
+    encoder.clear_cache();
+    assert_eq!(encoder.cached_len(), 0);
+    assert_eq!(encoder.encode("debug network error"), first);
+    assert_eq!(encoder.compute_count(), 5);
+}
+
+#[test]
+fn test_uncached_encoder_recomputes() {
+    let encoder = IntentEncoder::new(64);
+    let _ = encoder.encode("debug network error");
+    let _ = encoder.encode("debug network error");
+    assert_eq!(encoder.compute_count(), 2);
+    assert_eq!(encoder.cached_len(), 0);
+}
@@ -2,7 +2,7 @@
 
 use omni_memory::{
     Episode, MemoryGateEvent, MemoryGatePolicy, MemoryGateVerdict, MemoryLifecycleState,
-    MemoryUtilityLedger,
+    MemoryUtilityLedger, MemoryUtilityLedgerEntry,
 };
 
 fn episode_with_stats(
@@ -98,3 +98,93 @@ fn gate_event_matches_contract_shape() {
     ));
     assert!(value["decision"]["next_action"].is_string());
 }
+
+#[test]
+fn gate_policy_force_store_overrides_low_utility() {
+    let episode = episode_with_stats("mem-forced", "error", 0.12, 0, 6);
+    let ledger = MemoryUtilityLedger::from_episode(&episode, 0.10, 0.18, 0.12);
+    let policy = MemoryGatePolicy::default();
+
+    let baseline = policy.evaluate(&ledger, vec![], vec![], vec![]);
+    assert_eq!(baseline.verdict, MemoryGateVerdict::Obsolete);
+
+    let decision =
+        MemoryGatePolicy::force_store(&ledger, vec![], vec![], vec![], "operator pinned");
+    assert_eq!(decision.verdict, MemoryGateVerdict::ForcedStore);
+    assert_eq!(decision.next_action, "forced_store");
+    assert!(decision.reason.contains("operator pinned"));
+    assert!(decision.reason.contains("utility="));
+    assert!(
+        decision
+            .omega_factors
+            .iter()
+            .any(|factor| factor == "forced_store=true")
+    );
+
+    let event = MemoryGateEvent::from_decision("s1", 1, "mem-forced", &ledger, decision);
+    assert_eq!(event.state_after, MemoryLifecycleState::Active);
+}
+
+#[test]
+fn forced_store_gate_event_round_trips_through_contract_schema() {
+    let schema_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../../shared/schemas/omni.memory.gate_event.v1.schema.json");
+    let schema_text = std::fs::read_to_string(&schema_path)
+        .unwrap_or_else(|error| panic!("read {}: {error}", schema_path.display()));
+    let schema: serde_json::Value = serde_json::from_str(&schema_text)
+        .unwrap_or_else(|error| panic!("parse gate event schema: {error}"));
+    let validator = jsonschema::JSONSchema::compile(&schema)
+        .unwrap_or_else(|error| panic!("compile gate event schema: {error}"));
+
+    let episode = episode_with_stats("mem-forced", "error", 0.12, 0, 6);
+    let ledger = MemoryUtilityLedger::from_episode(&episode, 0.10, 0.18, 0.12);
+    let decision =
+        MemoryGatePolicy::force_store(&ledger, vec![], vec![], vec![], "operator pinned");
+    let event = MemoryGateEvent::from_decision("s1", 7, &episode.id, &ledger, decision);
+
+    let value = serde_json::to_value(&event)
+        .unwrap_or_else(|error| panic!("serialize forced gate event: {error}"));
+    assert_eq!(value["decision"]["verdict"], "forced_store");
+    if let Err(errors) = validator.validate(&value) {
+        let messages: Vec<String> = errors.map(|error| error.to_string()).collect();
+        panic!("forced gate event violates schema: {messages:?}");
+    }
+
+    let decoded: MemoryGateEvent = serde_json::from_value(value)
+        .unwrap_or_else(|error| panic!("deserialize forced gate event: {error}"));
+    assert_eq!(decoded, event);
+}
+
+#[test]
+fn utility_ledger_export_round_trips_through_import() {
+    let mut episode = episode_with_stats("mem-ledger", "completed", 0.5, 0, 0);
+    episode.mark_success();
+    episode.mark_success();
+    episode.mark_failure();
+    let entries = vec![
+        MemoryUtilityLedgerEntry {
+            memory_id: episode.id.clone(),
+            last_used: 1_700_000_000_000,
+            ledger: MemoryUtilityLedger::from_episode(&episode, 0.8, 0.6, 0.7),
+        },
+        MemoryUtilityLedgerEntry {
+            memory_id: "mem-idle".to_string(),
+            last_used: 1_600_000_000_000,
+            ledger: MemoryUtilityLedger::from_episode(
+                &episode_with_stats("mem-idle", "error", 0.2, 0, 3),
+                0.1,
+                0.2,
+                0.3,
+            ),
+        },
+    ];
+
+    let exported = MemoryUtilityLedger::export(&entries)
+        .unwrap_or_else(|error| panic!("export ledger: {error}"));
+    let imported = MemoryUtilityLedger::import(&exported)
+        .unwrap_or_else(|error| panic!("import ledger: {error}"));
+
+    assert_eq!(imported, entries);
+    assert_eq!(imported[0].ledger.usage_count, 3);
+    assert!(MemoryUtilityLedger::import("{not json").is_err());
+}
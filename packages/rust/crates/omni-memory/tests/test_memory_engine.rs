@@ -170,8 +170,9 @@ fn test_two_phase_search_with_config() {
         k1: 10,
         k2: 3,
         lambda: 0.4,
+        ..TwoPhaseConfig::default()
     };
-    let search = TwoPhaseSearch::new(q_table.clone(), encoder.clone(), config);
+    let search = TwoPhaseSearch::new(q_table.clone(), encoder.clone(), config).unwrap();
 
     // Create test episodes
     let episodes = vec![
@@ -261,16 +262,16 @@ fn test_batch_operations() {
 fn test_calculate_score_function() {
     use omni_memory::calculate_score;
 
-    // Pure semantic (lambda = 0)
-    let score = calculate_score(0.9, 0.5, 0.0);
+    // Pure semantic
+    let score = calculate_score(0.9, 0.5, 1.0, 0.0);
     assert!((score - 0.9).abs() < 0.001);
 
-    // Pure Q-value (lambda = 1)
-    let score = calculate_score(0.9, 0.5, 1.0);
+    // Pure Q-value
+    let score = calculate_score(0.9, 0.5, 0.0, 1.0);
     assert!((score - 0.5).abs() < 0.001);
 
-    // Balanced (lambda = 0.5)
-    let score = calculate_score(0.9, 0.5, 0.5);
+    // Balanced
+    let score = calculate_score(0.9, 0.5, 0.5, 0.5);
     assert!((score - 0.7).abs() < 0.001);
 }
 
@@ -80,3 +80,44 @@ fn save_state_uses_table_scoped_filenames() {
     assert!(root.join("beta.episodes.json").exists());
     assert!(root.join("beta.q_table.json").exists());
 }
+
+#[test]
+fn flush_makes_episodes_durable_across_reopen() {
+    let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    let config = StoreConfig {
+        path: temp_dir.path().join("memory").to_string_lossy().to_string(),
+        embedding_dim: 128,
+        table_name: "episodes".to_string(),
+    };
+
+    let store = EpisodeStore::new(config.clone());
+    for i in 0..5 {
+        let intent = format!("task {i}");
+        store
+            .store(Episode::new(
+                format!("ep-{i}"),
+                intent.clone(),
+                store.encoder().encode(&intent),
+                format!("experience {i}"),
+                "success".to_string(),
+            ))
+            .expect("failed to store episode");
+    }
+    store.update_q("ep-3", 1.0);
+
+    // Writes are in-memory until flushed.
+    let before_flush = EpisodeStore::new(config.clone());
+    before_flush.load_state().expect("failed to load state");
+    assert!(before_flush.is_empty());
+
+    store.flush().expect("failed to flush store");
+    drop(store);
+
+    let reopened = EpisodeStore::new(config);
+    reopened.load_state().expect("failed to load state");
+    assert_eq!(reopened.len(), 5);
+    for i in 0..5 {
+        assert!(reopened.get(&format!("ep-{i}")).is_some());
+    }
+    assert!(reopened.q_table.get_q("ep-3") > 0.5);
+}
@@ -2,7 +2,7 @@
 
 mod common;
 
-use omni_memory::{Episode, EpisodeStore, StoreConfig};
+use omni_memory::{Episode, EpisodeFilter, EpisodeStore, StoreConfig, TwoPhaseConfig};
 
 #[test]
 fn test_store_creation() {
@@ -34,6 +34,92 @@ fn test_store_episode() {
     assert_eq!(store.len(), 1);
 }
 
+#[test]
+fn test_insert_deduped_merges_near_identical() {
+    let store = EpisodeStore::default();
+    let embedding = store.encoder().encode("fix flaky network test");
+    let mut nudged = embedding.clone();
+    nudged[0] += 0.001;
+
+    let first = Episode::new(
+        "ep-001".to_string(),
+        "fix flaky network test".to_string(),
+        embedding,
+        "Added retry".to_string(),
+        "partial".to_string(),
+    );
+    let second = Episode::new(
+        "ep-002".to_string(),
+        "fix flaky network test!".to_string(),
+        nudged,
+        "Mocked the network".to_string(),
+        "success".to_string(),
+    );
+
+    assert_eq!(store.insert_deduped(first, 0.99).unwrap(), "ep-001");
+    assert_eq!(store.insert_deduped(second, 0.99).unwrap(), "ep-001");
+    assert_eq!(store.len(), 1);
+
+    let merged = store.get("ep-001").unwrap();
+    assert_eq!(merged.experience, "Mocked the network");
+    assert_eq!(merged.outcome, "success");
+    assert!(store.get("ep-002").is_none());
+
+    let distinct = Episode::new(
+        "ep-003".to_string(),
+        "write release notes".to_string(),
+        store.encoder().encode("write release notes"),
+        "Drafted notes".to_string(),
+        "success".to_string(),
+    );
+    assert_eq!(store.insert_deduped(distinct, 0.99).unwrap(), "ep-003");
+    assert_eq!(store.len(), 2);
+}
+
+#[test]
+fn test_search_filtered_by_task_type() {
+    let store = EpisodeStore::default();
+    for (i, task_type) in ["debug", "refactor", "debug", "refactor", "debug"]
+        .iter()
+        .enumerate()
+    {
+        let intent = format!("fix module {i}");
+        let episode = Episode::new(
+            format!("ep-{i}"),
+            intent.clone(),
+            store.encoder().encode(&intent),
+            format!("experience {i}"),
+            "success".to_string(),
+        )
+        .with_task_type(*task_type);
+        store.store(episode).unwrap();
+    }
+
+    let filter = EpisodeFilter::new().with_task_type("refactor");
+    let results = store.search_filtered("fix module 0", 10, &filter);
+    assert_eq!(results.len(), 2);
+    assert!(
+        results
+            .iter()
+            .all(|(ep, _)| ep.task_type.as_deref() == Some("refactor"))
+    );
+
+    let limited = store.search_filtered(
+        "fix module 0",
+        1,
+        &EpisodeFilter::new().with_task_type("debug"),
+    );
+    assert_eq!(limited.len(), 1);
+    assert_eq!(limited[0].0.id, "ep-0");
+
+    let none = store.search_filtered(
+        "fix module 0",
+        10,
+        &EpisodeFilter::new().with_outcome("failure"),
+    );
+    assert!(none.is_empty());
+}
+
 #[test]
 fn test_recall() {
     let store = EpisodeStore::default();
@@ -76,6 +162,36 @@ fn test_two_phase_recall() {
     assert!(results.len() <= 3);
 }
 
+#[test]
+fn test_two_phase_recall_with_config_weights() {
+    let store = EpisodeStore::default();
+    for (id, intent) in [
+        ("ep-relevant", "debug network timeout"),
+        ("ep-useful", "restart flaky service"),
+    ] {
+        let episode = Episode::new(
+            id.to_string(),
+            intent.to_string(),
+            store.encoder().encode(intent),
+            "experience".to_string(),
+            "success".to_string(),
+        );
+        store.store(episode).unwrap();
+    }
+    for _ in 0..10 {
+        store.update_q("ep-relevant", 0.0);
+        store.update_q("ep-useful", 1.0);
+    }
+
+    let semantic = TwoPhaseConfig::default().with_weights(1.0, 0.1);
+    let results = store.two_phase_recall_with_config("debug network timeout", &semantic);
+    assert_eq!(results[0].0.id, "ep-relevant");
+
+    let utility = TwoPhaseConfig::default().with_weights(0.1, 1.0);
+    let results = store.two_phase_recall_with_config("debug network timeout", &utility);
+    assert_eq!(results[0].0.id, "ep-useful");
+}
+
 #[test]
 fn test_q_update() {
     let store = EpisodeStore::default();
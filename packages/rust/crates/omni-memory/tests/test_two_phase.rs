@@ -1,6 +1,8 @@
 //! TwoPhaseSearch tests.
 
-use omni_memory::{Episode, IntentEncoder, QTable, TwoPhaseSearch};
+use omni_memory::{
+    Episode, IntentEncoder, QTable, TwoPhaseConfig, TwoPhaseConfigError, TwoPhaseSearch,
+};
 use std::sync::Arc;
 
 fn create_test_episodes() -> Vec<Episode> {
@@ -88,6 +90,148 @@ fn test_q_only() {
 
 #[test]
 fn test_calculate_score() {
-    let score = omni_memory::calculate_score(0.8, 0.5, 0.5);
+    let score = omni_memory::calculate_score(0.8, 0.5, 0.5, 0.5);
     assert!((score - 0.65).abs() < 0.001);
+
+    let config = TwoPhaseConfig::default().with_weights(0.2, 0.8);
+    let score = omni_memory::calculate_score(0.8, 0.5, 0.2, 0.8);
+    assert!((score - config.score(0.8, 0.5)).abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_weights_shift_ranking_toward_utility() {
+    let encoder = IntentEncoder::new(128);
+    let relevant = Episode::new(
+        "ep-relevant".to_string(),
+        "debug network timeout".to_string(),
+        encoder.encode("debug network timeout"),
+        "Checked DNS settings".to_string(),
+        "failure".to_string(),
+    );
+    let useful = Episode::new(
+        "ep-useful".to_string(),
+        "restart flaky service".to_string(),
+        encoder.encode("restart flaky service"),
+        "Restarted the worker".to_string(),
+        "success".to_string(),
+    );
+    let episodes = vec![relevant, useful];
+
+    let q_table = Arc::new(QTable::new());
+    for _ in 0..10 {
+        q_table.update("ep-relevant", 0.0);
+        q_table.update("ep-useful", 1.0);
+    }
+    let encoder = Arc::new(encoder);
+
+    let semantic = TwoPhaseConfig::default().with_weights(1.0, 0.1);
+    let search = TwoPhaseSearch::new(q_table.clone(), encoder.clone(), semantic).unwrap();
+    let results = search.search(&episodes, "debug network timeout", None, None, None);
+    assert_eq!(results[0].0.id, "ep-relevant");
+
+    let utility = TwoPhaseConfig::default().with_weights(0.1, 1.0);
+    assert!(utility.validate().is_ok());
+    let search = TwoPhaseSearch::new(q_table, encoder, utility).unwrap();
+    let results = search.search(&episodes, "debug network timeout", None, None, None);
+    assert_eq!(results[0].0.id, "ep-useful");
+}
+
+#[test]
+fn test_config_rejects_negative_weights() {
+    let config = TwoPhaseConfig::default().with_weights(-0.1, 1.0);
+    assert_eq!(
+        config.validate(),
+        Err(TwoPhaseConfigError::InvalidWeight("semantic_weight"))
+    );
+    let config = TwoPhaseConfig::default().with_weights(1.0, f32::NAN);
+    assert_eq!(
+        config.validate(),
+        Err(TwoPhaseConfigError::InvalidWeight("utility_weight"))
+    );
+    assert!(TwoPhaseConfig::new(20, 5, 1.5).is_err());
+    assert_eq!(TwoPhaseConfig::default().weights(), (0.7, 0.3));
+    let config = TwoPhaseConfig {
+        lambda: 0.5,
+        utility_weight: Some(2.0),
+        ..TwoPhaseConfig::default()
+    };
+    assert_eq!(config.weights(), (0.5, 2.0));
+}
+
+#[test]
+fn test_recall_pool_independent_of_final_limit() {
+    let encoder = IntentEncoder::new(128);
+    let episodes: Vec<Episode> = (0..80)
+        .map(|i| {
+            let intent = format!("intent number {i}");
+            Episode::new(
+                format!("ep-{i}"),
+                intent.clone(),
+                encoder.encode(&intent),
+                "experience".to_string(),
+                "success".to_string(),
+            )
+        })
+        .collect();
+
+    let config = TwoPhaseConfig::new(50, 5, 0.3).unwrap();
+    assert_eq!(config.recall_k(), 50);
+    assert_eq!(config.final_k(), 5);
+    let search = TwoPhaseSearch::new(Arc::new(QTable::new()), Arc::new(encoder), config).unwrap();
+
+    let outcome = search.search_with_stats(&episodes, "intent number 7", None, None, None);
+    assert_eq!(outcome.candidates_considered, 50);
+    assert_eq!(outcome.results.len(), 5);
+}
+
+#[test]
+fn test_config_rejects_recall_smaller_than_final() {
+    let err = TwoPhaseConfig::new(3, 5, 0.3).unwrap_err();
+    assert_eq!(
+        err,
+        TwoPhaseConfigError::RecallSmallerThanFinal {
+            recall_k: 3,
+            final_k: 5,
+        }
+    );
+    assert!(TwoPhaseConfig::default().validate().is_ok());
+}
+
+#[test]
+fn test_search_engine_rejects_invalid_config() {
+    let q_table = Arc::new(QTable::new());
+    let encoder = Arc::new(IntentEncoder::new(64));
+    let invalid = TwoPhaseConfig {
+        k1: 2,
+        k2: 5,
+        ..TwoPhaseConfig::default()
+    };
+    assert!(TwoPhaseSearch::new(q_table.clone(), encoder.clone(), invalid.clone()).is_err());
+
+    let mut search = TwoPhaseSearch::with_defaults(q_table, encoder);
+    assert_eq!(
+        search.set_config(invalid).unwrap_err(),
+        TwoPhaseConfigError::RecallSmallerThanFinal {
+            recall_k: 2,
+            final_k: 5,
+        }
+    );
+    assert_eq!(search.config().k1, TwoPhaseConfig::default().k1);
+}
+
+#[test]
+fn test_config_rejects_lambda_out_of_range() {
+    assert_eq!(
+        TwoPhaseConfig::new(20, 5, 1.5).unwrap_err(),
+        TwoPhaseConfigError::LambdaOutOfRange
+    );
+    assert_eq!(
+        TwoPhaseConfig::new(20, 5, -0.1).unwrap_err(),
+        TwoPhaseConfigError::LambdaOutOfRange
+    );
+    assert_eq!(
+        TwoPhaseConfig::new(20, 5, f32::NAN).unwrap_err(),
+        TwoPhaseConfigError::LambdaOutOfRange
+    );
+    assert!(TwoPhaseConfig::new(20, 5, 1.0).is_ok());
 }
@@ -6,6 +6,23 @@
 use serde::Deserialize;
 use std::fs;
 
+/// Errors from validated frontmatter extraction.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum FrontmatterError {
+    /// No `---` delimited frontmatter block was found.
+    #[error("no YAML frontmatter found")]
+    Missing,
+    /// The frontmatter block is not valid YAML.
+    #[error("invalid YAML frontmatter: {0}")]
+    InvalidYaml(String),
+    /// The frontmatter is valid YAML but not a key/value mapping.
+    #[error("YAML frontmatter is not a mapping")]
+    NotAMapping,
+    /// Required top-level keys are absent, null, or empty.
+    #[error("frontmatter missing required fields: {}", .0.join(", "))]
+    MissingFields(Vec<String>),
+}
+
 /// Extract YAML frontmatter from markdown content.
 ///
 /// Returns `Some(String)` if frontmatter is found, `None` otherwise.
@@ -38,6 +55,44 @@ pub fn extract_frontmatter(content: &str) -> Option<String> {
     Some(content_after_start[..end].to_string())
 }
 
+/// Extract YAML frontmatter and check that required top-level keys are present.
+///
+/// A key counts as missing when it is absent, `null`, or an empty string.
+///
+/// # Errors
+///
+/// Returns [`FrontmatterError::MissingFields`] listing every missing key, or another
+/// [`FrontmatterError`] variant if the frontmatter is absent or not a YAML mapping.
+pub fn extract_frontmatter_validated(
+    content: &str,
+    required_fields: &[&str],
+) -> Result<String, FrontmatterError> {
+    let frontmatter = extract_frontmatter(content).ok_or(FrontmatterError::Missing)?;
+    let value = parse_frontmatter(&frontmatter)
+        .map_err(|e| FrontmatterError::InvalidYaml(e.to_string()))?;
+    let mapping = match &value {
+        serde_yaml::Value::Mapping(mapping) => Some(mapping),
+        serde_yaml::Value::Null => None,
+        _ => return Err(FrontmatterError::NotAMapping),
+    };
+
+    let missing: Vec<String> = required_fields
+        .iter()
+        .filter(|field| match mapping.and_then(|m| m.get(**field)) {
+            None | Some(serde_yaml::Value::Null) => true,
+            Some(serde_yaml::Value::String(s)) => s.trim().is_empty(),
+            Some(_) => false,
+        })
+        .map(|field| (*field).to_string())
+        .collect();
+
+    if missing.is_empty() {
+        Ok(frontmatter)
+    } else {
+        Err(FrontmatterError::MissingFields(missing))
+    }
+}
+
 /// Parse YAML frontmatter content into a serde value.
 ///
 /// # Errors
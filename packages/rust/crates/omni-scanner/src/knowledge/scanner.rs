@@ -27,7 +27,7 @@ use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
 use crate::frontmatter::extract_frontmatter;
-use crate::knowledge::types::{KnowledgeCategory, KnowledgeEntry};
+use crate::knowledge::types::{KnowledgeCategory, KnowledgeEntry, normalize_tags};
 use sha2::{Digest, Sha256};
 
 /// YAML frontmatter structure for knowledge documents.
@@ -81,13 +81,29 @@ struct KnowledgeFrontmatter {
 /// let patterns = scanner.scan_category(PathBuf::from("assets/knowledge"), "pattern").unwrap();
 /// ```
 #[derive(Debug)]
-pub struct KnowledgeScanner;
+pub struct KnowledgeScanner {
+    /// Infer missing categories from the containing directory name.
+    infer_category: bool,
+}
 
 impl KnowledgeScanner {
     /// Create a new knowledge scanner with default settings.
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self {
+            infer_category: false,
+        }
+    }
+
+    /// Enable or disable category inference from directory names.
+    ///
+    /// When enabled, a document whose frontmatter omits `category` takes it from
+    /// the nearest parent directory naming a known category (e.g. `patterns/`
+    /// becomes [`KnowledgeCategory::Pattern`]). A frontmatter category always wins.
+    #[must_use]
+    pub fn with_category_inference(mut self, enabled: bool) -> Self {
+        self.infer_category = enabled;
+        self
     }
 
     /// Scan a single knowledge document.
@@ -102,6 +118,14 @@ impl KnowledgeScanner {
     /// `Some(entry)` if the document is valid, otherwise `None`.
     #[must_use]
     pub fn scan_document(path: &Path, base_path: &Path) -> Option<KnowledgeEntry> {
+        Self::scan_document_inner(path, base_path, false)
+    }
+
+    fn scan_document_inner(
+        path: &Path,
+        base_path: &Path,
+        infer_category: bool,
+    ) -> Option<KnowledgeEntry> {
         if !path.exists() || !path.is_file() {
             return None;
         }
@@ -144,12 +168,12 @@ impl KnowledgeScanner {
             )
         });
 
-        // Parse category
-        let category = metadata
-            .category
-            .as_ref()
-            .and_then(|c| c.parse().ok())
-            .unwrap_or(KnowledgeCategory::Unknown);
+        // Parse category, falling back to the directory name when absent
+        let category = match metadata.category.as_ref() {
+            Some(c) => c.parse().unwrap_or(KnowledgeCategory::Unknown),
+            None if infer_category => infer_category_from_path(relative_path),
+            None => KnowledgeCategory::Unknown,
+        };
 
         // Generate content preview (first 500 chars)
         let content_preview = content_without_frontmatter
@@ -162,13 +186,18 @@ impl KnowledgeScanner {
             .take(500)
             .collect();
 
+        // Normalize tags so `Git`, `git ` and `GIT` collapse to one retrievable tag
+        let original_tags = metadata.tags.unwrap_or_default();
+        let tags = normalize_tags(&original_tags);
+
         Some(KnowledgeEntry {
             id,
             file_path: relative_path.to_string_lossy().into_owned(),
             title,
             description: metadata.description.unwrap_or_default(),
             category,
-            tags: metadata.tags.unwrap_or_default(),
+            tags,
+            original_tags,
             authors: metadata.authors.unwrap_or_default(),
             source: metadata.source,
             version: metadata.version.unwrap_or_default(),
@@ -228,7 +257,7 @@ impl KnowledgeScanner {
         // Process in parallel using rayon
         let entries: Vec<KnowledgeEntry> = md_files
             .par_iter()
-            .filter_map(|path| Self::scan_document(path, base_path))
+            .filter_map(|path| Self::scan_document_inner(path, base_path, self.infer_category))
             .collect();
 
         log::info!(
@@ -334,6 +363,21 @@ impl KnowledgeScanner {
     }
 }
 
+/// Infer a category from the nearest parent directory naming a known category.
+///
+/// Returns [`KnowledgeCategory::Unknown`] when no ancestor directory matches.
+#[must_use]
+pub fn infer_category_from_path(relative_path: &Path) -> KnowledgeCategory {
+    relative_path
+        .parent()
+        .into_iter()
+        .flat_map(Path::ancestors)
+        .filter_map(|dir| dir.file_name().and_then(|n| n.to_str()))
+        .filter_map(|name| name.parse::<KnowledgeCategory>().ok())
+        .find(|category| *category != KnowledgeCategory::Unknown)
+        .unwrap_or(KnowledgeCategory::Unknown)
+}
+
 fn walk_depth_from(depth: i32) -> Result<usize, Box<dyn std::error::Error>> {
     if depth == -1 {
         return Ok(usize::MAX);
@@ -394,6 +438,69 @@ This document describes best practices for git commits.
         assert!(!entry.file_hash.is_empty());
     }
 
+    #[test]
+    fn test_scan_document_normalizes_tags() {
+        let temp_dir = TempDir::new().unwrap();
+        let doc_path = temp_dir.path().join("git-tags.md");
+
+        let content = r#"---
+title: "Git Tags"
+tags: [Git, "git ", GIT]
+---
+
+# Git Tags
+"#;
+
+        std::fs::write(&doc_path, content).unwrap();
+
+        let entry = KnowledgeScanner::scan_document(&doc_path, temp_dir.path()).unwrap();
+
+        assert_eq!(entry.tags, vec!["git"]);
+        assert_eq!(entry.original_tags, vec!["Git", "git ", "GIT"]);
+    }
+
+    #[test]
+    fn test_scan_all_infers_category_from_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let solutions_dir = temp_dir.path().join("solutions");
+        std::fs::create_dir_all(&solutions_dir).unwrap();
+
+        std::fs::write(
+            solutions_dir.join("fix-lock.md"),
+            "---\ntitle: \"Fix lock\"\n---\n# Fix lock\n",
+        )
+        .unwrap();
+        std::fs::write(
+            solutions_dir.join("explicit.md"),
+            "---\ntitle: \"Explicit\"\ncategory: \"pattern\"\n---\n# Explicit\n",
+        )
+        .unwrap();
+
+        let scanner = KnowledgeScanner::new().with_category_inference(true);
+        let entries = scanner.scan_all(temp_dir.path(), None).unwrap();
+        let category_of = |title: &str| {
+            entries
+                .iter()
+                .find(|e| e.title == title)
+                .map(|e| e.category)
+                .unwrap()
+        };
+
+        assert_eq!(category_of("Fix lock"), KnowledgeCategory::Solution);
+        // Frontmatter category always wins over the directory name
+        assert_eq!(category_of("Explicit"), KnowledgeCategory::Pattern);
+
+        // Inference is opt-in
+        let entries = KnowledgeScanner::new()
+            .scan_all(temp_dir.path(), None)
+            .unwrap();
+        assert!(
+            entries
+                .iter()
+                .any(|e| e.title == "Fix lock" && e.category == KnowledgeCategory::Unknown)
+        );
+    }
+
     #[test]
     fn test_scan_document_without_frontmatter() {
         let temp_dir = TempDir::new().unwrap();
@@ -87,9 +87,12 @@ pub struct KnowledgeMetadata {
     /// Category for organization and filtering
     #[serde(default)]
     pub category: Option<KnowledgeCategory>,
-    /// Tags for semantic search and discovery
+    /// Tags for semantic search and discovery (normalized: trimmed, lowercase, deduplicated)
     #[serde(default)]
     pub tags: Vec<String>,
+    /// Tags exactly as written in the frontmatter, before normalization
+    #[serde(default)]
+    pub original_tags: Vec<String>,
     /// Authors who created or maintain this document
     #[serde(default)]
     pub authors: Vec<String>,
@@ -131,6 +134,21 @@ impl KnowledgeMetadata {
     }
 }
 
+/// Normalize tags for retrieval: trim, lowercase, drop empties, and dedupe.
+///
+/// First occurrence order is preserved, so `[Git, git , GIT]` becomes `[git]`.
+#[must_use]
+pub fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if !tag.is_empty() && !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    normalized
+}
+
 /// Represents a discovered knowledge document.
 ///
 /// Contains metadata extracted from YAML frontmatter along with
@@ -150,9 +168,12 @@ pub struct KnowledgeEntry {
     /// Category for organization
     #[serde(default)]
     pub category: KnowledgeCategory,
-    /// Tags for semantic search
+    /// Tags for semantic search (normalized)
     #[serde(default)]
     pub tags: Vec<String>,
+    /// Tags as written in the frontmatter, before normalization
+    #[serde(default)]
+    pub original_tags: Vec<String>,
     /// Authors
     #[serde(default)]
     pub authors: Vec<String>,
@@ -184,6 +205,7 @@ impl KnowledgeEntry {
             description: String::new(),
             category: KnowledgeCategory::Unknown,
             tags: Vec::new(),
+            original_tags: Vec::new(),
             authors: Vec::new(),
             source: None,
             version: String::new(),
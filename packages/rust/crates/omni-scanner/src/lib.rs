@@ -41,6 +41,8 @@
 //! tags: [git, commit, best-practices]
 //! ---
 
+use std::collections::HashMap;
+
 // ============================================================================
 // Module Declarations
 // ============================================================================
@@ -54,27 +56,28 @@ pub mod skills;
 // ============================================================================
 
 pub use skills::{
-    CanonicalSkillPayload, CanonicalToolEntry,
+    CanonicalSkillPayload, CanonicalToolEntry, diff_canonical,
     metadata::{
         AssetRecord, DataRecord, DecoratorArgs, DocsAvailable, IndexToolEntry, ReferencePath,
         ReferenceRecord, ScanConfig, SkillIndexEntry, SkillMetadata, SkillStructure, SnifferRule,
-        StructureItem, SyncReport, TemplateRecord, TestRecord, ToolAnnotations, ToolRecord,
-        calculate_sync_ops,
+        StructureCategory, StructureItem, SyncReport, TemplateRecord, TestRecord, ToolAnnotations,
+        ToolRecord, calculate_sync_ops,
     },
-    scanner::SkillScanner,
+    scanner::{SkillScanner, export_tools_jsonl, scan_skill_index},
     tools::ToolsScanner,
+    validation::{ValidationIssue, ValidationReport, ValidationSeverity, validate_skills_tree},
 };
 
 // Re-export extract_frontmatter for external use
-pub use frontmatter::extract_frontmatter;
+pub use frontmatter::{FrontmatterError, extract_frontmatter, extract_frontmatter_validated};
 
 // ============================================================================
 // Re-exports from Knowledge Module
 // ============================================================================
 
 pub use knowledge::{
-    scanner::KnowledgeScanner,
-    types::{KnowledgeCategory, KnowledgeEntry, KnowledgeMetadata},
+    scanner::{KnowledgeScanner, infer_category_from_path},
+    types::{KnowledgeCategory, KnowledgeEntry, KnowledgeMetadata, normalize_tags},
 };
 
 // ============================================================================
@@ -95,6 +98,37 @@ pub fn knowledge_entry_schema() -> String {
     serde_json::to_string_pretty(&schema).unwrap_or_default()
 }
 
+/// Generate JSON Schema for `ToolRecord`.
+#[must_use]
+pub fn tool_record_schema() -> String {
+    let schema = schemars::schema_for!(ToolRecord);
+    serde_json::to_string_pretty(&schema).unwrap_or_default()
+}
+
+/// Generate JSON Schema for every scanner type that supports it, keyed by type name.
+#[must_use]
+pub fn all_scanner_schemas() -> HashMap<String, String> {
+    fn schema_of<T: schemars::JsonSchema>() -> String {
+        serde_json::to_string_pretty(&schemars::schema_for!(T)).unwrap_or_default()
+    }
+
+    HashMap::from([
+        ("SkillIndexEntry".to_string(), skill_index_schema()),
+        ("KnowledgeEntry".to_string(), knowledge_entry_schema()),
+        ("ToolRecord".to_string(), tool_record_schema()),
+        ("SkillMetadata".to_string(), schema_of::<SkillMetadata>()),
+        ("SkillStructure".to_string(), schema_of::<SkillStructure>()),
+        ("TemplateRecord".to_string(), schema_of::<TemplateRecord>()),
+        (
+            "ReferenceRecord".to_string(),
+            schema_of::<ReferenceRecord>(),
+        ),
+        ("AssetRecord".to_string(), schema_of::<AssetRecord>()),
+        ("DataRecord".to_string(), schema_of::<DataRecord>()),
+        ("TestRecord".to_string(), schema_of::<TestRecord>()),
+    ])
+}
+
 // ============================================================================
 // Version
 // ============================================================================
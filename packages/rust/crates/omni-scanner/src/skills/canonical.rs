@@ -8,7 +8,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::metadata::{ReferenceRecord, SkillMetadata, ToolRecord};
+use super::metadata::{ReferenceRecord, SkillMetadata, SyncReport, ToolRecord};
 
 /// One tool in the canonical payload: full tool data plus `ref_key` → path map.
 ///
@@ -46,3 +46,61 @@ pub struct CanonicalSkillPayload {
     #[serde(default)]
     pub references: HashMap<String, ReferenceRecord>,
 }
+
+/// Diff two canonical payloads of the same skill (old indexed vs freshly scanned).
+///
+/// Tools are keyed by full tool name: tools only in `new` are `added`, tools only in
+/// `old` are `deleted`, and tools whose entry (record or `skill_tool_references`) differs
+/// are `updated`. Changed SKILL.md metadata fields, directory records (`templates`, `assets`,
+/// `data`, `tests`), `structure`, `skill_md_path` and `references` are listed by name in
+/// `metadata_changes`. Output order is sorted by name.
+#[must_use]
+pub fn diff_canonical(old: &CanonicalSkillPayload, new: &CanonicalSkillPayload) -> SyncReport {
+    let mut report = SyncReport::new();
+
+    let mut new_names: Vec<&String> = new.skill_tools.keys().collect();
+    new_names.sort();
+    for name in new_names {
+        let entry = &new.skill_tools[name];
+        match old.skill_tools.get(name) {
+            None => report.added.push(entry.tool.clone()),
+            Some(previous) if previous != entry => report.updated.push(entry.tool.clone()),
+            Some(_) => report.unchanged_count += 1,
+        }
+    }
+
+    report.deleted = old
+        .skill_tools
+        .keys()
+        .filter(|name| !new.skill_tools.contains_key(*name))
+        .cloned()
+        .collect();
+    report.deleted.sort();
+
+    let (a, b) = (&old.metadata, &new.metadata);
+    let fields = [
+        ("skill_name", a.skill_name != b.skill_name),
+        ("version", a.version != b.version),
+        ("description", a.description != b.description),
+        ("routing_keywords", a.routing_keywords != b.routing_keywords),
+        ("authors", a.authors != b.authors),
+        ("intents", a.intents != b.intents),
+        ("require_refs", a.require_refs != b.require_refs),
+        ("repository", a.repository != b.repository),
+        ("permissions", a.permissions != b.permissions),
+        ("templates", a.templates != b.templates),
+        ("assets", a.assets != b.assets),
+        ("data", a.data != b.data),
+        ("tests", a.tests != b.tests),
+        ("structure", a.structure != b.structure),
+        ("skill_md_path", old.skill_md_path != new.skill_md_path),
+        ("references", old.references != new.references),
+    ];
+    report.metadata_changes = fields
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(field, _)| field.to_string())
+        .collect();
+
+    report
+}
@@ -42,6 +42,21 @@ pub struct SkillMetadata {
     /// Zero Trust: Empty permissions means NO access to any capabilities.
     #[serde(default)]
     pub permissions: Vec<String>,
+    /// Files under the skill's `templates/` directory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub templates: Vec<TemplateRecord>,
+    /// Files under the skill's `assets/` directory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assets: Vec<AssetRecord>,
+    /// Files under the skill's `data/` directory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub data: Vec<DataRecord>,
+    /// Files under the skill's `tests/` directory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tests: Vec<TestRecord>,
+    /// On-disk file tree of the skill, filled by `SkillScanner::scan_all_with_structure`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub structure: Option<SkillStructure>,
 }
 
 impl SkillMetadata {
@@ -71,6 +86,16 @@ impl SkillMetadata {
     pub fn keywords_summary(&self) -> String {
         self.routing_keywords.join(", ")
     }
+
+    /// Returns the schema version declared by `version`: its major component.
+    ///
+    /// `"2.1.0"` and `"v2"` both yield `Some(2)`; an empty or non-numeric version yields `None`.
+    #[must_use]
+    pub fn schema_version(&self) -> Option<u32> {
+        let version = self.version.trim();
+        let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+        version.split('.').next()?.parse().ok()
+    }
 }
 
 // =============================================================================
@@ -202,7 +227,7 @@ pub struct DecoratorArgs {
 /// - AST parsing of decorator kwargs
 /// - Function signature analysis
 /// - Docstring parsing
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, SchemarsJsonSchema, PartialEq, Eq)]
 pub struct ToolRecord {
     /// Name of the tool function.
     pub tool_name: String,
@@ -318,6 +343,46 @@ impl ToolRecord {
             resource_uri,
         }
     }
+
+    /// Checks the record for issues that degrade search and routing quality.
+    ///
+    /// Returns one human-readable diagnostic per issue; an empty vector means
+    /// the record looks healthy. Checks for empty `tool_name`/`description`,
+    /// an `input_schema` that is not a JSON object, and missing keywords.
+    #[must_use]
+    pub fn validate(&self) -> Vec<String> {
+        let mut issues = Vec::new();
+        let name = if self.tool_name.trim().is_empty() {
+            issues.push("tool_name is empty".to_string());
+            "<unnamed>"
+        } else {
+            self.tool_name.as_str()
+        };
+
+        if self.description.trim().is_empty() {
+            issues.push(format!(
+                "{name}: description is empty; add a docstring or description= to the decorator"
+            ));
+        }
+
+        if self.input_schema.trim().is_empty() {
+            issues.push(format!("{name}: input_schema is empty"));
+        } else {
+            match serde_json::from_str::<serde_json::Value>(&self.input_schema) {
+                Ok(serde_json::Value::Object(_)) => {}
+                Ok(_) => issues.push(format!("{name}: input_schema is not a JSON object")),
+                Err(e) => issues.push(format!("{name}: input_schema is not valid JSON ({e})")),
+            }
+        }
+
+        if self.keywords.iter().all(|k| k.trim().is_empty()) {
+            issues.push(format!(
+                "{name}: no keywords; add routing_keywords to SKILL.md to improve discovery"
+            ));
+        }
+
+        issues
+    }
 }
 
 // =============================================================================
@@ -512,7 +577,11 @@ impl SkillIndexEntry {
 // =============================================================================
 
 /// Represents the canonical skill structure as defined in settings.yaml.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+///
+/// Also used for a scanned skill's actual file tree, where `required` holds the
+/// required files found, `default` the contents of default directories, and
+/// `optional` everything else.
+#[derive(Debug, Clone, Serialize, Deserialize, SchemarsJsonSchema, PartialEq, Eq)]
 pub struct SkillStructure {
     /// Required items that must exist in a valid skill.
     pub required: Vec<StructureItem>,
@@ -523,8 +592,47 @@ pub struct SkillStructure {
     pub optional: Vec<StructureItem>,
 }
 
+/// Category of a skill file-tree item, derived from its top-level directory.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, SchemarsJsonSchema, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum StructureCategory {
+    /// `SKILL.md` and other skill metadata files.
+    Metadata,
+    /// `scripts/` - standalone executables (tools).
+    Scripts,
+    /// `references/` - markdown documentation.
+    References,
+    /// `templates/` - Jinja2 templates.
+    Templates,
+    /// `tests/` - skill tests.
+    Tests,
+    /// `data/` or `assets/` - static data files.
+    Data,
+    /// Anything outside the known directories.
+    Other,
+}
+
+impl StructureCategory {
+    /// Classify a path relative to the skill root by its first component.
+    #[must_use]
+    pub fn from_relative_path(path: &str) -> Self {
+        let mut components = path.trim_end_matches('/').split('/');
+        let first = components.next().unwrap_or_default();
+        let is_top_level = components.next().is_none();
+        match first {
+            "SKILL.md" if is_top_level => Self::Metadata,
+            "scripts" => Self::Scripts,
+            "references" => Self::References,
+            "templates" => Self::Templates,
+            "tests" => Self::Tests,
+            "data" | "assets" => Self::Data,
+            _ => Self::Other,
+        }
+    }
+}
+
 /// A single item in the skill structure definition.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, SchemarsJsonSchema, PartialEq, Eq)]
 pub struct StructureItem {
     /// Path or pattern for the item.
     pub path: String,
@@ -534,6 +642,9 @@ pub struct StructureItem {
     /// Type of item ("file" or "dir").
     #[serde(default)]
     pub item_type: String,
+    /// Category of a scanned item; `None` in structure definitions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<StructureCategory>,
 }
 
 impl Default for SkillStructure {
@@ -543,22 +654,26 @@ impl Default for SkillStructure {
                 path: "SKILL.md".to_string(),
                 description: "Skill metadata".to_string(),
                 item_type: "file".to_string(),
+                category: None,
             }],
             default: vec![
                 StructureItem {
                     path: "scripts/".to_string(),
                     description: "Standalone executables".to_string(),
                     item_type: "dir".to_string(),
+                    category: None,
                 },
                 StructureItem {
                     path: "templates/".to_string(),
                     description: "Jinja2 templates".to_string(),
                     item_type: "dir".to_string(),
+                    category: None,
                 },
                 StructureItem {
                     path: "references/".to_string(),
                     description: "Markdown documentation".to_string(),
                     item_type: "dir".to_string(),
+                    category: None,
                 },
             ],
             optional: Vec::new(),
@@ -589,7 +704,7 @@ impl SkillStructure {
 // =============================================================================
 
 /// Represents a discovered template file within a skill.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, SchemarsJsonSchema, PartialEq, Eq)]
 pub struct TemplateRecord {
     /// Name of the template.
     pub template_name: String,
@@ -610,6 +725,9 @@ pub struct TemplateRecord {
     /// Hash of the template file.
     #[serde(default)]
     pub file_hash: String,
+    /// Size of the file in bytes.
+    #[serde(default)]
+    pub file_size: u64,
 }
 
 impl TemplateRecord {
@@ -631,6 +749,7 @@ impl TemplateRecord {
             content_preview: String::new(),
             keywords: Vec::new(),
             file_hash: String::new(),
+            file_size: 0,
         }
     }
 }
@@ -789,7 +908,7 @@ impl ReferenceRecord {
 // =============================================================================
 
 /// Represents an asset file discovered in a skill.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, SchemarsJsonSchema, PartialEq, Eq)]
 pub struct AssetRecord {
     /// Name of the asset.
     pub asset_name: String,
@@ -808,6 +927,9 @@ pub struct AssetRecord {
     /// Hash of the asset file.
     #[serde(default)]
     pub file_hash: String,
+    /// Size of the file in bytes.
+    #[serde(default)]
+    pub file_size: u64,
 }
 
 impl AssetRecord {
@@ -822,6 +944,7 @@ impl AssetRecord {
             content_preview: String::new(),
             keywords: Vec::new(),
             file_hash: String::new(),
+            file_size: 0,
         }
     }
 }
@@ -831,7 +954,7 @@ impl AssetRecord {
 // =============================================================================
 
 /// Represents a data file discovered in a skill.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, SchemarsJsonSchema, PartialEq, Eq)]
 pub struct DataRecord {
     /// Name of the data.
     pub data_name: String,
@@ -852,6 +975,9 @@ pub struct DataRecord {
     /// Hash of the data file.
     #[serde(default)]
     pub file_hash: String,
+    /// Size of the file in bytes.
+    #[serde(default)]
+    pub file_size: u64,
 }
 
 impl DataRecord {
@@ -873,6 +999,7 @@ impl DataRecord {
             content_preview: String::new(),
             keywords: Vec::new(),
             file_hash: String::new(),
+            file_size: 0,
         }
     }
 }
@@ -882,7 +1009,7 @@ impl DataRecord {
 // =============================================================================
 
 /// Represents a test file discovered in a skill.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, SchemarsJsonSchema, PartialEq, Eq)]
 pub struct TestRecord {
     /// Name of the test.
     pub test_name: String,
@@ -903,6 +1030,9 @@ pub struct TestRecord {
     /// Hash of the test file.
     #[serde(default)]
     pub file_hash: String,
+    /// Size of the file in bytes.
+    #[serde(default)]
+    pub file_size: u64,
 }
 
 impl TestRecord {
@@ -924,6 +1054,7 @@ impl TestRecord {
             docstring: String::new(),
             keywords: Vec::new(),
             file_hash: String::new(),
+            file_size: 0,
         }
     }
 }
@@ -985,6 +1116,8 @@ pub struct SyncReport {
     pub deleted: Vec<String>,
     /// Count of unchanged tools (fast path hit).
     pub unchanged_count: usize,
+    /// Names of skill-level metadata fields that changed (canonical diffs only).
+    pub metadata_changes: Vec<String>,
 }
 
 impl SyncReport {
@@ -5,20 +5,23 @@
 pub mod canonical;
 pub mod metadata;
 pub mod prompt;
+mod records;
 pub mod resource;
 pub mod scanner;
 pub mod skill_command;
 pub mod tools;
+pub mod validation;
 
 // Re-export common types from submodules
-pub use canonical::{CanonicalSkillPayload, CanonicalToolEntry};
+pub use canonical::{CanonicalSkillPayload, CanonicalToolEntry, diff_canonical};
 pub use metadata::{
     AssetRecord, DataRecord, DecoratorArgs, DocsAvailable, IndexToolEntry, PromptRecord,
     ReferencePath, ReferenceRecord, ResourceRecord, ScanConfig, SkillIndexEntry, SkillMetadata,
-    SkillStructure, SnifferRule, StructureItem, SyncReport, TemplateRecord, TestRecord,
-    ToolAnnotations, ToolRecord, calculate_sync_ops,
+    SkillStructure, SnifferRule, StructureCategory, StructureItem, SyncReport, TemplateRecord,
+    TestRecord, ToolAnnotations, ToolRecord, calculate_sync_ops,
 };
 pub use prompt::PromptScanner;
 pub use resource::ResourceScanner;
-pub use scanner::SkillScanner;
+pub use scanner::{SkillScanner, scan_skill_index};
 pub use tools::ToolsScanner;
+pub use validation::{ValidationIssue, ValidationReport, ValidationSeverity, validate_skills_tree};
@@ -0,0 +1,123 @@
+//! Skill Records - Enumerates a skill's auxiliary directories into typed records.
+//!
+//! Fills `SkillMetadata` record vectors from:
+//! - `templates/` -> `TemplateRecord`
+//! - `assets/` -> `AssetRecord`
+//! - `data/` -> `DataRecord`
+//! - `tests/` -> `TestRecord`
+
+use std::path::Path;
+
+use crate::skills::metadata::{AssetRecord, DataRecord, SkillMetadata, TemplateRecord, TestRecord};
+
+/// One regular file found under a skill sub-directory.
+struct SkillFile {
+    /// Path relative to the skill root, with `/` separators.
+    path: String,
+    stem: String,
+    extension: String,
+    size: u64,
+}
+
+/// Populate `templates`, `assets`, `data` and `tests` on `metadata` from `skill_path`.
+///
+/// Missing directories leave the matching vector empty. Files are sorted by path;
+/// hidden entries and `__pycache__` are skipped. Record paths are relative to
+/// `skill_path` and sizes come from filesystem metadata; file contents are not
+/// read, so `file_hash` is left empty.
+pub(crate) fn populate_skill_records(skill_path: &Path, metadata: &mut SkillMetadata) {
+    let skill_name = metadata.skill_name.clone();
+
+    metadata.templates = list_skill_files(skill_path, "templates")
+        .into_iter()
+        .map(|file| {
+            let mut record = TemplateRecord::new(
+                file.stem,
+                String::new(),
+                skill_name.clone(),
+                file.path,
+                Vec::new(),
+            );
+            record.file_size = file.size;
+            record
+        })
+        .collect();
+
+    metadata.assets = list_skill_files(skill_path, "assets")
+        .into_iter()
+        .map(|file| {
+            let mut record =
+                AssetRecord::new(file.stem.clone(), file.stem, skill_name.clone(), file.path);
+            record.file_size = file.size;
+            record
+        })
+        .collect();
+
+    metadata.data = list_skill_files(skill_path, "data")
+        .into_iter()
+        .map(|file| {
+            let mut record = DataRecord::new(
+                file.stem,
+                file.extension,
+                skill_name.clone(),
+                file.path,
+                Vec::new(),
+            );
+            record.file_size = file.size;
+            record
+        })
+        .collect();
+
+    metadata.tests = list_skill_files(skill_path, "tests")
+        .into_iter()
+        .map(|file| {
+            let mut record = TestRecord::new(
+                file.stem,
+                skill_name.clone(),
+                file.path,
+                Vec::new(),
+                Vec::new(),
+            );
+            record.file_size = file.size;
+            record
+        })
+        .collect();
+}
+
+fn list_skill_files(skill_path: &Path, dir: &str) -> Vec<SkillFile> {
+    let dir = skill_path.join(dir);
+    if !dir.is_dir() {
+        return Vec::new();
+    }
+    walkdir::WalkDir::new(&dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            entry.depth() == 0 || (!name.starts_with('.') && name != "__pycache__")
+        })
+        .filter_map(std::result::Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let size = entry.metadata().ok()?.len();
+            let path = entry.path();
+            let relative = path.strip_prefix(skill_path).unwrap_or(path);
+            Some(SkillFile {
+                stem: path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                extension: path
+                    .extension()
+                    .map(|ext| ext.to_string_lossy().to_lowercase())
+                    .unwrap_or_default(),
+                size,
+                path: relative
+                    .components()
+                    .map(|part| part.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/"),
+            })
+        })
+        .collect()
+}
@@ -23,18 +23,21 @@
 //! ```
 
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use serde::Deserialize;
 
 use std::collections::HashMap;
 
-use crate::frontmatter::extract_frontmatter;
+use crate::frontmatter::{FrontmatterError, extract_frontmatter, extract_frontmatter_validated};
 use crate::skills::canonical::{CanonicalSkillPayload, CanonicalToolEntry};
 use crate::skills::metadata::{
     IndexToolEntry, ReferencePath, ReferenceRecord, SkillIndexEntry, SkillMetadata, SkillStructure,
-    SnifferRule, ToolRecord,
+    SnifferRule, StructureCategory, StructureItem, ToolRecord,
 };
+use crate::skills::records::populate_skill_records;
+use crate::skills::tools::ToolsScanner;
 
 /// TOML structure for rules.toml parsing.
 #[derive(Debug, Deserialize)]
@@ -310,13 +313,86 @@ struct SkillMetadataBlock {
 /// let all_metadatas = scanner.scan_all(PathBuf::from("assets/skills")).unwrap();
 /// ```
 #[derive(Debug, Clone)]
-pub struct SkillScanner;
+pub struct SkillScanner {
+    /// Lowest supported [`SkillMetadata::schema_version`], if bounded.
+    min_schema_version: Option<u32>,
+    /// Highest supported [`SkillMetadata::schema_version`], if bounded.
+    max_schema_version: Option<u32>,
+    /// Reject skills outside the supported range instead of warning.
+    strict_schema_check: bool,
+}
 
 impl SkillScanner {
+    /// Frontmatter keys every SKILL.md is expected to declare.
+    pub const REQUIRED_FRONTMATTER_FIELDS: &'static [&'static str] = &["name", "description"];
+
     /// Create a new skill scanner with default settings.
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self {
+            min_schema_version: None,
+            max_schema_version: None,
+            strict_schema_check: false,
+        }
+    }
+
+    /// Warn about skills whose schema version is below `version`.
+    #[must_use]
+    pub fn with_min_schema_version(mut self, version: u32) -> Self {
+        self.min_schema_version = Some(version);
+        self
+    }
+
+    /// Warn about skills whose schema version is above `version`.
+    #[must_use]
+    pub fn with_max_schema_version(mut self, version: u32) -> Self {
+        self.max_schema_version = Some(version);
+        self
+    }
+
+    /// Fail (or, in `scan_all`, skip) skills outside the supported schema range instead of warning.
+    #[must_use]
+    pub fn with_strict_schema_check(mut self, strict: bool) -> Self {
+        self.strict_schema_check = strict;
+        self
+    }
+
+    /// Describe why `metadata` falls outside the supported schema version range.
+    ///
+    /// Returns `None` when the version is supported or the skill declares no
+    /// parseable version (unversioned skills are not checked).
+    #[must_use]
+    pub fn schema_version_warning(&self, metadata: &SkillMetadata) -> Option<String> {
+        let version = metadata.schema_version()?;
+        if let Some(min) = self.min_schema_version
+            && version < min
+        {
+            return Some(format!(
+                "Skill {} declares schema version {version} (\"{}\"), below minimum supported {min}",
+                metadata.skill_name, metadata.version
+            ));
+        }
+        if let Some(max) = self.max_schema_version
+            && version > max
+        {
+            return Some(format!(
+                "Skill {} declares schema version {version} (\"{}\"), above maximum supported {max}; newer fields may be ignored",
+                metadata.skill_name, metadata.version
+            ));
+        }
+        None
+    }
+
+    /// Warn about an unsupported schema version, or fail in strict mode.
+    fn check_schema_version(&self, metadata: &SkillMetadata) -> Result<(), String> {
+        match self.schema_version_warning(metadata) {
+            Some(message) if self.strict_schema_check => Err(message),
+            Some(message) => {
+                log::warn!("{message}");
+                Ok(())
+            }
+            None => Ok(()),
+        }
     }
 
     /// Get the default skill structure (from settings.yaml).
@@ -352,11 +428,38 @@ impl SkillScanner {
         true
     }
 
+    /// Check each `require_refs` entry of `metadata` against the skill directory.
+    ///
+    /// Paths are resolved relative to `skill_path`; the flag reports whether the
+    /// referenced file exists on disk. Order follows `metadata.require_refs`.
+    #[must_use]
+    pub fn resolve_references(
+        skill_path: &Path,
+        metadata: &SkillMetadata,
+    ) -> Vec<(ReferencePath, bool)> {
+        metadata
+            .require_refs
+            .iter()
+            .map(|reference| {
+                let exists = skill_path.join(reference.as_str()).is_file();
+                if !exists {
+                    log::debug!(
+                        "Missing reference {} for skill: {}",
+                        reference,
+                        skill_path.display()
+                    );
+                }
+                (reference.clone(), exists)
+            })
+            .collect()
+    }
+
     /// Scan a single skill directory and extract its metadata.
     ///
     /// Returns `Ok(Some(metadata))` if SKILL.md is found and valid.
     /// Returns `Ok(None)` if SKILL.md is missing.
-    /// Returns `Err(...)` if SKILL.md exists but cannot be parsed.
+    /// Returns `Err(...)` if SKILL.md exists but cannot be parsed, or declares an
+    /// unsupported schema version under `with_strict_schema_check`.
     ///
     /// # Arguments
     ///
@@ -401,7 +504,9 @@ impl SkillScanner {
         }
 
         let content = fs::read_to_string(&skill_md_path)?;
-        let metadata = self.parse_skill_md(&content, skill_path)?;
+        let mut metadata = self.parse_skill_md(&content, skill_path)?;
+        self.check_schema_version(&metadata)?;
+        populate_skill_records(skill_path, &mut metadata);
 
         log::info!(
             "Scanned skill metadata: {} (v{}) - {} keywords",
@@ -463,6 +568,91 @@ impl SkillScanner {
         Ok(metadatas)
     }
 
+    /// Scan all skills like [`Self::scan_all`] and attach each skill's file tree.
+    ///
+    /// Every returned `SkillMetadata` has `structure` populated via
+    /// [`Self::scan_skill_structure`], so UIs can render the skill's directories.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the skills directory cannot be read.
+    pub fn scan_all_with_structure(
+        &self,
+        base_path: &Path,
+    ) -> Result<Vec<SkillMetadata>, Box<dyn std::error::Error>> {
+        let mut metadatas = self.scan_all(base_path, None)?;
+        for metadata in &mut metadatas {
+            let skill_path = base_path.join(&metadata.skill_name);
+            metadata.structure = Some(Self::scan_skill_structure(&skill_path));
+        }
+        Ok(metadatas)
+    }
+
+    /// Walk a skill directory and categorize every file and directory in it.
+    ///
+    /// Paths are relative to the skill root (directories end with `/`) and sorted.
+    /// Items required by the default structure go to `required`, items under the
+    /// default directories (`scripts/`, `templates/`, `references/`) to `default`,
+    /// and everything else to `optional`. Hidden entries and `__pycache__` are skipped.
+    #[must_use]
+    pub fn scan_skill_structure(skill_path: &Path) -> SkillStructure {
+        let canonical = SkillStructure::default();
+        let required_paths: Vec<&str> = canonical
+            .required
+            .iter()
+            .map(|item| item.path.as_str())
+            .collect();
+        let default_dirs = canonical.script_directories();
+
+        let mut structure = SkillStructure {
+            required: Vec::new(),
+            default: Vec::new(),
+            optional: Vec::new(),
+        };
+        let entries = walkdir::WalkDir::new(skill_path)
+            .min_depth(1)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| {
+                let name = entry.file_name().to_string_lossy();
+                !name.starts_with('.') && name != "__pycache__"
+            })
+            .filter_map(std::result::Result::ok);
+        for entry in entries {
+            let Ok(relative) = entry.path().strip_prefix(skill_path) else {
+                continue;
+            };
+            let mut path = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            let is_dir = entry.file_type().is_dir();
+            if is_dir {
+                path.push('/');
+            }
+            let category = StructureCategory::from_relative_path(&path);
+            let in_default_dir = path
+                .split('/')
+                .next()
+                .is_some_and(|top_level| default_dirs.contains(&top_level));
+            let item = StructureItem {
+                description: String::new(),
+                item_type: if is_dir { "dir" } else { "file" }.to_string(),
+                category: Some(category),
+                path,
+            };
+            if required_paths.contains(&item.path.as_str()) {
+                structure.required.push(item);
+            } else if in_default_dir {
+                structure.default.push(item);
+            } else {
+                structure.optional.push(item);
+            }
+        }
+        structure
+    }
+
     /// Internal helper for parallel skill scanning.
     #[inline]
     fn scan_skill_inner(
@@ -487,7 +677,12 @@ impl SkillScanner {
 
         // Read and parse the file
         let content = fs::read_to_string(&skill_md_path).ok()?;
-        let metadata = self.parse_skill_md(&content, skill_path).ok()?;
+        let mut metadata = self.parse_skill_md(&content, skill_path).ok()?;
+        if let Err(message) = self.check_schema_version(&metadata) {
+            log::warn!("Skipping skill: {message}");
+            return None;
+        }
+        populate_skill_records(skill_path, &mut metadata);
 
         log::info!(
             "Scanned skill metadata: {} (v{}) - {} keywords",
@@ -644,14 +839,26 @@ impl SkillScanner {
             .to_string_lossy()
             .to_string();
 
-        // Find YAML frontmatter (between first and second ---)
-        let Some(frontmatter) = extract_frontmatter(content) else {
-            log::warn!("No YAML frontmatter found in SKILL.md for: {skill_name}");
-            return Ok(SkillMetadata {
-                skill_name,
-                ..SkillMetadata::default()
-            });
-        };
+        // Find YAML frontmatter (between first and second ---) and check required keys.
+        // Missing keys are reported but not fatal so partially described skills still load.
+        let frontmatter =
+            match extract_frontmatter_validated(content, Self::REQUIRED_FRONTMATTER_FIELDS) {
+                Ok(frontmatter) => frontmatter,
+                Err(FrontmatterError::Missing) => {
+                    log::warn!("No YAML frontmatter found in SKILL.md for: {skill_name}");
+                    return Ok(SkillMetadata {
+                        skill_name,
+                        ..SkillMetadata::default()
+                    });
+                }
+                Err(e @ FrontmatterError::MissingFields(_)) => {
+                    log::warn!("SKILL.md for {skill_name}: {e}");
+                    extract_frontmatter(content).unwrap_or_default()
+                }
+                Err(e) => {
+                    return Err(anyhow::anyhow!("Failed to parse SKILL.md frontmatter: {e}").into());
+                }
+            };
 
         // Parse YAML frontmatter
         let frontmatter_data: SkillFrontmatter = serde_yaml::from_str(&frontmatter)
@@ -702,6 +909,11 @@ impl SkillScanner {
                 .collect(),
             repository,
             permissions,
+            templates: Vec::new(),
+            assets: Vec::new(),
+            data: Vec::new(),
+            tests: Vec::new(),
+            structure: None,
         })
     }
 }
@@ -712,4 +924,74 @@ impl Default for SkillScanner {
     }
 }
 
+/// Scan all skills under `base_path` into index entries with their tools attached.
+///
+/// One-shot equivalent of `SkillScanner::scan_all` followed by
+/// `ToolsScanner::scan_skill_scripts` and `SkillScanner::build_index_entry`
+/// for each skill. Entries are sorted by skill name.
+///
+/// # Errors
+///
+/// Returns an error if the skills directory cannot be read or a skill's
+/// scripts fail to parse.
+pub fn scan_skill_index(
+    base_path: &Path,
+) -> Result<Vec<SkillIndexEntry>, Box<dyn std::error::Error>> {
+    let skill_scanner = SkillScanner::new();
+    let tools_scanner = ToolsScanner::new();
+
+    let mut entries = Vec::new();
+    for metadata in skill_scanner.scan_all(base_path, None)? {
+        let skill_path = base_path.join(&metadata.skill_name);
+        let tools = tools_scanner.scan_skill_scripts(
+            &skill_path,
+            &metadata.skill_name,
+            &metadata.routing_keywords,
+            &metadata.intents,
+        )?;
+        entries.push(skill_scanner.build_index_entry(metadata, &tools, &skill_path));
+    }
+
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+/// Scan all skills under `base_path` and write every tool as one JSON object per line.
+///
+/// Skills are visited in name order and tools keep their scan order, so the output
+/// is stable across runs. Returns the number of tools written.
+///
+/// # Errors
+///
+/// Returns an error if the skills directory cannot be read, a skill's scripts
+/// fail to parse, or writing to `writer` fails.
+pub fn export_tools_jsonl(
+    base_path: &Path,
+    mut writer: impl Write,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let skill_scanner = SkillScanner::new();
+    let tools_scanner = ToolsScanner::new();
+
+    let mut metadatas = skill_scanner.scan_all(base_path, None)?;
+    metadatas.sort_by(|a, b| a.skill_name.cmp(&b.skill_name));
+
+    let mut written = 0;
+    for metadata in metadatas {
+        let skill_path = base_path.join(&metadata.skill_name);
+        let tools = tools_scanner.scan_skill_scripts(
+            &skill_path,
+            &metadata.skill_name,
+            &metadata.routing_keywords,
+            &metadata.intents,
+        )?;
+        for tool in &tools {
+            serde_json::to_writer(&mut writer, tool)?;
+            writer.write_all(b"\n")?;
+        }
+        written += tools.len();
+    }
+    writer.flush()?;
+    Ok(written)
+}
+
 // Note: Comprehensive tests are in tests/test_skill_scanner.rs
@@ -25,6 +25,7 @@
 //! }
 //! ```
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
@@ -55,13 +56,83 @@ use crate::skills::skill_command::parser::{ParsedParameter, extract_param_descri
 /// ).unwrap();
 /// ```
 #[derive(Debug)]
-pub struct ToolsScanner;
+pub struct ToolsScanner {
+    /// Log `ToolRecord::validate` diagnostics for every scanned tool.
+    log_validation: bool,
+    /// Extra keywords added for each routing keyword, keyed by lowercased keyword.
+    keyword_synonyms: HashMap<String, Vec<String>>,
+}
 
 impl ToolsScanner {
     /// Create a new script scanner.
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self {
+            log_validation: false,
+            keyword_synonyms: HashMap::new(),
+        }
+    }
+
+    /// Enable or disable logging of tool validation diagnostics.
+    ///
+    /// When enabled, each scanned `ToolRecord` is checked with
+    /// [`ToolRecord::validate`] and any issues are logged as warnings.
+    #[must_use]
+    pub fn with_validation_logging(mut self, enabled: bool) -> Self {
+        self.log_validation = enabled;
+        self
+    }
+
+    /// Expand routing keywords with synonyms in each tool's `keywords`.
+    ///
+    /// For every SKILL.md routing keyword found in `synonyms` (matched
+    /// case-insensitively), its synonyms are appended to the tool's keywords,
+    /// skipping any already present. E.g. `"commit" -> ["checkin"]`.
+    #[must_use]
+    pub fn with_keyword_synonyms(mut self, synonyms: HashMap<String, Vec<String>>) -> Self {
+        self.keyword_synonyms = synonyms
+            .into_iter()
+            .map(|(keyword, expansions)| (keyword.to_lowercase(), expansions))
+            .collect();
+        self
+    }
+
+    /// Build a tool's keywords: skill name, tool name, routing keywords, then synonyms.
+    fn combine_keywords(
+        &self,
+        skill_name: &str,
+        tool_name: &str,
+        skill_keywords: &[String],
+    ) -> Vec<String> {
+        let mut keywords = vec![skill_name.to_string(), tool_name.to_string()];
+        keywords.extend(skill_keywords.iter().cloned());
+        for keyword in skill_keywords {
+            let Some(synonyms) = self.keyword_synonyms.get(&keyword.to_lowercase()) else {
+                continue;
+            };
+            for synonym in synonyms {
+                if !keywords.contains(synonym) {
+                    keywords.push(synonym.clone());
+                }
+            }
+        }
+        keywords
+    }
+
+    /// Log validation diagnostics for scanned tools when enabled.
+    fn log_validation_issues(&self, tools: &[ToolRecord], skill_name: &str) {
+        if !self.log_validation {
+            return;
+        }
+
+        for tool in tools {
+            for issue in tool.validate() {
+                log::warn!(
+                    "ToolsScanner: skill '{skill_name}' ({}): {issue}",
+                    tool.file_path
+                );
+            }
+        }
     }
 
     /// Scan a scripts directory for @`skill_command` decorated functions.
@@ -146,6 +217,8 @@ impl ToolsScanner {
             );
         }
 
+        self.log_validation_issues(&tools, skill_name);
+
         Ok(tools)
     }
 
@@ -350,8 +423,7 @@ impl ToolsScanner {
             let input_schema = Self::generate_input_schema(&parameters, &description);
 
             // Combine keywords
-            let mut combined_keywords = vec![skill_name.to_string(), tool_name.clone()];
-            combined_keywords.extend(skill_keywords.iter().cloned());
+            let combined_keywords = self.combine_keywords(skill_name, &tool_name, skill_keywords);
 
             tools.push(ToolRecord::with_enrichment(
                 format!("{skill_name}.{tool_name}"),
@@ -572,8 +644,7 @@ impl ToolsScanner {
             let input_schema = Self::generate_input_schema(&parameters, &description);
 
             // Combine keywords
-            let mut combined_keywords = vec![skill_name.to_string(), tool_name.clone()];
-            combined_keywords.extend(skill_keywords.iter().cloned());
+            let combined_keywords = self.combine_keywords(skill_name, &tool_name, skill_keywords);
 
             tools.push(ToolRecord::with_enrichment(
                 format!("{skill_name}.{tool_name}"),
@@ -698,6 +769,8 @@ impl ToolsScanner {
             );
         }
 
+        self.log_validation_issues(&all_tools, skill_name);
+
         Ok(all_tools)
     }
 }
@@ -0,0 +1,231 @@
+//! Dry-run validation of a skills tree.
+//!
+//! [`validate_skills_tree`] checks every skill under a base directory without
+//! indexing anything and collects all problems into one [`ValidationReport`].
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use walkdir::WalkDir;
+
+use crate::frontmatter::{FrontmatterError, extract_frontmatter_validated};
+use crate::skills::metadata::ToolRecord;
+use crate::skills::scanner::SkillScanner;
+use crate::skills::tools::ToolsScanner;
+
+/// How serious a validation issue is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationSeverity {
+    /// The skill loads but is degraded (e.g. poorer routing).
+    Warning,
+    /// The skill or one of its tools will not load or resolve correctly.
+    Error,
+}
+
+/// A single problem found in a skill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValidationIssue {
+    /// Skill directory the issue belongs to.
+    pub skill_path: PathBuf,
+    /// Issue severity.
+    pub severity: ValidationSeverity,
+    /// Human-readable description.
+    pub message: String,
+}
+
+/// All issues found by [`validate_skills_tree`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ValidationReport {
+    /// Number of skill directories checked.
+    pub skills_checked: usize,
+    /// Issues ordered by skill path, in discovery order within a skill.
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Returns `true` if no issue has [`ValidationSeverity::Error`].
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        !self
+            .issues
+            .iter()
+            .any(|issue| issue.severity == ValidationSeverity::Error)
+    }
+
+    /// Number of issues with the given severity.
+    #[must_use]
+    pub fn count(&self, severity: ValidationSeverity) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == severity)
+            .count()
+    }
+
+    /// Issues reported for one skill directory.
+    pub fn issues_for<'a>(
+        &'a self,
+        skill_path: &'a Path,
+    ) -> impl Iterator<Item = &'a ValidationIssue> + 'a {
+        self.issues
+            .iter()
+            .filter(move |issue| issue.skill_path == skill_path)
+    }
+
+    fn push(&mut self, skill_path: &Path, severity: ValidationSeverity, message: String) {
+        self.issues.push(ValidationIssue {
+            skill_path: skill_path.to_path_buf(),
+            severity,
+            message,
+        });
+    }
+}
+
+/// Check every skill directory under `base_path` and report all problems found.
+///
+/// Reports, per skill: a missing or unparsable `SKILL.md`, missing required
+/// frontmatter fields, `require_refs` entries that do not exist, scripts without
+/// any `@skill_command` function, tools declared more than once, and
+/// [`ToolRecord::validate`] diagnostics. Nothing is written or indexed.
+#[must_use]
+pub fn validate_skills_tree(base_path: &Path) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    let entries = match fs::read_dir(base_path) {
+        Ok(entries) => entries,
+        Err(e) => {
+            report.push(
+                base_path,
+                ValidationSeverity::Error,
+                format!("cannot read skills directory: {e}"),
+            );
+            return report;
+        }
+    };
+    let mut skill_dirs: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    skill_dirs.sort();
+
+    let skill_scanner = SkillScanner::new();
+    let tools_scanner = ToolsScanner::new();
+    for skill_path in &skill_dirs {
+        report.skills_checked += 1;
+        validate_skill(&skill_scanner, &tools_scanner, skill_path, &mut report);
+    }
+    report
+}
+
+fn validate_skill(
+    skill_scanner: &SkillScanner,
+    tools_scanner: &ToolsScanner,
+    skill_path: &Path,
+    report: &mut ValidationReport,
+) {
+    use ValidationSeverity::{Error, Warning};
+
+    let content = match fs::read_to_string(skill_path.join("SKILL.md")) {
+        Ok(content) => content,
+        Err(e) => {
+            report.push(skill_path, Error, format!("cannot read SKILL.md: {e}"));
+            return;
+        }
+    };
+    match extract_frontmatter_validated(&content, SkillScanner::REQUIRED_FRONTMATTER_FIELDS) {
+        Ok(_) => {}
+        Err(e @ FrontmatterError::MissingFields(_)) => {
+            report.push(skill_path, Warning, format!("SKILL.md {e}"));
+        }
+        Err(e) => {
+            report.push(skill_path, Error, format!("SKILL.md {e}"));
+            return;
+        }
+    }
+    let metadata = match skill_scanner.parse_skill_md(&content, skill_path) {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            report.push(skill_path, Error, e.to_string());
+            return;
+        }
+    };
+
+    for (reference, exists) in SkillScanner::resolve_references(skill_path, &metadata) {
+        if !exists {
+            report.push(
+                skill_path,
+                Error,
+                format!("required reference not found: {reference}"),
+            );
+        }
+    }
+
+    let mut first_seen: HashMap<String, String> = HashMap::new();
+    for script in script_files(&skill_path.join("scripts")) {
+        let display = script.display().to_string();
+        let tools = fs::read_to_string(&script)
+            .map_err(|e| e.to_string())
+            .and_then(|source| {
+                tools_scanner
+                    .parse_content(
+                        &source,
+                        &display,
+                        &metadata.skill_name,
+                        &metadata.routing_keywords,
+                        &metadata.intents,
+                    )
+                    .map_err(|e| e.to_string())
+            });
+        let tools: Vec<ToolRecord> = match tools {
+            Ok(tools) => tools,
+            Err(e) => {
+                report.push(skill_path, Error, format!("cannot parse {display}: {e}"));
+                continue;
+            }
+        };
+        if tools.is_empty() {
+            report.push(
+                skill_path,
+                Warning,
+                format!("{display} has no @skill_command functions"),
+            );
+        }
+        for tool in tools {
+            if let Some(first) = first_seen.get(&tool.tool_name) {
+                report.push(
+                    skill_path,
+                    Error,
+                    format!(
+                        "duplicate tool name {} in {display} (first defined in {first})",
+                        tool.tool_name
+                    ),
+                );
+            } else {
+                first_seen.insert(tool.tool_name.clone(), display.clone());
+            }
+            for issue in tool.validate() {
+                report.push(skill_path, Warning, issue);
+            }
+        }
+    }
+}
+
+/// Python scripts the tools scanner would parse, in path order.
+fn script_files(scripts_dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = WalkDir::new(scripts_dir)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .map(walkdir::DirEntry::into_path)
+        .filter(|path| {
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            path.is_file()
+                && path.extension().is_some_and(|ext| ext == "py")
+                && !name.starts_with('_')
+        })
+        .collect();
+    files.sort();
+    files
+}
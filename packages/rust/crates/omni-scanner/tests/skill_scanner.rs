@@ -413,3 +413,169 @@ pattern = "pyproject.toml"
     assert_eq!(entry.sniffing_rules[0].pattern, "pyproject.toml");
     assert_eq!(entry.sniffing_rules[0].rule_type, "file_exists");
 }
+
+/// Scan a two-skill tree into index entries with tools nested per skill.
+#[test]
+fn test_scan_skill_index_nests_tools() {
+    let temp_dir = TempDir::new().unwrap();
+    let skills_dir = temp_dir.path().join("skills");
+
+    for (skill, keywords, tools) in [
+        ("writer", r#"["write"]"#, vec!["write_text", "polish_text"]),
+        ("git", r#"["commit"]"#, vec!["commit"]),
+    ] {
+        let scripts_dir = skills_dir.join(skill).join("scripts");
+        fs::create_dir_all(&scripts_dir).unwrap();
+        fs::write(
+            skills_dir.join(skill).join("SKILL.md"),
+            format!("---\nname: \"{skill}\"\nversion: \"1.0\"\nrouting_keywords: {keywords}\n---\n# {skill}\n"),
+        )
+        .unwrap();
+
+        let mut script = String::from("from agent.skills.decorators import skill_command\n");
+        for tool in tools {
+            script.push_str(&format!(
+                "\n@skill_command(name=\"{tool}\")\ndef {tool}(value: str) -> str:\n    '''Run {tool}.'''\n    return value\n"
+            ));
+        }
+        fs::write(scripts_dir.join("tools.py"), script).unwrap();
+    }
+
+    let entries = omni_scanner::scan_skill_index(&skills_dir).unwrap();
+    assert_eq!(entries.len(), 2);
+
+    let git = &entries[0];
+    assert_eq!(git.name, "git");
+    let git_tools: Vec<&str> = git.tools.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(git_tools, vec!["git.commit"]);
+
+    let writer = &entries[1];
+    assert_eq!(writer.name, "writer");
+    let mut writer_tools: Vec<&str> = writer.tools.iter().map(|t| t.name.as_str()).collect();
+    writer_tools.sort_unstable();
+    assert_eq!(
+        writer_tools,
+        vec!["writer.polish_text", "writer.write_text"]
+    );
+}
+
+/// Export a two-skill tree as JSON Lines, one `ToolRecord` per line.
+#[test]
+fn test_export_tools_jsonl_one_object_per_tool() {
+    let temp_dir = TempDir::new().unwrap();
+    let skills_dir = temp_dir.path().join("skills");
+
+    for (skill, tools) in [
+        ("writer", vec!["write_text", "polish_text"]),
+        ("git", vec!["commit"]),
+    ] {
+        let scripts_dir = skills_dir.join(skill).join("scripts");
+        fs::create_dir_all(&scripts_dir).unwrap();
+        fs::write(
+            skills_dir.join(skill).join("SKILL.md"),
+            format!("---\nname: \"{skill}\"\ndescription: \"{skill} tools\"\n---\n# {skill}\n"),
+        )
+        .unwrap();
+
+        let mut script = String::from("from agent.skills.decorators import skill_command\n");
+        for tool in tools {
+            script.push_str(&format!(
+                "\n@skill_command(name=\"{tool}\")\ndef {tool}(value: str) -> str:\n    '''Run {tool}.'''\n    return value\n"
+            ));
+        }
+        fs::write(scripts_dir.join("tools.py"), script).unwrap();
+    }
+
+    let mut out = Vec::new();
+    let written = omni_scanner::export_tools_jsonl(&skills_dir, &mut out).unwrap();
+    assert_eq!(written, 3);
+
+    let text = String::from_utf8(out).unwrap();
+    let records: Vec<omni_scanner::ToolRecord> = text
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0].tool_name, "git.commit");
+    let mut names: Vec<&str> = records.iter().map(|r| r.tool_name.as_str()).collect();
+    names.sort_unstable();
+    assert_eq!(
+        names,
+        vec!["git.commit", "writer.polish_text", "writer.write_text"]
+    );
+}
+
+/// Dry-run validation reports every issue in a mixed tree with its skill path.
+#[test]
+fn test_validate_skills_tree_reports_each_issue() {
+    use omni_scanner::{ValidationSeverity, validate_skills_tree};
+
+    let temp_dir = TempDir::new().unwrap();
+    let skills_dir = temp_dir.path().join("skills");
+    let write = |rel: &str, content: &str| {
+        let path = skills_dir.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    };
+    let tool = |name: &str| {
+        format!(
+            "from agent.skills.decorators import skill_command\n\n@skill_command(name=\"{name}\")\ndef {name}(value: str) -> str:\n    '''Run {name}.'''\n    return value\n"
+        )
+    };
+
+    // Valid skill.
+    write(
+        "good/SKILL.md",
+        "---\nname: good\ndescription: Good skill\nmetadata:\n  routing_keywords: [\"good\"]\n---\n",
+    );
+    write("good/scripts/run.py", &tool("run"));
+    // Frontmatter that is not valid YAML.
+    write("bad_yaml/SKILL.md", "---\nname: [unclosed\n---\n");
+    // Missing reference, undecorated script, duplicate tool.
+    write(
+        "broken/SKILL.md",
+        "---\nname: broken\ndescription: Broken skill\nmetadata:\n  routing_keywords: [\"broken\"]\n  require_refs: [\"references/missing.md\"]\n---\n",
+    );
+    write("broken/scripts/a.py", &tool("sync"));
+    write("broken/scripts/b.py", &tool("sync"));
+    write("broken/scripts/helpers.py", "def helper():\n    return 1\n");
+    // No SKILL.md at all.
+    fs::create_dir_all(skills_dir.join("empty")).unwrap();
+
+    let report = validate_skills_tree(&skills_dir);
+    assert_eq!(report.skills_checked, 4);
+    assert!(!report.is_valid());
+    assert_eq!(report.issues_for(&skills_dir.join("good")).count(), 0);
+
+    let messages = |skill: &str| -> Vec<(ValidationSeverity, String)> {
+        report
+            .issues_for(&skills_dir.join(skill))
+            .map(|issue| (issue.severity, issue.message.clone()))
+            .collect()
+    };
+    let bad_yaml = messages("bad_yaml");
+    assert_eq!(bad_yaml.len(), 1);
+    assert_eq!(bad_yaml[0].0, ValidationSeverity::Error);
+    assert!(bad_yaml[0].1.contains("invalid YAML"), "{bad_yaml:?}");
+
+    let empty = messages("empty");
+    assert_eq!(empty.len(), 1);
+    assert!(empty[0].1.contains("SKILL.md"), "{empty:?}");
+
+    let broken = messages("broken");
+    assert_eq!(broken.len(), 3, "{broken:?}");
+    assert!(broken.contains(&(
+        ValidationSeverity::Error,
+        "required reference not found: references/missing.md".to_string()
+    )));
+    assert!(broken.iter().any(|(severity, message)| {
+        *severity == ValidationSeverity::Error
+            && message.contains("duplicate tool name broken.sync")
+            && message.contains("b.py")
+    }));
+    assert!(broken.iter().any(|(severity, message)| {
+        *severity == ValidationSeverity::Warning
+            && message.contains("helpers.py has no @skill_command functions")
+    }));
+    assert_eq!(report.count(ValidationSeverity::Error), 4);
+}
@@ -68,7 +68,7 @@ fn generate_skill_metadata_schema() {
 /// Resolve output path for schema files.
 ///
 /// Uses PRJ_ROOT environment variable if set, otherwise falls back to
-/// CARGO_MANIFEST_DIR + "../../../packages/shared/schemas/"
+/// CARGO_MANIFEST_DIR + "../../../shared/schemas/" (`packages/shared/schemas`)
 fn resolve_output_path(filename: &str) -> PathBuf {
     // Try PRJ_ROOT first
     if let Ok(prj_root) = std::env::var("PRJ_ROOT") {
@@ -86,6 +86,21 @@ fn resolve_output_path(filename: &str) -> PathBuf {
         .unwrap_or_else(|_| PathBuf::from("."));
 
     manifest_dir
-        .join("../../../packages/shared/schemas")
+        .join("../../../shared/schemas")
         .join(filename)
 }
+
+/// Test that all_scanner_schemas aggregates valid JSON schemas keyed by type name.
+#[test]
+fn test_all_scanner_schemas_contains_core_types() {
+    let schemas = omni_scanner::all_scanner_schemas();
+
+    for name in ["SkillIndexEntry", "KnowledgeEntry", "ToolRecord"] {
+        let schema_json = schemas
+            .get(name)
+            .unwrap_or_else(|| panic!("missing schema for {name}"));
+        let parsed: serde_json::Value = serde_json::from_str(schema_json)
+            .unwrap_or_else(|err| panic!("{name} schema should be valid JSON: {err}"));
+        assert_eq!(parsed["title"], name);
+    }
+}
@@ -156,6 +156,11 @@ fn test_skill_index_entry_tool_format() {
             require_refs: vec![],
             repository: "".to_string(),
             permissions: vec![],
+            templates: vec![],
+            assets: vec![],
+            data: vec![],
+            tests: vec![],
+            structure: None,
         },
         &[tool],
         &skill_path,
@@ -406,6 +411,11 @@ fn test_build_index_entry_no_double_prefix_regression() {
             require_refs: vec![],
             repository: "".to_string(),
             permissions: vec![],
+            templates: vec![],
+            assets: vec![],
+            data: vec![],
+            tests: vec![],
+            structure: None,
         },
         &tools,
         &skill_path,
@@ -544,6 +554,11 @@ version: "1.0.0"
             require_refs: vec![],
             repository: "".to_string(),
             permissions: vec![],
+            templates: vec![],
+            assets: vec![],
+            data: vec![],
+            tests: vec![],
+            structure: None,
         },
         &[], // Empty tools
         &skill_path,
@@ -1,8 +1,8 @@
 //! Tests for skill_metadata module.
 //!
-//! Tests SkillMetadata, SnifferRule, ReferencePath, and related types.
+//! Tests SkillMetadata, SnifferRule, ReferencePath, ToolRecord, and related types.
 
-use omni_scanner::{ReferencePath, SkillIndexEntry, SnifferRule};
+use omni_scanner::{ReferencePath, SkillIndexEntry, SnifferRule, ToolRecord};
 
 /// Test SnifferRule creation and field access.
 #[test]
@@ -90,3 +90,31 @@ fn test_skill_index_entry_defaults() {
     assert_eq!(entry.authors, vec!["omni-dev-fusion".to_string()]);
     assert!(entry.sniffing_rules.is_empty());
 }
+
+/// Test ToolRecord validation reports empty description and broken schema.
+#[test]
+fn test_tool_record_validate_reports_issues() {
+    let mut record = ToolRecord::new(
+        "git.commit".to_string(),
+        String::new(),
+        "git".to_string(),
+        "assets/skills/git/scripts/commit.py".to_string(),
+        "commit".to_string(),
+    );
+    record.input_schema = "{\"type\": \"object\"".to_string();
+    record.keywords = vec!["commit".to_string()];
+
+    let issues = record.validate();
+    assert_eq!(issues.len(), 2, "unexpected issues: {issues:?}");
+    assert!(issues[0].contains("description is empty"));
+    assert!(issues[1].contains("input_schema is not valid JSON"));
+
+    record.description = "Commit staged changes".to_string();
+    record.input_schema = "[]".to_string();
+    let issues = record.validate();
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].contains("input_schema is not a JSON object"));
+
+    record.input_schema = r#"{"type": "object", "properties": {}}"#.to_string();
+    assert!(record.validate().is_empty());
+}
@@ -3,8 +3,9 @@
 //! Tests SKILL.md parsing and SkillScanner functionality.
 
 use omni_scanner::{
-    CanonicalSkillPayload, SkillMetadata, SkillScanner, SnifferRule, ToolRecord,
-    extract_frontmatter,
+    CanonicalSkillPayload, FrontmatterError, SkillMetadata, SkillScanner, SnifferRule,
+    StructureCategory, TemplateRecord, ToolRecord, diff_canonical, extract_frontmatter,
+    extract_frontmatter_validated,
 };
 use std::fs;
 use tempfile::TempDir;
@@ -241,6 +242,194 @@ routing_keywords: ["commit", "branch"]
     assert!(metadatas.iter().any(|m| m.skill_name == "git"));
 }
 
+/// Test scan_all_with_structure categorizes the skill file tree.
+#[test]
+fn test_scan_all_with_structure_categorizes_items() {
+    let temp_dir = TempDir::new().unwrap();
+    let skills_dir = temp_dir.path().join("skills");
+    let skill_path = skills_dir.join("git");
+    for dir in [
+        "scripts",
+        "references",
+        "templates",
+        "tests",
+        "data",
+        "__pycache__",
+    ] {
+        fs::create_dir_all(skill_path.join(dir)).unwrap();
+    }
+    fs::write(
+        skill_path.join("SKILL.md"),
+        "---\nname: git\ndescription: Use when running git.\n---\n# Git\n",
+    )
+    .unwrap();
+    fs::write(skill_path.join("scripts/commit.py"), "").unwrap();
+    fs::write(skill_path.join("references/workflow.md"), "").unwrap();
+    fs::write(skill_path.join("templates/message.j2"), "").unwrap();
+    fs::write(skill_path.join("tests/test_commit.py"), "").unwrap();
+    fs::write(skill_path.join("data/prefixes.json"), "").unwrap();
+    fs::write(skill_path.join("README.txt"), "").unwrap();
+    fs::write(skill_path.join("__pycache__/commit.pyc"), "").unwrap();
+
+    let scanner = SkillScanner::new();
+    let metadatas = scanner.scan_all_with_structure(&skills_dir).unwrap();
+    assert_eq!(metadatas.len(), 1);
+    let structure = metadatas[0]
+        .structure
+        .as_ref()
+        .expect("structure should be populated");
+
+    let required: Vec<&str> = structure.required.iter().map(|i| i.path.as_str()).collect();
+    assert_eq!(required, ["SKILL.md"]);
+    assert_eq!(
+        structure.required[0].category,
+        Some(StructureCategory::Metadata)
+    );
+
+    let default: Vec<(&str, &str, Option<StructureCategory>)> = structure
+        .default
+        .iter()
+        .map(|i| (i.path.as_str(), i.item_type.as_str(), i.category))
+        .collect();
+    assert_eq!(
+        default,
+        [
+            ("references/", "dir", Some(StructureCategory::References)),
+            (
+                "references/workflow.md",
+                "file",
+                Some(StructureCategory::References)
+            ),
+            ("scripts/", "dir", Some(StructureCategory::Scripts)),
+            (
+                "scripts/commit.py",
+                "file",
+                Some(StructureCategory::Scripts)
+            ),
+            ("templates/", "dir", Some(StructureCategory::Templates)),
+            (
+                "templates/message.j2",
+                "file",
+                Some(StructureCategory::Templates)
+            ),
+        ]
+    );
+
+    let optional: Vec<(&str, Option<StructureCategory>)> = structure
+        .optional
+        .iter()
+        .map(|i| (i.path.as_str(), i.category))
+        .collect();
+    assert_eq!(
+        optional,
+        [
+            ("README.txt", Some(StructureCategory::Other)),
+            ("data/", Some(StructureCategory::Data)),
+            ("data/prefixes.json", Some(StructureCategory::Data)),
+            ("tests/", Some(StructureCategory::Tests)),
+            ("tests/test_commit.py", Some(StructureCategory::Tests)),
+        ]
+    );
+
+    let plain = scanner.scan_all(&skills_dir, None).unwrap();
+    assert!(plain[0].structure.is_none());
+}
+
+/// Test resolve_references flags present and missing reference files.
+#[test]
+fn test_resolve_references_reports_existence() {
+    let temp_dir = TempDir::new().unwrap();
+    let skill_path = temp_dir.path().join("writer");
+    fs::create_dir_all(skill_path.join("references")).unwrap();
+    fs::write(
+        skill_path.join("SKILL.md"),
+        r#"---
+name: writer
+description: Use when writing docs.
+metadata:
+  version: "1.0.0"
+  require_refs:
+    - "references/style.md"
+    - "references/missing.md"
+---
+# Writer
+"#,
+    )
+    .unwrap();
+    fs::write(skill_path.join("references/style.md"), "# Style").unwrap();
+
+    let scanner = SkillScanner::new();
+    let metadata = scanner.scan_skill(&skill_path, None).unwrap().unwrap();
+    let resolved = SkillScanner::resolve_references(&skill_path, &metadata);
+
+    let flags: Vec<(&str, bool)> = resolved
+        .iter()
+        .map(|(reference, exists)| (reference.as_str(), *exists))
+        .collect();
+    assert_eq!(
+        flags,
+        [
+            ("references/style.md", true),
+            ("references/missing.md", false)
+        ]
+    );
+}
+
+/// Test scanning fills template, asset, data and test records.
+#[test]
+fn test_scan_skill_populates_directory_records() {
+    let temp_dir = TempDir::new().unwrap();
+    let skill_path = temp_dir.path().join("writer");
+    for dir in ["templates", "assets", "data", "tests"] {
+        fs::create_dir_all(skill_path.join(dir)).unwrap();
+    }
+    fs::write(
+        skill_path.join("SKILL.md"),
+        "---\nname: writer\ndescription: Use when writing.\n---\n# Writer\n",
+    )
+    .unwrap();
+    fs::write(skill_path.join("templates/outline.j2"), "{{ title }}").unwrap();
+    fs::write(skill_path.join("assets/logo.svg"), "<svg/>").unwrap();
+    fs::write(skill_path.join("data/tones.json"), "{\"tone\": 1}").unwrap();
+    fs::write(
+        skill_path.join("tests/test_outline.py"),
+        "def test_x(): pass\n",
+    )
+    .unwrap();
+
+    let scanner = SkillScanner::new();
+    let metadata = scanner.scan_skill(&skill_path, None).unwrap().unwrap();
+
+    assert_eq!(metadata.templates.len(), 1);
+    assert_eq!(metadata.templates[0].template_name, "outline");
+    assert_eq!(metadata.templates[0].file_size, 11);
+    assert_eq!(metadata.templates[0].file_path, "templates/outline.j2");
+
+    assert_eq!(metadata.assets.len(), 1);
+    assert_eq!(metadata.assets[0].asset_name, "logo");
+    assert_eq!(metadata.assets[0].file_size, 6);
+
+    assert_eq!(metadata.data.len(), 1);
+    assert_eq!(metadata.data[0].data_name, "tones");
+    assert_eq!(metadata.data[0].format, "json");
+    assert_eq!(metadata.data[0].file_size, 11);
+
+    assert_eq!(metadata.tests.len(), 1);
+    assert_eq!(metadata.tests[0].test_name, "test_outline");
+    assert_eq!(metadata.tests[0].file_size, 19);
+    assert_eq!(metadata.tests[0].file_path, "tests/test_outline.py");
+
+    for record_skill in [
+        &metadata.templates[0].skill_name,
+        &metadata.assets[0].skill_name,
+        &metadata.data[0].skill_name,
+        &metadata.tests[0].skill_name,
+    ] {
+        assert_eq!(record_skill, "writer");
+    }
+    assert!(metadata.tests[0].file_hash.is_empty());
+}
+
 /// Test extract frontmatter helper.
 #[test]
 fn test_extract_frontmatter() {
@@ -311,6 +500,11 @@ fn test_build_index_entry_deduplicates_tools() {
         require_refs: vec![],
         repository: "".to_string(),
         permissions: vec![],
+        templates: vec![],
+        assets: vec![],
+        data: vec![],
+        tests: vec![],
+        structure: None,
     };
 
     // Create tools with duplicate names (simulates docstring example matching)
@@ -380,6 +574,11 @@ fn test_build_index_entry_preserves_order() {
         require_refs: vec![],
         repository: "".to_string(),
         permissions: vec![],
+        templates: vec![],
+        assets: vec![],
+        data: vec![],
+        tests: vec![],
+        structure: None,
     };
 
     // Create tools where tool_b appears before tool_a
@@ -482,6 +681,11 @@ metadata:
         require_refs: vec![],
         repository: String::new(),
         permissions: vec![],
+        templates: vec![],
+        assets: vec![],
+        data: vec![],
+        tests: vec![],
+        structure: None,
     };
 
     let tools = vec![ToolRecord {
@@ -522,3 +726,153 @@ metadata:
     assert_eq!(payload.references.len(), 1);
     assert!(payload.references.contains_key("run_research_graph"));
 }
+
+/// Test that diff_canonical reports no ops for identical payloads and one delete for a removed tool.
+#[test]
+fn test_diff_canonical_detects_removed_tool() {
+    let temp_dir = TempDir::new().unwrap();
+    let skill_path = temp_dir.path().join("git");
+    fs::create_dir_all(&skill_path).unwrap();
+
+    let metadata = SkillMetadata {
+        skill_name: "git".to_string(),
+        version: "1.0".to_string(),
+        description: "Git skill".to_string(),
+        ..SkillMetadata::default()
+    };
+    let tools: Vec<ToolRecord> = ["status", "commit"]
+        .iter()
+        .map(|name| {
+            let mut tool = ToolRecord::new(
+                format!("git.{name}"),
+                format!("Run git {name}"),
+                "git".to_string(),
+                "git/scripts/commands.py".to_string(),
+                (*name).to_string(),
+            );
+            tool.file_hash = "hash".to_string();
+            tool
+        })
+        .collect();
+
+    let scanner = SkillScanner::new();
+    let old = scanner.build_canonical_payload(metadata.clone(), &tools, &skill_path);
+
+    let same = diff_canonical(&old, &old);
+    assert!(same.added.is_empty());
+    assert!(same.updated.is_empty());
+    assert!(same.deleted.is_empty());
+    assert!(same.metadata_changes.is_empty());
+    assert_eq!(same.unchanged_count, 2);
+
+    let mut changed_metadata = metadata;
+    changed_metadata.version = "1.1".to_string();
+    let new = scanner.build_canonical_payload(changed_metadata, &tools[..1], &skill_path);
+
+    let report = diff_canonical(&old, &new);
+    assert!(report.added.is_empty());
+    assert!(report.updated.is_empty());
+    assert_eq!(report.deleted, vec!["git.commit".to_string()]);
+    assert_eq!(report.unchanged_count, 1);
+    assert_eq!(report.metadata_changes, vec!["version".to_string()]);
+
+    let mut with_template = old.clone();
+    with_template.metadata.templates.push(TemplateRecord::new(
+        "outline".to_string(),
+        String::new(),
+        "git".to_string(),
+        "templates/outline.j2".to_string(),
+        Vec::new(),
+    ));
+    let report = diff_canonical(&old, &with_template);
+    assert_eq!(report.metadata_changes, vec!["templates".to_string()]);
+}
+
+/// Test that validated frontmatter extraction reports a SKILL.md missing `name`.
+#[test]
+fn test_extract_frontmatter_validated_missing_name() {
+    let content = r#"---
+description: "Git operations"
+metadata:
+  version: "1.0"
+---
+# Git
+"#;
+
+    let err = extract_frontmatter_validated(content, SkillScanner::REQUIRED_FRONTMATTER_FIELDS)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        FrontmatterError::MissingFields(vec!["name".to_string()])
+    );
+    assert_eq!(err.to_string(), "frontmatter missing required fields: name");
+
+    let complete = "---\nname: git\ndescription: Git operations\n---\n";
+    assert!(
+        extract_frontmatter_validated(complete, SkillScanner::REQUIRED_FRONTMATTER_FIELDS).is_ok()
+    );
+    assert_eq!(
+        extract_frontmatter_validated("# no frontmatter", &["name"]),
+        Err(FrontmatterError::Missing)
+    );
+
+    // The scanner still loads the skill, keeping the lenient behaviour.
+    let temp_dir = TempDir::new().unwrap();
+    let skill_path = temp_dir.path().join("git");
+    let metadata = SkillScanner::new()
+        .parse_skill_md(content, &skill_path)
+        .unwrap();
+    assert_eq!(metadata.skill_name, "git");
+    assert_eq!(metadata.version, "1.0");
+}
+
+/// Test that a skill outside the supported schema version range is reported.
+#[test]
+fn test_scan_skill_unsupported_schema_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let skill_path = temp_dir.path().join("future_skill");
+    fs::create_dir_all(&skill_path).unwrap();
+    fs::write(
+        skill_path.join("SKILL.md"),
+        r#"---
+name: future_skill
+description: Use when testing schema versions.
+metadata:
+  version: "3.1.0"
+---
+# Future Skill
+"#,
+    )
+    .unwrap();
+
+    let lenient = SkillScanner::new()
+        .with_min_schema_version(1)
+        .with_max_schema_version(2);
+    let metadata = lenient.scan_skill(&skill_path, None).unwrap().unwrap();
+    assert_eq!(metadata.schema_version(), Some(3));
+    let warning = lenient.schema_version_warning(&metadata).unwrap();
+    assert!(warning.contains("future_skill"), "{warning}");
+    assert!(warning.contains("above maximum supported 2"), "{warning}");
+
+    let older = SkillScanner::new().with_min_schema_version(4);
+    assert!(
+        older
+            .schema_version_warning(&metadata)
+            .unwrap()
+            .contains("below minimum supported 4")
+    );
+    assert!(
+        SkillScanner::new()
+            .schema_version_warning(&metadata)
+            .is_none()
+    );
+
+    let strict = lenient.with_strict_schema_check(true);
+    let err = strict.scan_skill(&skill_path, None).unwrap_err();
+    assert!(err.to_string().contains("schema version 3"), "{err}");
+    assert!(strict.scan_all(temp_dir.path(), None).unwrap().is_empty());
+
+    let unversioned = SkillMetadata::with_name("legacy");
+    assert_eq!(unversioned.schema_version(), None);
+    assert!(strict.schema_version_warning(&unversioned).is_none());
+}
@@ -1606,3 +1606,46 @@ def process_data(input: str) -> str:
         serde_json::from_str(&tool.input_schema).expect("Should be valid JSON");
     assert_eq!(schema["type"], "object");
 }
+
+/// Routing keywords are expanded with configured synonyms on each tool record.
+#[test]
+fn test_scan_scripts_expands_keyword_synonyms() {
+    let temp_dir = TempDir::new().unwrap();
+    let scripts_dir = temp_dir.path().join("git/scripts");
+    fs::create_dir_all(&scripts_dir).unwrap();
+    fs::write(
+        scripts_dir.join("commit.py"),
+        r#"
+from agent.skills.decorators import skill_command
+
+@skill_command(name="commit")
+def commit(message: str) -> str:
+    '''Create a commit.'''
+    return message
+"#,
+    )
+    .unwrap();
+
+    let synonyms = std::collections::HashMap::from([
+        (
+            "Commit".to_string(),
+            vec!["checkin".to_string(), "git".to_string()],
+        ),
+        ("unused".to_string(), vec!["never".to_string()]),
+    ]);
+    let scanner = ToolsScanner::new().with_keyword_synonyms(synonyms);
+    let tools = scanner
+        .scan_scripts(
+            &scripts_dir,
+            "git",
+            &["commit".to_string(), "vcs".to_string()],
+            &[],
+        )
+        .unwrap();
+
+    assert_eq!(tools.len(), 1);
+    let keywords = &tools[0].keywords;
+    assert!(keywords.contains(&"checkin".to_string()), "{keywords:?}");
+    assert!(!keywords.contains(&"never".to_string()));
+    assert_eq!(keywords.iter().filter(|k| *k == "git").count(), 1);
+}
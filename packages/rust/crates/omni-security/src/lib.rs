@@ -19,8 +19,10 @@ mod sandbox;
 
 pub use sandbox::{SandboxConfig, SandboxError, SandboxMode, SandboxResult, SandboxRunner};
 
-use regex::RegexSet;
+use omni_ast::{Lang, comment_ranges};
+use regex::{Regex, RegexSet};
 use serde::Serialize;
+use std::ops::Range;
 use std::sync::LazyLock;
 
 /// Security violation detected during scan
@@ -30,21 +32,41 @@ pub struct SecurityViolation {
     pub rule_id: String,
     /// Human-readable description of the violation
     pub description: String,
-    /// Redacted snippet showing context
+    /// Snippet of the surrounding context with every secret in it replaced by `[REDACTED]`
     pub snippet: String,
 }
 
-static SECRET_PATTERNS: LazyLock<RegexSet> = LazyLock::new(|| {
-    match RegexSet::new([
-        r"AKIA[0-9A-Z]{16}",                    // AWS Access Key ID
-        r"(?i)sk_(test|live)_[0-9a-zA-Z]{24}",  // Stripe Secret Key (test or live)
-        r"xox[baprs]-([0-9a-zA-Z\-]{10,48})",   // Slack Token (allows hyphens in token)
-        r"-----BEGIN [A-Z ]+ PRIVATE KEY-----", // PEM Private Key
-        r#"(?i)(api_key|access_token|secret)\s*[:=]\s*["'][A-Za-z0-9_=-]{16,}["']"#, // Generic API Key
-    ]) {
+/// Secret patterns, indexed in step with [`PATTERN_NAMES`].
+const SECRET_PATTERN_SOURCES: &[&str] = &[
+    r"AKIA[0-9A-Z]{16}",                    // AWS Access Key ID
+    r"(?i)sk_(test|live)_[0-9a-zA-Z]{24}",  // Stripe Secret Key (test or live)
+    r"xox[baprs]-([0-9a-zA-Z\-]{10,48})",   // Slack Token (allows hyphens in token)
+    r"-----BEGIN [A-Z ]+ PRIVATE KEY-----", // PEM Private Key
+    r#"(?i)(api_key|access_token|secret)\s*[:=]\s*["'][A-Za-z0-9_=-]{16,}["']"#, // Generic API Key
+];
+
+/// Bytes of surrounding context kept on each side of a redacted secret.
+const SNIPPET_CONTEXT: usize = 20;
+
+/// Index of the PEM rule in [`SECRET_PATTERN_SOURCES`]; it matches only the
+/// key header, so the key body follows the match.
+const PEM_PRIVATE_KEY_RULE: usize = 3;
+
+static SECRET_PATTERNS: LazyLock<RegexSet> =
+    LazyLock::new(|| match RegexSet::new(SECRET_PATTERN_SOURCES) {
         Ok(set) => set,
         Err(err) => panic!("invalid regex pattern in security scanner: {err}"),
-    }
+    });
+
+/// Individual regexes for locating a match once the set reports a hit.
+static SECRET_REGEXES: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    SECRET_PATTERN_SOURCES
+        .iter()
+        .map(|pattern| match Regex::new(pattern) {
+            Ok(re) => re,
+            Err(err) => panic!("invalid regex pattern in security scanner: {err}"),
+        })
+        .collect()
 });
 
 static PATTERN_NAMES: &[&str] = &[
@@ -69,53 +91,183 @@ impl SecretScanner {
         // O(n) matching - single pass through content
         let matches = SECRET_PATTERNS.matches(content);
 
-        if let Some(idx) = matches.iter().next() {
-            let description = PATTERN_NAMES
-                .get(idx)
-                .copied()
-                .unwrap_or("Unknown Secret")
-                .to_string();
-
-            return Some(SecurityViolation {
-                rule_id: format!("SEC-{:03}", idx + 1),
-                description,
-                snippet: "[REDACTED]".to_string(),
-            });
-        }
-        None
+        matches
+            .iter()
+            .next()
+            .map(|idx| Self::violation(content, idx))
     }
 
     /// Scan and return all violations (non-fail-fast)
     pub fn scan_all(content: &str) -> Vec<SecurityViolation> {
-        let matches = SECRET_PATTERNS.matches(content);
-        let mut violations = Vec::new();
-
-        for idx in &matches {
-            let description = PATTERN_NAMES
-                .get(idx)
-                .copied()
-                .unwrap_or("Unknown Secret")
-                .to_string();
-
-            violations.push(SecurityViolation {
-                rule_id: format!("SEC-{:03}", idx + 1),
-                description,
-                snippet: "[REDACTED]".to_string(),
-            });
-        }
-        violations
+        SECRET_PATTERNS
+            .matches(content)
+            .iter()
+            .map(|idx| Self::violation(content, idx))
+            .collect()
     }
 
     /// Check if content contains any secrets (boolean check only)
     pub fn contains_secrets(content: &str) -> bool {
         SECRET_PATTERNS.is_match(content)
     }
+
+    /// Scan source code, ignoring matches that lie entirely inside comments or
+    /// docstrings (Fail-fast on first remaining match)
+    ///
+    /// Comment ranges come from the `language` grammar via omni-ast. If the
+    /// source cannot be parsed for that language, everything is scanned as in
+    /// [`Self::scan`].
+    pub fn scan_source(content: &str, language: Lang) -> Option<SecurityViolation> {
+        let matches = SECRET_PATTERNS.matches(content);
+        if !matches.matched_any() {
+            return None;
+        }
+        let Ok(comments) = comment_ranges(content, language) else {
+            return Self::scan(content);
+        };
+        let in_comment = |range: &Range<usize>| {
+            comments
+                .iter()
+                .any(|c| c.start <= range.start && range.end <= c.end)
+        };
+
+        matches.iter().find_map(|idx| {
+            SECRET_REGEXES
+                .get(idx)?
+                .find_iter(content)
+                .map(|m| m.range())
+                .find(|range| !in_comment(range))
+                .map(|range| Self::violation_at(content, idx, Some(range)))
+        })
+    }
+
+    /// Build the violation for pattern `idx`, with a redacted snippet around its first match.
+    fn violation(content: &str, idx: usize) -> SecurityViolation {
+        let range = SECRET_REGEXES
+            .get(idx)
+            .and_then(|re| re.find(content))
+            .map(|m| m.range());
+        Self::violation_at(content, idx, range)
+    }
+
+    /// Build the violation for pattern `idx`, with a redacted snippet around `range`.
+    fn violation_at(content: &str, idx: usize, range: Option<Range<usize>>) -> SecurityViolation {
+        let description = PATTERN_NAMES
+            .get(idx)
+            .copied()
+            .unwrap_or("Unknown Secret")
+            .to_string();
+        let snippet = range.map_or_else(
+            || "[REDACTED]".to_string(),
+            |range| redacted_snippet(content, idx, range),
+        );
+
+        SecurityViolation {
+            rule_id: format!("SEC-{:03}", idx + 1),
+            description,
+            snippet,
+        }
+    }
+}
+
+/// Extract `context` bytes around `byte_range` of `text`, with the range itself
+/// replaced by `[REDACTED]`.
+///
+/// All offsets are widened to the nearest char boundaries (the secret outward, the
+/// context outward too), so slicing never splits a multibyte character. Ranges past
+/// the end of `text` are clamped.
+#[must_use]
+pub fn safe_snippet(text: &str, byte_range: Range<usize>, context: usize) -> String {
+    let len = text.len();
+    let start = floor_char_boundary(text, byte_range.start.min(len));
+    let end = ceil_char_boundary(text, byte_range.end.clamp(start, len));
+    let before = floor_char_boundary(text, start.saturating_sub(context));
+    let after = ceil_char_boundary(text, end.saturating_add(context).min(len));
+    format!("{}[REDACTED]{}", &text[before..start], &text[end..after])
+}
+
+/// Snippet around `range`, a match of pattern `idx`, with every secret in view redacted.
+///
+/// No context is kept after a PEM header, and a PEM header elsewhere in the window
+/// is redacted through the end of the window, so key bodies never leak.
+fn redacted_snippet(content: &str, idx: usize, range: Range<usize>) -> String {
+    let len = content.len();
+    let start = floor_char_boundary(content, range.start.min(len));
+    let end = ceil_char_boundary(content, range.end.clamp(start, len));
+    let after_context = if idx == PEM_PRIVATE_KEY_RULE {
+        0
+    } else {
+        SNIPPET_CONTEXT
+    };
+    let before = floor_char_boundary(content, start.saturating_sub(SNIPPET_CONTEXT));
+    let after = ceil_char_boundary(content, end.saturating_add(after_context).min(len));
+
+    let mut secrets: Vec<Range<usize>> = SECRET_REGEXES
+        .iter()
+        .enumerate()
+        .flat_map(|(rule, re)| {
+            re.find_iter(content).map(move |m| {
+                if rule == PEM_PRIVATE_KEY_RULE {
+                    m.start()..len
+                } else {
+                    m.range()
+                }
+            })
+        })
+        .filter(|secret| secret.start < after && secret.end > before)
+        .map(|secret| {
+            floor_char_boundary(content, secret.start.max(before))
+                ..ceil_char_boundary(content, secret.end.min(after))
+        })
+        .chain(std::iter::once(start..end))
+        .collect();
+    secrets.sort_by_key(|secret| secret.start);
+
+    let mut snippet = String::new();
+    let mut cursor = before;
+    let mut secrets = secrets.into_iter().peekable();
+    while let Some(secret) = secrets.next() {
+        let mut secret_end = secret.end;
+        while let Some(next) = secrets.next_if(|next| next.start <= secret_end) {
+            secret_end = secret_end.max(next.end);
+        }
+        snippet.push_str(&content[cursor..secret.start.max(cursor)]);
+        snippet.push_str("[REDACTED]");
+        cursor = cursor.max(secret_end);
+    }
+    snippet.push_str(&content[cursor..after]);
+    snippet
+}
+
+/// Largest char boundary of `s` at or below `index` (`index <= s.len()`).
+fn floor_char_boundary(s: &str, mut index: usize) -> usize {
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Smallest char boundary of `s` at or above `index` (`index <= s.len()`).
+fn ceil_char_boundary(s: &str, mut index: usize) -> usize {
+    while !s.is_char_boundary(index) {
+        index += 1;
+    }
+    index
 }
 
 // =============================================================================
 // Permission Gatekeeper - Access Control for Skills
 // =============================================================================
 
+/// Outcome of [`PermissionGatekeeper::explain`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PermissionDecision {
+    /// Whether the tool call is allowed
+    pub allowed: bool,
+    /// Permission pattern that granted access, if any
+    pub matched_pattern: Option<String>,
+}
+
 /// `PermissionGatekeeper` - Zero Trust Access Control
 ///
 /// Validates skill tool calls against declared permissions.
@@ -135,12 +287,22 @@ impl PermissionGatekeeper {
     /// Returns `true` when at least one permission pattern matches the tool.
     #[must_use]
     pub fn check(tool_name: &str, permissions: &[String]) -> bool {
-        for pattern in permissions {
-            if Self::matches_pattern(tool_name, pattern) {
-                return true;
-            }
+        Self::explain(tool_name, permissions).allowed
+    }
+
+    /// Like [`Self::check`], but also reports which permission pattern granted access.
+    ///
+    /// The first matching pattern wins; `matched_pattern` is `None` when denied.
+    #[must_use]
+    pub fn explain(tool_name: &str, permissions: &[String]) -> PermissionDecision {
+        let matched_pattern = permissions
+            .iter()
+            .find(|pattern| Self::matches_pattern(tool_name, pattern))
+            .cloned();
+        PermissionDecision {
+            allowed: matched_pattern.is_some(),
+            matched_pattern,
         }
-        false
     }
 
     fn matches_pattern(tool: &str, pattern: &str) -> bool {
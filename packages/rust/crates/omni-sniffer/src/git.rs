@@ -2,7 +2,10 @@
 //!
 //! Uses libgit2 for fast git operations.
 
-use git2::{Repository, Status, StatusOptions};
+use git2::{
+    DiffOptions, Patch, Repository, Status, StatusOptions, SubmoduleIgnore, SubmoduleStatus,
+};
+use serde::Serialize;
 
 use crate::error::SnifferError;
 
@@ -59,3 +62,140 @@ pub fn scan_git_status(
 
     Ok((branch, modified, staged, dirty_files))
 }
+
+/// Count submodules with uncommitted or unrecorded changes using libgit2.
+///
+/// A submodule counts as modified when its checked-out commit differs from the
+/// one recorded by the superproject, or its working tree or index is dirty
+/// (including untracked files).
+///
+/// # Errors
+///
+/// Returns an error if repository cannot be opened or submodules cannot be listed.
+pub fn scan_submodule_status(repo_path: &std::path::Path) -> Result<usize, SnifferError> {
+    let repo =
+        Repository::open(repo_path).map_err(|_| SnifferError::RepoOpen(repo_path.to_path_buf()))?;
+
+    let dirty = SubmoduleStatus::INDEX_MODIFIED
+        | SubmoduleStatus::WD_MODIFIED
+        | SubmoduleStatus::WD_INDEX_MODIFIED
+        | SubmoduleStatus::WD_WD_MODIFIED
+        | SubmoduleStatus::WD_UNTRACKED;
+
+    let submodules = repo
+        .submodules()
+        .map_err(|e| SnifferError::StatusScan(e.to_string()))?;
+
+    let mut modified = 0;
+    for submodule in &submodules {
+        let Some(name) = submodule.name() else {
+            continue;
+        };
+        let status = repo
+            .submodule_status(name, SubmoduleIgnore::None)
+            .map_err(|e| SnifferError::StatusScan(e.to_string()))?;
+        if status.intersects(dirty) {
+            modified += 1;
+        }
+    }
+
+    Ok(modified)
+}
+
+/// Unified-diff hunks for one changed file, from [`scan_dirty_hunks`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileHunks {
+    /// Path relative to the repository root.
+    pub path: String,
+    /// Hunks in unified-diff form, each starting with its `@@` header.
+    pub hunks: Vec<String>,
+    /// Whether lines were dropped to stay within the per-file line limit.
+    pub truncated: bool,
+}
+
+/// Extract unified-diff hunks for uncommitted changes (staged, unstaged and
+/// untracked) relative to `HEAD`.
+///
+/// At most `max_files` files are returned, each with at most
+/// `max_lines_per_file` diff lines (hunk headers excluded). Binary files and
+/// files without textual hunks are skipped.
+///
+/// # Errors
+///
+/// Returns an error if repository cannot be opened or the diff cannot be computed.
+pub fn scan_dirty_hunks(
+    repo_path: &std::path::Path,
+    max_files: usize,
+    max_lines_per_file: usize,
+) -> Result<Vec<FileHunks>, SnifferError> {
+    let repo =
+        Repository::open(repo_path).map_err(|_| SnifferError::RepoOpen(repo_path.to_path_buf()))?;
+    let scan_err = |e: git2::Error| SnifferError::StatusScan(e.to_string());
+
+    // Unborn HEAD (no commits yet): diff against the empty tree.
+    let head_tree = repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+
+    let mut opts = DiffOptions::new();
+    opts.include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .show_untracked_content(true);
+    let diff = repo
+        .diff_tree_to_workdir_with_index(head_tree.as_ref(), Some(&mut opts))
+        .map_err(scan_err)?;
+
+    let mut files = Vec::new();
+    for idx in 0..diff.deltas().len() {
+        if files.len() >= max_files {
+            break;
+        }
+        let Some(patch) = Patch::from_diff(&diff, idx).map_err(scan_err)? else {
+            continue;
+        };
+        let delta = patch.delta();
+        if delta.flags().is_binary() || patch.num_hunks() == 0 {
+            continue;
+        }
+        let file_path = delta
+            .new_file()
+            .path()
+            .or_else(|| delta.old_file().path())
+            .map_or_else(|| "unknown".to_string(), |p| p.display().to_string());
+
+        let mut hunks = Vec::new();
+        let mut lines_used = 0;
+        let mut truncated = false;
+        'hunks: for hunk_idx in 0..patch.num_hunks() {
+            let (hunk, line_count) = patch.hunk(hunk_idx).map_err(scan_err)?;
+            let mut text = String::from_utf8_lossy(hunk.header()).into_owned();
+            for line_idx in 0..line_count {
+                let line = patch.line_in_hunk(hunk_idx, line_idx).map_err(scan_err)?;
+                let origin = line.origin();
+                if !matches!(origin, '+' | '-' | ' ') {
+                    continue;
+                }
+                if lines_used >= max_lines_per_file {
+                    truncated = true;
+                    if text.lines().count() > 1 {
+                        hunks.push(text);
+                    }
+                    break 'hunks;
+                }
+                lines_used += 1;
+                text.push(origin);
+                text.push_str(&String::from_utf8_lossy(line.content()));
+                if !text.ends_with('\n') {
+                    text.push('\n');
+                }
+            }
+            hunks.push(text);
+        }
+
+        files.push(FileHunks {
+            path: file_path,
+            hunks,
+            truncated,
+        });
+    }
+
+    Ok(files)
+}
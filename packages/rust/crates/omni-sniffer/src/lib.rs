@@ -46,6 +46,6 @@ mod sniffer;
 
 pub use context::scan_scratchpad_context;
 pub use error::SnifferError;
-pub use git::scan_git_status;
+pub use git::{FileHunks, scan_dirty_hunks, scan_git_status, scan_submodule_status};
 pub use glob_engine::{SnifferEngine, SnifferRule};
-pub use sniffer::OmniSniffer;
+pub use sniffer::{DEFAULT_SNAPSHOT_TTL, OmniSniffer};
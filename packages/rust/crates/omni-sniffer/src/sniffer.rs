@@ -3,17 +3,28 @@
 //! The sensory system of the Omni `DevEnv`.
 
 use std::path::Path;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use crate::context::scan_scratchpad_context;
 use crate::error::SnifferError;
-use crate::git::scan_git_status;
+use crate::git::{FileHunks, scan_dirty_hunks, scan_git_status, scan_submodule_status};
 use omni_types::EnvironmentSnapshot;
 
+/// How long [`OmniSniffer::get_snapshot`] reuses a snapshot by default (zero: no caching).
+pub const DEFAULT_SNAPSHOT_TTL: Duration = Duration::ZERO;
+
 /// High-performance environment sniffer using libgit2.
 /// The sensory system of the Omni `DevEnv`.
+///
+/// Snapshots can be cached for a configurable TTL (see [`Self::with_snapshot_ttl`])
+/// so frequent polling does not rescan the repository on every call.
 pub struct OmniSniffer {
     repo_path: std::path::PathBuf,
+    snapshot_ttl: Duration,
+    cached: Mutex<Option<(Instant, EnvironmentSnapshot)>>,
+    scans: AtomicUsize,
 }
 
 impl OmniSniffer {
@@ -21,9 +32,28 @@ impl OmniSniffer {
     pub fn new<P: AsRef<Path>>(root: P) -> Self {
         Self {
             repo_path: root.as_ref().to_path_buf(),
+            snapshot_ttl: DEFAULT_SNAPSHOT_TTL,
+            cached: Mutex::new(None),
+            scans: AtomicUsize::new(0),
         }
     }
 
+    /// Set how long [`Self::get_snapshot`] reuses the last snapshot.
+    ///
+    /// A zero TTL, the default, disables caching.
+    #[must_use]
+    pub fn with_snapshot_ttl(mut self, ttl: Duration) -> Self {
+        self.snapshot_ttl = ttl;
+        self
+    }
+
+    /// Number of full snapshot scans performed so far (each opens the repository
+    /// more than once).
+    #[must_use]
+    pub fn scan_count(&self) -> usize {
+        self.scans.load(Ordering::Relaxed)
+    }
+
     /// Get Git status using libgit2 (10-50x faster than subprocess).
     ///
     /// # Errors
@@ -33,6 +63,24 @@ impl OmniSniffer {
         scan_git_status(&self.repo_path)
     }
 
+    /// Count submodules with local modifications.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnifferError`] if repository cannot be opened or scanned.
+    pub fn scan_submodules(&self) -> Result<usize, SnifferError> {
+        scan_submodule_status(&self.repo_path)
+    }
+
+    /// Get unified-diff hunks for uncommitted changes, bounded to `max_files`
+    /// files and `max_lines_per_file` diff lines per file.
+    ///
+    /// Returns an empty list if the repository cannot be opened or diffed.
+    #[must_use]
+    pub fn get_dirty_hunks(&self, max_files: usize, max_lines_per_file: usize) -> Vec<FileHunks> {
+        scan_dirty_hunks(&self.repo_path, max_files, max_lines_per_file).unwrap_or_default()
+    }
+
     /// Scan Scratchpad for active context (IO-intensive).
     #[must_use]
     pub fn scan_context(&self) -> usize {
@@ -41,13 +89,45 @@ impl OmniSniffer {
 
     /// Get a complete environment snapshot with timestamp.
     ///
-    /// Returns [`EnvironmentSnapshot`] containing all sensory data.
+    /// Returns the cached snapshot if it is younger than the snapshot TTL,
+    /// otherwise rescans. Concurrent callers share a single rescan; with a zero
+    /// TTL the cache is bypassed and callers scan independently.
     #[must_use]
     pub fn get_snapshot(&self) -> EnvironmentSnapshot {
+        if self.snapshot_ttl.is_zero() {
+            return self.scan_snapshot();
+        }
+        let mut cached = self.cached.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some((taken_at, snapshot)) = cached.as_ref()
+            && taken_at.elapsed() < self.snapshot_ttl
+        {
+            return snapshot.clone();
+        }
+        let snapshot = self.scan_snapshot();
+        *cached = Some((Instant::now(), snapshot.clone()));
+        snapshot
+    }
+
+    /// Rescan and return a new snapshot, bypassing (and refreshing) the cache.
+    #[must_use]
+    pub fn get_snapshot_fresh(&self) -> EnvironmentSnapshot {
+        if self.snapshot_ttl.is_zero() {
+            return self.scan_snapshot();
+        }
+        let mut cached = self.cached.lock().unwrap_or_else(PoisonError::into_inner);
+        let snapshot = self.scan_snapshot();
+        *cached = Some((Instant::now(), snapshot.clone()));
+        snapshot
+    }
+
+    fn scan_snapshot(&self) -> EnvironmentSnapshot {
+        self.scans.fetch_add(1, Ordering::Relaxed);
         let (branch, modified, staged, dirty_files) = self
             .scan_git()
             .unwrap_or_else(|_| ("unavailable".to_string(), 0, 0, vec![]));
 
+        let modified_submodules = self.scan_submodules().unwrap_or(0);
+
         let context_lines = self.scan_context();
 
         // Get current timestamp
@@ -59,6 +139,7 @@ impl OmniSniffer {
             git_branch: branch,
             git_modified: modified,
             git_staged: staged,
+            git_modified_submodules: modified_submodules,
             active_context_lines: context_lines,
             dirty_files,
             timestamp,
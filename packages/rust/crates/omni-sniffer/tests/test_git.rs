@@ -1,8 +1,15 @@
 //! Tests for git module - Git repository inspection.
 
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use git2::{Repository, Signature};
 use tempfile::TempDir;
 
-use omni_sniffer::{SnifferError, scan_git_status};
+use omni_sniffer::{
+    OmniSniffer, SnifferError, scan_dirty_hunks, scan_git_status, scan_submodule_status,
+};
 
 #[test]
 fn test_scan_git_status_non_repo() {
@@ -10,3 +17,83 @@ fn test_scan_git_status_non_repo() {
     let result = scan_git_status(dir.path());
     assert!(matches!(result, Err(SnifferError::RepoOpen(_))));
 }
+
+/// Stage everything in `repo` and commit it on HEAD.
+fn commit_all(repo: &Repository, message: &str) -> Result<(), git2::Error> {
+    let mut index = repo.index()?;
+    index.add_all(["*"], git2::IndexAddOption::DEFAULT, None)?;
+    index.write()?;
+    let tree = repo.find_tree(index.write_tree()?)?;
+    let sig = Signature::now("Test", "test@example.com")?;
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_submodule_status_counts_dirty_submodule() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    let upstream_path = dir.path().join("upstream");
+    let upstream = Repository::init(&upstream_path)?;
+    fs::write(upstream_path.join("lib.txt"), "v1\n")?;
+    commit_all(&upstream, "upstream init")?;
+
+    let super_path = dir.path().join("super");
+    let superproject = Repository::init(&super_path)?;
+    fs::write(super_path.join("README.md"), "super\n")?;
+    commit_all(&superproject, "super init")?;
+
+    let upstream_url = upstream_path.to_string_lossy().into_owned();
+    let mut submodule = superproject.submodule(&upstream_url, Path::new("vendor/lib"), true)?;
+    submodule.clone(None)?;
+    submodule.add_finalize()?;
+    commit_all(&superproject, "add submodule")?;
+
+    assert_eq!(scan_submodule_status(&super_path)?, 0);
+
+    fs::write(super_path.join("vendor/lib/lib.txt"), "v2\n")?;
+    assert_eq!(scan_submodule_status(&super_path)?, 1);
+    let snapshot = OmniSniffer::new(&super_path).get_snapshot();
+    assert_eq!(snapshot.git_modified_submodules, 1);
+    Ok(())
+}
+
+#[test]
+fn test_get_dirty_hunks_returns_changed_lines() -> Result<(), Box<dyn Error>> {
+    let dir = TempDir::new()?;
+    let repo = Repository::init(dir.path())?;
+    let original = "line 1\nline 2\nline 3\nline 4\nline 5\nline 6\nline 7\nline 8\nline 9\n";
+    fs::write(dir.path().join("notes.txt"), original)?;
+    fs::write(dir.path().join("other.txt"), "keep\n")?;
+    commit_all(&repo, "init")?;
+
+    fs::write(
+        dir.path().join("notes.txt"),
+        original.replace("line 5\n", "line five\n"),
+    )?;
+    fs::write(dir.path().join("other.txt"), "changed\n")?;
+
+    let files = OmniSniffer::new(dir.path()).get_dirty_hunks(10, 100);
+    assert_eq!(files.len(), 2);
+    let Some(notes) = files.iter().find(|f| f.path == "notes.txt") else {
+        panic!("expected hunks for notes.txt, got {files:?}");
+    };
+    assert!(!notes.truncated);
+    assert_eq!(notes.hunks.len(), 1);
+    assert!(notes.hunks[0].starts_with("@@"));
+    assert!(notes.hunks[0].contains("-line 5\n"));
+    assert!(notes.hunks[0].contains("+line five\n"));
+
+    // Limits bound the number of files and lines per file.
+    let bounded = scan_dirty_hunks(dir.path(), 1, 2)?;
+    assert_eq!(bounded.len(), 1);
+    assert!(bounded[0].truncated);
+    let diff_lines = bounded[0]
+        .hunks
+        .iter()
+        .flat_map(|h| h.lines().skip(1))
+        .count();
+    assert_eq!(diff_lines, 2);
+    Ok(())
+}
@@ -1,6 +1,10 @@
 //! Tests for sniffer module - environment sniffing.
 
+use std::time::Duration;
+
+use omni_sniffer::OmniSniffer;
 use omni_types::EnvironmentSnapshot;
+use tempfile::TempDir;
 
 #[test]
 fn test_snapshot_rendering() {
@@ -8,6 +12,7 @@ fn test_snapshot_rendering() {
         git_branch: "main".to_string(),
         git_modified: 3,
         git_staged: 1,
+        git_modified_submodules: 0,
         active_context_lines: 42,
         dirty_files: vec!["src/main.rs".to_string(), "Cargo.toml".to_string()],
         timestamp: 0.0,
@@ -18,3 +23,32 @@ fn test_snapshot_rendering() {
     assert!(prompt.contains("Branch: main"));
     assert!(prompt.contains("Modified: 3"));
 }
+
+#[test]
+fn test_snapshot_cache_ttl() {
+    let Ok(dir) = TempDir::new() else {
+        panic!("failed to create temp dir");
+    };
+    if let Err(e) = git2::Repository::init(dir.path()) {
+        panic!("failed to init repository: {e}");
+    }
+    let sniffer = OmniSniffer::new(dir.path()).with_snapshot_ttl(Duration::from_millis(200));
+
+    let first = sniffer.get_snapshot();
+    let second = sniffer.get_snapshot();
+    assert_eq!(sniffer.scan_count(), 1);
+    assert!((first.timestamp - second.timestamp).abs() < f64::EPSILON);
+
+    std::thread::sleep(Duration::from_millis(250));
+    let _ = sniffer.get_snapshot();
+    assert_eq!(sniffer.scan_count(), 2);
+
+    let _ = sniffer.get_snapshot_fresh();
+    assert_eq!(sniffer.scan_count(), 3);
+
+    // Caching is opt-in: by default every call rescans.
+    let uncached = OmniSniffer::new(dir.path());
+    let _ = uncached.get_snapshot();
+    let _ = uncached.get_snapshot();
+    assert_eq!(uncached.scan_count(), 2);
+}
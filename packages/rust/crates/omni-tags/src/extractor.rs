@@ -3,6 +3,7 @@
 //! Extracts symbols (functions, classes, etc.) from source code using ast-grep
 //! patterns. Part of The Cartographer.
 
+use std::fmt::Write as _;
 use std::path::Path;
 use std::str::FromStr;
 
@@ -60,18 +61,19 @@ impl TagExtractor {
 
         // Build CCA-style outline
         let mut output = String::new();
-        output.push_str(&format!("// OUTLINE: {}\n", path.display()));
-        output.push_str(&format!("// Total symbols: {}\n", symbols.len()));
+        let _ = writeln!(output, "// OUTLINE: {}", path.display());
+        let _ = writeln!(output, "// Total symbols: {}", symbols.len());
 
         for sym in &symbols {
             let kind_str = format!("{:?}", sym.kind).to_lowercase();
-            output.push_str(&format!(
-                "L{: <4} {: <12} {} {}\n",
+            let _ = writeln!(
+                output,
+                "L{: <4} {: <12} {} {}",
                 sym.line,
                 format!("[{}]", kind_str),
                 sym.name,
                 sym.signature
-            ));
+            );
         }
 
         Ok(output)
@@ -143,10 +145,10 @@ impl TagExtractor {
         }
 
         let mut output = String::new();
-        output.push_str(&format!("// RULE SEARCH: {}\n", path.display()));
-        output.push_str(&format!("// Total matches: {}\n", matches.len()));
+        let _ = writeln!(output, "// RULE SEARCH: {}", path.display());
+        let _ = writeln!(output, "// Total matches: {}", matches.len());
         for m in &matches {
-            output.push_str(&format!("L{: <4}:{: <3} {}\n", m.line, m.column, m.content));
+            let _ = writeln!(output, "L{: <4}:{: <3} {}", m.line, m.column, m.content);
         }
 
         Ok(output)
@@ -169,6 +171,24 @@ impl TagExtractor {
         path: P,
         pattern: &str,
         language: Option<&str>,
+    ) -> Result<String, SearchError> {
+        Self::search_file_with_config(path, pattern, language, &SearchConfig::default())
+    }
+
+    /// Search for a pattern in a single file, capping matches at
+    /// `config.max_matches_per_file`.
+    ///
+    /// When the cap is hit the output carries a `// Truncated:` notice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the file cannot be read, the language is unsupported,
+    /// or the pattern is invalid.
+    pub fn search_file_with_config<P: AsRef<Path>>(
+        path: P,
+        pattern: &str,
+        language: Option<&str>,
+        config: &SearchConfig,
     ) -> Result<String, SearchError> {
         let path = path.as_ref();
         let content = omni_io::read_text_safe(path, 1024 * 1024)?;
@@ -191,7 +211,8 @@ impl TagExtractor {
             }
         };
 
-        let matches = Self::search_content(&content, pattern, lang, path)?;
+        let (matches, truncated) =
+            Self::search_content(&content, pattern, lang, path, config.max_matches_per_file)?;
 
         if matches.is_empty() {
             return Ok(format!(
@@ -203,12 +224,19 @@ impl TagExtractor {
 
         // Build formatted output
         let mut output = String::new();
-        output.push_str(&format!("// SEARCH: {}\n", path.display()));
-        output.push_str(&format!("// Pattern: {}\n", pattern));
-        output.push_str(&format!("// Total matches: {}\n", matches.len()));
+        let _ = writeln!(output, "// SEARCH: {}", path.display());
+        let _ = writeln!(output, "// Pattern: {pattern}");
+        let _ = writeln!(output, "// Total matches: {}", matches.len());
+        if truncated {
+            let _ = writeln!(
+                output,
+                "// Truncated: stopped at max_matches_per_file ({})",
+                config.max_matches_per_file
+            );
+        }
 
         for m in &matches {
-            output.push_str(&format!("L{: <4}:{: <3} {}\n", m.line, m.column, m.content));
+            let _ = writeln!(output, "L{: <4}:{: <3} {}", m.line, m.column, m.content);
         }
 
         Ok(output)
@@ -232,6 +260,7 @@ impl TagExtractor {
 
         let dir = dir.as_ref();
         let mut all_matches: Vec<SearchMatch> = Vec::new();
+        let mut truncated_files: Vec<String> = Vec::new();
         let mut file_count = 0;
 
         let walker = WalkDir::new(dir).follow_links(false).into_iter();
@@ -275,7 +304,16 @@ impl TagExtractor {
 
                 match std::fs::read_to_string(path) {
                     Ok(content) => {
-                        let matches = Self::search_content(&content, pattern, lang, path)?;
+                        let (matches, truncated) = Self::search_content(
+                            &content,
+                            pattern,
+                            lang,
+                            path,
+                            config.max_matches_per_file,
+                        )?;
+                        if truncated {
+                            truncated_files.push(path.to_string_lossy().to_string());
+                        }
                         all_matches.extend(matches);
 
                         if all_matches.len() >= config.max_matches_per_file * 10 {
@@ -298,31 +336,41 @@ impl TagExtractor {
 
         // Group matches by file
         let mut output = String::new();
-        output.push_str(&format!("// SEARCH: {}\n", dir.display()));
-        output.push_str(&format!("// Pattern: {}\n", pattern));
-        output.push_str(&format!("// Files searched: {}\n", file_count));
-        output.push_str(&format!("// Total matches: {}\n", all_matches.len()));
+        let _ = writeln!(output, "// SEARCH: {}", dir.display());
+        let _ = writeln!(output, "// Pattern: {pattern}");
+        let _ = writeln!(output, "// Files searched: {file_count}");
+        let _ = writeln!(output, "// Total matches: {}", all_matches.len());
+        for file in &truncated_files {
+            let _ = writeln!(
+                output,
+                "// Truncated: {} stopped at max_matches_per_file ({})",
+                file, config.max_matches_per_file
+            );
+        }
 
         // Group by file
         let mut current_file = String::new();
         for m in all_matches {
             if m.path != current_file {
                 current_file = m.path.clone();
-                output.push_str(&format!("\n// File: {}\n", current_file));
+                let _ = writeln!(output, "\n// File: {current_file}");
             }
-            output.push_str(&format!("L{: <4}:{: <3} {}\n", m.line, m.column, m.content));
+            let _ = writeln!(output, "L{: <4}:{: <3} {}", m.line, m.column, m.content);
         }
 
         Ok(output)
     }
 
-    /// Internal: Search content for a pattern
+    /// Internal: Search content for a pattern, keeping at most `max_matches`.
+    ///
+    /// Returns the matches and whether more matches existed beyond the cap.
     fn search_content(
         content: &str,
         pattern_str: &str,
         lang: SupportLang,
         path: &Path,
-    ) -> Result<Vec<SearchMatch>, SearchError> {
+        max_matches: usize,
+    ) -> Result<(Vec<SearchMatch>, bool), SearchError> {
         let root = lang.ast_grep(content);
         let root_node = root.root();
 
@@ -333,10 +381,15 @@ impl TagExtractor {
         };
 
         let mut matches = Vec::new();
+        let mut truncated = false;
 
         // DFS search through all nodes
         for node in root_node.dfs() {
             if let Some(m) = pattern.match_node(node.clone()) {
+                if matches.len() >= max_matches {
+                    truncated = true;
+                    break; // Limit matches per file
+                }
                 let start_pos = m.start_pos();
                 let line = start_pos.line();
                 // Column calculation requires node reference; use line for simplicity
@@ -369,14 +422,10 @@ impl TagExtractor {
                     content: m.text().to_string(),
                     captures,
                 });
-
-                if matches.len() >= 100 {
-                    break; // Limit matches per file
-                }
             }
         }
 
-        Ok(matches)
+        Ok((matches, truncated))
     }
 
     /// Extract symbols from Python source using AST patterns
@@ -403,13 +452,16 @@ impl TagExtractor {
             }
         }
 
-        // Extract functions
+        // Extract functions (async defs are handled by the async pattern below)
         let pattern = Pattern::new(PYTHON_DEF_PATTERN, lang);
         for node in root_node.dfs() {
             if let Some(m) = pattern.match_node(node.clone()) {
+                if m.text().trim_start().starts_with("async") {
+                    continue;
+                }
                 let name = Self::get_capture(&m, "NAME");
                 let line = m.start_pos().line();
-                let sig = format!("def {}", name);
+                let sig = format!("def {}{}", name, Self::python_params(&m));
                 symbols.push(Symbol {
                     name: name.clone(),
                     kind: SymbolKind::Function,
@@ -425,7 +477,7 @@ impl TagExtractor {
             if let Some(m) = pattern.match_node(node.clone()) {
                 let name = Self::get_capture(&m, "NAME");
                 let line = m.start_pos().line();
-                let sig = format!("async def {}", name);
+                let sig = format!("async def {}{}", name, Self::python_params(&m));
                 symbols.push(Symbol {
                     name: name.clone(),
                     kind: SymbolKind::AsyncFunction,
@@ -436,8 +488,7 @@ impl TagExtractor {
         }
 
         // Sort by line number and deduplicate
-        symbols.sort_by_key(|s| s.line);
-        symbols
+        Self::sort_and_dedup(symbols)
     }
 
     /// Extract symbols from Rust source using AST patterns
@@ -528,8 +579,7 @@ impl TagExtractor {
             }
         }
 
-        symbols.sort_by_key(|s| s.line);
-        symbols
+        Self::sort_and_dedup(symbols)
     }
 
     /// Extract symbols from JavaScript source
@@ -572,8 +622,7 @@ impl TagExtractor {
             }
         }
 
-        symbols.sort_by_key(|s| s.line);
-        symbols
+        Self::sort_and_dedup(symbols)
     }
 
     /// Extract symbols from TypeScript source
@@ -603,10 +652,25 @@ impl TagExtractor {
             }
         }
 
+        Self::sort_and_dedup(symbols)
+    }
+
+    /// Sort symbols by line and drop repeated `(name, kind, line)` entries
+    /// produced when a pattern matches overlapping nodes.
+    fn sort_and_dedup(mut symbols: Vec<Symbol>) -> Vec<Symbol> {
         symbols.sort_by_key(|s| s.line);
+        let mut seen = std::collections::HashSet::new();
+        symbols.retain(|s| seen.insert((s.name.clone(), s.kind.clone(), s.line)));
         symbols
     }
 
+    /// Parameter list text (e.g. `(a, b)`) of a matched Python function node.
+    fn python_params<D: omni_ast::Doc>(m: &omni_ast::NodeMatch<D>) -> String {
+        m.field("parameters")
+            .map(|params| params.text().to_string())
+            .unwrap_or_default()
+    }
+
     /// Get the text of a variable capture from a matched node
     fn get_capture<D: omni_ast::Doc>(m: &omni_ast::NodeMatch<D>, capture: &str) -> String {
         m.get_env()
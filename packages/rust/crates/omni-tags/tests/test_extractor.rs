@@ -4,7 +4,7 @@ use std::fs::File;
 use std::io::Write;
 use tempfile::TempDir;
 
-use omni_tags::TagExtractor;
+use omni_tags::{SearchConfig, TagExtractor};
 
 #[test]
 fn test_python_outline() {
@@ -70,3 +70,82 @@ trait Printable {
     assert!(outline.contains("impl"));
     assert!(outline.contains("Printable"));
 }
+
+#[test]
+fn test_python_outline_deduplicates_overlapping_matches() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("dup.py");
+    let content = r#"
+@decorator
+def decorated(x):
+    return x
+
+@retry
+async def fetch(url: str) -> str:
+    return url
+"#;
+    File::create(&path)
+        .unwrap()
+        .write_all(content.as_bytes())
+        .unwrap();
+
+    let outline = TagExtractor::outline_file(&path, Some("python")).unwrap();
+
+    let count = |name: &str| {
+        outline
+            .lines()
+            .filter(|line| line.split_whitespace().nth(2) == Some(name))
+            .count()
+    };
+    assert_eq!(count("decorated"), 1, "{outline}");
+    assert_eq!(count("fetch"), 1, "{outline}");
+    assert!(outline.contains("async def fetch"));
+    assert!(outline.contains("// Total symbols: 2"));
+}
+
+#[test]
+fn test_python_outline_keeps_overload_signatures() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("overload.py");
+    let content = r#"
+from typing import overload
+
+@overload
+def f(a, b): ...
+
+@overload
+def f(a): ...
+"#;
+    File::create(&path)
+        .unwrap()
+        .write_all(content.as_bytes())
+        .unwrap();
+
+    let outline = TagExtractor::outline_file(&path, Some("python")).unwrap();
+
+    assert!(outline.contains("def f(a, b)"), "{outline}");
+    assert!(outline.contains("def f(a)"), "{outline}");
+    assert!(outline.contains("// Total symbols: 2"), "{outline}");
+}
+
+#[test]
+fn test_search_file_respects_max_matches_per_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("many.py");
+    let content: String = (0..150).map(|i| format!("call_{i}()\n")).collect();
+    File::create(&path)
+        .unwrap()
+        .write_all(content.as_bytes())
+        .unwrap();
+
+    let config = SearchConfig {
+        max_matches_per_file: 120,
+        ..SearchConfig::default()
+    };
+    let output =
+        TagExtractor::search_file_with_config(&path, "$FN()", Some("python"), &config).unwrap();
+
+    assert!(output.contains("// Total matches: 120"), "{output}");
+    assert!(output.contains("// Truncated:"), "{output}");
+    assert_eq!(output.lines().filter(|l| l.starts_with('L')).count(), 120);
+}
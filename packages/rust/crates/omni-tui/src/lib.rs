@@ -14,12 +14,14 @@
 
 pub mod components;
 pub mod event;
+pub mod parent_monitor;
 pub mod renderer;
 pub mod socket;
 pub mod state;
 
 pub use components::{FoldablePanel, PanelState, TuiApp};
 pub use event::{Event, EventHandler, TuiEvent};
+pub use parent_monitor::{ParentMonitor, is_process_alive};
 pub use renderer::TuiRenderer;
 pub use socket::{SocketClient, SocketEvent, SocketServer};
 pub use state::{
@@ -16,12 +16,15 @@ use anyhow::{Context, Result};
 use clap::Parser;
 use crossterm::event::{self, Event as CEvent, KeyCode};
 use log::{info, warn};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc;
 use std::thread;
 use std::time::{Duration, Instant};
 
 use omni_tui::{
-    TuiRenderer,
+    ParentMonitor, TuiRenderer,
+    parent_monitor::DEFAULT_POLL_INTERVAL,
     socket::{SocketClient, SocketEvent, SocketServer},
     state::{AppState, ExecutionState},
 };
@@ -49,11 +52,23 @@ struct Args {
 }
 
 /// Run the event processing loop (with or without TUI)
-fn run_event_loop(state: &mut AppState, server_handle: thread::JoinHandle<()>, headless: bool) {
+///
+/// Exits when the user quits or `shutdown` is raised (e.g. the parent process died).
+fn run_event_loop(
+    state: &mut AppState,
+    server_handle: thread::JoinHandle<()>,
+    headless: bool,
+    shutdown: &AtomicBool,
+) {
     let tick_rate = Duration::from_millis(100);
     let mut last_tick = Instant::now();
 
     loop {
+        if shutdown.load(Ordering::SeqCst) {
+            info!("Shutdown requested, leaving event loop");
+            break;
+        }
+
         // Only handle input if not headless
         if !headless {
             let timeout = tick_rate.saturating_sub(last_tick.elapsed());
@@ -80,6 +95,12 @@ fn run_event_loop(state: &mut AppState, server_handle: thread::JoinHandle<()>, h
         }
     }
 
+    // The connection thread may be blocked on a peer that no longer exists
+    if shutdown.load(Ordering::SeqCst) && !server_handle.is_finished() {
+        warn!("Socket thread still running at shutdown, detaching");
+        return;
+    }
+
     let _ = server_handle.join();
 }
 
@@ -118,6 +139,12 @@ fn main() -> Result<()> {
 
     info!("Socket connection established");
 
+    // Watch the parent process so we don't linger as an orphan
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let _parent_monitor = args.pid.map(|pid| {
+        ParentMonitor::spawn_with_flag(pid, DEFAULT_POLL_INTERVAL, Arc::clone(&shutdown))
+    });
+
     // Create app state with execution state
     let mut state = AppState::new("Omni Agent".to_string());
     state.set_execution_state(ExecutionState::new());
@@ -152,6 +179,7 @@ fn main() -> Result<()> {
         &mut state,
         server_handle,
         args.headless || renderer.is_none(),
+        &shutdown,
     );
 
     // Cleanup
@@ -0,0 +1,134 @@
+//! Parent process liveness monitor
+//!
+//! When omni-tui is launched by a Python agent with `--pid <parent_pid>`, a
+//! background thread polls the parent and raises a shutdown flag once it is
+//! gone, so the renderer does not outlive its parent as an orphan.
+
+use log::{info, warn};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// Default interval between parent liveness checks
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Check whether a process with the given PID is still alive
+///
+/// Uses `kill(pid, 0)`, which performs the permission and existence checks
+/// without delivering a signal. `EPERM` means the process exists but belongs
+/// to another user, so it counts as alive.
+#[cfg(unix)]
+pub fn is_process_alive(pid: i32) -> bool {
+    use nix::errno::Errno;
+    use nix::sys::signal::kill;
+    use nix::unistd::Pid;
+
+    if pid <= 0 {
+        return false;
+    }
+
+    match kill(Pid::from_raw(pid), None) {
+        Ok(()) | Err(Errno::EPERM) => true,
+        Err(_) => false,
+    }
+}
+
+/// Check whether a process with the given PID is still alive
+///
+/// Liveness checks are not supported on this platform; always reports alive.
+#[cfg(not(unix))]
+pub fn is_process_alive(_pid: i32) -> bool {
+    true
+}
+
+/// Background monitor that signals shutdown when the parent process exits
+#[derive(Debug)]
+pub struct ParentMonitor {
+    pid: i32,
+    shutdown: Arc<AtomicBool>,
+    stop: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl ParentMonitor {
+    /// Spawn a monitor thread polling `pid` every `poll_interval`
+    pub fn spawn(pid: i32, poll_interval: Duration) -> Self {
+        Self::spawn_with_flag(pid, poll_interval, Arc::new(AtomicBool::new(false)))
+    }
+
+    /// Spawn a monitor thread that raises an existing shutdown flag
+    ///
+    /// The thread also stops polling once the flag is set by someone else.
+    pub fn spawn_with_flag(pid: i32, poll_interval: Duration, shutdown: Arc<AtomicBool>) -> Self {
+        let flag = Arc::clone(&shutdown);
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = Arc::clone(&stop);
+        let handle = thread::Builder::new()
+            .name("omni-tui-parent-monitor".to_string())
+            .spawn(move || {
+                while !flag.load(Ordering::SeqCst) && !stop_flag.load(Ordering::SeqCst) {
+                    if !is_process_alive(pid) {
+                        info!("Parent process {pid} exited, requesting shutdown");
+                        flag.store(true, Ordering::SeqCst);
+                        break;
+                    }
+                    thread::sleep(poll_interval);
+                }
+            });
+
+        let handle = match handle {
+            Ok(handle) => Some(handle),
+            Err(e) => {
+                warn!("Failed to spawn parent monitor thread: {e}");
+                None
+            }
+        };
+
+        Self {
+            pid,
+            shutdown,
+            stop,
+            handle,
+        }
+    }
+
+    /// PID of the monitored parent process
+    pub fn pid(&self) -> i32 {
+        self.pid
+    }
+
+    /// Shared shutdown flag raised when the parent exits
+    pub fn shutdown_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.shutdown)
+    }
+
+    /// Check whether shutdown has been requested
+    pub fn is_shutdown_requested(&self) -> bool {
+        self.shutdown.load(Ordering::SeqCst)
+    }
+
+    /// Wait until shutdown is requested or `timeout` elapses
+    ///
+    /// Returns `true` if shutdown was requested.
+    pub fn wait_for_shutdown(&self, timeout: Duration) -> bool {
+        let deadline = std::time::Instant::now() + timeout;
+        while !self.is_shutdown_requested() {
+            if std::time::Instant::now() >= deadline {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        true
+    }
+}
+
+impl Drop for ParentMonitor {
+    fn drop(&mut self) {
+        // Stop polling without raising the shared shutdown flag
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
@@ -116,6 +116,8 @@ impl TuiRenderer {
                 }
                 crate::components::AppLayout::SplitView => app.render_split_view(f, content_area),
             }
+        } else {
+            Self::render_log(f, content_area, state);
         }
 
         // Render status bar
@@ -136,6 +138,18 @@ impl TuiRenderer {
         f.render_widget(status, status_area);
     }
 
+    /// Render the tail of the log window that passes the active filter
+    fn render_log(f: &mut ratatui::Frame, area: ratatui::layout::Rect, state: &AppState) {
+        let lines = state.visible_log_lines();
+        let tail = lines.len().saturating_sub(usize::from(area.height));
+        let text = lines[tail..].join("\n");
+
+        let log = ratatui::widgets::Paragraph::new(text)
+            .style(ratatui::style::Style::default().fg(ratatui::style::Color::Gray));
+
+        f.render_widget(log, area);
+    }
+
     /// Render panels
     fn render_panels(f: &mut ratatui::Frame, area: ratatui::layout::Rect, app: &TuiApp) {
         let panel_collection = app.panels();
@@ -4,7 +4,7 @@
 //! - TaskItem: Individual task representation
 //! - ExecutionState: Task graph and execution tracking
 //! - LogWindow: Bounded rolling log (max 1000 lines)
-//! - AppState: Main application state with mpsc event receiver
+//! - AppState: Main application state with mpsc event receiver and optional event coalescing
 
 use crate::components::{FoldablePanel, TuiApp};
 use crate::socket::{SocketEvent, SocketServer};
@@ -13,6 +13,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::broadcast;
 
 /// Maximum number of log lines to keep in the rolling window
@@ -300,6 +301,15 @@ impl LogWindow {
         self.lines.iter().map(|s| s.as_str()).collect()
     }
 
+    /// Get lines matching a predicate, oldest first
+    pub fn filtered(&self, predicate: impl Fn(&str) -> bool) -> Vec<&str> {
+        self.lines
+            .iter()
+            .map(|s| s.as_str())
+            .filter(|line| predicate(line))
+            .collect()
+    }
+
     /// Get all lines as Strings
     pub fn get_lines_owned(&self) -> Vec<String> {
         self.lines.iter().cloned().collect()
@@ -370,6 +380,19 @@ pub struct AppState {
     event_receiver: Option<mpsc::Receiver<SocketEvent>>,
     /// Processed count for legacy event processing
     processed_count: Arc<Mutex<usize>>,
+    /// Case-insensitive substring filter for rendered log lines (None = all)
+    log_filter: Option<String>,
+
+    /// Coalescing window for IPC events (None = apply every drain immediately)
+    coalesce_window: Option<Duration>,
+    /// IPC events buffered until the coalescing window elapses
+    pending_events: Vec<SocketEvent>,
+    /// Time of the last applied batch
+    last_flush: Option<Instant>,
+    /// Number of batched state updates applied from IPC events
+    state_update_count: u64,
+    /// Number of IPC events folded into an earlier event's state update
+    coalesced_event_count: u64,
 }
 
 impl AppState {
@@ -389,6 +412,12 @@ impl AppState {
             log_window: LogWindow::new(MAX_LOG_LINES),
             event_receiver: None,
             processed_count: Arc::new(Mutex::new(0)),
+            log_filter: None,
+            coalesce_window: None,
+            pending_events: Vec::new(),
+            last_flush: None,
+            state_update_count: 0,
+            coalesced_event_count: 0,
         }
     }
 
@@ -407,6 +436,12 @@ impl AppState {
             log_window: LogWindow::new(MAX_LOG_LINES),
             event_receiver: None,
             processed_count: Arc::new(Mutex::new(0)),
+            log_filter: None,
+            coalesce_window: None,
+            pending_events: Vec::new(),
+            last_flush: None,
+            state_update_count: 0,
+            coalesced_event_count: 0,
         }
     }
 
@@ -494,6 +529,69 @@ impl AppState {
         &mut self.log_window
     }
 
+    /// Set the log filter pattern applied during rendering (empty clears it)
+    ///
+    /// Matching is a case-insensitive substring search, so `error` also
+    /// matches the `[ERROR]` level tag.
+    pub fn set_filter(&mut self, pattern: String) {
+        self.log_filter = if pattern.is_empty() {
+            None
+        } else {
+            Some(pattern.to_lowercase())
+        };
+    }
+
+    /// Clear the log filter
+    pub fn clear_filter(&mut self) {
+        self.log_filter = None;
+    }
+
+    /// Get the active log filter pattern
+    pub fn log_filter(&self) -> Option<&str> {
+        self.log_filter.as_deref()
+    }
+
+    /// Get log lines that pass the active filter
+    pub fn visible_log_lines(&self) -> Vec<&str> {
+        match self.log_filter.as_deref() {
+            Some(pattern) => self
+                .log_window
+                .filtered(|line| line.to_lowercase().contains(pattern)),
+            None => self.log_window.get_lines(),
+        }
+    }
+
+    /// Set the IPC event coalescing window (None disables coalescing)
+    ///
+    /// Events drained within the window of the previous update are buffered and
+    /// applied together once the window elapses. Sparse events still apply immediately.
+    pub fn set_coalesce_window(&mut self, window: Option<Duration>) {
+        self.coalesce_window = window;
+        if window.is_none() {
+            self.flush_pending_events();
+        }
+    }
+
+    /// Get the IPC event coalescing window
+    pub fn coalesce_window(&self) -> Option<Duration> {
+        self.coalesce_window
+    }
+
+    /// Number of batched state updates applied from IPC events
+    pub fn state_update_count(&self) -> u64 {
+        self.state_update_count
+    }
+
+    /// Number of IPC events folded into an earlier event's state update
+    pub fn coalesced_event_count(&self) -> u64 {
+        self.coalesced_event_count
+    }
+
+    /// Number of IPC events buffered and not yet applied
+    pub fn pending_event_count(&self) -> usize {
+        self.pending_events.len()
+    }
+
     /// Process events from mpsc channel (non-blocking)
     pub fn process_ipc_events(&mut self) {
         // Take the receiver out to avoid borrow issues, then put it back
@@ -502,12 +600,34 @@ impl AppState {
         };
 
         // Non-blocking try_iter to drain the queue every frame
-        for event in receiver.try_iter() {
-            self.reduce(&event);
-        }
+        self.pending_events.extend(receiver.try_iter());
 
         // Put the receiver back
         self.event_receiver = Some(receiver);
+
+        let window_elapsed = match (self.coalesce_window, self.last_flush) {
+            (Some(window), Some(last)) => last.elapsed() >= window,
+            _ => true,
+        };
+        if window_elapsed {
+            self.flush_pending_events();
+        }
+    }
+
+    /// Apply all buffered IPC events as a single state update
+    pub fn flush_pending_events(&mut self) {
+        if self.pending_events.is_empty() {
+            return;
+        }
+
+        let batch = std::mem::take(&mut self.pending_events);
+        for event in &batch {
+            self.reduce(event);
+        }
+
+        self.state_update_count += 1;
+        self.coalesced_event_count += (batch.len() - 1) as u64;
+        self.last_flush = Some(Instant::now());
     }
 
     /// Reducer: Process SocketEvent and update state
@@ -0,0 +1,41 @@
+//! Tests for parent process liveness monitoring
+
+#![cfg(unix)]
+
+use std::process::Command;
+use std::time::Duration;
+
+use omni_tui::{ParentMonitor, is_process_alive};
+
+/// Test: Monitor signals shutdown after the watched "parent" process is terminated
+#[test]
+fn test_parent_monitor_signals_shutdown_on_exit() {
+    let mut parent = Command::new("sleep")
+        .arg("30")
+        .spawn()
+        .unwrap_or_else(|error| panic!("Failed to spawn stand-in parent: {error}"));
+    let pid =
+        i32::try_from(parent.id()).unwrap_or_else(|error| panic!("PID out of range: {error}"));
+
+    assert!(is_process_alive(pid));
+
+    let monitor = ParentMonitor::spawn(pid, Duration::from_millis(20));
+    assert!(!monitor.wait_for_shutdown(Duration::from_millis(100)));
+
+    parent
+        .kill()
+        .unwrap_or_else(|error| panic!("Failed to kill stand-in parent: {error}"));
+    parent
+        .wait()
+        .unwrap_or_else(|error| panic!("Failed to reap stand-in parent: {error}"));
+
+    assert!(monitor.wait_for_shutdown(Duration::from_secs(5)));
+    assert!(!is_process_alive(pid));
+}
+
+/// Test: Invalid PIDs are never reported alive
+#[test]
+fn test_is_process_alive_invalid_pid() {
+    assert!(!is_process_alive(0));
+    assert!(!is_process_alive(-1));
+}
@@ -6,7 +6,7 @@ use std::time::Duration;
 use tempfile::TempDir;
 
 use omni_tui::socket::SocketEvent;
-use omni_tui::state::{AppState, PanelType, ReceivedEvent};
+use omni_tui::state::{AppState, LogWindow, PanelType, ReceivedEvent};
 
 /// Test: Basic state creation
 #[test]
@@ -312,3 +312,103 @@ fn test_special_characters() {
     assert_eq!(events.len(), 1);
     assert!(events[0].payload["text"].as_str().unwrap().contains("世界"));
 }
+
+fn log_event(index: usize) -> SocketEvent {
+    SocketEvent {
+        source: "agent".to_string(),
+        topic: "log".to_string(),
+        payload: serde_json::json!({"level": "info", "message": format!("line {index}")}),
+        timestamp: "2026-01-31T12:00:00Z".to_string(),
+    }
+}
+
+/// Test: Burst of IPC events within the coalescing window applies as few state updates
+#[test]
+fn test_ipc_event_coalescing_burst() {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut state = AppState::empty();
+    state.set_event_receiver(rx);
+    state.set_coalesce_window(Some(Duration::from_millis(200)));
+
+    for i in 0..100 {
+        tx.send(log_event(i)).unwrap();
+        state.process_ipc_events();
+    }
+    std::thread::sleep(Duration::from_millis(250));
+    state.process_ipc_events();
+
+    assert_eq!(state.log_window().len(), 100);
+    assert_eq!(state.pending_event_count(), 0);
+    assert!(
+        state.state_update_count() <= 2,
+        "expected burst to coalesce, got {} updates",
+        state.state_update_count()
+    );
+    assert_eq!(
+        state.state_update_count() + state.coalesced_event_count(),
+        100
+    );
+}
+
+/// Test: Sparse IPC events are applied immediately, one update each
+#[test]
+fn test_ipc_event_coalescing_sparse() {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut state = AppState::empty();
+    state.set_event_receiver(rx);
+    state.set_coalesce_window(Some(Duration::from_millis(10)));
+
+    for i in 0..3 {
+        tx.send(log_event(i)).unwrap();
+        state.process_ipc_events();
+        assert_eq!(state.log_window().len(), i + 1);
+        std::thread::sleep(Duration::from_millis(20));
+    }
+
+    assert_eq!(state.state_update_count(), 3);
+    assert_eq!(state.coalesced_event_count(), 0);
+}
+
+/// Test: Log window filter returns only matching lines
+#[test]
+fn test_log_window_filtered() {
+    let mut window = LogWindow::new(10);
+    window.add_line("info", "starting run", "t1");
+    window.add_line("error", "connection refused", "t2");
+    window.add_line("debug", "retrying connection", "t3");
+    window.add_line("error", "gave up", "t4");
+
+    let errors = window.filtered(|line| line.contains("[ERROR]"));
+    assert_eq!(
+        errors,
+        vec!["[t2] [ERROR] connection refused", "[t4] [ERROR] gave up"]
+    );
+
+    let connection = window.filtered(|line| line.contains("connection"));
+    assert_eq!(connection.len(), 2);
+    assert!(window.filtered(|line| line.contains("missing")).is_empty());
+}
+
+/// Test: AppState filter applies to visible log lines and can be cleared
+#[test]
+fn test_app_state_log_filter() {
+    let mut state = AppState::empty();
+    state
+        .log_window_mut()
+        .add_line("info", "build started", "t1");
+    state
+        .log_window_mut()
+        .add_line("error", "build failed", "t2");
+    state.log_window_mut().add_line("info", "cleanup", "t3");
+
+    state.set_filter("Error".to_string());
+    assert_eq!(state.log_filter(), Some("error"));
+    assert_eq!(state.visible_log_lines(), vec!["[t2] [ERROR] build failed"]);
+
+    state.set_filter("build".to_string());
+    assert_eq!(state.visible_log_lines().len(), 2);
+
+    state.set_filter(String::new());
+    assert_eq!(state.log_filter(), None);
+    assert_eq!(state.visible_log_lines().len(), 3);
+}
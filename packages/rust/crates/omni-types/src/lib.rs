@@ -241,6 +241,9 @@ pub struct EnvironmentSnapshot {
     pub git_modified: usize,
     /// Number of staged files
     pub git_staged: usize,
+    /// Number of submodules with local modifications
+    #[serde(default)]
+    pub git_modified_submodules: usize,
     /// Number of lines in active context (SCRATCHPAD.md)
     pub active_context_lines: usize,
     /// List of modified file paths
@@ -263,6 +266,7 @@ impl EnvironmentSnapshot {
             git_branch: "unknown".to_string(),
             git_modified: 0,
             git_staged: 0,
+            git_modified_submodules: 0,
             active_context_lines: 0,
             dirty_files: vec![],
             timestamp: 0.0,
@@ -290,14 +294,21 @@ impl EnvironmentSnapshot {
             }
         };
 
+        let submodule_desc = if self.git_modified_submodules > 0 {
+            format!(" | Submodules modified: {}", self.git_modified_submodules)
+        } else {
+            String::new()
+        };
+
         format!(
             "[LIVE ENVIRONMENT STATE]\n\
-            - Git: Branch: {} | Modified: {} | Staged: {} | Status: {}\n\
+            - Git: Branch: {} | Modified: {} | Staged: {} | Status: {}{}\n\
             - Active Context: {} lines in SCRATCHPAD.md",
             self.git_branch,
             self.git_modified,
             self.git_staged,
             dirty_desc,
+            submodule_desc,
             self.active_context_lines
         )
     }
@@ -26,6 +26,7 @@ use crate::{
     VectorStoreError,
 };
 
+mod codec;
 mod lifecycle;
 mod maintenance;
 mod read_ops;
@@ -41,6 +42,8 @@ pub struct CheckpointStore {
     datasets: Arc<Mutex<dashmap::DashMap<String, Dataset>>>,
     repaired_tables: Arc<Mutex<std::collections::HashSet<String>>>,
     dimension: usize,
+    /// When true, checkpoint payloads are zstd-compressed on write.
+    compress_content: bool,
 }
 
 const PREVIEW_MAX_LEN: usize = 200;
@@ -77,9 +80,20 @@ impl CheckpointStore {
             datasets: Arc::new(Mutex::new(dashmap::DashMap::new())),
             repaired_tables: Arc::new(Mutex::new(std::collections::HashSet::new())),
             dimension: dimension.unwrap_or(DEFAULT_DIMENSION),
+            compress_content: false,
         })
     }
 
+    /// Compress checkpoint payloads with zstd on write.
+    ///
+    /// Reads always decompress transparently, so tables may mix compressed and
+    /// uncompressed rows. The `content_encoding` metadata key is reserved for this.
+    #[must_use]
+    pub fn with_compression(mut self, enabled: bool) -> Self {
+        self.compress_content = enabled;
+        self
+    }
+
     /// Get the table path for a checkpoint table.
     fn table_path(&self, table_name: &str) -> PathBuf {
         self.base_path.join(format!("{table_name}.lance"))
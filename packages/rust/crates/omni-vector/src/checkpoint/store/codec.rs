@@ -0,0 +1,66 @@
+//! Checkpoint payload compression.
+//!
+//! Compressed payloads are zstd-compressed and base64-encoded so they still fit
+//! the UTF-8 content column; the encoding is recorded under
+//! [`CONTENT_ENCODING_KEY`] in the row metadata so reads can reverse it.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+use crate::VectorStoreError;
+
+/// Metadata key recording how the content column is encoded.
+pub(super) const CONTENT_ENCODING_KEY: &str = "content_encoding";
+/// Encoding marker for zstd-compressed, base64-encoded content.
+pub(super) const ZSTD_BASE64_ENCODING: &str = "zstd+base64";
+/// zstd level: favours speed, since checkpoints are written on the hot path.
+const ZSTD_LEVEL: i32 = 3;
+
+/// Compress `content` into its stored `zstd+base64` form.
+pub(super) fn compress_content(content: &str) -> Result<String, VectorStoreError> {
+    let compressed = zstd::encode_all(content.as_bytes(), ZSTD_LEVEL)?;
+    Ok(STANDARD.encode(compressed))
+}
+
+/// Decode stored `content` according to the encoding marker in `metadata`.
+///
+/// Content without a marker (including rows written before compression existed)
+/// is returned unchanged.
+pub(super) fn decode_content(
+    content: &str,
+    metadata: Option<&str>,
+) -> Result<String, VectorStoreError> {
+    let encoding = metadata
+        .and_then(|m| serde_json::from_str::<serde_json::Value>(m).ok())
+        .and_then(|m| {
+            m.get(CONTENT_ENCODING_KEY)
+                .and_then(serde_json::Value::as_str)
+                .map(ToString::to_string)
+        });
+    match encoding.as_deref() {
+        None => Ok(content.to_string()),
+        Some(ZSTD_BASE64_ENCODING) => {
+            let compressed = STANDARD.decode(content).map_err(|e| {
+                VectorStoreError::General(format!("Invalid base64 checkpoint content: {e}"))
+            })?;
+            let bytes = zstd::decode_all(compressed.as_slice())?;
+            String::from_utf8(bytes).map_err(|e| {
+                VectorStoreError::General(format!("Checkpoint content is not UTF-8: {e}"))
+            })
+        }
+        Some(other) => Err(VectorStoreError::General(format!(
+            "Unsupported checkpoint content encoding: {other}"
+        ))),
+    }
+}
+
+/// Remove the encoding marker from stored metadata before handing it to callers.
+pub(super) fn strip_encoding_marker(metadata: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(metadata) {
+        Ok(serde_json::Value::Object(mut map)) if map.contains_key(CONTENT_ENCODING_KEY) => {
+            map.remove(CONTENT_ENCODING_KEY);
+            serde_json::Value::Object(map).to_string()
+        }
+        _ => metadata.to_string(),
+    }
+}
@@ -1,5 +1,14 @@
 use super::*;
 
+/// One checkpoint row as stored, before content decoding.
+struct ThreadRow {
+    checkpoint_id: String,
+    parent_id: Option<String>,
+    timestamp: f64,
+    content: String,
+    metadata: Option<String>,
+}
+
 impl CheckpointStore {
     /// Get the latest checkpoint for a thread.
     ///
@@ -22,7 +31,7 @@ impl CheckpointStore {
             .await;
 
         let mut scanner = dataset.scan();
-        scanner.project(&[CONTENT_COLUMN, CHECKPOINT_TIMESTAMP_COLUMN])?;
+        scanner.project(&[CONTENT_COLUMN, METADATA_COLUMN, CHECKPOINT_TIMESTAMP_COLUMN])?;
         // PREDICATE PUSH-DOWN: Filter by thread_id column
         let filter_expr = format!("{} = '{}'", THREAD_ID_COLUMN, thread_id.replace('\'', "''"));
         scanner.filter(&filter_expr)?;
@@ -32,12 +41,16 @@ impl CheckpointStore {
             .await
             .map_err(VectorStoreError::LanceDB)?;
 
-        let mut latest_content: Option<String> = None;
+        let mut latest: Option<(String, Option<String>)> = None;
         let mut latest_timestamp = f64::NEG_INFINITY;
 
         while let Some(batch) = stream.try_next().await.map_err(VectorStoreError::LanceDB)? {
             let content_col_opt = batch.column_by_name(CONTENT_COLUMN);
             let timestamp_col_opt = batch.column_by_name(CHECKPOINT_TIMESTAMP_COLUMN);
+            let metadata_strs = batch.column_by_name(METADATA_COLUMN).and_then(|c| {
+                c.as_any()
+                    .downcast_ref::<lance::deps::arrow_array::StringArray>()
+            });
 
             if let (Some(content_col), Some(timestamp_col)) = (content_col_opt, timestamp_col_opt) {
                 let content_strs = content_col
@@ -56,14 +69,21 @@ impl CheckpointStore {
                         let timestamp = timestamp_vals.value(i);
                         if timestamp > latest_timestamp {
                             latest_timestamp = timestamp;
-                            latest_content = Some(content_strs.value(i).to_string());
+                            latest = Some((
+                                content_strs.value(i).to_string(),
+                                metadata_strs
+                                    .filter(|m| !m.is_null(i))
+                                    .map(|m| m.value(i).to_string()),
+                            ));
                         }
                     }
                 }
             }
         }
 
-        Ok(latest_content)
+        latest
+            .map(|(content, metadata)| codec::decode_content(&content, metadata.as_deref()))
+            .transpose()
     }
 
     /// Get checkpoint history for a thread (newest first).
@@ -77,6 +97,73 @@ impl CheckpointStore {
         thread_id: &str,
         limit: usize,
     ) -> Result<Vec<String>, VectorStoreError> {
+        let mut checkpoints = self.scan_thread_rows(table_name, thread_id).await?;
+
+        // Sort by timestamp descending and limit
+        checkpoints.sort_by(|a, b| {
+            b.timestamp
+                .partial_cmp(&a.timestamp)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        checkpoints.truncate(limit);
+
+        checkpoints
+            .into_iter()
+            .map(|row| codec::decode_content(&row.content, row.metadata.as_deref()))
+            .collect()
+    }
+
+    /// Get checkpoints for a thread whose timestamp lies in `[start_ts, end_ts]` (oldest first).
+    ///
+    /// Embeddings are not loaded; `embedding` is always `None` in the returned records.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if dataset scan or row decoding fails.
+    pub async fn checkpoints_between(
+        &mut self,
+        table_name: &str,
+        thread_id: &str,
+        start_ts: f64,
+        end_ts: f64,
+    ) -> Result<Vec<CheckpointRecord>, VectorStoreError> {
+        if start_ts.is_nan() || end_ts.is_nan() || start_ts > end_ts {
+            return Ok(Vec::new());
+        }
+
+        let mut rows: Vec<ThreadRow> = self
+            .scan_thread_rows(table_name, thread_id)
+            .await?
+            .into_iter()
+            .filter(|row| (start_ts..=end_ts).contains(&row.timestamp))
+            .collect();
+        rows.sort_by(|a, b| {
+            a.timestamp
+                .partial_cmp(&b.timestamp)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(CheckpointRecord {
+                    content: codec::decode_content(&row.content, row.metadata.as_deref())?,
+                    metadata: row.metadata.map(|m| codec::strip_encoding_marker(&m)),
+                    checkpoint_id: row.checkpoint_id,
+                    thread_id: thread_id.to_string(),
+                    parent_id: row.parent_id,
+                    timestamp: row.timestamp,
+                    embedding: None,
+                })
+            })
+            .collect()
+    }
+
+    /// Scan the undecoded checkpoint rows of one thread (unordered).
+    async fn scan_thread_rows(
+        &mut self,
+        table_name: &str,
+        thread_id: &str,
+    ) -> Result<Vec<ThreadRow>, VectorStoreError> {
         let table_path = self.table_path(table_name);
         if !table_path.exists() {
             return Ok(Vec::new());
@@ -88,7 +175,13 @@ impl CheckpointStore {
             .await;
 
         let mut scanner = dataset.scan();
-        scanner.project(&[CONTENT_COLUMN, CHECKPOINT_TIMESTAMP_COLUMN])?;
+        scanner.project(&[
+            ID_COLUMN,
+            CONTENT_COLUMN,
+            METADATA_COLUMN,
+            CHECKPOINT_TIMESTAMP_COLUMN,
+            CHECKPOINT_PARENT_ID_COLUMN,
+        ])?;
         // PREDICATE PUSH-DOWN: Filter by thread_id column
         let filter_expr = format!("{} = '{}'", THREAD_ID_COLUMN, thread_id.replace('\'', "''"));
         scanner.filter(&filter_expr)?;
@@ -98,38 +191,52 @@ impl CheckpointStore {
             .await
             .map_err(VectorStoreError::LanceDB)?;
 
-        let mut checkpoints: Vec<(f64, String)> = Vec::new();
+        let mut rows: Vec<ThreadRow> = Vec::new();
 
         while let Some(batch) = stream.try_next().await.map_err(VectorStoreError::LanceDB)? {
-            let content_col_opt = batch.column_by_name(CONTENT_COLUMN);
-            let timestamp_col_opt = batch.column_by_name(CHECKPOINT_TIMESTAMP_COLUMN);
-
-            if let (Some(content_col), Some(timestamp_col)) = (content_col_opt, timestamp_col_opt) {
-                let content_strs = content_col
-                    .as_any()
-                    .downcast_ref::<lance::deps::arrow_array::StringArray>();
-                let timestamp_vals = timestamp_col
-                    .as_any()
-                    .downcast_ref::<lance::deps::arrow_array::Float64Array>();
-
-                if let (Some(content_strs), Some(timestamp_vals)) = (content_strs, timestamp_vals) {
-                    for i in 0..batch.num_rows() {
-                        if content_strs.is_null(i) || timestamp_vals.is_null(i) {
-                            continue;
-                        }
-
-                        checkpoints
-                            .push((timestamp_vals.value(i), content_strs.value(i).to_string()));
-                    }
+            let string_col = |name: &str| {
+                batch.column_by_name(name).and_then(|c| {
+                    c.as_any()
+                        .downcast_ref::<lance::deps::arrow_array::StringArray>()
+                })
+            };
+            let (Some(id_strs), Some(content_strs)) =
+                (string_col(ID_COLUMN), string_col(CONTENT_COLUMN))
+            else {
+                continue;
+            };
+            let metadata_strs = string_col(METADATA_COLUMN);
+            let parent_strs = string_col(CHECKPOINT_PARENT_ID_COLUMN);
+            let Some(timestamp_vals) =
+                batch
+                    .column_by_name(CHECKPOINT_TIMESTAMP_COLUMN)
+                    .and_then(|c| {
+                        c.as_any()
+                            .downcast_ref::<lance::deps::arrow_array::Float64Array>()
+                    })
+            else {
+                continue;
+            };
+
+            for i in 0..batch.num_rows() {
+                if id_strs.is_null(i) || content_strs.is_null(i) || timestamp_vals.is_null(i) {
+                    continue;
                 }
+                let optional = |arr: Option<&lance::deps::arrow_array::StringArray>| {
+                    arr.filter(|a| !a.is_null(i))
+                        .map(|a| a.value(i).to_string())
+                };
+                rows.push(ThreadRow {
+                    checkpoint_id: id_strs.value(i).to_string(),
+                    parent_id: optional(parent_strs),
+                    timestamp: timestamp_vals.value(i),
+                    content: content_strs.value(i).to_string(),
+                    metadata: optional(metadata_strs),
+                });
             }
         }
 
-        // Sort by timestamp descending and limit
-        checkpoints.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
-        checkpoints.truncate(limit);
-
-        Ok(checkpoints.into_iter().map(|(_, c)| c).collect())
+        Ok(rows)
     }
 
     /// Get checkpoint by ID.
@@ -156,7 +263,7 @@ impl CheckpointStore {
         let mut scanner = dataset.scan();
         let filter_str = format!("{} = '{}'", ID_COLUMN, checkpoint_id.replace('\'', "''"));
         scanner.filter(filter_str.as_str())?;
-        scanner.project(&[CONTENT_COLUMN])?;
+        scanner.project(&[CONTENT_COLUMN, METADATA_COLUMN])?;
 
         let mut stream = scanner
             .try_into_stream()
@@ -170,7 +277,15 @@ impl CheckpointStore {
                     .downcast_ref::<lance::deps::arrow_array::StringArray>()
                 {
                     if batch.num_rows() > 0 && !arr.is_null(0) {
-                        return Ok(Some(arr.value(0).to_string()));
+                        let metadata = batch
+                            .column_by_name(METADATA_COLUMN)
+                            .and_then(|c| {
+                                c.as_any()
+                                    .downcast_ref::<lance::deps::arrow_array::StringArray>()
+                            })
+                            .filter(|m| !m.is_null(0))
+                            .map(|m| m.value(0));
+                        return codec::decode_content(arr.value(0), metadata).map(Some);
                     }
                 }
             }
@@ -135,6 +135,13 @@ impl CheckpointStore {
 
         results.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal));
         results.truncate(limit);
-        Ok(results)
+        // Decode only the rows that survive truncation.
+        results
+            .into_iter()
+            .map(|(content, metadata, distance)| {
+                let content = codec::decode_content(&content, Some(&metadata))?;
+                Ok((content, codec::strip_encoding_marker(&metadata), distance))
+            })
+            .collect()
     }
 }
@@ -103,7 +103,10 @@ impl CheckpointStore {
                         }
 
                         let id = id_strs.value(i).to_string();
-                        let content = content_strs.value(i);
+                        let content = codec::decode_content(
+                            content_strs.value(i),
+                            (!metadata_strs.is_null(i)).then(|| metadata_strs.value(i)),
+                        )?;
                         let timestamp = ts_vals.value(i);
                         let parent_checkpoint_id = if parent_vals.is_null(i) {
                             None
@@ -132,7 +135,7 @@ impl CheckpointStore {
                             let truncated: String = content.chars().take(PREVIEW_MAX_LEN).collect();
                             format!("{truncated}...")
                         } else {
-                            content.to_string()
+                            content
                         };
 
                         let record = crate::checkpoint::TimelineRecord {
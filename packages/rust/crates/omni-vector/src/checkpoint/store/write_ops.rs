@@ -25,6 +25,17 @@ impl CheckpointStore {
                 }
             }
         }
+        // `content_encoding` is reserved: it must describe the stored content.
+        metadata_map.remove(codec::CONTENT_ENCODING_KEY);
+        let content = if self.compress_content {
+            metadata_map.insert(
+                codec::CONTENT_ENCODING_KEY.to_string(),
+                serde_json::Value::from(codec::ZSTD_BASE64_ENCODING),
+            );
+            codec::compress_content(&record.content)?
+        } else {
+            record.content.clone()
+        };
         let checkpoint_step = metadata_map
             .get("step")
             .and_then(serde_json::Value::as_i64)
@@ -69,9 +80,7 @@ impl CheckpointStore {
                     checkpoint_step,
                 ])),
                 Arc::new(vector_array),
-                Arc::new(lance::deps::arrow_array::StringArray::from(vec![
-                    record.content.clone(),
-                ])),
+                Arc::new(lance::deps::arrow_array::StringArray::from(vec![content])),
                 Arc::new(lance::deps::arrow_array::StringArray::from(vec![metadata])),
             ],
         )
@@ -52,6 +52,32 @@ pub enum VectorStoreError {
     #[error("Embedding dimension must be positive")]
     InvalidEmbeddingDimension,
 
+    /// `add` was asked to write placeholder vectors while `reject_fake_embeddings` is set
+    #[error("Placeholder embeddings rejected for table {0}; supply real vectors via add_documents")]
+    FakeEmbeddingsRejected(String),
+
+    /// Keyword index write failed under `strict_dual_write`; the LanceDB append was undone
+    #[error("Keyword index write failed for table {table}; LanceDB append rolled back: {reason}")]
+    DualWriteRolledBack {
+        /// Table whose append was rolled back
+        table: String,
+        /// Underlying keyword index error
+        reason: String,
+    },
+
+    /// Keyword index write failed under `strict_dual_write` and undoing the LanceDB append failed too
+    #[error(
+        "Keyword index write failed for table {table}: {reason}; LanceDB rollback also failed: {rollback_error}"
+    )]
+    DualWriteRollbackFailed {
+        /// Table whose append could not be rolled back
+        table: String,
+        /// Underlying keyword index error
+        reason: String,
+        /// Error raised while undoing the append
+        rollback_error: String,
+    },
+
     /// General error with message
     #[error("{0}")]
     General(String),
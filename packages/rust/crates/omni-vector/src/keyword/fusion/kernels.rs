@@ -31,9 +31,38 @@ pub fn distance_to_score(distance: f64) -> f64 {
     1.0 / (1.0 + distance.max(0.0))
 }
 
+/// Selectable distance → similarity conversion for vector search scores.
+///
+/// Negative distances are clamped to `0` before conversion, so identical vectors
+/// always score `1.0`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScoreTransform {
+    /// `1 / (1 + distance)`; range `(0, 1]`. Compresses differences at large distances.
+    #[default]
+    Inverse,
+    /// `max(0, 1 - distance)`; range `[0, 1]`. Any distance `>= 1` scores `0`.
+    Linear,
+    /// `exp(-distance)`; range `(0, 1]`. Decays faster than `Inverse`.
+    Exponential,
+}
+
+impl ScoreTransform {
+    /// Convert a non-negative `distance` into a similarity score.
+    #[inline]
+    #[must_use]
+    pub fn apply(self, distance: f32) -> f32 {
+        let distance = distance.max(0.0);
+        match self {
+            Self::Inverse => 1.0 / (1.0 + distance),
+            Self::Linear => (1.0 - distance).max(0.0),
+            Self::Exponential => (-distance).exp(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{distance_to_score, rrf_term, rrf_term_batch};
+    use super::{ScoreTransform, distance_to_score, rrf_term, rrf_term_batch};
 
     #[test]
     fn test_rrf_term() {
@@ -57,4 +86,20 @@ mod tests {
         assert!((distance_to_score(1.0) - 0.5).abs() < 1e-6);
         assert!(distance_to_score(-0.5) <= 1.0);
     }
+
+    #[test]
+    fn test_score_transform_documented_values() {
+        for transform in [
+            ScoreTransform::Inverse,
+            ScoreTransform::Linear,
+            ScoreTransform::Exponential,
+        ] {
+            assert!((transform.apply(0.0) - 1.0).abs() < 1e-6);
+        }
+        assert!((ScoreTransform::Inverse.apply(0.5) - (1.0 / 1.5)).abs() < 1e-6);
+        assert!((ScoreTransform::Linear.apply(0.5) - 0.5).abs() < 1e-6);
+        assert!((ScoreTransform::Exponential.apply(0.5) - (-0.5_f32).exp()).abs() < 1e-6);
+        assert!(ScoreTransform::Linear.apply(2.0).abs() < 1e-6);
+        assert!((ScoreTransform::default().apply(1.0) - 0.5).abs() < 1e-6);
+    }
 }
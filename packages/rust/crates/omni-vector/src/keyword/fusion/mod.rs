@@ -12,7 +12,7 @@ mod types;
 mod weighted_rrf;
 
 pub use adaptive_rrf::apply_adaptive_rrf;
-pub use kernels::{distance_to_score, rrf_term, rrf_term_batch};
+pub use kernels::{ScoreTransform, distance_to_score, rrf_term, rrf_term_batch};
 pub use rrf::apply_rrf;
-pub use types::HybridSearchResult;
-pub use weighted_rrf::apply_weighted_rrf;
+pub use types::{HybridSearchResult, RankingExplanation};
+pub use weighted_rrf::{apply_weighted_rrf, apply_weighted_rrf_explained};
@@ -12,3 +12,29 @@ pub struct HybridSearchResult {
     /// BM25 keyword score from keyword search
     pub keyword_score: f32,
 }
+
+/// Per-component breakdown of a weighted RRF score.
+///
+/// `vector_contribution + keyword_contribution + boost` equals the fused `rrf_score`; the raw
+/// side scores are `None` when the tool was not a hit on that side.
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Serialize)]
+pub struct RankingExplanation {
+    /// Raw semantic similarity before fusion, when the tool was a vector hit.
+    pub vector_score: Option<f32>,
+    /// Raw keyword (BM25) score before fusion, when the tool was a keyword hit.
+    pub keyword_score: Option<f32>,
+    /// Fused contribution from the vector ranking (including sparse-keyword fallback bonus).
+    pub vector_contribution: f32,
+    /// Fused contribution from the keyword ranking.
+    pub keyword_contribution: f32,
+    /// Name/metadata boost applied after fusion.
+    pub boost: f32,
+}
+
+impl RankingExplanation {
+    /// Sum of all components.
+    #[must_use]
+    pub fn total(&self) -> f32 {
+        self.vector_contribution + self.keyword_contribution + self.boost
+    }
+}
@@ -13,14 +13,13 @@ use super::match_util::{
     NameMatchResult, build_name_lower_arrow, build_name_token_automaton_with_phrase,
     count_name_token_matches_and_exact,
 };
-use super::types::HybridSearchResult;
+use super::types::{HybridSearchResult, RankingExplanation};
 
 /// Apply Weighted RRF with Field Boosting.
 ///
 /// Algorithm: weighted vector + keyword streams, smart fallback for sparse keyword results,
 /// dynamic field boosting (name token match, exact phrase, metadata alignment).
 #[must_use]
-#[allow(clippy::too_many_lines, clippy::needless_pass_by_value)]
 pub fn apply_weighted_rrf(
     vector_results: Vec<(String, f32)>,
     keyword_results: Vec<ToolSearchResult>,
@@ -29,7 +28,32 @@ pub fn apply_weighted_rrf(
     keyword_weight: f32,
     query: &str,
 ) -> Vec<HybridSearchResult> {
+    apply_weighted_rrf_explained(
+        vector_results,
+        keyword_results,
+        k,
+        semantic_weight,
+        keyword_weight,
+        query,
+    )
+    .into_iter()
+    .map(|(result, _)| result)
+    .collect()
+}
+
+/// [`apply_weighted_rrf`], also returning the per-component breakdown of each fused score.
+#[must_use]
+#[allow(clippy::too_many_lines, clippy::needless_pass_by_value)]
+pub fn apply_weighted_rrf_explained(
+    vector_results: Vec<(String, f32)>,
+    keyword_results: Vec<ToolSearchResult>,
+    k: f32,
+    semantic_weight: f32,
+    keyword_weight: f32,
+    query: &str,
+) -> Vec<(HybridSearchResult, RankingExplanation)> {
     let mut fusion_map: HashMap<String, HybridSearchResult> = HashMap::new();
+    let mut explanations: HashMap<String, RankingExplanation> = HashMap::new();
     let query_lower = query.to_lowercase();
     let query_parts: Vec<&str> = query_lower.split_whitespace().collect();
     let file_discovery_intent = is_file_discovery_query(&query_lower, &query_parts);
@@ -64,6 +88,14 @@ pub fn apply_weighted_rrf(
         let rrf_score = effective_vec_weight * super::kernels::rrf_term(k, rank);
         let fallback_bonus = if is_keyword_sparse { score * 0.3 } else { 0.0 };
 
+        explanations.insert(
+            name.clone(),
+            RankingExplanation {
+                vector_score: Some(score),
+                vector_contribution: rrf_score + fallback_bonus,
+                ..RankingExplanation::default()
+            },
+        );
         fusion_map.insert(
             name.clone(),
             HybridSearchResult {
@@ -80,6 +112,9 @@ pub fn apply_weighted_rrf(
             let rrf_score = effective_kw_weight * super::kernels::rrf_term(k, rank);
             let tool_name = result.tool_name.as_str();
 
+            let explanation = explanations.entry(result.tool_name.clone()).or_default();
+            explanation.keyword_score = Some(result.score);
+            explanation.keyword_contribution += rrf_score;
             if let Some(entry) = fusion_map.get_mut(tool_name) {
                 entry.rrf_score += rrf_score;
                 entry.keyword_score = result.score;
@@ -138,10 +173,19 @@ pub fn apply_weighted_rrf(
         if let Some(entry) = fusion_map.get_mut(&keys_ordered[i]) {
             entry.rrf_score += delta;
         }
+        if let Some(explanation) = explanations.get_mut(&keys_ordered[i]) {
+            explanation.boost += delta;
+        }
     }
 
-    let mut results: Vec<_> = fusion_map.into_values().collect();
-    results.sort_by(|a, b| {
+    let mut results: Vec<_> = fusion_map
+        .into_values()
+        .map(|result| {
+            let explanation = explanations.remove(&result.tool_name).unwrap_or_default();
+            (result, explanation)
+        })
+        .collect();
+    results.sort_by(|(a, _), (b, _)| {
         b.rrf_score
             .total_cmp(&a.rrf_score)
             .then_with(|| a.tool_name.cmp(&b.tool_name))
@@ -1,11 +1,12 @@
 //! `KeywordIndex` - Tantivy wrapper for keyword search with `BM25`.
 
 use std::cell::RefCell;
+use std::collections::BTreeSet;
 use std::path::Path;
 
 use tantivy::collector::TopDocs;
 use tantivy::query::QueryParser;
-use tantivy::schema::{Field, IndexRecordOption, Schema, TextOptions, Value};
+use tantivy::schema::{Field, IndexRecordOption, STORED, STRING, Schema, TextOptions, Value};
 use tantivy::tokenizer::{
     AsciiFoldingFilter, LowerCaser, RemoveLongFilter, SimpleTokenizer, TextAnalyzer,
 };
@@ -37,6 +38,10 @@ pub struct KeywordIndex {
     pub keywords: Field,
     /// Field handle for intents (used for semantic alignment)
     pub intents: Field,
+    /// Field handle for the untokenized tool name (used for exact deletes)
+    pub tool_id: Field,
+    /// Field handle for the untokenized owning skill name (used to scope repairs)
+    pub skill_name: Field,
 }
 
 #[allow(clippy::missing_errors_doc, clippy::doc_markdown)]
@@ -61,6 +66,8 @@ impl KeywordIndex {
         schema_builder.add_text_field("category", text_options.clone());
         schema_builder.add_text_field("keywords", text_options.clone());
         schema_builder.add_text_field("intents", text_options);
+        schema_builder.add_text_field("tool_id", STRING | STORED);
+        schema_builder.add_text_field("skill_name", STRING | STORED);
 
         let schema = schema_builder.build();
         Index::create_in_dir(path, schema)
@@ -109,6 +116,12 @@ impl KeywordIndex {
         let intents = schema
             .get_field("intents")
             .map_err(|_| VectorStoreError::General("Missing intents field".to_string()))?;
+        let tool_id = schema
+            .get_field("tool_id")
+            .map_err(|_| VectorStoreError::General("Missing tool_id field".to_string()))?;
+        let skill_name = schema
+            .get_field("skill_name")
+            .map_err(|_| VectorStoreError::General("Missing skill_name field".to_string()))?;
 
         // 3. Create Reader
         let reader = index
@@ -126,6 +139,8 @@ impl KeywordIndex {
             category,
             keywords,
             intents,
+            tool_id,
+            skill_name,
         })
     }
 
@@ -174,9 +189,13 @@ impl KeywordIndex {
         let keywords = schema
             .get_field("keywords")
             .map_err(|_| VectorStoreError::General("Missing keywords field".to_string()))?;
-        // Check for intents field - if missing, recreate the index (schema migration)
-        let Ok(intents) = schema.get_field("intents") else {
-            // Schema is missing intents field - recreate the index.
+        // Check for intents / tool_id / skill_name fields - if missing, recreate the index
+        // (schema migration).
+        let (Ok(intents), Ok(tool_id), Ok(skill_name)) = (
+            schema.get_field("intents"),
+            schema.get_field("tool_id"),
+            schema.get_field("skill_name"),
+        ) else {
             return Self::new_with_migration(path);
         };
 
@@ -196,6 +215,8 @@ impl KeywordIndex {
             category,
             keywords,
             intents,
+            tool_id,
+            skill_name,
         })
     }
 
@@ -222,15 +243,16 @@ impl KeywordIndex {
         let writer = cache
             .as_mut()
             .ok_or_else(|| VectorStoreError::General("writer cache unavailable".to_string()))?;
-        let term = Term::from_field_text(self.tool_name, name);
-        writer.delete_term(term);
+        writer.delete_term(Term::from_field_text(self.tool_id, name));
         writer
             .add_document(doc!(
                 self.tool_name => name,
                 self.description => description,
                 self.category => category,
                 self.keywords => keywords.join(" "),
-                self.intents => intents.join(" | ")
+                self.intents => intents.join(" | "),
+                self.tool_id => name,
+                self.skill_name => skill_of(name)
             ))
             .map_err(VectorStoreError::Tantivy)?;
         writer.commit().map_err(VectorStoreError::Tantivy)?;
@@ -240,9 +262,23 @@ impl KeywordIndex {
     }
 
     /// Bulk upsert documents. Reuses a cached IndexWriter when possible.
+    ///
+    /// Each document's skill is taken from its tool name (`skill.tool`); use
+    /// [`Self::bulk_upsert_with_skills`] when the owning skill is known.
     pub fn bulk_upsert<I>(&self, docs: I) -> Result<(), VectorStoreError>
     where
         I: IntoIterator<Item = (String, String, String, Vec<String>, Vec<String>)>,
+    {
+        self.bulk_upsert_with_skills(
+            docs.into_iter()
+                .map(|doc| (skill_of(&doc.0).to_string(), doc)),
+        )
+    }
+
+    /// Bulk upsert `(skill_name, document)` pairs, recording the exact owning skill.
+    pub fn bulk_upsert_with_skills<I>(&self, docs: I) -> Result<(), VectorStoreError>
+    where
+        I: IntoIterator<Item = (String, (String, String, String, Vec<String>, Vec<String>))>,
     {
         let mut cache = self.writer_cache.borrow_mut();
         if cache.is_none() {
@@ -255,19 +291,20 @@ impl KeywordIndex {
         let writer = cache
             .as_mut()
             .ok_or_else(|| VectorStoreError::General("writer cache unavailable".to_string()))?;
-        for (name, description, category, kw_list, intent_list) in docs {
+        for (skill, (name, description, category, kw_list, intent_list)) in docs {
             if !crate::skill::is_routable_tool_name(&name) {
                 continue;
             }
-            let term = Term::from_field_text(self.tool_name, &name);
-            writer.delete_term(term);
+            writer.delete_term(Term::from_field_text(self.tool_id, &name));
             writer
                 .add_document(doc!(
+                    self.tool_id => name.as_str(),
                     self.tool_name => name,
                     self.description => description,
                     self.category => category,
                     self.keywords => kw_list.join(" "),
-                    self.intents => intent_list.join(" | ")
+                    self.intents => intent_list.join(" | "),
+                    self.skill_name => skill
                 ))
                 .map_err(VectorStoreError::Tantivy)?;
         }
@@ -290,14 +327,15 @@ impl KeywordIndex {
             if !crate::skill::is_routable_tool_name(&tool.name) {
                 continue;
             }
-            let term = Term::from_field_text(self.tool_name, &tool.name);
-            writer.delete_term(term);
+            writer.delete_term(Term::from_field_text(self.tool_id, &tool.name));
             writer.add_document(doc!(
                 self.tool_name => tool.name.as_str(),
                 self.description => tool.description.as_str(),
                 self.category => tool.skill_name.as_str(),
                 self.keywords => tool.routing_keywords.join(" "),
-                self.intents => tool.intents.join(" | ")
+                self.intents => tool.intents.join(" | "),
+                self.tool_id => tool.name.as_str(),
+                self.skill_name => tool.skill_name.as_str()
             ))?;
         }
         writer.commit()?;
@@ -396,6 +434,7 @@ impl KeywordIndex {
                 intents,
                 category,
                 parameters: vec![],
+                explanation: None,
             });
         }
 
@@ -409,6 +448,82 @@ impl KeywordIndex {
         Ok(searcher.num_docs())
     }
 
+    /// List the tool names of every document currently committed to the index.
+    ///
+    /// Reloads the reader first so writes committed by other handles are visible.
+    pub fn tool_names(&self) -> Result<Vec<String>, VectorStoreError> {
+        self.indexed_tools(|_| true)
+    }
+
+    /// List the tool names of committed documents whose owning skill is in `skills`.
+    ///
+    /// Skills are compared exactly against the stored `skill_name` field.
+    pub fn tool_names_in_skills(
+        &self,
+        skills: &BTreeSet<&str>,
+    ) -> Result<Vec<String>, VectorStoreError> {
+        self.indexed_tools(|skill| skills.contains(skill))
+    }
+
+    fn indexed_tools(
+        &self,
+        keep_skill: impl Fn(&str) -> bool,
+    ) -> Result<Vec<String>, VectorStoreError> {
+        self.reader.reload().map_err(VectorStoreError::Tantivy)?;
+        let searcher = self.reader.searcher();
+        let limit = usize::try_from(searcher.num_docs())
+            .unwrap_or(usize::MAX)
+            .max(1);
+        let top_docs = searcher
+            .search(&tantivy::query::AllQuery, &TopDocs::with_limit(limit))
+            .map_err(VectorStoreError::Tantivy)?;
+
+        let mut names = Vec::with_capacity(top_docs.len());
+        for (_score, doc_address) in top_docs {
+            let doc: TantivyDocument = searcher
+                .doc(doc_address)
+                .map_err(VectorStoreError::Tantivy)?;
+            let skill = doc
+                .get_first(self.skill_name)
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            if !keep_skill(skill) {
+                continue;
+            }
+            if let Some(name) = doc.get_first(self.tool_name).and_then(|v| v.as_str()) {
+                names.push(name.to_string());
+            }
+        }
+        names.sort();
+        names.dedup();
+        Ok(names)
+    }
+
+    /// Delete documents by exact tool name, in a single commit.
+    pub fn delete_documents(&self, names: &[String]) -> Result<(), VectorStoreError> {
+        if names.is_empty() {
+            return Ok(());
+        }
+        let mut cache = self.writer_cache.borrow_mut();
+        if cache.is_none() {
+            *cache = Some(
+                self.index
+                    .writer(100_000_000)
+                    .map_err(VectorStoreError::Tantivy)?,
+            );
+        }
+        let writer = cache
+            .as_mut()
+            .ok_or_else(|| VectorStoreError::General("writer cache unavailable".to_string()))?;
+        for name in names {
+            writer.delete_term(Term::from_field_text(self.tool_id, name));
+        }
+        writer.commit().map_err(VectorStoreError::Tantivy)?;
+        drop(cache);
+        self.reader.reload().map_err(VectorStoreError::Tantivy)?;
+        Ok(())
+    }
+
     /// Check if index exists
     pub fn exists<P: AsRef<Path>>(path: P) -> bool {
         path.as_ref()
@@ -473,6 +588,7 @@ impl KeywordIndex {
                 routing_keywords: keywords,
                 intents,
                 parameters: vec![],
+                explanation: None,
                 category: doc
                     .get_first(self.category)
                     .and_then(|v| v.as_str())
@@ -484,3 +600,8 @@ impl KeywordIndex {
         }
     }
 }
+
+/// Skill part of a canonical `skill.tool` name, used when no owning skill is given.
+fn skill_of(tool_name: &str) -> &str {
+    tool_name.split('.').next().unwrap_or("")
+}
@@ -17,8 +17,8 @@ pub use entity_aware::{
     EntityMatchType, MAX_ENTITY_MATCHES, apply_entity_boost, apply_triple_rrf,
 };
 pub use fusion::{
-    HybridSearchResult, apply_adaptive_rrf, apply_rrf, apply_weighted_rrf, distance_to_score,
-    rrf_term, rrf_term_batch,
+    HybridSearchResult, RankingExplanation, ScoreTransform, apply_adaptive_rrf, apply_rrf,
+    apply_weighted_rrf, apply_weighted_rrf_explained, distance_to_score, rrf_term, rrf_term_batch,
 };
 pub use index::KeywordIndex;
 use serde::{Deserialize, Serialize};
@@ -21,7 +21,7 @@ pub use omni_lance::{
     CATEGORY_COLUMN, CONTENT_COLUMN, DEFAULT_DIMENSION, FILE_PATH_COLUMN, ID_COLUMN,
     INTENTS_COLUMN, METADATA_COLUMN, ROUTING_KEYWORDS_COLUMN, SKILL_NAME_COLUMN, THREAD_ID_COLUMN,
     TOOL_NAME_COLUMN, VECTOR_COLUMN, VectorRecordBatchReader, extract_optional_string,
-    extract_string,
+    extract_string, normalize_l2,
 };
 
 // ============================================================================
@@ -40,17 +40,19 @@ pub use omni_scanner::skills::{
 pub use checkpoint::{CheckpointRecord, CheckpointStore};
 pub use error::VectorStoreError;
 pub use keyword::{
-    HybridSearchResult, KEYWORD_WEIGHT, KeywordIndex, KeywordSearchBackend, RRF_K, SEMANTIC_WEIGHT,
-    apply_rrf, apply_weighted_rrf, distance_to_score, rrf_term, rrf_term_batch,
+    HybridSearchResult, KEYWORD_WEIGHT, KeywordIndex, KeywordSearchBackend, RRF_K,
+    RankingExplanation, SEMANTIC_WEIGHT, ScoreTransform, apply_rrf, apply_weighted_rrf,
+    apply_weighted_rrf_explained, distance_to_score, rrf_term, rrf_term_batch,
 };
 pub use ops::{
-    AgenticSearchConfig, CompactionStats, FragmentInfo, IndexBuildProgress, IndexStats,
-    IndexStatus, IndexThresholds, MergeInsertStats, MigrateResult, MigrationItem,
-    OMNI_SCHEMA_VERSION, QueryIntent, Recommendation, TableColumnAlteration, TableColumnType,
-    TableHealthReport, TableInfo, TableNewColumn, TableVersionInfo, schema_version_from_schema,
+    AgenticSearchConfig, CompactionStats, ConsistencyReport, FragmentInfo, IndexBuildProgress,
+    IndexStats, IndexStatus, IndexThresholds, MergeInsertStats, MigrateResult, MigrationItem,
+    OMNI_SCHEMA_VERSION, QueryIntent, Recommendation, SelfTestReport, SelfTestStep,
+    TableColumnAlteration, TableColumnType, TableHealthReport, TableInfo, TableNewColumn,
+    TableVersionInfo, schema_version_from_schema,
 };
 pub use search::SearchOptions;
-pub use skill::{ToolSearchOptions, ToolSearchResult};
+pub use skill::{MergeStrategy, ToolResultField, ToolSearchOptions, ToolSearchResult};
 
 // ============================================================================
 // Module Declarations
@@ -76,6 +78,12 @@ pub type QueryMetricsCell = Arc<(AtomicU64, AtomicU64)>; // (query_count, last_q
 /// Callback for index build progress (Started / Progress / Done). Set optionally for polling or UI.
 pub type IndexProgressCallback = Arc<dyn Fn(crate::ops::IndexBuildProgress) + Send + Sync>;
 
+/// Metadata key recording the original char count of content truncated by `max_content_chars`.
+pub const ORIGINAL_CONTENT_CHARS_KEY: &str = "original_content_chars";
+
+/// Marker appended to content truncated by `max_content_chars`.
+pub const TRUNCATION_MARKER: &str = "...";
+
 /// High-performance embedded vector database using `LanceDB`.
 #[derive(Clone)]
 pub struct VectorStore {
@@ -88,6 +96,14 @@ pub struct VectorStore {
     pub keyword_backend: KeywordSearchBackend,
     /// Optional index cache size in bytes. When set, datasets are opened via DatasetBuilder.
     pub index_cache_size_bytes: Option<usize>,
+    /// When true, document vectors are L2-normalized before they are written.
+    pub normalize_on_insert: bool,
+    /// When true, `add` errors instead of writing placeholder vectors for tool records.
+    pub reject_fake_embeddings: bool,
+    /// When true, a failed keyword-index write rolls back the LanceDB append and errors.
+    pub strict_dual_write: bool,
+    /// Optional cap on content length in chars; longer content is truncated on write.
+    pub max_content_chars: Option<usize>,
     /// In-process per-table query metrics (query_count, last_query_ms). Wired when agentic_search runs.
     pub(crate) query_metrics: Arc<DashMap<String, QueryMetricsCell>>,
     /// Optional callback for index build progress (Started/Done; Progress when Lance exposes API).
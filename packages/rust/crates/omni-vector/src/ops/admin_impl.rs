@@ -279,21 +279,7 @@ impl VectorStore {
     /// is preserved.  This prevents the Tantivy keyword index from being destroyed
     /// every time the skills table is rebuilt.
     pub async fn drop_table(&mut self, table_name: &str) -> Result<(), VectorStoreError> {
-        let table_path = self.table_path(table_name);
-        let is_memory_mode = self.base_path.as_os_str() == ":memory:";
-        let drop_path = if is_memory_mode {
-            let Some(id) = self.memory_mode_id else {
-                return Err(VectorStoreError::General(
-                    "memory_mode_id missing while in :memory: mode".to_string(),
-                ));
-            };
-            std::env::temp_dir()
-                .join("omni_lance")
-                .join(format!("{id:016x}"))
-                .join(table_name)
-        } else {
-            table_path.clone()
-        };
+        let drop_path = self.dataset_storage_path(table_name)?;
         {
             let mut cache = self.datasets.lock().await;
             cache.remove(table_name);
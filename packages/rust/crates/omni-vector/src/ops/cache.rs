@@ -40,8 +40,12 @@ impl DatasetCache {
     }
 
     /// Insert or replace; evict oldest entries if over `max_cached_tables`.
+    ///
+    /// Replacing an existing key never evicts other entries.
     pub fn insert(&mut self, key: String, value: Dataset) {
-        self.evict_until_under_capacity(1);
+        if !self.entries.contains_key(&key) {
+            self.evict_until_under_capacity(1);
+        }
         self.lru_order.retain(|k| k != &key);
         self.lru_order.push_back(key.clone());
         self.entries.insert(key, value);
@@ -71,6 +75,12 @@ impl DatasetCache {
         self.entries.contains_key(key)
     }
 
+    /// Cached table names, least recently used first.
+    #[must_use]
+    pub fn keys_lru(&self) -> Vec<String> {
+        self.lru_order.iter().cloned().collect()
+    }
+
     fn bump_lru(&mut self, key: &str) {
         self.lru_order.retain(|k| k != key);
         self.lru_order.push_back(key.to_string());
@@ -0,0 +1,150 @@
+//! Consistency checks between a LanceDB table and the Tantivy keyword index.
+//!
+//! `add_documents` dual-writes rows to LanceDB and the keyword index; a failed
+//! keyword write is only logged, so the two can drift. These helpers detect
+//! and reconcile that drift.
+//!
+//! The keyword index is shared by every table of the store, so a table only
+//! owns the keyword documents whose stored `skill_name` matches a skill it has
+//! command rows for; documents of other skills are never reported or deleted.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use futures::TryStreamExt;
+use lance::deps::arrow_array::{Array, StringArray};
+
+use crate::error::VectorStoreError;
+use crate::ops::types::ConsistencyReport;
+use crate::{CONTENT_COLUMN, KeywordDoc, METADATA_COLUMN, VectorStore};
+
+impl VectorStore {
+    /// Compare the tools of `table_name` against the keyword index.
+    ///
+    /// Returns an empty report when the keyword index is disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table cannot be scanned or the keyword index cannot be read.
+    pub async fn verify_consistency(
+        &self,
+        table_name: &str,
+    ) -> Result<ConsistencyReport, VectorStoreError> {
+        let expected = self.keyword_docs_in_table(table_name).await?;
+        self.consistency_report(table_name, &expected)
+    }
+
+    /// Reconcile the keyword index with `table_name`.
+    ///
+    /// Re-indexes tools missing from the keyword index and deletes keyword
+    /// documents of the table's skills with no backing row. Returns the drift
+    /// found before repair.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table cannot be scanned or the keyword index cannot be written.
+    pub async fn repair_keyword_index(
+        &self,
+        table_name: &str,
+    ) -> Result<ConsistencyReport, VectorStoreError> {
+        let mut expected = self.keyword_docs_in_table(table_name).await?;
+        let report = self.consistency_report(table_name, &expected)?;
+        let Some(ref kw_index) = self.keyword_index else {
+            return Ok(report);
+        };
+        if report.is_consistent() {
+            return Ok(report);
+        }
+
+        let missing: Vec<(String, KeywordDoc)> = report
+            .missing_in_keyword
+            .iter()
+            .filter_map(|name| expected.remove(name))
+            .collect();
+        if !missing.is_empty() {
+            kw_index.bulk_upsert_with_skills(missing)?;
+        }
+        kw_index.delete_documents(&report.orphaned_in_keyword)?;
+        Ok(report)
+    }
+
+    /// Diff the expected keyword documents against what the keyword index holds.
+    fn consistency_report(
+        &self,
+        table_name: &str,
+        expected: &BTreeMap<String, (String, KeywordDoc)>,
+    ) -> Result<ConsistencyReport, VectorStoreError> {
+        let Some(ref kw_index) = self.keyword_index else {
+            return Ok(ConsistencyReport {
+                table_name: table_name.to_string(),
+                lance_tools: expected.len(),
+                ..ConsistencyReport::default()
+            });
+        };
+        let owned_skills: BTreeSet<&str> =
+            expected.values().map(|(skill, _)| skill.as_str()).collect();
+        let indexed: BTreeSet<String> = kw_index
+            .tool_names_in_skills(&owned_skills)?
+            .into_iter()
+            .collect();
+
+        Ok(ConsistencyReport {
+            table_name: table_name.to_string(),
+            lance_tools: expected.len(),
+            keyword_documents: indexed.len(),
+            missing_in_keyword: expected
+                .keys()
+                .filter(|name| !indexed.contains(*name))
+                .cloned()
+                .collect(),
+            orphaned_in_keyword: indexed
+                .iter()
+                .filter(|name| !expected.contains_key(*name))
+                .cloned()
+                .collect(),
+        })
+    }
+
+    /// `(skill_name, document)` pairs derived from the command rows of `table_name`,
+    /// keyed by tool name.
+    async fn keyword_docs_in_table(
+        &self,
+        table_name: &str,
+    ) -> Result<BTreeMap<String, (String, KeywordDoc)>, VectorStoreError> {
+        let table_path = self.table_path(table_name);
+        if !table_path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        let dataset = self
+            .open_dataset_at_uri(table_path.to_string_lossy().as_ref())
+            .await?;
+        let mut scanner = dataset.scan();
+        scanner.project(&[CONTENT_COLUMN, METADATA_COLUMN])?;
+        let mut stream = scanner.try_into_stream().await?;
+
+        let mut contents = Vec::new();
+        let mut metadatas = Vec::new();
+        while let Some(batch) = stream.try_next().await? {
+            let content_arr = batch
+                .column_by_name(CONTENT_COLUMN)
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+            let meta_arr = batch
+                .column_by_name(METADATA_COLUMN)
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+            let (Some(content_arr), Some(meta_arr)) = (content_arr, meta_arr) else {
+                continue;
+            };
+            for i in 0..batch.num_rows() {
+                if meta_arr.is_null(i) {
+                    continue;
+                }
+                contents.push(content_arr.value(i).to_string());
+                metadatas.push(meta_arr.value(i).to_string());
+            }
+        }
+
+        Ok(Self::keyword_docs_for(&contents, &metadatas)
+            .into_iter()
+            .map(|entry| (entry.1.0.clone(), entry))
+            .collect())
+    }
+}
@@ -32,6 +32,10 @@ impl VectorStore {
             keyword_index: None,
             keyword_backend: KeywordSearchBackend::Tantivy,
             index_cache_size_bytes: None,
+            normalize_on_insert: false,
+            reject_fake_embeddings: false,
+            strict_dual_write: false,
+            max_content_chars: None,
             query_metrics: Arc::new(DashMap::new()),
             index_progress_callback: None,
             memory_mode_id,
@@ -108,6 +112,43 @@ impl VectorStore {
         self
     }
 
+    /// L2-normalize document vectors before writing them (see [`omni_lance::normalize_l2`]).
+    #[must_use]
+    pub fn with_normalize_on_insert(mut self, enabled: bool) -> Self {
+        self.normalize_on_insert = enabled;
+        self
+    }
+
+    /// Table names whose datasets are currently cached, least recently used first.
+    ///
+    /// Tables evicted under `DatasetCacheConfig::max_cached_tables` are absent and
+    /// are reopened on next access.
+    pub async fn cached_table_names(&self) -> Vec<String> {
+        self.datasets.lock().await.keys_lru()
+    }
+
+    /// Make `add` fail instead of writing placeholder vectors (see [`VectorStoreError::FakeEmbeddingsRejected`]).
+    #[must_use]
+    pub fn with_reject_fake_embeddings(mut self, enabled: bool) -> Self {
+        self.reject_fake_embeddings = enabled;
+        self
+    }
+
+    /// Make `add_documents` all-or-nothing across LanceDB and the keyword index
+    /// (see [`VectorStoreError::DualWriteRolledBack`]).
+    #[must_use]
+    pub fn with_strict_dual_write(mut self, enabled: bool) -> Self {
+        self.strict_dual_write = enabled;
+        self
+    }
+
+    /// Truncate document content longer than `max_chars` on write (see [`ORIGINAL_CONTENT_CHARS_KEY`]).
+    #[must_use]
+    pub fn with_max_content_chars(mut self, max_chars: usize) -> Self {
+        self.max_content_chars = Some(max_chars);
+        self
+    }
+
     /// Open an existing dataset at the given URI, using optional index cache size when set.
     ///
     /// # Errors
@@ -143,6 +184,28 @@ impl VectorStore {
         }
     }
 
+    /// Path where the dataset of `table_name` is actually stored.
+    ///
+    /// Same as [`Self::table_path`] except in `:memory:` mode, where tables live
+    /// under a per-store directory in the system temp dir.
+    pub(crate) fn dataset_storage_path(
+        &self,
+        table_name: &str,
+    ) -> Result<PathBuf, VectorStoreError> {
+        if self.base_path.as_os_str() != ":memory:" {
+            return Ok(self.table_path(table_name));
+        }
+        let Some(id) = self.memory_mode_id else {
+            return Err(VectorStoreError::General(
+                "memory_mode_id missing while in :memory: mode".to_string(),
+            ));
+        };
+        Ok(std::env::temp_dir()
+            .join("omni_lance")
+            .join(format!("{id:016x}"))
+            .join(table_name))
+    }
+
     /// Create the Arrow schema for the vector store tables.
     ///
     /// Uses Dictionary encoding for low-cardinality columns
@@ -4,9 +4,10 @@
 //! are met, and table compaction to reduce fragmentation.
 
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
 
 use chrono::Duration as ChronoDuration;
+use lance::dataset::cleanup::CleanupPolicyBuilder;
 use lance::dataset::optimize::{CompactionOptions, compact_files};
 use lance_index::traits::DatasetIndexExt;
 
@@ -161,4 +162,42 @@ impl VectorStore {
             duration_ms,
         })
     }
+
+    /// Remove old dataset versions and the files only they reference; returns bytes reclaimed.
+    ///
+    /// A version is removed only if it is outside the newest `keep_last` versions (at least 1)
+    /// and older than `older_than_secs`. Removed versions can no longer be checked out.
+    pub async fn cleanup_old_versions(
+        &self,
+        table_name: &str,
+        keep_last: usize,
+        older_than_secs: u64,
+    ) -> Result<u64, VectorStoreError> {
+        let table_path = self.table_path(table_name);
+        if !table_path.exists() {
+            return Err(VectorStoreError::TableNotFound(table_name.to_string()));
+        }
+
+        let dataset = self
+            .open_dataset_at_uri(table_path.to_string_lossy().as_ref())
+            .await?;
+        let older_than =
+            ChronoDuration::seconds(i64::try_from(older_than_secs).unwrap_or(i64::MAX));
+        let policy = CleanupPolicyBuilder::default()
+            .before_timestamp(chrono::DateTime::<chrono::Utc>::from(SystemTime::now()) - older_than)
+            .retain_n_versions(&dataset, keep_last.max(1))
+            .await
+            .map_err(VectorStoreError::LanceDB)?
+            .build();
+        let stats = dataset
+            .cleanup_with_policy(policy)
+            .await
+            .map_err(VectorStoreError::LanceDB)?;
+        log::info!(
+            "Cleaned up {} old versions of '{table_name}' ({} bytes)",
+            stats.old_versions,
+            stats.bytes_removed
+        );
+        Ok(stats.bytes_removed)
+    }
 }
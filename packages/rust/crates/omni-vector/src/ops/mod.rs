@@ -3,6 +3,7 @@
 mod agentic;
 mod cache;
 pub mod column_read;
+mod consistency;
 mod maintenance;
 mod migration;
 mod observability;
@@ -18,8 +19,8 @@ pub use migration::{
     MigrateResult, MigrationItem, OMNI_SCHEMA_VERSION, schema_version_from_schema,
 };
 pub use types::{
-    CompactionStats, DocumentRow, FragmentInfo, IndexBuildProgress, IndexCacheStats, IndexStats,
-    IndexStatus, IndexThresholds, MergeInsertStats, QueryMetrics, Recommendation,
-    TableColumnAlteration, TableColumnType, TableHealthReport, TableInfo, TableNewColumn,
-    TableVersionInfo,
+    CompactionStats, ConsistencyReport, DocumentRow, FragmentInfo, IndexBuildProgress,
+    IndexCacheStats, IndexStats, IndexStatus, IndexThresholds, MergeInsertStats, QueryMetrics,
+    Recommendation, SelfTestReport, SelfTestStep, TableColumnAlteration, TableColumnType,
+    TableHealthReport, TableInfo, TableNewColumn, TableVersionInfo,
 };
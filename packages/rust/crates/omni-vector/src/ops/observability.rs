@@ -1,21 +1,30 @@
-//! Observability: table health analysis and recommendations.
+//! Observability: table health analysis, recommendations and a lifecycle self-test.
 //!
 //! Phase 5 of the `LanceDB` 2.0 roadmap.
 //! Query metrics are in-process (not from Lance tracing yet).
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use futures::TryStreamExt;
+use lance::deps::arrow_array::{Array, Float32Array, StringArray};
 
-use crate::VectorStore;
 use crate::error::VectorStoreError;
 use crate::ops::types::{
-    IndexCacheStats, IndexStatus, QueryMetrics, Recommendation, TableHealthReport,
+    IndexCacheStats, IndexStatus, QueryMetrics, Recommendation, SelfTestReport, SelfTestStep,
+    TableHealthReport,
 };
+use crate::{ID_COLUMN, VectorStore};
 
 /// Fragmentation ratio above which we recommend compaction.
 const FRAGMENTATION_RATIO_THRESHOLD: f64 = 0.01;
 /// Row count above which we recommend having indices.
 const ROW_COUNT_INDEX_THRESHOLD: usize = 1000;
+/// Prefix of the disposable tables created by [`VectorStore::self_test`].
+const SELF_TEST_TABLE_PREFIX: &str = "__omni_self_test";
+/// Ids of the two rows written by [`VectorStore::self_test`].
+const SELF_TEST_IDS: [&str; 2] = ["self_test.alpha", "self_test.beta"];
 
 impl VectorStore {
     /// Analyze table health and return a report with recommendations.
@@ -126,4 +135,176 @@ impl VectorStore {
             hit_rate,
         })
     }
+
+    /// Exercise the full table lifecycle on a disposable table: create (with an
+    /// initial write), read by id, vector search, delete and drop.
+    ///
+    /// Step failures are reported in the returned [`SelfTestReport`]; once a step
+    /// fails the remaining ones are skipped, but the table is still dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store points at a single `.lance` table directory,
+    /// where a disposable table would overwrite the real one.
+    pub async fn self_test(&self) -> Result<SelfTestReport, VectorStoreError> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        let table_name = format!("{SELF_TEST_TABLE_PREFIX}_{nanos}");
+        let storage_path = self.dataset_storage_path(&table_name)?;
+        if storage_path == self.base_path {
+            return Err(VectorStoreError::General(
+                "self_test requires a store directory, not a single .lance table path".to_string(),
+            ));
+        }
+
+        let started = Instant::now();
+        let mut steps = Vec::new();
+        self.run_self_test_steps(&table_name, &mut steps).await;
+
+        let drop_started = Instant::now();
+        let dropped = async {
+            self.datasets.lock().await.remove(&table_name);
+            if storage_path.exists() {
+                std::fs::remove_dir_all(&storage_path)?;
+            }
+            Ok(())
+        }
+        .await;
+        record_step(&mut steps, "drop", drop_started, dropped);
+
+        Ok(SelfTestReport {
+            passed: steps.iter().all(|step| step.passed),
+            total_ms: elapsed_ms(started),
+            steps,
+        })
+    }
+
+    /// Create, read, search and delete steps of [`Self::self_test`], stopping at the first failure.
+    async fn run_self_test_steps(&self, table_name: &str, steps: &mut Vec<SelfTestStep>) {
+        let alpha = vec![1.0_f32; self.dimension];
+        let beta = vec![-1.0_f32; self.dimension];
+
+        let started = Instant::now();
+        let created = async {
+            let initial = self.build_document_batch(
+                SELF_TEST_IDS.iter().map(ToString::to_string).collect(),
+                vec![alpha.clone(), beta],
+                vec!["self-test alpha".to_string(), "self-test beta".to_string()],
+                vec![serde_json::json!({ "type": "self_test" }).to_string(); 2],
+            )?;
+            let (dataset, _) = self
+                .get_or_create_dataset(table_name, true, Some(initial))
+                .await?;
+            expect_rows(&dataset, SELF_TEST_IDS.len()).await?;
+            Ok(dataset)
+        }
+        .await;
+        let Some(mut dataset) = record_step(steps, "create", started, created) else {
+            return;
+        };
+
+        let started = Instant::now();
+        let read = async {
+            let mut scanner = dataset.scan();
+            scanner.project(&[ID_COLUMN])?;
+            scanner.filter(&format!("{ID_COLUMN} = '{}'", SELF_TEST_IDS[0]))?;
+            let ids = collect_ids(scanner.try_into_stream().await?).await?;
+            if ids != [SELF_TEST_IDS[0]] {
+                return Err(VectorStoreError::General(format!(
+                    "read by id returned {ids:?}"
+                )));
+            }
+            Ok(())
+        }
+        .await;
+        if record_step(steps, "read", started, read).is_none() {
+            return;
+        }
+
+        let started = Instant::now();
+        let searched = async {
+            let mut scanner = dataset.scan();
+            scanner.project(&[ID_COLUMN])?;
+            scanner.nearest(crate::VECTOR_COLUMN, &Float32Array::from(alpha), 1)?;
+            let ids = collect_ids(scanner.try_into_stream().await?).await?;
+            if ids.first().map(String::as_str) != Some(SELF_TEST_IDS[0]) {
+                return Err(VectorStoreError::General(format!(
+                    "nearest neighbour was {ids:?}"
+                )));
+            }
+            Ok(())
+        }
+        .await;
+        if record_step(steps, "search", started, searched).is_none() {
+            return;
+        }
+
+        let started = Instant::now();
+        let deleted = async {
+            dataset
+                .delete(&format!("{ID_COLUMN} = '{}'", SELF_TEST_IDS[0]))
+                .await?;
+            expect_rows(&dataset, SELF_TEST_IDS.len() - 1).await
+        }
+        .await;
+        record_step(steps, "delete", started, deleted);
+    }
+}
+
+/// Milliseconds elapsed since `started`, saturating at `u64::MAX`.
+fn elapsed_ms(started: Instant) -> u64 {
+    u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Append a [`SelfTestStep`] for `result` and return its value on success.
+fn record_step<T>(
+    steps: &mut Vec<SelfTestStep>,
+    name: &str,
+    started: Instant,
+    result: Result<T, VectorStoreError>,
+) -> Option<T> {
+    let duration_ms = elapsed_ms(started);
+    let (value, error) = match result {
+        Ok(value) => (Some(value), None),
+        Err(e) => (None, Some(e.to_string())),
+    };
+    steps.push(SelfTestStep {
+        name: name.to_string(),
+        passed: error.is_none(),
+        duration_ms,
+        error,
+    });
+    value
+}
+
+/// Fail unless `dataset` holds exactly `expected` rows.
+async fn expect_rows(
+    dataset: &lance::dataset::Dataset,
+    expected: usize,
+) -> Result<(), VectorStoreError> {
+    let rows = dataset.count_rows(None).await?;
+    if rows == expected {
+        Ok(())
+    } else {
+        Err(VectorStoreError::General(format!(
+            "expected {expected} rows, found {rows}"
+        )))
+    }
+}
+
+/// Collect the id column of every batch in `stream`.
+async fn collect_ids(
+    mut stream: lance::dataset::scanner::DatasetRecordBatchStream,
+) -> Result<Vec<String>, VectorStoreError> {
+    let mut ids = Vec::new();
+    while let Some(batch) = stream.try_next().await? {
+        if let Some(arr) = batch
+            .column_by_name(ID_COLUMN)
+            .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+        {
+            ids.extend((0..arr.len()).map(|i| arr.value(i).to_string()));
+        }
+    }
+    Ok(ids)
 }
@@ -203,6 +203,57 @@ pub struct TableHealthReport {
     pub recommendations: Vec<Recommendation>,
 }
 
+/// One step of [crate::VectorStore::self_test].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelfTestStep {
+    /// Step name (`create`, `read`, `search`, `delete`, `drop`).
+    pub name: String,
+    /// Whether the step succeeded.
+    pub passed: bool,
+    /// Wall-clock duration of the step in milliseconds.
+    pub duration_ms: u64,
+    /// Error message when the step failed.
+    pub error: Option<String>,
+}
+
+/// Result of [crate::VectorStore::self_test]: per-step timings and an overall verdict.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SelfTestReport {
+    /// True when every step passed.
+    pub passed: bool,
+    /// Total wall-clock duration in milliseconds.
+    pub total_ms: u64,
+    /// Steps in execution order; steps after a failure are not run, except `drop`.
+    pub steps: Vec<SelfTestStep>,
+}
+
+/// Drift between a table and the keyword index, from
+/// [crate::VectorStore::verify_consistency].
+///
+/// The keyword index is keyed by canonical tool name, so only command rows
+/// (metadata `type = "command"`) of the table are compared.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ConsistencyReport {
+    /// Table that was compared.
+    pub table_name: String,
+    /// Number of distinct tool names derived from the table's command rows.
+    pub lance_tools: usize,
+    /// Number of distinct keyword-index tool names belonging to the table's skills.
+    pub keyword_documents: usize,
+    /// Tools present in LanceDB but missing from the keyword index.
+    pub missing_in_keyword: Vec<String>,
+    /// Tools of the table's skills present in the keyword index but absent from LanceDB.
+    pub orphaned_in_keyword: Vec<String>,
+}
+
+impl ConsistencyReport {
+    /// True when LanceDB and the keyword index agree.
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        self.missing_in_keyword.is_empty() && self.orphaned_in_keyword.is_empty()
+    }
+}
+
 /// Index cache statistics (Lance Dataset in-memory index cache).
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct IndexCacheStats {
@@ -9,6 +9,13 @@ fn default_write_params() -> WriteParams {
     }
 }
 
+/// Keyword-index document: `(tool_name, description, category, routing_keywords, intents)`.
+type KeywordDoc = (String, String, String, Vec<String>, Vec<String>);
+
+/// Set once the placeholder-vector warning in `VectorStore::add` has been logged.
+static FAKE_EMBEDDING_WARNED: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
 /// Build dictionary-encoded columns for low-cardinality `SKILL_NAME` and `CATEGORY`.
 #[allow(clippy::expect_used, clippy::missing_panics_doc)]
 fn build_dictionary_columns(
@@ -102,6 +109,25 @@ fn parse_metadata_value(s: &str) -> Option<serde_json::Value> {
         .or_else(|| serde_json::from_str(s).ok())
 }
 
+/// Cut `content` to at most `max_chars` chars including [`TRUNCATION_MARKER`].
+///
+/// When `max_chars` is shorter than the marker, only the part of the marker that fits is kept.
+/// Returns the original char count when truncation happened.
+fn truncate_content(content: &mut String, max_chars: usize) -> Option<usize> {
+    let original_chars = content.chars().count();
+    if original_chars <= max_chars {
+        return None;
+    }
+    let keep = max_chars.saturating_sub(TRUNCATION_MARKER.chars().count());
+    let cut = content
+        .char_indices()
+        .nth(keep)
+        .map_or(content.len(), |(idx, _)| idx);
+    content.truncate(cut);
+    content.extend(TRUNCATION_MARKER.chars().take(max_chars - keep));
+    Some(original_chars)
+}
+
 /// Single-pass metadata extraction for Arrow-native columns (avoids full Value tree).
 #[derive(serde::Deserialize, Default)]
 struct MetadataExtract {
@@ -151,6 +177,126 @@ impl VectorStore {
         out
     }
 
+    /// Mirror freshly appended rows into the keyword index.
+    ///
+    /// A keyword failure is logged and tolerated unless `strict_dual_write` is set;
+    /// then the LanceDB append is undone (restoring `prev_version`, or deleting
+    /// `appended_ids` when the write created the table) and an error is returned.
+    async fn finish_dual_write(
+        &self,
+        table_name: &str,
+        dataset: Dataset,
+        prev_version: Option<u64>,
+        appended_ids: &[String],
+        contents: &[String],
+        metadatas: &[String],
+    ) -> Result<(), VectorStoreError> {
+        let Some(ref kw_index) = self.keyword_index else {
+            return Ok(());
+        };
+        let keyword_docs = Self::keyword_docs_for(contents, metadatas);
+        if keyword_docs.is_empty() {
+            return Ok(());
+        }
+        let Err(e) = kw_index.bulk_upsert_with_skills(keyword_docs) else {
+            return Ok(());
+        };
+        if !self.strict_dual_write {
+            // LanceDB already has the rows; `verify_consistency` detects the drift.
+            log::warn!("Keyword index write failed for table {table_name}: {e}");
+            return Ok(());
+        }
+
+        let rollback = match prev_version {
+            Some(version) => self.restore_version(table_name, &dataset, version).await,
+            None => {
+                self.delete_appended(table_name, dataset, appended_ids)
+                    .await
+            }
+        };
+        if let Err(rollback_error) = rollback {
+            return Err(VectorStoreError::DualWriteRollbackFailed {
+                table: table_name.to_string(),
+                reason: e.to_string(),
+                rollback_error: rollback_error.to_string(),
+            });
+        }
+        Err(VectorStoreError::DualWriteRolledBack {
+            table: table_name.to_string(),
+            reason: e.to_string(),
+        })
+    }
+
+    /// Restore `version` of `table_name` as its latest version and cache it.
+    async fn restore_version(
+        &self,
+        table_name: &str,
+        dataset: &Dataset,
+        version: u64,
+    ) -> Result<(), VectorStoreError> {
+        let mut restored = dataset.checkout_version(version).await?;
+        restored.restore().await?;
+        self.datasets
+            .lock()
+            .await
+            .insert(table_name.to_string(), restored);
+        Ok(())
+    }
+
+    /// Delete `ids` from a table created by the failed write and cache the result.
+    async fn delete_appended(
+        &self,
+        table_name: &str,
+        mut dataset: Dataset,
+        ids: &[String],
+    ) -> Result<(), VectorStoreError> {
+        let escaped: Vec<String> = ids.iter().map(|id| id.replace('\'', "''")).collect();
+        dataset
+            .delete(&format!("{ID_COLUMN} IN ('{}')", escaped.join("','")))
+            .await?;
+        self.datasets
+            .lock()
+            .await
+            .insert(table_name.to_string(), dataset);
+        Ok(())
+    }
+
+    /// Build keyword-index documents for the command rows among `metadatas`.
+    ///
+    /// Returns `(skill_name, document)` pairs in the shape accepted by
+    /// [`KeywordIndex::bulk_upsert_with_skills`].
+    fn keyword_docs_for(contents: &[String], metadatas: &[String]) -> Vec<(String, KeywordDoc)> {
+        let mut keyword_docs = Vec::new();
+        for (content, meta_str) in contents.iter().zip(metadatas) {
+            let Some(meta) = parse_metadata_value(meta_str) else {
+                continue;
+            };
+            if meta.get("type").and_then(|s| s.as_str()) != Some("command") {
+                continue;
+            }
+            let Some(name) = Self::canonical_tool_name_from_metadata(&meta) else {
+                continue;
+            };
+            let category = meta
+                .get("category")
+                .and_then(|s| s.as_str())
+                .or_else(|| meta.get("skill_name").and_then(|s| s.as_str()))
+                .unwrap_or("unknown")
+                .to_string();
+            let skill = meta
+                .get("skill_name")
+                .and_then(|s| s.as_str())
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .unwrap_or_else(|| name.split('.').next().unwrap_or(""))
+                .to_string();
+            let kws = crate::skill::resolve_routing_keywords(&meta);
+            let intents = crate::skill::resolve_intents(&meta);
+            keyword_docs.push((skill, (name, content.clone(), category, kws, intents)));
+        }
+        keyword_docs
+    }
+
     fn canonical_tool_name_from_metadata(meta: &serde_json::Value) -> Option<String> {
         let skill_name = meta
             .get("skill_name")
@@ -192,7 +338,7 @@ impl VectorStore {
     fn build_document_batch(
         &self,
         ids: Vec<String>,
-        vectors: Vec<Vec<f32>>,
+        mut vectors: Vec<Vec<f32>>,
         contents: Vec<String>,
         metadatas: Vec<String>,
     ) -> Result<
@@ -228,6 +374,12 @@ impl VectorStore {
             ));
         }
 
+        if self.normalize_on_insert {
+            for v in &mut vectors {
+                omni_lance::normalize_l2(v);
+            }
+        }
+
         let id_array = StringArray::from(ids.clone());
         let content_array = StringArray::from(contents);
         let flat_values: Vec<f32> = vectors.into_iter().flatten().collect();
@@ -325,6 +477,11 @@ impl VectorStore {
         if tools.is_empty() {
             return Ok(());
         }
+        if self.reject_fake_embeddings {
+            return Err(VectorStoreError::FakeEmbeddingsRejected(
+                table_name.to_string(),
+            ));
+        }
 
         // 1. Write to Keyword Index if enabled
         if let Some(kw_index) = &self.keyword_index {
@@ -350,6 +507,7 @@ impl VectorStore {
                         intents: t.intents.clone(),
                         category: t.category.clone(),
                         parameters: t.parameters.clone(),
+                        explanation: None,
                     }
                 })
                 .collect();
@@ -394,6 +552,13 @@ impl VectorStore {
             .collect();
 
         // Standard vectors (dummy for now as SkillIndexer provides actual vectors via add_documents)
+        if !FAKE_EMBEDDING_WARNED.swap(true, std::sync::atomic::Ordering::Relaxed) {
+            log::warn!(
+                "VectorStore::add is writing placeholder vectors for table {table_name}; \
+                 semantic search over these rows is meaningless. Use add_documents with real \
+                 embeddings or enable reject_fake_embeddings."
+            );
+        }
         let vectors: Vec<Vec<f32>> = (0..ids.len()).map(|_| vec![0.0; self.dimension]).collect();
 
         self.add_documents(table_name, ids, vectors, contents, metadatas)
@@ -401,28 +566,78 @@ impl VectorStore {
         Ok(())
     }
 
+    /// Apply `max_content_chars`: truncate over-length content and record the original
+    /// length under [`ORIGINAL_CONTENT_CHARS_KEY`] in object metadata.
+    fn cap_content_lengths(
+        &self,
+        ids: &[String],
+        contents: &mut [String],
+        metadatas: &mut [String],
+    ) {
+        let Some(max_chars) = self.max_content_chars else {
+            return;
+        };
+        for ((id, content), metadata) in ids
+            .iter()
+            .zip(contents.iter_mut())
+            .zip(metadatas.iter_mut())
+        {
+            let Some(original_chars) = truncate_content(content, max_chars) else {
+                continue;
+            };
+            log::warn!(
+                "Truncated content for '{id}' from {original_chars} to {max_chars} chars (max_content_chars)"
+            );
+            let meta = if metadata.trim().is_empty() {
+                Some(serde_json::Value::Object(serde_json::Map::new()))
+            } else {
+                parse_metadata_value(metadata)
+            };
+            let Some(serde_json::Value::Object(mut obj)) = meta else {
+                log::warn!(
+                    "Metadata for '{id}' is not a JSON object; {ORIGINAL_CONTENT_CHARS_KEY} not recorded"
+                );
+                continue;
+            };
+            obj.insert(
+                ORIGINAL_CONTENT_CHARS_KEY.to_string(),
+                serde_json::Value::from(original_chars),
+            );
+            *metadata = serde_json::Value::Object(obj).to_string();
+        }
+    }
+
     /// Batch add documents with vectors to a table.
+    ///
+    /// Content longer than `max_content_chars` (when set) is truncated before writing.
     pub async fn add_documents(
         &self,
         table_name: &str,
         ids: Vec<String>,
         vectors: Vec<Vec<f32>>,
-        contents: Vec<String>,
-        metadatas: Vec<String>,
+        mut contents: Vec<String>,
+        mut metadatas: Vec<String>,
     ) -> Result<(), VectorStoreError> {
         use lance::deps::arrow_array::RecordBatchIterator;
 
         if ids.is_empty() {
             return Ok(());
         }
+        self.cap_content_lengths(&ids, &mut contents, &mut metadatas);
 
         let contents_for_keyword = contents.clone();
         let metadatas_for_keyword = metadatas.clone();
+        let ids_for_rollback = if self.strict_dual_write {
+            ids.clone()
+        } else {
+            Vec::new()
+        };
         let (schema, batch) = self.build_document_batch(ids, vectors, contents, metadatas)?;
 
         let (mut dataset, created) = self
             .get_or_create_dataset(table_name, false, Some((schema.clone(), batch.clone())))
             .await?;
+        let prev_version = (!created).then(|| dataset.version().version);
         if !created {
             dataset
                 .append(
@@ -433,38 +648,15 @@ impl VectorStore {
         }
 
         // DUAL WRITE: Also write to Keyword Index if enabled
-        if let Some(ref kw_index) = self.keyword_index {
-            let mut keyword_docs = Vec::new();
-            for (i, meta_str) in metadatas_for_keyword.iter().enumerate() {
-                if let Some(meta) = parse_metadata_value(meta_str) {
-                    if meta.get("type").and_then(|s| s.as_str()) != Some("command") {
-                        continue;
-                    }
-                    let Some(name) = Self::canonical_tool_name_from_metadata(&meta) else {
-                        continue;
-                    };
-                    let category = meta
-                        .get("category")
-                        .and_then(|s| s.as_str())
-                        .or_else(|| meta.get("skill_name").and_then(|s| s.as_str()))
-                        .unwrap_or("unknown")
-                        .to_string();
-                    let kws = crate::skill::resolve_routing_keywords(&meta);
-                    let intents = crate::skill::resolve_intents(&meta);
-                    keyword_docs.push((
-                        name,
-                        contents_for_keyword[i].clone(),
-                        category,
-                        kws,
-                        intents,
-                    ));
-                }
-            }
-            if !keyword_docs.is_empty() {
-                let _ = kw_index.bulk_upsert(keyword_docs);
-            }
-        }
-        Ok(())
+        self.finish_dual_write(
+            table_name,
+            dataset,
+            prev_version,
+            &ids_for_rollback,
+            &contents_for_keyword,
+            &metadatas_for_keyword,
+        )
+        .await
     }
 
     /// Add documents with rows grouped by a partition column so fragments align by partition
@@ -475,8 +667,8 @@ impl VectorStore {
         partition_by: &str,
         ids: Vec<String>,
         vectors: Vec<Vec<f32>>,
-        contents: Vec<String>,
-        metadatas: Vec<String>,
+        mut contents: Vec<String>,
+        mut metadatas: Vec<String>,
     ) -> Result<(), VectorStoreError> {
         use lance::deps::arrow_array::RecordBatchIterator;
         use std::collections::BTreeMap;
@@ -490,6 +682,7 @@ impl VectorStore {
                 "Mismatched input lengths for ids/vectors/contents/metadatas".to_string(),
             ));
         }
+        self.cap_content_lengths(&ids, &mut contents, &mut metadatas);
 
         let partition_values: Vec<String> = metadatas
             .iter()
@@ -513,6 +706,7 @@ impl VectorStore {
         let metadatas_for_keyword = metadatas.clone();
 
         let (mut dataset, _) = self.get_or_create_dataset(table_name, false, None).await?;
+        let prev_version = dataset.version().version;
         let schema = self.create_schema();
 
         for (_partition_value, indices) in groups {
@@ -525,46 +719,29 @@ impl VectorStore {
             let (_, batch) =
                 self.build_document_batch(part_ids, part_vectors, part_contents, part_metadatas)?;
             let batches: Vec<Result<_, crate::error::ArrowError>> = vec![Ok(batch)];
-            dataset
+            if let Err(e) = dataset
                 .append(
                     Box::new(RecordBatchIterator::new(batches, schema.clone())),
                     Some(default_write_params()),
                 )
-                .await?;
-        }
-
-        if let Some(ref kw_index) = self.keyword_index {
-            let mut keyword_docs = Vec::new();
-            for (i, meta_str) in metadatas_for_keyword.iter().enumerate() {
-                if let Some(meta) = parse_metadata_value(meta_str) {
-                    if meta.get("type").and_then(|s| s.as_str()) != Some("command") {
-                        continue;
-                    }
-                    let Some(name) = Self::canonical_tool_name_from_metadata(&meta) else {
-                        continue;
-                    };
-                    let category = meta
-                        .get("category")
-                        .and_then(|s| s.as_str())
-                        .or_else(|| meta.get("skill_name").and_then(|s| s.as_str()))
-                        .unwrap_or("unknown")
-                        .to_string();
-                    let kws = crate::skill::resolve_routing_keywords(&meta);
-                    let intents = crate::skill::resolve_intents(&meta);
-                    keyword_docs.push((
-                        name,
-                        contents_for_keyword[i].clone(),
-                        category,
-                        kws,
-                        intents,
-                    ));
-                }
-            }
-            if !keyword_docs.is_empty() {
-                let _ = kw_index.bulk_upsert(keyword_docs);
+                .await
+            {
+                // Undo the partitions appended so far rather than leave a partial write.
+                self.restore_version(table_name, &dataset, prev_version)
+                    .await?;
+                return Err(e.into());
             }
         }
-        Ok(())
+
+        self.finish_dual_write(
+            table_name,
+            dataset,
+            Some(prev_version),
+            &ids,
+            &contents_for_keyword,
+            &metadatas_for_keyword,
+        )
+        .await
     }
 
     /// Replace all documents in a table with the provided batch atomically
@@ -600,8 +777,8 @@ impl VectorStore {
         table_name: &str,
         ids: Vec<String>,
         vectors: Vec<Vec<f32>>,
-        contents: Vec<String>,
-        metadatas: Vec<String>,
+        mut contents: Vec<String>,
+        mut metadatas: Vec<String>,
         match_on: &str,
     ) -> Result<MergeInsertStats, VectorStoreError> {
         use lance::dataset::{MergeInsertBuilder, WhenMatched, WhenNotMatched};
@@ -610,6 +787,7 @@ impl VectorStore {
         if ids.is_empty() {
             return Ok(MergeInsertStats::default());
         }
+        self.cap_content_lengths(&ids, &mut contents, &mut metadatas);
 
         let (schema, batch) = self.build_document_batch(ids, vectors, contents, metadatas)?;
         let source_batches: Vec<Result<_, crate::error::ArrowError>> = vec![Ok(batch)];
@@ -664,23 +842,10 @@ impl VectorStore {
     ) -> Result<(Dataset, bool), VectorStoreError> {
         use lance::deps::arrow_array::RecordBatchIterator;
 
-        let table_path = self.table_path(table_name);
-        let is_memory_mode = self.base_path.as_os_str() == ":memory:";
-        let write_uri = if is_memory_mode {
-            let Some(id) = self.memory_mode_id else {
-                return Err(VectorStoreError::General(
-                    "memory_mode_id missing while in :memory: mode".to_string(),
-                ));
-            };
-            std::env::temp_dir()
-                .join("omni_lance")
-                .join(format!("{id:016x}"))
-                .join(table_name)
-                .to_string_lossy()
-                .into_owned()
-        } else {
-            table_path.to_string_lossy().into_owned()
-        };
+        let write_uri = self
+            .dataset_storage_path(table_name)?
+            .to_string_lossy()
+            .into_owned();
         let write_path = std::path::Path::new(&write_uri);
 
         {
@@ -1,6 +1,6 @@
 use crate::{
     CONTENT_COLUMN, FILE_PATH_COLUMN, ID_COLUMN, INTENTS_COLUMN, ROUTING_KEYWORDS_COLUMN,
-    TOOL_NAME_COLUMN,
+    ScoreTransform, TOOL_NAME_COLUMN,
 };
 
 /// Tunable scanner options for vector search.
@@ -21,6 +21,8 @@ pub struct SearchOptions {
     pub projected_columns: Vec<&'static str>,
     /// Columns to include in IPC output (None = all). Reduces transfer when caller needs only id, content, _distance, etc.
     pub ipc_projection: Option<Vec<String>>,
+    /// Distance → similarity conversion for callers that score results (e.g. hybrid search).
+    pub score_transform: ScoreTransform,
 }
 
 impl Default for SearchOptions {
@@ -40,6 +42,7 @@ impl Default for SearchOptions {
                 INTENTS_COLUMN,
             ],
             ipc_projection: None,
+            score_transform: ScoreTransform::default(),
         }
     }
 }
@@ -437,7 +437,7 @@ fn search_results_to_ipc(
 /// Schema: name, description, score, `skill_name`, `tool_name`, `file_path`,
 /// `routing_keywords` (List<Utf8>), intents (List<Utf8>), category, metadata (Utf8 JSON),
 /// `vector_score`, `keyword_score`, `final_score`, `confidence`, `ranking_reason`,
-/// `input_schema_digest`.
+/// `input_schema_digest`, `explanation` (Utf8 JSON, null when the ranker reported none).
 /// Python `ToolSearchPayload.from_arrow_table` consumes this canonical contract directly.
 #[allow(clippy::too_many_lines)]
 pub(crate) fn tool_search_results_to_ipc(
@@ -521,6 +521,14 @@ pub(crate) fn tool_search_results_to_ipc(
     let confidence_refs: Vec<&str> = confidences.iter().map(String::as_str).collect();
     let ranking_reason_refs: Vec<&str> = ranking_reasons.iter().map(String::as_str).collect();
     let digest_refs: Vec<&str> = input_schema_digests.iter().map(String::as_str).collect();
+    let explanations: Vec<Option<String>> = results
+        .iter()
+        .map(|r| {
+            r.explanation
+                .as_ref()
+                .and_then(|explanation| serde_json::to_string(explanation).ok())
+        })
+        .collect();
 
     let mut rk_builder = ListBuilder::new(StringBuilder::new());
     for r in results {
@@ -565,6 +573,7 @@ pub(crate) fn tool_search_results_to_ipc(
         Field::new("confidence", DataType::Utf8, true),
         Field::new("ranking_reason", DataType::Utf8, true),
         Field::new("input_schema_digest", DataType::Utf8, true),
+        Field::new("explanation", DataType::Utf8, true),
     ]);
 
     let batch = RecordBatch::try_new(
@@ -586,6 +595,7 @@ pub(crate) fn tool_search_results_to_ipc(
             Arc::new(StringArray::from(confidence_refs)),
             Arc::new(StringArray::from(ranking_reason_refs)),
             Arc::new(StringArray::from(digest_refs)),
+            Arc::new(StringArray::from(explanations)),
         ],
     )
     .map_err(|e| e.to_string())?;
@@ -1098,6 +1108,7 @@ impl VectorStore {
                     intents,
                     category,
                     parameters,
+                    explanation: None,
                 });
             }
         }
@@ -1174,25 +1185,46 @@ impl VectorStore {
     /// # Errors
     ///
     /// Returns an error if vector search fails.
-    #[allow(clippy::cast_possible_truncation)]
     pub async fn hybrid_search(
         &self,
         table_name: &str,
         query: &str,
         query_vector: Vec<f32>,
         limit: usize,
+    ) -> Result<Vec<HybridSearchResult>, VectorStoreError> {
+        self.hybrid_search_with_options(
+            table_name,
+            query,
+            query_vector,
+            limit,
+            SearchOptions::default(),
+        )
+        .await
+    }
+
+    /// [`VectorStore::hybrid_search`] with explicit vector-side search options.
+    ///
+    /// Vector distances are converted to scores with `options.score_transform` before fusion.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if vector search fails.
+    #[allow(clippy::cast_possible_truncation)]
+    pub async fn hybrid_search_with_options(
+        &self,
+        table_name: &str,
+        query: &str,
+        query_vector: Vec<f32>,
+        limit: usize,
+        options: SearchOptions,
     ) -> Result<Vec<HybridSearchResult>, VectorStoreError> {
         let table_path = self.table_path(table_name);
         if !table_path.exists() {
             return Err(VectorStoreError::TableNotFound(table_name.to_string()));
         }
 
-        let vector_fut = self.search_optimized(
-            table_name,
-            query_vector,
-            limit * 2,
-            SearchOptions::default(),
-        );
+        let score_transform = options.score_transform;
+        let vector_fut = self.search_optimized(table_name, query_vector, limit * 2, options);
         let kw_fut = async {
             match self.keyword_search(table_name, query, limit * 2).await {
                 Ok(v) => Ok(v),
@@ -1206,7 +1238,7 @@ impl VectorStore {
 
         let vector_scores: Vec<(String, f32)> = vector_results
             .iter()
-            .map(|r| (r.id.clone(), 1.0 - r.distance as f32))
+            .map(|r| (r.id.clone(), score_transform.apply(r.distance as f32)))
             .collect();
 
         let fused_results = apply_weighted_rrf(
@@ -1438,6 +1470,7 @@ mod tests {
             intents: vec!["Save changes".to_string()],
             category: "vcs".to_string(),
             parameters: vec![],
+            explanation: None,
         };
         let bytes = tool_search_results_to_ipc(&[r]).unwrap();
         assert!(!bytes.is_empty());
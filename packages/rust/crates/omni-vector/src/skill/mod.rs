@@ -44,10 +44,98 @@ pub struct ToolSearchResult {
     pub category: String,
     /// Parameter names from index (for param-type boost when input_schema is empty).
     pub parameters: Vec<String>,
+    /// Breakdown of `score` into fusion components, when hybrid ranking produced it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<crate::keyword::RankingExplanation>,
+}
+
+impl ToolSearchResult {
+    /// Reset every field not listed in `fields` to its empty/default value.
+    ///
+    /// An empty `fields` slice keeps the full result.
+    pub fn project(&mut self, fields: &[ToolResultField]) {
+        if fields.is_empty() {
+            return;
+        }
+        let keep = |field| fields.contains(&field);
+        if !keep(ToolResultField::Name) {
+            self.name.clear();
+        }
+        if !keep(ToolResultField::Description) {
+            self.description.clear();
+        }
+        if !keep(ToolResultField::InputSchema) {
+            self.input_schema = serde_json::json!({});
+        }
+        if !keep(ToolResultField::Score) {
+            self.score = 0.0;
+            self.vector_score = None;
+            self.keyword_score = None;
+            self.explanation = None;
+        }
+        if !keep(ToolResultField::SkillName) {
+            self.skill_name.clear();
+        }
+        if !keep(ToolResultField::ToolName) {
+            self.tool_name.clear();
+        }
+        if !keep(ToolResultField::FilePath) {
+            self.file_path.clear();
+        }
+        if !keep(ToolResultField::RoutingKeywords) {
+            self.routing_keywords.clear();
+        }
+        if !keep(ToolResultField::Intents) {
+            self.intents.clear();
+        }
+        if !keep(ToolResultField::Category) {
+            self.category.clear();
+        }
+        if !keep(ToolResultField::Parameters) {
+            self.parameters.clear();
+        }
+    }
+}
+
+/// Field of [`ToolSearchResult`] selectable through [`ToolSearchOptions::projection`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToolResultField {
+    /// `name`
+    Name,
+    /// `description`
+    Description,
+    /// `input_schema`
+    InputSchema,
+    /// `score`, `vector_score` and `keyword_score`
+    Score,
+    /// `skill_name`
+    SkillName,
+    /// `tool_name`
+    ToolName,
+    /// `file_path`
+    FilePath,
+    /// `routing_keywords`
+    RoutingKeywords,
+    /// `intents`
+    Intents,
+    /// `category`
+    Category,
+    /// `parameters`
+    Parameters,
+}
+
+/// How `search_tools_multi` combines per-query scores for the same tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeStrategy {
+    /// Keep the best score any query gave the tool.
+    #[default]
+    MaxScore,
+    /// Average the scores across all queries (a query that did not return the tool counts as 0).
+    MeanScore,
 }
 
 /// Optional runtime controls for `search_tools` ranking pipeline.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct ToolSearchOptions {
     /// Whether to apply metadata-aware rerank bonus after fusion.
     ///
@@ -62,6 +150,29 @@ pub struct ToolSearchOptions {
     /// Override keyword (BM25) weight for weighted-RRF fusion.
     /// When `None`, falls back to the global `KEYWORD_WEIGHT` constant.
     pub keyword_weight: Option<f32>,
+
+    /// Distance → similarity conversion applied to vector-side scores.
+    pub score_transform: crate::ScoreTransform,
+
+    /// Result fields to populate; empty returns the full `ToolSearchResult`.
+    ///
+    /// Ranking still sees every field. Unrequested fields are reset afterwards,
+    /// and `input_schema` JSON is not parsed unless requested.
+    pub projection: Vec<ToolResultField>,
+
+    /// Keep only results scoring at least `top_score * relative_threshold`.
+    ///
+    /// Applied after sorting, in addition to the absolute `threshold`: a result
+    /// must pass both cutoffs to be returned.
+    pub relative_threshold: Option<f32>,
+}
+
+impl ToolSearchOptions {
+    /// Whether `field` is part of the requested projection.
+    #[must_use]
+    pub fn includes_field(&self, field: ToolResultField) -> bool {
+        self.projection.is_empty() || self.projection.contains(&field)
+    }
 }
 
 impl Default for ToolSearchOptions {
@@ -70,6 +181,9 @@ impl Default for ToolSearchOptions {
             rerank: true,
             semantic_weight: None,
             keyword_weight: None,
+            score_transform: crate::ScoreTransform::default(),
+            projection: Vec::new(),
+            relative_threshold: None,
         }
     }
 }
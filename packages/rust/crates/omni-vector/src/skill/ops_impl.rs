@@ -496,15 +496,135 @@ impl VectorStore {
         .await
     }
 
+    /// Search with several query vectors and merge the results per tool.
+    ///
+    /// Each query fetches up to `4 * limit` candidates; scores for the same tool are
+    /// combined with `strategy` (a query that did not return the tool counts as 0
+    /// for `MeanScore`), then the merged list is sorted and truncated to `limit`.
+    pub async fn search_tools_multi(
+        &self,
+        table_name: &str,
+        queries: &[Vec<f32>],
+        limit: usize,
+        strategy: skill::MergeStrategy,
+    ) -> Result<Vec<skill::ToolSearchResult>, VectorStoreError> {
+        const CANDIDATES_PER_RESULT: usize = 4;
+        type Scores = (Vec<f32>, Vec<f32>);
+
+        if queries.is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+        // All queries read the same table version, so a concurrent write cannot
+        // make one query see rows another did not.
+        let snapshot = self.open_scan_snapshot(table_name).await;
+        let per_query_limit = limit.saturating_mul(CANDIDATES_PER_RESULT);
+
+        let mut merged: std::collections::HashMap<String, (skill::ToolSearchResult, Scores)> =
+            std::collections::HashMap::new();
+        for query in queries {
+            for result in self
+                .search_tools_in_snapshot(
+                    snapshot.as_ref(),
+                    table_name,
+                    query,
+                    None,
+                    per_query_limit,
+                    0.0,
+                    skill::ToolSearchOptions::default(),
+                    None,
+                )
+                .await?
+            {
+                let score = result.score;
+                let vector_score = result.vector_score;
+                let (_, (scores, vector_scores)) = merged
+                    .entry(result.name.clone())
+                    .or_insert_with(|| (result, Scores::default()));
+                scores.push(score);
+                vector_scores.extend(vector_score);
+            }
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let query_count = queries.len() as f32;
+        let combine = |scores: &[f32]| match strategy {
+            skill::MergeStrategy::MaxScore => {
+                scores.iter().copied().fold(f32::NEG_INFINITY, f32::max)
+            }
+            skill::MergeStrategy::MeanScore => scores.iter().sum::<f32>() / query_count,
+        };
+        let mut results: Vec<skill::ToolSearchResult> = merged
+            .into_values()
+            .map(|(mut result, (scores, vector_scores))| {
+                result.score = combine(&scores);
+                result.vector_score = (!vector_scores.is_empty()).then(|| combine(&vector_scores));
+                result
+            })
+            .collect();
+        results.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        results.truncate(limit);
+        Ok(results)
+    }
+
     /// Search for tools with explicit ranking options.
     /// When `where_filter` is set (e.g. `skill_name = 'git'`), only rows matching the predicate are scanned.
+    ///
+    /// Isolation: the vector scan reads the single table version that is latest when the
+    /// call starts (snapshot isolation). A concurrent `add_documents` or delete commits a new
+    /// version atomically, so the scan sees all of its rows or none of them. The keyword
+    /// index used for `query_text` is not versioned and may already include newer writes.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn search_tools_with_options(
+        &self,
+        table_name: &str,
+        query_vector: &[f32],
+        query_text: Option<&str>,
+        limit: usize,
+        threshold: f32,
+        options: skill::ToolSearchOptions,
+        where_filter: Option<&str>,
+    ) -> Result<Vec<skill::ToolSearchResult>, VectorStoreError> {
+        let snapshot = self.open_scan_snapshot(table_name).await;
+        self.search_tools_in_snapshot(
+            snapshot.as_ref(),
+            table_name,
+            query_vector,
+            query_text,
+            limit,
+            threshold,
+            options,
+            where_filter,
+        )
+        .await
+    }
+
+    /// Open the latest version of a table for a read-only scan.
+    ///
+    /// The returned dataset stays pinned to that version even if writers commit newer
+    /// ones. Returns `None` when the table does not exist or cannot be opened.
+    async fn open_scan_snapshot(&self, table_name: &str) -> Option<Dataset> {
+        let table_path = self.table_path(table_name);
+        if !table_path.exists() {
+            return None;
+        }
+        self.open_dataset_at_uri(table_path.to_string_lossy().as_ref())
+            .await
+            .ok()
+    }
+
     #[allow(
         clippy::too_many_arguments,
         clippy::too_many_lines,
         clippy::collapsible_if
     )]
-    pub async fn search_tools_with_options(
+    async fn search_tools_in_snapshot(
         &self,
+        snapshot: Option<&Dataset>,
         table_name: &str,
         query_vector: &[f32],
         query_text: Option<&str>,
@@ -513,14 +633,12 @@ impl VectorStore {
         options: skill::ToolSearchOptions,
         where_filter: Option<&str>,
     ) -> Result<Vec<skill::ToolSearchResult>, VectorStoreError> {
+        let parse_input_schema = options.includes_field(skill::ToolResultField::InputSchema);
         let mut results_map: std::collections::HashMap<String, skill::ToolSearchResult> =
             std::collections::HashMap::new();
         let table_path = self.table_path(table_name);
         if table_path.exists() {
-            if let Ok(dataset) = self
-                .open_dataset_at_uri(table_path.to_string_lossy().as_ref())
-                .await
-            {
+            if let Some(dataset) = snapshot {
                 let schema = dataset.schema();
                 let has_metadata = schema.field(METADATA_COLUMN).is_some();
                 let project_cols: Vec<&str> = if has_metadata {
@@ -630,7 +748,7 @@ impl VectorStore {
                                             let diff = db_val - query_val;
                                             dist_sq += diff * diff;
                                         }
-                                        let score = 1.0 / (1.0 + dist_sq.sqrt());
+                                        let score = options.score_transform.apply(dist_sq.sqrt());
                                         let row_id = i_arr.value(i).to_string();
                                         let (
                                             canonical_tool_name,
@@ -723,10 +841,14 @@ impl VectorStore {
                                                     })
                                                     .unwrap_or("")
                                                     .to_string();
-                                                let schema = meta.get("input_schema").map_or_else(
-                                                    || serde_json::json!({}),
-                                                    skill::normalize_input_schema_value,
-                                                );
+                                                let schema = if parse_input_schema {
+                                                    meta.get("input_schema").map_or_else(
+                                                        || serde_json::json!({}),
+                                                        skill::normalize_input_schema_value,
+                                                    )
+                                                } else {
+                                                    serde_json::json!({})
+                                                };
                                                 (canon, skill, file_path, rk, inv, cat, schema)
                                             } else {
                                                 continue;
@@ -786,6 +908,7 @@ impl VectorStore {
                                                 intents,
                                                 category,
                                                 parameters: vec![],
+                                                explanation: None,
                                             },
                                         );
                                     }
@@ -806,7 +929,7 @@ impl VectorStore {
                 .keyword_search(table_name, text, limit * 2)
                 .await
                 .unwrap_or_default();
-            let fused = apply_weighted_rrf(
+            let fused = apply_weighted_rrf_explained(
                 vector_scores,
                 kw_hits.clone(),
                 keyword::RRF_K,
@@ -827,7 +950,7 @@ impl VectorStore {
                 ) || part.starts_with("*.")
             });
 
-            for f in fused {
+            for (f, mut explanation) in fused {
                 if let Some(mut tool) = results_map
                     .get(&f.tool_name)
                     .cloned()
@@ -844,9 +967,11 @@ impl VectorStore {
                             }
                         }
                         tool.score += rerank_bonus;
+                        explanation.boost += rerank_bonus;
                     }
                     tool.vector_score = Some(f.vector_score);
                     tool.keyword_score = Some(f.keyword_score);
+                    tool.explanation = Some(explanation);
                     new_map.insert(f.tool_name, tool);
                 }
             }
@@ -861,7 +986,14 @@ impl VectorStore {
                 .total_cmp(&a.score)
                 .then_with(|| a.tool_name.cmp(&b.tool_name))
         });
+        if let (Some(ratio), Some(top)) = (options.relative_threshold, res.first()) {
+            let cutoff = top.score * ratio;
+            res.retain(|r| r.score >= cutoff);
+        }
         res.truncate(limit);
+        for tool in &mut res {
+            tool.project(&options.projection);
+        }
         Ok(res)
     }
 
@@ -871,7 +1003,8 @@ impl VectorStore {
         table_name: &str,
     ) -> Result<Vec<skill::ToolSearchResult>, VectorStoreError> {
         // ... (existing implementation)
-        self.get_tools_by_skill_internal(table_name, None).await
+        self.get_tools_by_skill_internal(table_name, None, None)
+            .await
     }
 
     /// Get all tools belonging to a specific skill.
@@ -879,7 +1012,20 @@ impl VectorStore {
         &self,
         skill_name: &str,
     ) -> Result<Vec<skill::ToolSearchResult>, VectorStoreError> {
-        self.get_tools_by_skill_internal("tools", Some(skill_name))
+        self.get_tools_by_skill_internal("tools", Some(skill_name), None)
+            .await
+    }
+
+    /// Fetch tools by row ID with an `id IN (...)` filter (no vector search).
+    ///
+    /// Results follow the order of `ids`; IDs that are not found are skipped and
+    /// repeated IDs are returned once.
+    pub async fn get_by_ids(
+        &self,
+        table_name: &str,
+        ids: &[String],
+    ) -> Result<Vec<skill::ToolSearchResult>, VectorStoreError> {
+        self.get_tools_by_skill_internal(table_name, None, Some(ids))
             .await
     }
 
@@ -888,9 +1034,10 @@ impl VectorStore {
         &self,
         table_name: &str,
         skill_filter: Option<&str>,
+        id_filter: Option<&[String]>,
     ) -> Result<Vec<skill::ToolSearchResult>, VectorStoreError> {
         let table_path = self.table_path(table_name);
-        if !table_path.exists() {
+        if !table_path.exists() || id_filter.is_some_and(<[String]>::is_empty) {
             return Ok(Vec::new());
         }
         let dataset = self
@@ -920,17 +1067,31 @@ impl VectorStore {
                 crate::CATEGORY_COLUMN,
             ]
         };
+        let mut project_cols = project_cols;
+        if id_filter.is_some() {
+            project_cols.push(ID_COLUMN);
+        }
         let mut scanner = dataset.scan();
         scanner.project(&project_cols)?;
 
+        let mut predicates = Vec::new();
         if let Some(skill) = skill_filter {
-            scanner.filter(&format!("skill_name = '{skill}'"))?;
+            predicates.push(format!("skill_name = '{skill}'"));
+        }
+        if let Some(ids) = id_filter {
+            let escaped: Vec<String> = ids.iter().map(|id| id.replace('\'', "''")).collect();
+            predicates.push(format!("{ID_COLUMN} IN ('{}')", escaped.join("','")));
+        }
+        if !predicates.is_empty() {
+            scanner.filter(&predicates.join(" AND "))?;
         }
 
         let mut stream = scanner.try_into_stream().await?;
         let mut tools = Vec::new();
+        let mut row_ids = Vec::new();
         while let Some(batch) = stream.try_next().await? {
             use lance::deps::arrow_array::Array;
+            let row_id_col = batch.column_by_name(ID_COLUMN);
             let c_col = batch.column_by_name(CONTENT_COLUMN);
             let m_col = batch.column_by_name(METADATA_COLUMN);
             let sk_col = batch.column_by_name(crate::SKILL_NAME_COLUMN);
@@ -1058,6 +1219,7 @@ impl VectorStore {
                             serde_json::json!({}),
                         )
                     };
+                    row_ids.push(str_at_col(row_id_col, i));
                     tools.push(skill::ToolSearchResult {
                         name,
                         description: ca.value(i).to_string(),
@@ -1072,10 +1234,16 @@ impl VectorStore {
                         intents,
                         category,
                         parameters: vec![],
+                        explanation: None,
                     });
                 }
             }
         }
+        if let Some(ids) = id_filter {
+            let mut by_id: std::collections::HashMap<String, skill::ToolSearchResult> =
+                row_ids.into_iter().zip(tools).collect();
+            return Ok(ids.iter().filter_map(|id| by_id.remove(id)).collect());
+        }
         Ok(tools)
     }
 }
@@ -1,5 +1,6 @@
 //! Tests for checkpoint store operations.
 
+use futures::TryStreamExt;
 use lance::dataset::Dataset;
 use omni_vector::CheckpointRecord;
 use omni_vector::CheckpointStore;
@@ -1027,3 +1028,128 @@ async fn test_auto_repair_interrupted_state_on_open() {
         .unwrap();
     assert_eq!(valid_count, 2);
 }
+
+#[tokio::test]
+async fn test_checkpoints_between_time_window() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("time_window");
+    clean_test_db(&db_path);
+
+    let mut store = CheckpointStore::new(db_path.to_str().unwrap(), Some(10))
+        .await
+        .unwrap();
+
+    let records = vec![
+        ("cp-1", "thread-w", 1000.0),
+        ("cp-2", "thread-w", 2000.0),
+        ("cp-3", "thread-w", 3000.0),
+        ("cp-4", "thread-w", 4000.0),
+        ("cp-other", "thread-z", 2500.0),
+    ];
+    for (id, thread, ts) in records {
+        let record = CheckpointRecord {
+            checkpoint_id: id.to_string(),
+            thread_id: thread.to_string(),
+            parent_id: None,
+            timestamp: ts,
+            content: format!(r#"{{"id": "{id}"}}"#),
+            embedding: None,
+            metadata: None,
+        };
+        store
+            .save_checkpoint("window_table", &record)
+            .await
+            .unwrap();
+    }
+
+    let window = store
+        .checkpoints_between("window_table", "thread-w", 1500.0, 3000.0)
+        .await
+        .unwrap();
+    let ids: Vec<&str> = window.iter().map(|r| r.checkpoint_id.as_str()).collect();
+    assert_eq!(ids, vec!["cp-2", "cp-3"]);
+    assert!(window.iter().all(|r| r.thread_id == "thread-w"));
+    assert_eq!(window[0].content, r#"{"id": "cp-2"}"#);
+
+    let empty = store
+        .checkpoints_between("window_table", "thread-w", 5000.0, f64::INFINITY)
+        .await
+        .unwrap();
+    assert!(empty.is_empty());
+}
+
+#[tokio::test]
+async fn test_checkpoint_compression_roundtrip() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("compressed");
+    clean_test_db(&db_path);
+
+    let mut store = CheckpointStore::new(db_path.to_str().unwrap(), Some(10))
+        .await
+        .unwrap()
+        .with_compression(true);
+
+    let messages: Vec<String> = (0..500)
+        .map(|i| format!("step {i}: the agent inspected the repository state"))
+        .collect();
+    let payload = serde_json::json!({ "messages": messages }).to_string();
+    let record = CheckpointRecord {
+        checkpoint_id: "cp-big".to_string(),
+        thread_id: "thread-c".to_string(),
+        parent_id: None,
+        timestamp: 1000.0,
+        content: payload.clone(),
+        embedding: None,
+        metadata: Some(r#"{"step": 1}"#.to_string()),
+    };
+    store
+        .save_checkpoint("compressed_table", &record)
+        .await
+        .unwrap();
+
+    let latest = store
+        .get_latest("compressed_table", "thread-c")
+        .await
+        .unwrap();
+    assert_eq!(latest.as_deref(), Some(payload.as_str()));
+    let by_id = store.get_by_id("compressed_table", "cp-big").await.unwrap();
+    assert_eq!(by_id.as_deref(), Some(payload.as_str()));
+    let window = store
+        .checkpoints_between("compressed_table", "thread-c", 0.0, 2000.0)
+        .await
+        .unwrap();
+    assert_eq!(window[0].content, payload);
+    assert!(
+        !window[0]
+            .metadata
+            .as_deref()
+            .unwrap()
+            .contains("content_encoding")
+    );
+
+    // The raw stored column holds the compressed form.
+    let dataset = Dataset::open(db_path.join("compressed_table.lance").to_str().unwrap())
+        .await
+        .unwrap();
+    let batches: Vec<_> = dataset
+        .scan()
+        .try_into_stream()
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    let stored = batches[0]
+        .column_by_name("content")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<lance::deps::arrow_array::StringArray>()
+        .unwrap()
+        .value(0)
+        .to_string();
+    assert!(
+        stored.len() < payload.len() / 4,
+        "stored {} bytes",
+        stored.len()
+    );
+}
@@ -59,6 +59,7 @@ fn generate_keyword_results(count: usize) -> Vec<ToolSearchResult> {
             intents: vec![],
             category: "benchmark".to_string(),
             parameters: vec![],
+            explanation: None,
         })
         .collect();
 
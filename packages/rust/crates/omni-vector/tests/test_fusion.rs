@@ -23,6 +23,7 @@ fn make_tool_result(name: &str, score: f32) -> ToolSearchResult {
         intents: vec![],
         category: "tool".to_string(),
         parameters: vec![],
+        explanation: None,
     }
 }
 
@@ -1,6 +1,6 @@
 //! Integration tests for hybrid search (vector + keyword).
 
-use omni_vector::VectorStore;
+use omni_vector::{ScoreTransform, SearchOptions, VectorStore};
 
 /// Setup a vector store with keyword index enabled for testing.
 async fn setup_store(path: &std::path::Path, dim: usize) -> VectorStore {
@@ -371,3 +371,60 @@ async fn test_hybrid_search_fallback_on_keyword_error() {
     // Should still return results from vector search
     assert!(!results.is_empty());
 }
+
+#[tokio::test]
+async fn test_hybrid_search_vector_scores_use_score_transform() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let store = VectorStore::new(temp_dir.path().to_str().unwrap(), Some(4))
+        .await
+        .unwrap();
+    store
+        .add_documents(
+            "transform_test",
+            vec!["near".to_string(), "far".to_string()],
+            vec![vec![1.0, 0.0, 0.0, 0.0], vec![0.0, 0.5, 0.0, 0.0]],
+            vec!["near".to_string(), "far".to_string()],
+            vec!["{}".to_string(), "{}".to_string()],
+        )
+        .await
+        .unwrap();
+
+    let query_vector = vec![0.9, 0.0, 0.0, 0.0];
+    let vector_rows = store
+        .search_optimized(
+            "transform_test",
+            query_vector.clone(),
+            10,
+            SearchOptions::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(vector_rows.len(), 2);
+
+    let transform = ScoreTransform::Exponential;
+    let results = store
+        .hybrid_search_with_options(
+            "transform_test",
+            "unmatched",
+            query_vector,
+            10,
+            SearchOptions {
+                score_transform: transform,
+                ..SearchOptions::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    for row in &vector_rows {
+        let hit = results.iter().find(|r| r.tool_name == row.id).unwrap();
+        #[allow(clippy::cast_possible_truncation)]
+        let expected = transform.apply(row.distance as f32);
+        assert!(
+            (hit.vector_score - expected).abs() < 1e-6,
+            "{}: {} != {expected}",
+            row.id,
+            hit.vector_score
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! Tests for LanceDB / keyword index consistency checks and repair.
+
+use omni_vector::{KeywordIndex, VectorStore, VectorStoreError};
+
+fn tool_metadata(skill_name: &str, tool_name: &str) -> String {
+    serde_json::json!({
+        "type": "command",
+        "skill_name": skill_name,
+        "tool_name": tool_name,
+        "command": tool_name,
+        "file_path": format!("{skill_name}/scripts/tools.py"),
+        "keywords": [skill_name, tool_name],
+        "intents": [],
+        "input_schema": "{}",
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_keyword_drift_detected_and_repaired() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("consistency_db");
+    let store =
+        VectorStore::new_with_keyword_index(db_path.to_str().unwrap(), Some(8), true, None, None)
+            .await
+            .unwrap();
+
+    // A second handle holds the Tantivy writer lock, so the store's keyword
+    // write fails after the LanceDB append succeeds.
+    {
+        let other = KeywordIndex::new(&db_path).unwrap();
+        other
+            .upsert_document("git.stale", "Orphaned entry", "git", &[], &[])
+            .unwrap();
+        other
+            .upsert_document("git.gone", "Orphaned entry", "git", &[], &[])
+            .unwrap();
+        store
+            .add_documents(
+                "skills",
+                vec!["git.status".to_string(), "git.commit".to_string()],
+                vec![vec![0.1; 8], vec![0.2; 8]],
+                vec![
+                    "Show git status".to_string(),
+                    "Create a git commit".to_string(),
+                ],
+                vec![
+                    tool_metadata("git", "status"),
+                    tool_metadata("git", "commit"),
+                ],
+            )
+            .await
+            .unwrap();
+    }
+    assert_eq!(store.count("skills").await.unwrap(), 2);
+
+    let report = store.verify_consistency("skills").await.unwrap();
+    assert!(!report.is_consistent());
+    assert_eq!(report.lance_tools, 2);
+    assert_eq!(
+        report.missing_in_keyword,
+        vec!["git.commit".to_string(), "git.status".to_string()]
+    );
+    assert_eq!(
+        report.orphaned_in_keyword,
+        vec!["git.gone".to_string(), "git.stale".to_string()]
+    );
+
+    let repaired = store.repair_keyword_index("skills").await.unwrap();
+    assert_eq!(repaired.missing_in_keyword, report.missing_in_keyword);
+
+    let after = store.verify_consistency("skills").await.unwrap();
+    assert!(after.is_consistent(), "{after:?}");
+    assert_eq!(after.keyword_documents, 2);
+    assert!(store.keyword_index_contains("git.commit"));
+    assert!(store.keyword_index_contains("git.status"));
+    assert!(!store.keyword_index_contains("git.stale"));
+    assert!(!store.keyword_index_contains("git.gone"));
+}
+
+#[tokio::test]
+async fn test_repair_keeps_other_tables_keyword_documents() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("shared_index_db");
+    let store =
+        VectorStore::new_with_keyword_index(db_path.to_str().unwrap(), Some(8), true, None, None)
+            .await
+            .unwrap();
+
+    store
+        .add_documents(
+            "skills",
+            vec!["git.status".to_string()],
+            vec![vec![0.1; 8]],
+            vec!["Show git status".to_string()],
+            vec![tool_metadata("git", "status")],
+        )
+        .await
+        .unwrap();
+    store
+        .add_documents(
+            "extra_skills",
+            vec!["docker.ps".to_string()],
+            vec![vec![0.2; 8]],
+            vec!["List containers".to_string()],
+            vec![tool_metadata("docker", "ps")],
+        )
+        .await
+        .unwrap();
+
+    let report = store.verify_consistency("skills").await.unwrap();
+    assert!(report.is_consistent(), "{report:?}");
+    assert_eq!(report.keyword_documents, 1);
+
+    store.repair_keyword_index("skills").await.unwrap();
+    assert!(store.keyword_index_contains("git.status"));
+    assert!(store.keyword_index_contains("docker.ps"));
+    let other = store.verify_consistency("extra_skills").await.unwrap();
+    assert!(other.is_consistent(), "{other:?}");
+}
+
+/// Ownership is decided by the stored skill name, not by the tool-name prefix.
+#[tokio::test]
+async fn test_repair_keeps_same_prefix_tools_of_other_skills() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("prefix_db");
+    let store =
+        VectorStore::new_with_keyword_index(db_path.to_str().unwrap(), Some(8), true, None, None)
+            .await
+            .unwrap();
+
+    store
+        .add_documents(
+            "skills",
+            vec!["git.status".to_string()],
+            vec![vec![0.1; 8]],
+            vec!["Show git status".to_string()],
+            vec![tool_metadata("git", "status")],
+        )
+        .await
+        .unwrap();
+    // `git.push` belongs to the `vcs` skill even though its name starts with `git.`.
+    store
+        .add_documents(
+            "vcs_skills",
+            vec!["git.push".to_string()],
+            vec![vec![0.2; 8]],
+            vec!["Push commits".to_string()],
+            vec![tool_metadata("vcs", "git.push")],
+        )
+        .await
+        .unwrap();
+
+    let report = store.verify_consistency("skills").await.unwrap();
+    assert!(report.is_consistent(), "{report:?}");
+    assert_eq!(report.keyword_documents, 1);
+
+    store.repair_keyword_index("skills").await.unwrap();
+    assert!(store.keyword_index_contains("git.status"));
+    assert!(store.keyword_index_contains("git.push"));
+}
+
+#[tokio::test]
+async fn test_strict_dual_write_rolls_back_lance_append() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("strict_db");
+    let store =
+        VectorStore::new_with_keyword_index(db_path.to_str().unwrap(), Some(8), true, None, None)
+            .await
+            .unwrap()
+            .with_strict_dual_write(true);
+
+    // Seed the table with a non-command row, which never touches the keyword index.
+    store
+        .add_documents(
+            "skills",
+            vec!["readme".to_string()],
+            vec![vec![0.1; 8]],
+            vec!["Project readme".to_string()],
+            vec![serde_json::json!({ "type": "knowledge" }).to_string()],
+        )
+        .await
+        .unwrap();
+    assert_eq!(store.count("skills").await.unwrap(), 1);
+
+    let other_dir = tempfile::tempdir().unwrap();
+    let other_path = other_dir.path().join("strict_db_new_table");
+    let fresh = VectorStore::new_with_keyword_index(
+        other_path.to_str().unwrap(),
+        Some(8),
+        true,
+        None,
+        None,
+    )
+    .await
+    .unwrap()
+    .with_strict_dual_write(true);
+
+    // Hold the Tantivy writer lock from another handle to force keyword failures.
+    let _lock_holder = {
+        let holder = KeywordIndex::new(&db_path).unwrap();
+        holder
+            .upsert_document("lock.holder", "Holds the writer", "lock", &[], &[])
+            .unwrap();
+        holder
+    };
+    let _fresh_lock_holder = {
+        let holder = KeywordIndex::new(&other_path).unwrap();
+        holder
+            .upsert_document("lock.holder", "Holds the writer", "lock", &[], &[])
+            .unwrap();
+        holder
+    };
+
+    let err = store
+        .add_documents(
+            "skills",
+            vec!["git.commit".to_string()],
+            vec![vec![0.2; 8]],
+            vec!["Create a git commit".to_string()],
+            vec![tool_metadata("git", "commit")],
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, VectorStoreError::DualWriteRolledBack { .. }));
+    assert_eq!(store.count("skills").await.unwrap(), 1);
+
+    // A table created by the failed write is left empty as well.
+    let err = fresh
+        .add_documents(
+            "skills",
+            vec!["git.commit".to_string()],
+            vec![vec![0.2; 8]],
+            vec!["Create a git commit".to_string()],
+            vec![tool_metadata("git", "commit")],
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, VectorStoreError::DualWriteRolledBack { .. }));
+    assert_eq!(fresh.count("skills").await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_strict_dual_write_rollback_keeps_existing_rows_with_same_id() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("strict_upsert_db");
+    // Seed the row without a keyword index so this process holds no Tantivy writer.
+    let seed =
+        VectorStore::new_with_keyword_index(db_path.to_str().unwrap(), Some(8), false, None, None)
+            .await
+            .unwrap();
+    seed.add_documents(
+        "skills",
+        vec!["git.commit".to_string()],
+        vec![vec![0.1; 8]],
+        vec!["Create a git commit".to_string()],
+        vec![tool_metadata("git", "commit")],
+    )
+    .await
+    .unwrap();
+    drop(seed);
+
+    let store =
+        VectorStore::new_with_keyword_index(db_path.to_str().unwrap(), Some(8), true, None, None)
+            .await
+            .unwrap()
+            .with_strict_dual_write(true);
+    assert_eq!(store.count("skills").await.unwrap(), 1);
+
+    let _lock_holder = {
+        let holder = KeywordIndex::new(&db_path).unwrap();
+        holder
+            .upsert_document("lock.holder", "Holds the writer", "lock", &[], &[])
+            .unwrap();
+        holder
+    };
+
+    // Re-adding an existing id must not delete the row that was already there.
+    let err = store
+        .add_documents(
+            "skills",
+            vec!["git.commit".to_string()],
+            vec![vec![0.2; 8]],
+            vec!["Create a git commit (v2)".to_string()],
+            vec![tool_metadata("git", "commit")],
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, VectorStoreError::DualWriteRolledBack { .. }));
+    assert_eq!(store.count("skills").await.unwrap(), 1);
+
+    let err = store
+        .add_documents_partitioned(
+            "skills",
+            "skill_name",
+            vec!["git.commit".to_string(), "docker.ps".to_string()],
+            vec![vec![0.2; 8], vec![0.3; 8]],
+            vec![
+                "Create a git commit (v2)".to_string(),
+                "List containers".to_string(),
+            ],
+            vec![
+                tool_metadata("git", "commit"),
+                tool_metadata("docker", "ps"),
+            ],
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, VectorStoreError::DualWriteRolledBack { .. }));
+    assert_eq!(store.count("skills").await.unwrap(), 1);
+}
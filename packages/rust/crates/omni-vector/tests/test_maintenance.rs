@@ -150,6 +150,31 @@ async fn test_bounded_cache_eviction() {
     assert_eq!(store.count("c").await.unwrap(), 5);
 }
 
+#[tokio::test]
+async fn test_bounded_cache_evicts_least_recently_used() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("lru_cache");
+    let store = VectorStore::new_with_cache_options(
+        db_path.to_str().unwrap(),
+        Some(64),
+        DatasetCacheConfig {
+            max_cached_tables: Some(2),
+        },
+    )
+    .await
+    .unwrap();
+    add_tools_table(&store, "a", 5, &["x"]).await;
+    add_tools_table(&store, "b", 5, &["y"]).await;
+    add_tools_table(&store, "c", 5, &["z"]).await;
+
+    assert_eq!(store.cached_table_names().await, vec!["b", "c"]);
+
+    // "a" was evicted: the next write reopens it from disk and evicts "b".
+    add_tools_table(&store, "a", 5, &["x"]).await;
+    assert_eq!(store.cached_table_names().await, vec!["c", "a"]);
+    assert_eq!(store.count("a").await.unwrap(), 10);
+}
+
 #[tokio::test]
 async fn test_create_index_background_finishes() {
     let temp_dir = tempfile::tempdir().unwrap();
@@ -170,6 +195,46 @@ async fn test_create_index_background_finishes() {
     panic!("create_index_background did not create vector index within 15s");
 }
 
+#[tokio::test]
+async fn test_cleanup_old_versions_keeps_latest_data() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("cleanup_versions");
+    let store = VectorStore::new(db_path.to_str().unwrap(), Some(64))
+        .await
+        .unwrap();
+    add_tools_table(&store, "t", 10, &["a"]).await;
+    store
+        .delete("t", vec!["skill_a.cmd_0".to_string()])
+        .await
+        .unwrap();
+    store
+        .delete("t", vec!["skill_a.cmd_1".to_string()])
+        .await
+        .unwrap();
+    let latest = store.get_dataset_version("t").await.unwrap();
+    let versions_before = store.list_versions("t").await.unwrap().len();
+    assert!(versions_before >= 3);
+
+    // Young versions survive an age cutoff in the future.
+    let kept = store.cleanup_old_versions("t", 1, 3600).await.unwrap();
+    assert_eq!(kept, 0);
+    assert_eq!(
+        store.list_versions("t").await.unwrap().len(),
+        versions_before
+    );
+
+    let reclaimed = store.cleanup_old_versions("t", 2, 0).await.unwrap();
+    assert!(reclaimed > 0);
+    let versions_after = store.list_versions("t").await.unwrap();
+    assert_eq!(versions_after.len(), 2);
+    assert_eq!(
+        versions_after.iter().map(|v| v.version_id).max(),
+        Some(latest)
+    );
+    assert_eq!(store.count("t").await.unwrap(), 8);
+    assert!(store.checkout_version("t", 1).await.is_err());
+}
+
 #[tokio::test]
 async fn test_compact_table_not_found() {
     let temp_dir = tempfile::tempdir().unwrap();
@@ -1,4 +1,4 @@
-//! Tests for Phase 5 observability: analyze_table_health.
+//! Tests for Phase 5 observability: analyze_table_health and self_test.
 
 use omni_vector::{Recommendation, VectorStore};
 
@@ -121,3 +121,16 @@ async fn snapshot_observability_contract_v1() {
     });
     assert_json_snapshot!("observability_contract_v1", view);
 }
+
+#[tokio::test]
+async fn test_self_test_passes_on_fresh_memory_store() {
+    let store = VectorStore::new(":memory:", Some(8)).await.unwrap();
+    let report = store.self_test().await.unwrap();
+    assert!(report.passed, "{report:?}");
+    let names: Vec<&str> = report.steps.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["create", "read", "search", "delete", "drop"]);
+    assert!(report.steps.iter().all(|s| s.error.is_none()));
+
+    // The disposable table is dropped, so the self-test can be repeated.
+    assert!(store.self_test().await.unwrap().passed);
+}
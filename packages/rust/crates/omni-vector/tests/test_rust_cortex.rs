@@ -1,6 +1,9 @@
 //! Tests for Rust-Native Cortex: search_tools and load_tool_registry
 
-use omni_vector::{AgenticSearchConfig, QueryIntent, ToolSearchOptions, VectorStore};
+use omni_vector::{
+    AgenticSearchConfig, MergeStrategy, QueryIntent, ScoreTransform, ToolResultField,
+    ToolSearchOptions, VectorStore,
+};
 
 fn clean_test_db(path: &std::path::Path) {
     if path.exists() {
@@ -647,6 +650,87 @@ async fn test_search_tools_field_boosting() {
     );
 }
 
+#[tokio::test]
+async fn test_search_tools_explains_fused_score() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let store = VectorStore::new_with_keyword_index(
+        temp_dir.path().join("explain_test").to_str().unwrap(),
+        Some(10),
+        true,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let tools = [
+        ("git.commit", "Commit changes to repository", "commit"),
+        ("git.status", "Show git status", "status"),
+    ];
+    let ids: Vec<String> = tools.iter().map(|t| t.0.to_string()).collect();
+    let contents: Vec<String> = tools.iter().map(|t| t.1.to_string()).collect();
+    let metadatas: Vec<String> = tools
+        .iter()
+        .map(|(id, _, tool)| {
+            format!(
+                r#"{{"skill_name": "git", "tool_name": "{tool}", "type": "command", "command": "{id}", "file_path": "git/{tool}.py", "routing_keywords": ["git", "{tool}"], "input_schema": {{}}}}"#
+            )
+        })
+        .collect();
+    store
+        .add_documents("tools", ids, vec![vec![0.5; 10]; 2], contents, metadatas)
+        .await
+        .unwrap();
+    store
+        .bulk_index_keywords(
+            tools
+                .iter()
+                .map(|(id, description, tool)| {
+                    (
+                        (*id).to_string(),
+                        (*description).to_string(),
+                        "git".to_string(),
+                        vec!["git".to_string(), (*tool).to_string()],
+                        vec![],
+                    )
+                })
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+
+    let results = store
+        .search_tools("tools", &[0.5; 10], Some("git commit"), 10, 0.0)
+        .await
+        .unwrap();
+
+    let commit = results
+        .iter()
+        .find(|r| r.name == "git.commit")
+        .expect("git.commit should be discovered");
+    let explanation = commit
+        .explanation
+        .expect("hybrid ranking should explain the score");
+    assert!(explanation.vector_score.is_some());
+    assert!(explanation.keyword_score.is_some());
+    assert!(explanation.vector_contribution > 0.0);
+    assert!(explanation.keyword_contribution > 0.0);
+    assert!(
+        explanation.boost > 0.0,
+        "name token match should boost git.commit"
+    );
+    assert!(
+        (explanation.total() - commit.score).abs() < 1e-5,
+        "components {explanation:?} should sum to score {}",
+        commit.score
+    );
+
+    let vector_only = store
+        .search_tools("tools", &[0.5; 10], None, 10, 0.0)
+        .await
+        .unwrap();
+    assert!(vector_only.iter().all(|r| r.explanation.is_none()));
+}
+
 #[tokio::test]
 async fn test_search_tools_keyword_rescue() {
     // Test that tools not found by vector search can be rescued by keyword
@@ -1009,6 +1093,118 @@ async fn test_search_tools_file_discovery_intent_boost_without_keyword_backend()
     );
 }
 
+/// A minimal projection keeps names and scores and leaves heavy fields empty.
+#[tokio::test]
+async fn test_search_tools_with_minimal_projection() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("projection_test");
+    clean_test_db(&db_path);
+
+    let store = VectorStore::new(db_path.to_str().unwrap(), Some(4))
+        .await
+        .unwrap();
+    store
+        .add_documents(
+            "tools",
+            vec!["git.commit".to_string()],
+            vec![vec![1.0, 0.0, 0.0, 0.0]],
+            vec!["Commit staged changes".to_string()],
+            vec![r#"{"skill_name": "git", "tool_name": "git.commit", "type": "command", "command": "git.commit", "file_path": "git/scripts/commit.py", "routing_keywords": ["git","commit"], "intents": ["save changes"], "category": "git", "input_schema": {"type": "object", "properties": {"message": {"type": "string"}}}}"#.to_string()],
+        )
+        .await
+        .unwrap();
+
+    let results = store
+        .search_tools_with_options(
+            "tools",
+            &[1.0, 0.0, 0.0, 0.0],
+            None,
+            5,
+            0.0,
+            ToolSearchOptions {
+                projection: vec![ToolResultField::Name, ToolResultField::Score],
+                ..ToolSearchOptions::default()
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 1);
+    let result = &results[0];
+    assert_eq!(result.name, "git.commit");
+    assert!(result.score > 0.0);
+    assert!(result.description.is_empty());
+    assert_eq!(result.input_schema, serde_json::json!({}));
+    assert!(result.file_path.is_empty());
+    assert!(result.routing_keywords.is_empty());
+    assert!(result.intents.is_empty());
+}
+
+/// A relative threshold drops hits far below the top score but keeps near-ties.
+#[tokio::test]
+async fn test_search_tools_relative_threshold_keeps_near_ties() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("relative_threshold_test");
+    clean_test_db(&db_path);
+
+    let store = VectorStore::new(db_path.to_str().unwrap(), Some(4))
+        .await
+        .unwrap();
+    let tools = [
+        ("git.commit", vec![1.0, 0.0, 0.0, 0.0]),
+        ("git.amend", vec![0.95, 0.05, 0.0, 0.0]),
+        ("python.run", vec![0.0, 1.0, 0.0, 0.0]),
+    ];
+    store
+        .add_documents(
+            "tools",
+            tools.iter().map(|t| t.0.to_string()).collect(),
+            tools.iter().map(|t| t.1.clone()).collect(),
+            tools.iter().map(|t| format!("{} tool", t.0)).collect(),
+            tools
+                .iter()
+                .map(|t| {
+                    let skill = t.0.split('.').next().unwrap();
+                    serde_json::json!({
+                        "skill_name": skill,
+                        "tool_name": t.0,
+                        "type": "command",
+                        "command": t.0,
+                    })
+                    .to_string()
+                })
+                .collect(),
+        )
+        .await
+        .unwrap();
+
+    let query = [1.0, 0.0, 0.0, 0.0];
+    let unfiltered = store
+        .search_tools("tools", &query, None, 5, 0.0)
+        .await
+        .unwrap();
+    assert_eq!(unfiltered.len(), 3);
+
+    let results = store
+        .search_tools_with_options(
+            "tools",
+            &query,
+            None,
+            5,
+            0.0,
+            ToolSearchOptions {
+                relative_threshold: Some(0.8),
+                ..ToolSearchOptions::default()
+            },
+            None,
+        )
+        .await
+        .unwrap();
+    let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec!["git.commit", "git.amend"]);
+}
+
 /// Rerank stage should be optional via ToolSearchOptions.
 #[tokio::test]
 async fn test_search_tools_with_options_can_disable_rerank_boost() {
@@ -1074,6 +1270,9 @@ async fn test_search_tools_with_options_can_disable_rerank_boost() {
                 rerank: false,
                 semantic_weight: None,
                 keyword_weight: None,
+                score_transform: ScoreTransform::default(),
+                projection: Vec::new(),
+                relative_threshold: None,
             },
             None,
         )
@@ -1222,3 +1421,185 @@ async fn test_search_tools_same_tool_name_different_skills() {
     // filesystem.status should also be present
     assert!(results.iter().any(|r| r.name == "filesystem.status"));
 }
+
+/// Multi-vector search keeps, per tool, the best score across the queries.
+#[tokio::test]
+async fn test_search_tools_multi_max_score() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("multi_query_test");
+    clean_test_db(&db_path);
+
+    let store = VectorStore::new(db_path.to_str().unwrap(), Some(4))
+        .await
+        .unwrap();
+    let tools = [
+        ("git.commit", vec![1.0, 0.0, 0.0, 0.0]),
+        ("python.run", vec![0.0, 1.0, 0.0, 0.0]),
+        ("docker.build", vec![0.5, 0.5, 0.0, 0.0]),
+    ];
+    store
+        .add_documents(
+            "tools",
+            tools.iter().map(|t| t.0.to_string()).collect(),
+            tools.iter().map(|t| t.1.clone()).collect(),
+            tools.iter().map(|t| format!("{} tool", t.0)).collect(),
+            tools
+                .iter()
+                .map(|t| {
+                    let skill = t.0.split('.').next().unwrap();
+                    serde_json::json!({
+                        "skill_name": skill,
+                        "tool_name": t.0,
+                        "type": "command",
+                        "command": t.0,
+                    })
+                    .to_string()
+                })
+                .collect(),
+        )
+        .await
+        .unwrap();
+
+    let queries = vec![vec![1.0, 0.0, 0.0, 0.0], vec![0.0, 1.0, 0.0, 0.0]];
+    let merged = store
+        .search_tools_multi("tools", &queries, 5, MergeStrategy::MaxScore)
+        .await
+        .unwrap();
+    assert_eq!(merged.len(), 3);
+
+    let mut single_scores: Vec<std::collections::HashMap<String, (f32, Option<f32>)>> = Vec::new();
+    for query in &queries {
+        let results = store
+            .search_tools("tools", query, None, 5, 0.0)
+            .await
+            .unwrap();
+        single_scores.push(
+            results
+                .into_iter()
+                .map(|r| (r.name, (r.score, r.vector_score)))
+                .collect(),
+        );
+    }
+    for result in &merged {
+        let expected = single_scores
+            .iter()
+            .map(|scores| scores[&result.name].0)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let expected_vector = single_scores
+            .iter()
+            .filter_map(|scores| scores[&result.name].1)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let vector_score = result.vector_score.unwrap();
+        assert!(
+            (vector_score - expected_vector).abs() < 1e-6,
+            "{}: vector_score {} != {}",
+            result.name,
+            vector_score,
+            expected_vector
+        );
+        assert!(
+            (result.score - expected).abs() < 1e-6,
+            "{}: {} != {}",
+            result.name,
+            result.score,
+            expected
+        );
+    }
+    assert!(merged.windows(2).all(|w| w[0].score >= w[1].score));
+}
+
+async fn add_tool_batch(store: &VectorStore, names: &[String]) {
+    store
+        .add_documents(
+            "tools",
+            names.to_vec(),
+            names.iter().map(|_| vec![1.0, 0.0, 0.0, 0.0]).collect(),
+            names.iter().map(|n| format!("{n} tool")).collect(),
+            names
+                .iter()
+                .map(|n| {
+                    serde_json::json!({
+                        "skill_name": n.split('.').next().unwrap(),
+                        "tool_name": n,
+                        "type": "command",
+                        "command": n,
+                    })
+                    .to_string()
+                })
+                .collect(),
+        )
+        .await
+        .unwrap();
+}
+
+/// Every query of one `search_tools_multi` call reads the same table version, even
+/// when writes commit between the per-query scans.
+#[tokio::test]
+async fn test_search_tools_multi_shares_one_snapshot() {
+    const BATCH: usize = 3;
+    const BATCHES: usize = 5;
+    const QUERIES: usize = 8;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("multi_snapshot_test");
+    clean_test_db(&db_path);
+    let store = VectorStore::new(db_path.to_str().unwrap(), Some(4))
+        .await
+        .unwrap();
+    let batch_names = |batch: usize| -> Vec<String> {
+        (0..BATCH)
+            .map(|i| format!("batch{batch}.tool{i}"))
+            .collect()
+    };
+    add_tool_batch(&store, &batch_names(0)).await;
+
+    // Every tool has the query's vector, so each single-query score is the same.
+    let query = vec![1.0, 0.0, 0.0, 0.0];
+    let single = store
+        .search_tools("tools", &query, None, 100, 0.0)
+        .await
+        .unwrap();
+    let expected = single[0].score;
+    let queries = vec![query; QUERIES];
+
+    let writer = async {
+        for batch in 1..=BATCHES {
+            add_tool_batch(&store, &batch_names(batch)).await;
+            tokio::task::yield_now().await;
+        }
+    };
+    let reader = async {
+        let mut runs = Vec::new();
+        for _ in 0..(BATCHES * 2) {
+            runs.push(
+                store
+                    .search_tools_multi("tools", &queries, 100, MergeStrategy::MeanScore)
+                    .await
+                    .unwrap(),
+            );
+            tokio::task::yield_now().await;
+        }
+        runs
+    };
+    let ((), runs) = tokio::join!(writer, reader);
+    for merged in runs {
+        assert_eq!(merged.len() % BATCH, 0, "scan observed a partial batch");
+        // A tool committed between two queries would be missing from the earlier
+        // ones and get a lower mean score.
+        for result in merged {
+            assert!(
+                (result.score - expected).abs() < 1e-6,
+                "{} was not seen by every query: {} != {}",
+                result.name,
+                result.score,
+                expected
+            );
+        }
+    }
+
+    let latest = store
+        .search_tools_multi("tools", &queries, 100, MergeStrategy::MeanScore)
+        .await
+        .unwrap();
+    assert_eq!(latest.len(), BATCH * (BATCHES + 1));
+}
@@ -267,6 +267,7 @@ async fn test_vector_distance_calculation() {
         intents: vec![],
         category: "test".to_string(),
         parameters: vec![],
+        explanation: None,
     };
 
     let opposite = ToolSearchResult {
@@ -283,6 +284,7 @@ async fn test_vector_distance_calculation() {
         intents: vec![],
         category: "test".to_string(),
         parameters: vec![],
+        explanation: None,
     };
 
     let orthogonal = ToolSearchResult {
@@ -299,6 +301,7 @@ async fn test_vector_distance_calculation() {
         intents: vec![],
         category: "test".to_string(),
         parameters: vec![],
+        explanation: None,
     };
 
     // Verify results are ordered by score
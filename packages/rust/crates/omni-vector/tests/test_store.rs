@@ -272,3 +272,210 @@ async fn test_delete_by_metadata_source() {
     assert_eq!(deleted, 3);
     assert_eq!(store.count("knowledge_chunks").await.unwrap(), 0);
 }
+
+#[tokio::test]
+async fn test_normalize_on_insert_stores_unit_vectors() {
+    use futures::TryStreamExt;
+    use lance::dataset::Dataset;
+    use lance::deps::arrow_array::{Array, FixedSizeListArray, Float32Array};
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("normalize_store");
+    let store = VectorStore::new(db_path.to_str().unwrap(), Some(4))
+        .await
+        .unwrap()
+        .with_normalize_on_insert(true);
+
+    store
+        .add_documents(
+            "normalized",
+            vec!["doc-1".to_string()],
+            vec![vec![3.0, 4.0, 0.0, 0.0]],
+            vec!["content".to_string()],
+            vec!["{}".to_string()],
+        )
+        .await
+        .unwrap();
+
+    let table_path = store.table_path("normalized");
+    let dataset = Dataset::open(table_path.to_str().unwrap()).await.unwrap();
+    let batches: Vec<_> = dataset
+        .scan()
+        .try_into_stream()
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    let vectors = batches[0]
+        .column_by_name(omni_vector::VECTOR_COLUMN)
+        .unwrap()
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap()
+        .value(0);
+    let values = vectors.as_any().downcast_ref::<Float32Array>().unwrap();
+    let norm = values.values().iter().map(|x| x * x).sum::<f32>().sqrt();
+    assert!((norm - 1.0).abs() < 1e-5, "expected unit norm, got {norm}");
+    assert!((values.value(0) - 0.6).abs() < 1e-6);
+}
+
+#[tokio::test]
+async fn test_get_by_ids_returns_requested_subset_in_order() {
+    use omni_scanner::skills::ToolRecord;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("get_by_ids");
+    let store = VectorStore::new(db_path.to_str().unwrap(), Some(8))
+        .await
+        .unwrap();
+
+    let tools: Vec<ToolRecord> = (0..5)
+        .map(|i| {
+            ToolRecord::new(
+                format!("demo.tool_{i}"),
+                format!("Tool number {i}"),
+                "demo".to_string(),
+                format!("demo/scripts/tool_{i}.py"),
+                format!("tool_{i}"),
+            )
+        })
+        .collect();
+    store.add("tools", tools).await.unwrap();
+
+    let ids = vec![
+        "demo.tool_3".to_string(),
+        "demo.tool_0".to_string(),
+        "demo.missing".to_string(),
+        "demo.tool_4".to_string(),
+    ];
+    let results = store.get_by_ids("tools", &ids).await.unwrap();
+    let names: Vec<&str> = results.iter().map(|r| r.tool_name.as_str()).collect();
+    assert_eq!(names, vec!["demo.tool_3", "demo.tool_0", "demo.tool_4"]);
+    assert_eq!(results[1].description, "Tool number 0");
+
+    let quoted = store
+        .get_by_ids("tools", &["demo.o'brien".to_string()])
+        .await
+        .unwrap();
+    assert!(quoted.is_empty());
+}
+
+#[tokio::test]
+async fn test_add_rejects_fake_embeddings_when_enabled() {
+    use omni_scanner::skills::ToolRecord;
+    use omni_vector::VectorStoreError;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("reject_fake");
+    let store = VectorStore::new(db_path.to_str().unwrap(), Some(8))
+        .await
+        .unwrap()
+        .with_reject_fake_embeddings(true);
+
+    let tool = ToolRecord::new(
+        "demo.tool".to_string(),
+        "Demo tool".to_string(),
+        "demo".to_string(),
+        "demo/scripts/tool.py".to_string(),
+        "tool".to_string(),
+    );
+    let err = store.add("tools", vec![tool]).await.unwrap_err();
+    assert!(matches!(err, VectorStoreError::FakeEmbeddingsRejected(ref table) if table == "tools"));
+    assert!(!store.table_path("tools").exists());
+}
+
+#[tokio::test]
+async fn test_max_content_chars_truncates_and_marks_content() {
+    use futures::TryStreamExt;
+    use lance::dataset::Dataset;
+    use lance::deps::arrow_array::{Array, StringArray};
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("max_content");
+    let store = VectorStore::new(db_path.to_str().unwrap(), Some(4))
+        .await
+        .unwrap()
+        .with_max_content_chars(10);
+
+    let long = "héllo wörld, this description is far too long".to_string();
+    store
+        .add_documents(
+            "docs",
+            vec!["long".to_string(), "short".to_string()],
+            vec![vec![1.0, 0.0, 0.0, 0.0], vec![0.0, 1.0, 0.0, 0.0]],
+            vec![long.clone(), "brief".to_string()],
+            vec![r#"{"source": "a.md"}"#.to_string(), "{}".to_string()],
+        )
+        .await
+        .unwrap();
+
+    let table_path = store.table_path("docs");
+    let dataset = Dataset::open(table_path.to_str().unwrap()).await.unwrap();
+    let batches: Vec<_> = dataset
+        .scan()
+        .try_into_stream()
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    let column = |name: &str| {
+        batches[0]
+            .column_by_name(name)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap()
+            .clone()
+    };
+    let ids = column(omni_vector::ID_COLUMN);
+    let contents = column(omni_vector::CONTENT_COLUMN);
+    let metadatas = column(omni_vector::METADATA_COLUMN);
+    for row in 0..ids.len() {
+        let meta: serde_json::Value = serde_json::from_str(metadatas.value(row)).unwrap();
+        match ids.value(row) {
+            "long" => {
+                assert_eq!(contents.value(row), "héllo w...");
+                assert_eq!(
+                    meta[omni_vector::ORIGINAL_CONTENT_CHARS_KEY],
+                    long.chars().count()
+                );
+                assert_eq!(meta["source"], "a.md");
+            }
+            "short" => {
+                assert_eq!(contents.value(row), "brief");
+                assert!(meta.get(omni_vector::ORIGINAL_CONTENT_CHARS_KEY).is_none());
+            }
+            other => panic!("unexpected row {other}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_max_content_chars_below_marker_length_never_exceeds_cap() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("tiny_max_content");
+    let store = VectorStore::new(db_path.to_str().unwrap(), Some(4))
+        .await
+        .unwrap()
+        .with_max_content_chars(2);
+
+    store
+        .add_documents(
+            "docs",
+            vec!["long".to_string()],
+            vec![vec![1.0, 0.0, 0.0, 0.0]],
+            vec!["far too long".to_string()],
+            vec!["{}".to_string()],
+        )
+        .await
+        .unwrap();
+
+    let results = store
+        .search("docs", vec![1.0, 0.0, 0.0, 0.0], 1)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].content, "..");
+}
@@ -41,6 +41,7 @@ impl PySessionWindow {
             dict.set_item("role", slot.role.as_str())?;
             dict.set_item("content", slot.content.as_str())?;
             dict.set_item("tool_count", slot.tool_count)?;
+            dict.set_item("token_count", slot.token_count)?;
             if let Some(ref id) = slot.checkpoint_id {
                 dict.set_item("checkpoint_id", id.as_str())?;
             }
@@ -12,21 +12,31 @@ pub struct TurnSlot {
     pub content: String,
     /// Number of tool calls in this turn.
     pub tool_count: u32,
+    /// Token count of `content`, used for budgeted context building.
+    #[serde(default)]
+    pub token_count: usize,
     /// Optional memory checkpoint ID for consolidation.
     pub checkpoint_id: Option<String>,
 }
 
 impl TurnSlot {
-    /// Build a turn slot from role, content, and tool count.
+    /// Build a turn slot from role, content, and tool count. Token count is computed from content.
     pub fn new(role: &str, content: &str, tool_count: u32) -> Self {
         Self {
             role: role.to_string(),
             content: content.to_string(),
             tool_count,
+            token_count: omni_tokenizer::count_tokens(content),
             checkpoint_id: None,
         }
     }
 
+    /// Override the token count (e.g. when restored from an external tokenizer).
+    pub fn with_token_count(mut self, token_count: usize) -> Self {
+        self.token_count = token_count;
+        self
+    }
+
     /// Attach a checkpoint ID to this turn.
     pub fn with_checkpoint(mut self, checkpoint_id: String) -> Self {
         self.checkpoint_id = Some(checkpoint_id);
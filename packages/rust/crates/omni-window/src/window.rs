@@ -61,6 +61,26 @@ impl SessionWindow {
         out
     }
 
+    /// Most recent turns whose summed `token_count` fits `max_tokens` (oldest to newest).
+    /// Walks newest-to-oldest and stops at the first turn that would exceed the budget.
+    #[must_use]
+    pub fn recent_within_budget(&self, max_tokens: usize) -> Vec<&TurnSlot> {
+        let mut used = 0usize;
+        let mut out: Vec<&TurnSlot> = Vec::new();
+        for slot in self.ring.iter().rev() {
+            let Some(next) = used.checked_add(slot.token_count) else {
+                break;
+            };
+            if next > max_tokens {
+                break;
+            }
+            used = next;
+            out.push(slot);
+        }
+        out.reverse();
+        out
+    }
+
     /// Stats for consolidation trigger and UI.
     #[must_use]
     pub fn get_stats(&self) -> (u64, u64, usize) {
@@ -87,6 +107,24 @@ impl SessionWindow {
         out
     }
 
+    /// Snapshot all retained turns (oldest to newest) for persistence.
+    #[must_use]
+    pub fn export(&self) -> Vec<TurnSlot> {
+        self.ring.iter().cloned().collect()
+    }
+
+    /// Replace the window contents with previously exported turns (oldest to newest).
+    /// Keeps only the newest `max_turns` slots and recomputes tool-call stats.
+    pub fn import(&mut self, slots: Vec<TurnSlot>) {
+        let skip = slots.len().saturating_sub(self.max_turns);
+        self.ring = slots.into_iter().skip(skip).collect();
+        self.total_tool_calls = self
+            .ring
+            .iter()
+            .map(|slot| u64::from(slot.tool_count))
+            .sum();
+    }
+
     /// Session identifier for this window.
     #[must_use]
     pub fn session_id(&self) -> &str {
@@ -1,6 +1,6 @@
-//! Integration tests for SessionWindow.
+//! Integration tests for `SessionWindow`.
 
-use omni_window::SessionWindow;
+use omni_window::{SessionWindow, TurnSlot};
 
 #[test]
 fn test_append_and_get_recent() {
@@ -37,3 +37,73 @@ fn test_max_turns_trim() {
     assert_eq!(recent.len(), 3);
     assert_eq!(recent[0].content, "2");
 }
+
+#[test]
+fn test_export_import_roundtrip() {
+    let mut w = SessionWindow::new("s1", 3);
+    for i in 0..5 {
+        w.append_turn("user", &i.to_string(), i, None);
+    }
+    let exported = w.export();
+    assert_eq!(exported.len(), 3);
+
+    let mut restored = SessionWindow::new("s1", 3);
+    restored.import(exported);
+    let contents: Vec<&str> = restored
+        .get_recent_turns(10)
+        .iter()
+        .map(|slot| slot.content.as_str())
+        .collect();
+    assert_eq!(contents, vec!["2", "3", "4"]);
+    assert_eq!(restored.get_stats(), (3, 9, 3));
+
+    restored.append_turn("assistant", "5", 0, None);
+    let recent = restored.get_recent_turns(10);
+    assert_eq!(recent[0].content, "3");
+    assert_eq!(recent[2].content, "5");
+}
+
+#[test]
+fn test_import_trims_to_capacity() {
+    let mut source = SessionWindow::new("s1", 10);
+    for i in 0..6 {
+        source.append_turn("user", &i.to_string(), 1, None);
+    }
+    let mut smaller = SessionWindow::new("s1", 4);
+    smaller.import(source.export());
+    let recent = smaller.get_recent_turns(10);
+    assert_eq!(recent.len(), 4);
+    assert_eq!(recent[0].content, "2");
+    assert_eq!(smaller.get_stats(), (4, 4, 4));
+}
+
+#[test]
+fn test_recent_within_budget() {
+    let mut w = SessionWindow::new("s1", 10);
+    w.import(
+        [("a", 40), ("b", 30), ("c", 20), ("d", 10)]
+            .into_iter()
+            .map(|(content, tokens)| TurnSlot::new("user", content, 0).with_token_count(tokens))
+            .collect(),
+    );
+
+    let contents = |budget: usize| -> Vec<String> {
+        w.recent_within_budget(budget)
+            .iter()
+            .map(|slot| slot.content.clone())
+            .collect()
+    };
+    assert_eq!(contents(65), vec!["b", "c", "d"]);
+    assert_eq!(contents(60), vec!["b", "c", "d"]);
+    assert_eq!(contents(59), vec!["c", "d"]);
+    assert_eq!(contents(100), vec!["a", "b", "c", "d"]);
+    assert!(contents(5).is_empty());
+}
+
+#[test]
+fn test_append_turn_records_token_count() {
+    let mut w = SessionWindow::new("s1", 10);
+    w.append_turn("user", "hello world", 0, None);
+    let recent = w.get_recent_turns(1);
+    assert!(recent[0].token_count > 0);
+}
@@ -1,3 +1,5 @@
+use crate::InjectionOrderStrategy;
+
 /// Window limits for injected prompt content.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InjectionWindowConfig {
@@ -5,6 +7,14 @@ pub struct InjectionWindowConfig {
     pub max_entries: usize,
     /// Maximum retained character budget across entries.
     pub max_chars: usize,
+    /// Keep a single entry per question instead of retaining duplicates.
+    pub dedupe_questions: bool,
+    /// Decides which duplicate survives when `dedupe_questions` is enabled.
+    ///
+    /// `PriorityDesc` keeps the higher-priority entry (the latest on ties).
+    /// `CategoryThenPriority` keeps the latest: Q&A entries share one category,
+    /// which the window orders by arrival.
+    pub ordering: InjectionOrderStrategy,
 }
 
 impl Default for InjectionWindowConfig {
@@ -12,6 +22,8 @@ impl Default for InjectionWindowConfig {
         Self {
             max_entries: 8,
             max_chars: 4_000,
+            dedupe_questions: false,
+            ordering: InjectionOrderStrategy::CategoryThenPriority,
         }
     }
 }
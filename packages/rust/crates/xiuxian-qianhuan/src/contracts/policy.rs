@@ -33,6 +33,9 @@ pub struct InjectionPolicy {
     pub max_blocks: usize,
     /// Maximum char budget across all blocks.
     pub max_chars: usize,
+    /// Optional token budget for the rendered blocks; lowest-priority blocks are dropped to fit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<usize>,
     /// Deterministic ordering strategy.
     pub ordering: InjectionOrderStrategy,
     /// Allowed categories for this turn.
@@ -47,6 +50,7 @@ impl Default for InjectionPolicy {
             mode: InjectionMode::Classified,
             max_blocks: 12,
             max_chars: 8_000,
+            max_tokens: None,
             ordering: InjectionOrderStrategy::CategoryThenPriority,
             enabled_categories: vec![
                 PromptContextCategory::Safety,
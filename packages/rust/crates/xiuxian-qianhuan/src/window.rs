@@ -1,12 +1,18 @@
 use std::collections::VecDeque;
 
-use crate::{InjectionError, InjectionWindowConfig, QaEntry, xml};
+use crate::{InjectionError, InjectionOrderStrategy, InjectionWindowConfig, QaEntry, xml};
 
 /// Bounded session-level system prompt injection window.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SystemPromptInjectionWindow {
     config: InjectionWindowConfig,
-    entries: VecDeque<QaEntry>,
+    entries: VecDeque<WindowEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WindowEntry {
+    entry: QaEntry,
+    priority: u16,
 }
 
 impl SystemPromptInjectionWindow {
@@ -29,6 +35,24 @@ impl SystemPromptInjectionWindow {
         Ok(window)
     }
 
+    /// Parse XML and construct a bounded injection window, skipping malformed `<qa>` entries.
+    ///
+    /// Returns the window built from valid entries together with one error per skipped entry.
+    ///
+    /// # Errors
+    /// Returns [`InjectionError`] when the payload is empty or contains no `<qa>` entries.
+    pub fn from_xml_lenient(
+        raw: &str,
+        config: InjectionWindowConfig,
+    ) -> Result<(Self, Vec<InjectionError>), InjectionError> {
+        let (parsed, errors) = xml::parse_qa_entries_lenient(raw)?;
+        let mut window = Self::new(config);
+        for entry in parsed {
+            window.push(entry);
+        }
+        Ok((window, errors))
+    }
+
     /// Parse and normalize XML under window limits.
     pub fn normalize_xml(
         raw: &str,
@@ -40,7 +64,32 @@ impl SystemPromptInjectionWindow {
 
     /// Add one Q&A entry and enforce window bounds.
     pub fn push(&mut self, entry: QaEntry) {
-        self.entries.push_back(entry);
+        self.push_with_priority(entry, 0);
+    }
+
+    /// Add one Q&A entry with an explicit priority and enforce window bounds.
+    ///
+    /// Higher value means higher priority. Priority only matters when
+    /// `dedupe_questions` is enabled with [`InjectionOrderStrategy::PriorityDesc`].
+    pub fn push_with_priority(&mut self, entry: QaEntry, priority: u16) {
+        if self.config.dedupe_questions {
+            let key = question_key(&entry.question);
+            if let Some(index) = self
+                .entries
+                .iter()
+                .position(|existing| question_key(&existing.entry.question) == key)
+            {
+                let keep_existing = match self.config.ordering {
+                    InjectionOrderStrategy::PriorityDesc => self.entries[index].priority > priority,
+                    InjectionOrderStrategy::CategoryThenPriority => false,
+                };
+                if keep_existing {
+                    return;
+                }
+                let _ = self.entries.remove(index);
+            }
+        }
+        self.entries.push_back(WindowEntry { entry, priority });
         self.enforce_limits();
     }
 
@@ -64,18 +113,18 @@ impl SystemPromptInjectionWindow {
     /// Total char count of retained entries.
     #[must_use]
     pub fn total_chars(&self) -> usize {
-        self.entries.iter().map(QaEntry::char_len).sum()
+        self.entries.iter().map(|slot| slot.entry.char_len()).sum()
     }
 
     /// Iterate over retained entries in chronological order.
     pub fn iter(&self) -> impl Iterator<Item = &QaEntry> {
-        self.entries.iter()
+        self.entries.iter().map(|slot| &slot.entry)
     }
 
     /// Render bounded entries as canonical XML payload.
     #[must_use]
     pub fn render_xml(&self) -> String {
-        xml::render_xml(self.entries.iter().cloned())
+        xml::render_xml(self.iter().cloned())
     }
 
     fn enforce_limits(&mut self) {
@@ -91,12 +140,22 @@ impl SystemPromptInjectionWindow {
         if self.total_chars() > max_chars
             && let Some(last) = self.entries.pop_back()
         {
-            self.entries
-                .push_back(truncate_entry_to_budget(last, max_chars));
+            self.entries.push_back(WindowEntry {
+                entry: truncate_entry_to_budget(last.entry, max_chars),
+                priority: last.priority,
+            });
         }
     }
 }
 
+fn question_key(question: &str) -> String {
+    question
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
 fn truncate_entry_to_budget(entry: QaEntry, max_chars: usize) -> QaEntry {
     let question_budget = (max_chars / 3).max(32).min(max_chars);
     let question = truncate_chars(&entry.question, question_budget);
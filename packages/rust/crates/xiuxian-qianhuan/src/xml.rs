@@ -32,6 +32,34 @@ pub(crate) fn parse_qa_entries(raw: &str) -> Result<Vec<QaEntry>, InjectionError
     Ok(entries)
 }
 
+/// Parse `<qa>` entries, skipping malformed ones instead of aborting.
+///
+/// Returns valid entries alongside one error per skipped `<qa>` block. Payload-level
+/// failures (empty payload, no `<qa>` at all) are still returned as `Err`.
+pub(crate) fn parse_qa_entries_lenient(
+    raw: &str,
+) -> Result<(Vec<QaEntry>, Vec<InjectionError>), InjectionError> {
+    let payload = raw.trim();
+    if payload.is_empty() {
+        return Err(InjectionError::EmptyPayload);
+    }
+
+    let blocks = extract_tag_blocks(payload, QA_TAG);
+    if blocks.is_empty() {
+        return parse_qa_entries(payload).map(|entries| (entries, Vec::new()));
+    }
+
+    let mut entries = Vec::with_capacity(blocks.len());
+    let mut errors = Vec::new();
+    for block in blocks {
+        match parse_qa_block(&block) {
+            Ok(entry) => entries.push(entry),
+            Err(error) => errors.push(error),
+        }
+    }
+    Ok((entries, errors))
+}
+
 pub(crate) fn render_xml(entries: impl Iterator<Item = QaEntry>) -> String {
     let mut lines = vec![format!("<{SYSTEM_PROMPT_INJECTION_TAG}>")];
     for entry in entries {
@@ -1,8 +1,8 @@
 #![doc = "Integration tests for the system prompt injection window."]
 
 use xiuxian_qianhuan::{
-    InjectionError, InjectionWindowConfig, QaEntry, SYSTEM_PROMPT_INJECTION_TAG,
-    SystemPromptInjectionWindow,
+    InjectionError, InjectionOrderStrategy, InjectionWindowConfig, QaEntry,
+    SYSTEM_PROMPT_INJECTION_TAG, SystemPromptInjectionWindow,
 };
 
 #[test]
@@ -36,6 +36,7 @@ fn normalize_xml_enforces_window_limits() {
     let mut window = SystemPromptInjectionWindow::new(InjectionWindowConfig {
         max_entries: 2,
         max_chars: 120,
+        ..InjectionWindowConfig::default()
     });
     window.push(QaEntry {
         question: "q1".to_string(),
@@ -77,3 +78,93 @@ fn parse_rejects_invalid_payload() {
         .expect_err("invalid qa should fail");
     assert_eq!(error, InjectionError::MissingAnswer);
 }
+
+#[test]
+fn lenient_parse_skips_malformed_entries() {
+    let raw = r#"
+<system_prompt_injection>
+  <qa><q>first</q><a>one</a></qa>
+  <qa><q>second</q><a>two</qa>
+  <qa><q>third</q><a>three</a><source>ops</source></qa>
+</system_prompt_injection>
+"#;
+    let (window, errors) =
+        SystemPromptInjectionWindow::from_xml_lenient(raw, InjectionWindowConfig::default())
+            .expect("valid entries should survive a malformed sibling");
+    assert_eq!(window.len(), 2);
+    assert_eq!(errors, vec![InjectionError::MissingAnswer]);
+
+    let questions: Vec<&str> = window.iter().map(|entry| entry.question.as_str()).collect();
+    assert_eq!(questions, vec!["first", "third"]);
+
+    let strict = SystemPromptInjectionWindow::from_xml(raw, InjectionWindowConfig::default());
+    assert_eq!(strict, Err(InjectionError::MissingAnswer));
+}
+
+#[test]
+fn duplicate_questions_keep_latest_entry() {
+    let raw = r#"
+<system_prompt_injection>
+  <qa><q>Which database?</q><a>sqlite</a></qa>
+  <qa><q>Which cache?</q><a>valkey</a></qa>
+  <qa><q>which  database?</q><a>postgres</a></qa>
+</system_prompt_injection>
+"#;
+    let config = InjectionWindowConfig {
+        dedupe_questions: true,
+        ..InjectionWindowConfig::default()
+    };
+    let window = SystemPromptInjectionWindow::from_xml(raw, config).expect("xml should parse");
+    assert_eq!(window.len(), 2);
+
+    let rendered = window.render_xml();
+    assert_eq!(rendered.matches("<qa>").count(), 2);
+    assert!(!rendered.contains("<a>sqlite</a>"));
+    assert!(rendered.contains("<a>postgres</a>"));
+    let cache_pos = rendered.find("Which cache?").expect("cache entry present");
+    let db_pos = rendered
+        .find("which  database?")
+        .expect("latest db entry present");
+    assert!(
+        cache_pos < db_pos,
+        "replaced entry moves to newest position"
+    );
+}
+
+#[test]
+fn priority_ordering_keeps_highest_priority_duplicate() {
+    let mut window = SystemPromptInjectionWindow::new(InjectionWindowConfig {
+        dedupe_questions: true,
+        ordering: InjectionOrderStrategy::PriorityDesc,
+        ..InjectionWindowConfig::default()
+    });
+    let entry = |answer: &str| QaEntry {
+        question: "Which database?".to_string(),
+        answer: answer.to_string(),
+        source: None,
+    };
+    window.push_with_priority(entry("postgres"), 10);
+    window.push_with_priority(entry("sqlite"), 1);
+    assert_eq!(window.len(), 1);
+    assert!(window.render_xml().contains("<a>postgres</a>"));
+
+    window.push_with_priority(entry("valkey"), 10);
+    assert_eq!(window.len(), 1);
+    assert!(
+        window.render_xml().contains("<a>valkey</a>"),
+        "ties keep latest"
+    );
+}
+
+#[test]
+fn duplicate_questions_are_kept_by_default() {
+    let mut window = SystemPromptInjectionWindow::new(InjectionWindowConfig::default());
+    for answer in ["a1", "a2"] {
+        window.push(QaEntry {
+            question: "same".to_string(),
+            answer: answer.to_string(),
+            source: None,
+        });
+    }
+    assert_eq!(window.len(), 2);
+}
@@ -20,20 +20,7 @@ pub struct PyExternalSymbol {
 impl PyExternalSymbol {
     #[new]
     fn new(name: &str, kind: &str, file: &str, line: usize, crate_name: &str) -> Self {
-        let kind = match kind {
-            "struct" => SymbolKind::Struct,
-            "enum" => SymbolKind::Enum,
-            "trait" => SymbolKind::Trait,
-            "fn" => SymbolKind::Function,
-            "method" => SymbolKind::Method,
-            "field" => SymbolKind::Field,
-            "impl" => SymbolKind::Impl,
-            "mod" => SymbolKind::Mod,
-            "const" => SymbolKind::Const,
-            "static" => SymbolKind::Static,
-            "type" => SymbolKind::TypeAlias,
-            _ => SymbolKind::Unknown,
-        };
+        let kind = SymbolKind::from_tag(kind).unwrap_or(SymbolKind::Unknown);
         Self {
             inner: ExternalSymbol {
                 name: name.to_string(),
@@ -207,6 +194,8 @@ impl PyDependencyIndexResult {
                 errors,
                 crates_indexed,
                 error_details,
+                lockfile_hash: None,
+                skipped: false,
             },
         }
     }
@@ -236,6 +225,16 @@ impl PyDependencyIndexResult {
         self.inner.error_details.clone()
     }
 
+    #[getter]
+    fn lockfile_hash(&self) -> Option<String> {
+        self.inner.lockfile_hash.clone()
+    }
+
+    #[getter]
+    fn skipped(&self) -> bool {
+        self.inner.skipped
+    }
+
     fn to_dict(&self) -> String {
         let value = serde_json::json!({
             "files_processed": self.inner.files_processed,
@@ -243,6 +242,8 @@ impl PyDependencyIndexResult {
             "errors": self.inner.errors,
             "crates_indexed": self.inner.crates_indexed,
             "error_details": self.inner.error_details,
+            "lockfile_hash": self.inner.lockfile_hash,
+            "skipped": self.inner.skipped,
         });
         serde_json::to_string(&value).unwrap_or_else(|_| "{}".to_string())
     }
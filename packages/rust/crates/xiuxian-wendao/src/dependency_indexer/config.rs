@@ -51,9 +51,22 @@ impl From<ConfigExternalDependency> for ConfigExternalDependencyHelper {
 pub struct DependencyConfig {
     /// List of external dependency configurations
     pub manifests: Vec<ConfigExternalDependency>,
+    /// Package names to index; empty means every discovered package
+    pub include_packages: Vec<String>,
+    /// Package names never indexed (takes precedence over `include_packages`)
+    pub exclude_packages: Vec<String>,
 }
 
 impl DependencyConfig {
+    /// Whether the external package `name` passes the include/exclude filter.
+    #[must_use]
+    pub fn should_index_package(&self, name: &str) -> bool {
+        if self.exclude_packages.iter().any(|p| p == name) {
+            return false;
+        }
+        self.include_packages.is_empty() || self.include_packages.iter().any(|p| p == name)
+    }
+
     /// Load configuration from YAML file.
     #[must_use]
     pub fn load(path: &str) -> Self {
@@ -127,7 +140,25 @@ impl DependencyConfig {
                     Vec::new()
                 };
 
-                Self { manifests }
+                // Parse the optional ast_symbols_external_filter section
+                let filter = config.get("ast_symbols_external_filter");
+                let package_list = |key: &str| -> Vec<String> {
+                    filter
+                        .and_then(|f| f.get(key))
+                        .and_then(|v| v.as_sequence())
+                        .map(|arr| {
+                            arr.iter()
+                                .filter_map(|v| v.as_str().map(std::string::ToString::to_string))
+                                .collect()
+                        })
+                        .unwrap_or_default()
+                };
+
+                Self {
+                    manifests,
+                    include_packages: package_list("include_packages"),
+                    exclude_packages: package_list("exclude_packages"),
+                }
             }
             Err(e) => {
                 log::warn!("Failed to read config: {e}");
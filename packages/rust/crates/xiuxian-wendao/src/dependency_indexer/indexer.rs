@@ -4,12 +4,26 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt::Write as _;
 use std::path::PathBuf;
 use std::process::Command;
+use xxhash_rust::xxh3;
 
 pub use crate::dependency_indexer::config::DependencyConfig as DependencyBuildConfig;
 pub use crate::dependency_indexer::symbols::{ExternalSymbol, SymbolIndex};
 
+/// Lockfiles (relative to the project root) whose contents decide whether a rebuild is needed.
+const LOCKFILES: &[&str] = &["Cargo.lock", "uv.lock", "poetry.lock", "requirements.txt"];
+
+/// Index cache file, relative to the project root.
+const INDEX_CACHE_PATH: &str = ".cache/xiuxian-wendao/dependency-symbol-index.txt";
+
+/// Cache header line prefix carrying the lockfile hash of the cached build.
+const LOCKFILE_HASH_HEADER: &str = "# lockfile_hash ";
+
+/// Cache header line prefix carrying one `name version` crate entry.
+const CRATE_HEADER: &str = "# crate ";
+
 /// Dependency indexer configuration (for Python bindings compatibility)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DependencyConfig {
@@ -41,6 +55,10 @@ pub struct DependencyIndexResult {
     pub crates_indexed: usize,
     /// Detailed error messages for failed crate processing
     pub error_details: Vec<String>,
+    /// Hash of the project lockfiles and config this build is based on (`None` when no lockfile exists)
+    pub lockfile_hash: Option<String>,
+    /// True when the lockfile hash was unchanged and the cached index was reused
+    pub skipped: bool,
 }
 
 /// Statistics about the index
@@ -63,6 +81,8 @@ pub struct DependencyIndexer {
     project_root: PathBuf,
     /// Config path
     config_path: Option<PathBuf>,
+    /// Lockfile hash of the last completed build
+    lockfile_hash: Option<String>,
 }
 
 impl DependencyIndexer {
@@ -73,18 +93,20 @@ impl DependencyIndexer {
             crate_versions: HashMap::new(),
             project_root: PathBuf::from(project_root),
             config_path: config_path.map(PathBuf::from),
+            lockfile_hash: None,
         }
     }
 
     /// Load the existing index from disk.
     ///
+    /// Also restores the indexed crates and the lockfile hash of the cached build,
+    /// so a following [`Self::build`] can skip an unchanged project.
+    ///
     /// # Errors
     ///
     /// Returns an error when the cached index exists but cannot be read or parsed.
     pub fn load_index(&mut self) -> Result<(), String> {
-        let cache_path = self
-            .project_root
-            .join(".cache/xiuxian-wendao/dependency-symbol-index.txt");
+        let cache_path = self.project_root.join(INDEX_CACHE_PATH);
         if !cache_path.exists() {
             return Ok(());
         }
@@ -96,18 +118,74 @@ impl DependencyIndexer {
                 cache_path.display()
             ));
         }
+        self.crate_versions.clear();
+        self.lockfile_hash = None;
+        for line in data.lines() {
+            if let Some(hash) = line.strip_prefix(LOCKFILE_HASH_HEADER) {
+                self.lockfile_hash = Some(hash.to_string());
+            } else if let Some((name, version)) = line
+                .strip_prefix(CRATE_HEADER)
+                .and_then(|entry| entry.split_once(' '))
+            {
+                self.crate_versions
+                    .insert(name.to_string(), version.to_string());
+            }
+        }
         Ok(())
     }
 
+    /// Write the index, indexed crates and lockfile hash to the cache file read by
+    /// [`Self::load_index`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the cache directory or file cannot be written.
+    pub fn save_index(&self) -> Result<(), String> {
+        let cache_path = self.project_root.join(INDEX_CACHE_PATH);
+        if let Some(parent) = cache_path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create cache dir '{}': {e}", parent.display()))?;
+        }
+        let mut data = String::new();
+        if let Some(hash) = &self.lockfile_hash {
+            let _ = writeln!(data, "{LOCKFILE_HASH_HEADER}{hash}");
+        }
+        let mut crates: Vec<_> = self.crate_versions.iter().collect();
+        crates.sort();
+        for (name, version) in crates {
+            let _ = writeln!(data, "{CRATE_HEADER}{name} {version}");
+        }
+        data.push_str(&self.symbol_index.serialize());
+        std::fs::write(&cache_path, data)
+            .map_err(|e| format!("Failed to write cache file '{}': {e}", cache_path.display()))
+    }
+
     /// Build the dependency index with parallel crate processing.
+    ///
+    /// When the lockfile hash (see [`Self::compute_lockfile_hash`]) matches the previous
+    /// error-free build, the existing symbol index is kept and the result has `skipped` set.
+    /// A completed build is written to the index cache (see [`Self::save_index`]); a build
+    /// with failed crates records no lockfile hash, so the next build retries them.
     pub fn build(&mut self, verbose: bool) -> DependencyIndexResult {
         use rayon::prelude::*;
 
         // Load configuration
-        let config_path = self.config_path.as_ref().map_or_else(
-            || "packages/conf/references.yaml".to_string(),
-            |p| p.to_string_lossy().to_string(),
-        );
+        let config_path = self.resolved_config_path();
+        let lockfile_hash = self.compute_lockfile_hash();
+        if lockfile_hash.is_some() && lockfile_hash == self.lockfile_hash {
+            if verbose {
+                log::info!("Lockfiles unchanged, reusing cached dependency index");
+            }
+            return DependencyIndexResult {
+                files_processed: 0,
+                total_symbols: self.symbol_index.symbol_count(),
+                errors: 0,
+                crates_indexed: self.crate_versions.len(),
+                error_details: Vec::new(),
+                lockfile_hash,
+                skipped: true,
+            };
+        }
 
         let config = DependencyBuildConfig::load(&config_path);
 
@@ -118,27 +196,7 @@ impl DependencyIndexer {
             );
         }
 
-        // Collect all manifest paths to process
-        let mut all_manifests: Vec<(String, PathBuf)> = Vec::new();
-
-        for ext_dep in &config.manifests {
-            if ext_dep.pkg_type != "rust" {
-                continue;
-            }
-
-            for pattern in &ext_dep.manifests {
-                let manifest_paths = find_files(pattern, &self.project_root);
-                for manifest_path in manifest_paths {
-                    // Extract crate name from path for ordering
-                    let crate_name = manifest_path
-                        .file_stem()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or("unknown")
-                        .to_string();
-                    all_manifests.push((crate_name, manifest_path));
-                }
-            }
-        }
+        let all_manifests = self.collect_rust_manifests(&config);
 
         if verbose {
             log::info!("Found {} manifests to process", all_manifests.len());
@@ -149,20 +207,22 @@ impl DependencyIndexer {
         let results: Vec<(String, String, PathBuf, Vec<ExternalSymbol>, bool, String)> =
             all_manifests
                 .into_par_iter()
-                .map(|(crate_name, manifest_path)| {
-                    let result = Self::process_manifest_inner(&manifest_path);
+                .filter_map(|(crate_name, manifest_path)| {
+                    let result = Self::process_manifest_inner(&manifest_path, &config);
                     match result {
-                        Ok((name, version, path, symbols)) => {
-                            (name, version, path, symbols, false, String::new())
+                        // Package filtered out by include/exclude_packages
+                        Ok(None) => None,
+                        Ok(Some((name, version, path, symbols))) => {
+                            Some((name, version, path, symbols, false, String::new()))
                         }
-                        Err(e) => (
+                        Err(e) => Some((
                             crate_name,
                             String::new(),
                             manifest_path,
                             Vec::new(),
                             true,
                             e,
-                        ),
+                        )),
                     }
                 })
                 .collect();
@@ -173,8 +233,13 @@ impl DependencyIndexer {
             errors: 0,
             crates_indexed: 0,
             error_details: Vec::new(),
+            lockfile_hash: lockfile_hash.clone(),
+            skipped: false,
         };
 
+        self.symbol_index.clear();
+        self.crate_versions.clear();
+
         for (crate_name, version, _source_path, symbols, is_error, error_msg) in results {
             if is_error {
                 if verbose {
@@ -201,13 +266,80 @@ impl DependencyIndexer {
             );
         }
 
+        self.lockfile_hash = if result.errors == 0 {
+            lockfile_hash
+        } else {
+            None
+        };
+        if let Err(e) = self.save_index() {
+            log::warn!("Failed to save dependency index cache: {e}");
+        }
         result
     }
 
+    /// Find the Rust manifests matched by `config`, paired with their file stem.
+    fn collect_rust_manifests(&self, config: &DependencyBuildConfig) -> Vec<(String, PathBuf)> {
+        let mut all_manifests: Vec<(String, PathBuf)> = Vec::new();
+
+        for ext_dep in &config.manifests {
+            if ext_dep.pkg_type != "rust" {
+                continue;
+            }
+
+            for pattern in &ext_dep.manifests {
+                let manifest_paths = find_files(pattern, &self.project_root);
+                for manifest_path in manifest_paths {
+                    // Extract crate name from path for ordering
+                    let crate_name = manifest_path
+                        .file_stem()
+                        .and_then(|n| n.to_str())
+                        .unwrap_or("unknown")
+                        .to_string();
+                    all_manifests.push((crate_name, manifest_path));
+                }
+            }
+        }
+        all_manifests
+    }
+
+    /// Hash the project lockfiles together with the dependency config.
+    ///
+    /// The config is included so that changing manifest patterns or package filters
+    /// also triggers a rebuild. Returns `None` when none of the lockfiles exist.
+    #[must_use]
+    pub fn compute_lockfile_hash(&self) -> Option<String> {
+        let mut buf = Vec::new();
+        for name in LOCKFILES {
+            if let Ok(bytes) = std::fs::read(self.project_root.join(name)) {
+                buf.extend_from_slice(name.as_bytes());
+                buf.push(0);
+                buf.extend_from_slice(&bytes);
+            }
+        }
+        if buf.is_empty() {
+            return None;
+        }
+        if let Ok(config) = std::fs::read(self.resolved_config_path()) {
+            buf.extend_from_slice(&config);
+        }
+        Some(format!("{:016x}", xxh3::xxh3_64(&buf)))
+    }
+
+    /// Config path passed at construction, or the default `references.yaml`.
+    fn resolved_config_path(&self) -> String {
+        self.config_path.as_ref().map_or_else(
+            || "packages/conf/references.yaml".to_string(),
+            |p| p.to_string_lossy().to_string(),
+        )
+    }
+
     /// Process a single manifest (thread-safe version for parallel processing).
+    ///
+    /// Returns `Ok(None)` without extracting symbols when `config` filters the package out.
     fn process_manifest_inner(
         manifest_path: &PathBuf,
-    ) -> Result<(String, String, PathBuf, Vec<ExternalSymbol>), String> {
+        config: &DependencyBuildConfig,
+    ) -> Result<Option<(String, String, PathBuf, Vec<ExternalSymbol>)>, String> {
         use std::fs;
 
         let content = fs::read_to_string(manifest_path)
@@ -223,6 +355,10 @@ impl DependencyIndexer {
             .and_then(|n| n.as_str())
             .ok_or("No package name found in manifest")?;
 
+        if !config.should_index_package(package_name) {
+            return Ok(None);
+        }
+
         let version = value
             .get("package")
             .and_then(|p| p.get("version"))
@@ -238,7 +374,12 @@ impl DependencyIndexer {
         // Extract symbols from Rust source files
         let symbols = extract_symbols_from_crate(&source_path, package_name);
 
-        Ok((package_name.to_string(), version, source_path, symbols))
+        Ok(Some((
+            package_name.to_string(),
+            version,
+            source_path,
+            symbols,
+        )))
     }
 
     /// Search for symbols by name pattern.
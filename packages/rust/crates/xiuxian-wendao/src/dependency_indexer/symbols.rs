@@ -62,6 +62,47 @@ pub enum SymbolKind {
     Unknown,
 }
 
+impl SymbolKind {
+    /// Short tag used in serialized indexes and the Python API (`fn`, `struct`, `type`, ...).
+    #[must_use]
+    pub const fn as_tag(&self) -> &'static str {
+        match self {
+            Self::Struct => "struct",
+            Self::Enum => "enum",
+            Self::Trait => "trait",
+            Self::Function => "fn",
+            Self::Method => "method",
+            Self::Field => "field",
+            Self::Impl => "impl",
+            Self::Mod => "mod",
+            Self::Const => "const",
+            Self::Static => "static",
+            Self::TypeAlias => "type",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    /// Parse a tag produced by [`Self::as_tag`]; returns `None` for unrecognized tags.
+    #[must_use]
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        Some(match tag {
+            "struct" => Self::Struct,
+            "enum" => Self::Enum,
+            "trait" => Self::Trait,
+            "fn" => Self::Function,
+            "method" => Self::Method,
+            "field" => Self::Field,
+            "impl" => Self::Impl,
+            "mod" => Self::Mod,
+            "const" => Self::Const,
+            "static" => Self::Static,
+            "type" => Self::TypeAlias,
+            "unknown" => Self::Unknown,
+            _ => return None,
+        })
+    }
+}
+
 /// Symbol index for fast lookup.
 #[derive(Debug, Default, Clone)]
 pub struct SymbolIndex {
@@ -176,20 +217,7 @@ impl SymbolIndex {
 
         for crate_sym in &self.by_crate {
             for sym in &crate_sym.symbols {
-                let kind_str = match sym.kind {
-                    SymbolKind::Struct => "struct",
-                    SymbolKind::Enum => "enum",
-                    SymbolKind::Trait => "trait",
-                    SymbolKind::Function => "fn",
-                    SymbolKind::Method => "method",
-                    SymbolKind::Field => "field",
-                    SymbolKind::Impl => "impl",
-                    SymbolKind::Mod => "mod",
-                    SymbolKind::Const => "const",
-                    SymbolKind::Static => "static",
-                    SymbolKind::TypeAlias => "type",
-                    SymbolKind::Unknown => "unknown",
-                };
+                let kind_str = sym.kind.as_tag();
 
                 let line = sym.line;
                 let file = sym.file.to_string_lossy();
@@ -226,20 +254,7 @@ impl SymbolIndex {
             let kind_str = parts[2];
             let loc = parts[3];
 
-            let kind = match kind_str {
-                "struct" => SymbolKind::Struct,
-                "enum" => SymbolKind::Enum,
-                "trait" => SymbolKind::Trait,
-                "fn" => SymbolKind::Function,
-                "method" => SymbolKind::Method,
-                "field" => SymbolKind::Field,
-                "impl" => SymbolKind::Impl,
-                "mod" => SymbolKind::Mod,
-                "const" => SymbolKind::Const,
-                "static" => SymbolKind::Static,
-                "type" => SymbolKind::TypeAlias,
-                _ => SymbolKind::Unknown,
-            };
+            let kind = SymbolKind::from_tag(kind_str).unwrap_or(SymbolKind::Unknown);
 
             // Parse file:line
             let mut file_parts = loc.rsplitn(2, ':');
@@ -60,6 +60,29 @@ pub struct InferredRelation {
     pub relation_type: String,
     /// Human-readable description of the relation.
     pub description: String,
+    /// Confidence in the inference, from 0.0 to 1.0.
+    #[serde(default = "default_relation_confidence")]
+    pub confidence: f32,
+}
+
+fn default_relation_confidence() -> f32 {
+    1.0
+}
+
+/// Confidence for relations declared explicitly (skill `name` in SKILL.md).
+const CONFIDENCE_DECLARED: f32 = 1.0;
+/// Confidence for wikilinks carrying a type hint (`[[Name#type]]`).
+const CONFIDENCE_TYPED_REF: f32 = 0.9;
+/// Confidence for plain wikilinks (`[[Name]]`).
+const CONFIDENCE_REF: f32 = 0.7;
+/// Confidence for tag-derived relations.
+const CONFIDENCE_TAG: f32 = 0.5;
+
+/// Options for note enhancement.
+#[derive(Debug, Clone, Default)]
+pub struct EnhancerConfig {
+    /// Inferred relations below this confidence are dropped (0.0 keeps all).
+    pub min_confidence: f32,
 }
 
 /// A note enriched with secondary analysis.
@@ -77,6 +100,9 @@ pub struct EnhancedNote {
     pub ref_stats: RefStatsData,
     /// Relations inferred from note structure.
     pub inferred_relations: Vec<InferredRelation>,
+    /// Number of inferred relations dropped by `EnhancerConfig::min_confidence`.
+    #[serde(default)]
+    pub dropped_relations: usize,
 }
 
 /// Serializable entity reference.
@@ -202,9 +228,9 @@ pub fn parse_frontmatter(content: &str) -> NoteFrontmatter {
 /// Infer relations from note structure.
 ///
 /// Relations inferred:
-/// - `DOCUMENTED_IN`: Entity refs → this document
-/// - `CONTAINS`: Skill SKILL.md → its skill name
-/// - `RELATED_TO`: Document → tags
+/// - `DOCUMENTED_IN`: Entity refs → this document (0.9 typed, 0.7 plain)
+/// - `CONTAINS`: Skill SKILL.md → its skill name (1.0)
+/// - `RELATED_TO`: Document → tags (0.5)
 #[must_use]
 pub fn infer_relations(
     note_path: &str,
@@ -222,6 +248,11 @@ pub fn infer_relations(
             target: doc_name.to_string(),
             relation_type: "DOCUMENTED_IN".to_string(),
             description: format!("{} documented in {}", entity_ref.name, doc_name),
+            confidence: if entity_ref.entity_type.is_some() {
+                CONFIDENCE_TYPED_REF
+            } else {
+                CONFIDENCE_REF
+            },
         });
     }
 
@@ -234,6 +265,7 @@ pub fn infer_relations(
             target: doc_name.to_string(),
             relation_type: "CONTAINS".to_string(),
             description: format!("Skill {name} defined in {doc_name}"),
+            confidence: CONFIDENCE_DECLARED,
         });
     }
 
@@ -244,6 +276,7 @@ pub fn infer_relations(
             target: format!("tag:{tag}"),
             relation_type: "RELATED_TO".to_string(),
             description: format!("{doc_name} tagged with {tag}"),
+            confidence: CONFIDENCE_TAG,
         });
     }
 
@@ -257,6 +290,12 @@ pub fn infer_relations(
 /// Enhance a single note with full secondary analysis.
 #[must_use]
 pub fn enhance_note(input: &NoteInput) -> EnhancedNote {
+    enhance_note_with_config(input, &EnhancerConfig::default())
+}
+
+/// Enhance a single note, dropping inferred relations below `config.min_confidence`.
+#[must_use]
+pub fn enhance_note_with_config(input: &NoteInput, config: &EnhancerConfig) -> EnhancedNote {
     let frontmatter = parse_frontmatter(&input.content);
     let entity_refs_raw = extract_entity_refs(&input.content);
     let stats_raw = get_ref_stats(&input.content);
@@ -276,7 +315,10 @@ pub fn enhance_note(input: &NoteInput) -> EnhancedNote {
         by_type: stats_raw.by_type.clone(),
     };
 
-    let relations = infer_relations(&input.path, &input.title, &frontmatter, &entity_refs_raw);
+    let mut relations = infer_relations(&input.path, &input.title, &frontmatter, &entity_refs_raw);
+    let inferred_count = relations.len();
+    relations.retain(|r| r.confidence >= config.min_confidence);
+    let dropped_relations = inferred_count - relations.len();
 
     EnhancedNote {
         path: input.path.clone(),
@@ -285,6 +327,7 @@ pub fn enhance_note(input: &NoteInput) -> EnhancedNote {
         entity_refs,
         ref_stats,
         inferred_relations: relations,
+        dropped_relations,
     }
 }
 
@@ -294,6 +337,40 @@ pub fn enhance_notes_batch(inputs: &[NoteInput]) -> Vec<EnhancedNote> {
     inputs.par_iter().map(enhance_note).collect()
 }
 
+/// Batch enhance multiple notes with progress reporting.
+///
+/// Notes are enhanced in parallel with Rayon; `callback(done, total)` is invoked on
+/// the calling thread after each note completes, so it does not need to be `Send`.
+/// Results are returned in input order.
+pub fn enhance_notes_batch_with_progress(
+    inputs: &[NoteInput],
+    mut callback: impl FnMut(usize, usize),
+) -> Vec<EnhancedNote> {
+    use rayon::prelude::*;
+
+    let total = inputs.len();
+    let mut results: Vec<Option<EnhancedNote>> = vec![None; total];
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    std::thread::scope(|scope| {
+        scope.spawn(move || {
+            inputs
+                .par_iter()
+                .enumerate()
+                .for_each_with(tx, |tx, (index, input)| {
+                    let _ = tx.send((index, enhance_note(input)));
+                });
+        });
+
+        for (done, (index, note)) in rx.iter().enumerate() {
+            results[index] = Some(note);
+            callback(done + 1, total);
+        }
+    });
+
+    results.into_iter().flatten().collect()
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -419,4 +496,59 @@ mod tests {
         assert_eq!(results[0].entity_refs.len(), 1);
         assert_eq!(results[1].entity_refs.len(), 2);
     }
+
+    #[test]
+    fn test_enhance_notes_batch_with_progress() {
+        let inputs: Vec<NoteInput> = (0..5)
+            .map(|i| NoteInput {
+                path: format!("note_{i}.md"),
+                title: format!("Note {i}"),
+                content: format!("About [[Entity{i}]]"),
+            })
+            .collect();
+
+        let mut progress = Vec::new();
+        let results = enhance_notes_batch_with_progress(&inputs, |done, total| {
+            progress.push((done, total));
+        });
+
+        assert_eq!(progress, vec![(1, 5), (2, 5), (3, 5), (4, 5), (5, 5)]);
+        assert_eq!(results.len(), 5);
+        for (i, note) in results.iter().enumerate() {
+            assert_eq!(note.path, format!("note_{i}.md"));
+            assert_eq!(note.entity_refs[0].name, format!("Entity{i}"));
+        }
+    }
+
+    #[test]
+    fn test_enhance_note_min_confidence() {
+        let input = NoteInput {
+            path: "assets/skills/git/SKILL.md".to_string(),
+            title: "Git Skill".to_string(),
+            content: "---\nname: git\ntags:\n  - vcs\n---\nUses [[Python#lang]] and [[Shell]]"
+                .to_string(),
+        };
+
+        let all = enhance_note(&input);
+        assert_eq!(all.inferred_relations.len(), 4);
+        assert_eq!(all.dropped_relations, 0);
+
+        let config = EnhancerConfig {
+            min_confidence: 0.8,
+        };
+        let filtered = enhance_note_with_config(&input, &config);
+        let kept: Vec<(&str, &str)> = filtered
+            .inferred_relations
+            .iter()
+            .map(|r| (r.relation_type.as_str(), r.source.as_str()))
+            .collect();
+        assert_eq!(kept, vec![("DOCUMENTED_IN", "Python"), ("CONTAINS", "git")]);
+        assert!(
+            filtered
+                .inferred_relations
+                .iter()
+                .all(|r| r.confidence >= 0.8)
+        );
+        assert_eq!(filtered.dropped_relations, 2);
+    }
 }
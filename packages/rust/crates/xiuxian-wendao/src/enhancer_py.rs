@@ -99,6 +99,12 @@ impl PyInferredRelation {
     fn description(&self) -> String {
         self.inner.description.clone()
     }
+
+    /// Confidence of the inference (0.0 - 1.0).
+    #[getter]
+    fn confidence(&self) -> f32 {
+        self.inner.confidence
+    }
 }
 
 /// Python wrapper for `EnhancedNote`.
@@ -162,6 +168,12 @@ impl PyEnhancedNote {
             .collect()
     }
 
+    /// Relations dropped by the confidence threshold.
+    #[getter]
+    fn dropped_relations(&self) -> usize {
+        self.inner.dropped_relations
+    }
+
     /// Serialize full result to JSON.
     fn to_json(&self) -> String {
         serde_json::to_string(&self.inner).unwrap_or_else(|_| "{}".to_string())
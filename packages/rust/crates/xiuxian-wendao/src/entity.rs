@@ -303,8 +303,60 @@ impl Relation {
         self.metadata.insert(key, value);
         self
     }
+
+    /// All documents this relation was inferred from: `source_doc` first, then any
+    /// extra documents recorded under the `source_docs` metadata key (deduplicated).
+    #[must_use]
+    pub fn source_docs(&self) -> Vec<String> {
+        let mut docs: Vec<String> = self.source_doc.iter().cloned().collect();
+        if let Some(extra) = self
+            .metadata
+            .get(RELATION_SOURCE_DOCS_KEY)
+            .and_then(|v| v.as_array())
+        {
+            for doc in extra.iter().filter_map(|v| v.as_str()) {
+                if !docs.iter().any(|d| d == doc) {
+                    docs.push(doc.to_string());
+                }
+            }
+        }
+        docs
+    }
+
+    /// Fold a re-inferred copy of this relation into `self`.
+    ///
+    /// When `other` comes from a source document not yet recorded, confidence is
+    /// combined as independent evidence (`1 - (1 - a)(1 - b)`), so it only grows;
+    /// re-ingesting an already recorded document leaves it unchanged. The source
+    /// documents of both are kept in `source_docs` metadata.
+    pub fn merge(&mut self, other: &Self) {
+        let mut docs = self.source_docs();
+        let mut new_evidence = false;
+        for doc in other.source_docs() {
+            if !docs.contains(&doc) {
+                docs.push(doc);
+                new_evidence = true;
+            }
+        }
+        if new_evidence {
+            self.confidence =
+                (1.0 - (1.0 - self.confidence) * (1.0 - other.confidence)).clamp(0.0, 1.0);
+        }
+        if self.source_doc.is_none() {
+            self.source_doc = docs.first().cloned();
+        }
+        if !docs.is_empty() {
+            self.metadata.insert(
+                RELATION_SOURCE_DOCS_KEY.to_string(),
+                serde_json::Value::from(docs),
+            );
+        }
+    }
 }
 
+/// Relation metadata key listing every source document of a merged relation.
+pub const RELATION_SOURCE_DOCS_KEY: &str = "source_docs";
+
 /// Knowledge graph statistics.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct GraphStats {
@@ -0,0 +1,64 @@
+//! Cluster detection (connected components over relations).
+
+use super::{KnowledgeGraph, read_lock};
+use std::collections::HashMap;
+
+impl KnowledgeGraph {
+    /// Group entities into clusters of entity IDs connected by relations.
+    ///
+    /// Relations refer to entities by name, so components are found over names (as in
+    /// [`Self::compute_importance`]) and then mapped back to entity IDs. Relations are
+    /// treated as undirected edges; entities without relations form singleton clusters.
+    /// Each cluster is sorted, and clusters are ordered by size (largest first), then by
+    /// their first ID.
+    #[must_use]
+    pub fn detect_clusters(&self) -> Vec<Vec<String>> {
+        let mut names: Vec<String> = read_lock(&self.entities)
+            .values()
+            .map(|e| e.name.clone())
+            .collect();
+        names.sort_unstable();
+        let index: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect();
+
+        let mut parent: Vec<usize> = (0..names.len()).collect();
+        for relation in read_lock(&self.relations).values() {
+            if let (Some(&a), Some(&b)) = (
+                index.get(relation.source.as_str()),
+                index.get(relation.target.as_str()),
+            ) {
+                let (root_a, root_b) = (find_root(&mut parent, a), find_root(&mut parent, b));
+                if root_a != root_b {
+                    parent[root_a.max(root_b)] = root_a.min(root_b);
+                }
+            }
+        }
+
+        let entities_by_name = read_lock(&self.entities_by_name);
+        let mut groups: HashMap<usize, Vec<String>> = HashMap::new();
+        for (i, name) in names.iter().enumerate() {
+            if let Some(id) = entities_by_name.get(name) {
+                let root = find_root(&mut parent, i);
+                groups.entry(root).or_default().push(id.clone());
+            }
+        }
+        let mut clusters: Vec<Vec<String>> = groups.into_values().collect();
+        for cluster in &mut clusters {
+            cluster.sort_unstable();
+        }
+        clusters.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a[0].cmp(&b[0])));
+        clusters
+    }
+}
+
+/// Union-find root lookup with path halving.
+fn find_root(parent: &mut [usize], mut node: usize) -> usize {
+    while parent[node] != node {
+        parent[node] = parent[parent[node]];
+        node = parent[node];
+    }
+    node
+}
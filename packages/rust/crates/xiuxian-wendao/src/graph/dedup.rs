@@ -235,7 +235,7 @@ fn normalize_name(name: &str) -> String {
 }
 
 /// Calculate Levenshtein distance between two strings.
-fn levenshtein_distance(a: &str, b: &str) -> usize {
+pub(crate) fn levenshtein_distance(a: &str, b: &str) -> usize {
     let a_chars: Vec<char> = a.chars().collect();
     let b_chars: Vec<char> = b.chars().collect();
 
@@ -0,0 +1,107 @@
+//! Entity importance scoring (PageRank over the relation graph).
+
+use super::{KnowledgeGraph, read_lock};
+use std::collections::HashMap;
+
+const DEFAULT_DAMPING: f32 = 0.85;
+const DEFAULT_MAX_ITERATIONS: usize = 50;
+const DEFAULT_TOLERANCE: f32 = 1e-6;
+
+/// Parameters for [`KnowledgeGraph::compute_importance_with`].
+#[derive(Debug, Clone, Copy)]
+pub struct ImportanceConfig {
+    /// Probability of following a relation instead of jumping to a random entity (0.0-1.0).
+    pub damping: f32,
+    /// Upper bound on power iterations.
+    pub max_iterations: usize,
+    /// Stop early once the L1 change between iterations falls below this value.
+    pub tolerance: f32,
+}
+
+impl Default for ImportanceConfig {
+    fn default() -> Self {
+        Self {
+            damping: DEFAULT_DAMPING,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+}
+
+impl KnowledgeGraph {
+    /// Compute PageRank importance per entity name with the default [`ImportanceConfig`].
+    #[must_use]
+    pub fn compute_importance(&self) -> HashMap<String, f32> {
+        self.compute_importance_with(&ImportanceConfig::default())
+    }
+
+    /// Compute PageRank importance per entity name.
+    ///
+    /// Each relation is a directed edge from source to target, so entities with many
+    /// inbound relations rank highest. Mass from entities without outgoing relations is
+    /// spread uniformly. Scores are normalized so the most important entity has 1.0.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn compute_importance_with(&self, config: &ImportanceConfig) -> HashMap<String, f32> {
+        let mut names: Vec<String> = read_lock(&self.entities)
+            .values()
+            .map(|e| e.name.clone())
+            .collect();
+        if names.is_empty() {
+            return HashMap::new();
+        }
+        names.sort_unstable();
+        let index: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| (name.as_str(), idx))
+            .collect();
+
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); names.len()];
+        for relation in read_lock(&self.relations).values() {
+            if let (Some(&source), Some(&target)) = (
+                index.get(relation.source.as_str()),
+                index.get(relation.target.as_str()),
+            ) && source != target
+            {
+                outgoing[source].push(target);
+            }
+        }
+
+        let n = names.len() as f32;
+        let damping = config.damping.clamp(0.0, 1.0);
+        let mut scores = vec![1.0 / n; names.len()];
+        let mut next = vec![0.0_f32; names.len()];
+        for _ in 0..config.max_iterations.max(1) {
+            let dangling: f32 = outgoing
+                .iter()
+                .zip(&scores)
+                .filter(|(targets, _)| targets.is_empty())
+                .map(|(_, score)| score)
+                .sum();
+            next.fill((1.0 - damping) / n + damping * dangling / n);
+            for (source, targets) in outgoing.iter().enumerate() {
+                if targets.is_empty() {
+                    continue;
+                }
+                let share = damping * scores[source] / targets.len() as f32;
+                for &target in targets {
+                    next[target] += share;
+                }
+            }
+
+            let delta: f32 = next.iter().zip(&scores).map(|(a, b)| (a - b).abs()).sum();
+            std::mem::swap(&mut scores, &mut next);
+            if delta < config.tolerance {
+                break;
+            }
+        }
+
+        let max = scores.iter().copied().fold(0.0_f32, f32::max);
+        names
+            .into_iter()
+            .zip(scores)
+            .map(|(name, score)| (name, if max > 0.0 { score / max } else { 0.0 }))
+            .collect()
+    }
+}
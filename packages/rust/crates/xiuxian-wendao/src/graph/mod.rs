@@ -7,9 +7,13 @@
 //! - `persistence.rs`: JSON save/load, entity/relation parsing
 //! - `lance_persistence.rs`: Arrow/Lance save/load (columnar, vector-ready)
 //! - `dedup.rs`: Entity deduplication and normalization
+//! - `importance.rs`: PageRank-style entity importance scoring
+//! - `clusters.rs`: Connected-component cluster detection
 //! - `skill_registry.rs`: Bulk skill entity registration (Bridge 4)
 
+mod clusters;
 mod dedup;
+mod importance;
 mod intent;
 pub(crate) mod lance_persistence;
 mod persistence;
@@ -24,6 +28,8 @@ use thiserror::Error;
 
 // Re-export sub-module public items
 pub use dedup::DeduplicationResult;
+pub(crate) use dedup::levenshtein_distance;
+pub use importance::ImportanceConfig;
 pub use intent::{QueryIntent, extract_intent};
 pub use persistence::{entity_from_dict, relation_from_dict};
 pub use skill_registry::{SkillDoc, SkillRegistrationResult};
@@ -132,6 +138,9 @@ impl KnowledgeGraph {
 
     /// Add a relation.
     ///
+    /// If a relation with the same (source, target, type) already exists, the new one is
+    /// merged into it via [`Relation::merge`] instead of being stored twice.
+    ///
     /// # Errors
     ///
     /// Returns [`GraphError::InvalidRelation`] if source/target entities do not exist.
@@ -141,8 +150,27 @@ impl KnowledgeGraph {
         let mut outgoing = write_lock(&self.outgoing_relations);
         let mut incoming = write_lock(&self.incoming_relations);
 
-        if relations.contains_key(&relation.id) {
-            info!("Relation already exists: {}", relation.id);
+        let existing_id = if relations.contains_key(&relation.id) {
+            Some(relation.id.clone())
+        } else {
+            outgoing.get(&relation.source).and_then(|ids| {
+                ids.iter()
+                    .find(|id| {
+                        relations.get(*id).is_some_and(|r| {
+                            r.target == relation.target && r.relation_type == relation.relation_type
+                        })
+                    })
+                    .cloned()
+            })
+        };
+        if let Some(existing_id) = existing_id
+            && let Some(existing) = relations.get_mut(&existing_id)
+        {
+            existing.merge(&relation);
+            info!(
+                "Merged duplicate relation: {} (confidence {:.3})",
+                existing_id, existing.confidence
+            );
             return Ok(());
         }
 
@@ -189,13 +217,22 @@ impl KnowledgeGraph {
     }
 
     /// Get an entity by name.
+    ///
+    /// Falls back to a case-insensitive match against entity aliases when no entity
+    /// has this exact name; if several entities share the alias, the smallest ID wins.
     #[must_use]
     pub fn get_entity_by_name(&self, name: &str) -> Option<Entity> {
-        let entities_by_name = read_lock(&self.entities_by_name);
-        if let Some(entity_id) = entities_by_name.get(name) {
-            return read_lock(&self.entities).get(entity_id).cloned();
+        let entity_id = read_lock(&self.entities_by_name).get(name).cloned();
+        let entities = read_lock(&self.entities);
+        if let Some(entity_id) = entity_id {
+            return entities.get(&entity_id).cloned();
         }
-        None
+        let name_lower = name.to_lowercase();
+        entities
+            .values()
+            .filter(|e| e.aliases.iter().any(|a| a.to_lowercase() == name_lower))
+            .min_by(|a, b| a.id.cmp(&b.id))
+            .cloned()
     }
 
     /// Get entities by type.
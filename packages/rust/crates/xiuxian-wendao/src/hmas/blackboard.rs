@@ -1,7 +1,4 @@
-use super::protocol::{
-    HmasConclusionPayload, HmasDigitalThreadPayload, HmasEvidencePayload, HmasRecordKind,
-    HmasSourceNode, HmasTaskPayload,
-};
+use super::protocol::{HmasPayload, HmasRecordKind, HmasSourceNode};
 use comrak::{
     Arena, Options,
     nodes::{AstNode, NodeValue},
@@ -9,6 +6,8 @@ use comrak::{
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
 use std::path::Path;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -103,6 +102,57 @@ fn parse_code_fence_info(info: &str) -> (bool, Option<HmasRecordKind>) {
     (is_json, fence_kind)
 }
 
+fn resolve_block(
+    report: &mut HmasValidationReport,
+    active_heading_kind: Option<HmasRecordKind>,
+    info: &str,
+    line: usize,
+    payload: String,
+) -> Option<ExtractedBlock> {
+    let (is_json, explicit_kind) = parse_code_fence_info(info);
+
+    let resolved_kind = match (active_heading_kind, explicit_kind) {
+        (Some(heading_kind), Some(fence_kind)) => {
+            if heading_kind != fence_kind {
+                report.push_issue(
+                    line,
+                    "fence_heading_kind_mismatch",
+                    format!(
+                        "heading kind {} does not match fenced block kind {}",
+                        heading_kind.as_code(),
+                        fence_kind.as_code()
+                    ),
+                    Some(fence_kind),
+                );
+            }
+            Some(fence_kind)
+        }
+        (Some(heading_kind), None) => Some(heading_kind),
+        (None, Some(fence_kind)) => Some(fence_kind),
+        (None, None) => None,
+    };
+
+    let kind = resolved_kind?;
+
+    if !is_json {
+        report.push_issue(
+            line,
+            "unexpected_fence_language",
+            format!(
+                "{} block must use JSON fenced code block (`json` language)",
+                kind.as_code()
+            ),
+            Some(kind),
+        );
+        return None;
+    }
+    Some(ExtractedBlock {
+        kind,
+        line,
+        json_payload: payload,
+    })
+}
+
 fn collect_blocks(markdown: &str, report: &mut HmasValidationReport) -> Vec<ExtractedBlock> {
     let arena = Arena::new();
     let root = parse_document(&arena, markdown, &Options::default());
@@ -120,53 +170,15 @@ fn collect_blocks(markdown: &str, report: &mut HmasValidationReport) -> Vec<Extr
                 active_heading_kind = HmasRecordKind::from_heading_text(&heading_text(node));
             }
             NodeValue::CodeBlock(block) => {
-                let line = node_line(node);
-                let info = block.info.trim().to_string();
-                let payload = block.literal.clone();
-                let (is_json, explicit_kind) = parse_code_fence_info(&info);
-
-                let resolved_kind = match (active_heading_kind, explicit_kind) {
-                    (Some(heading_kind), Some(fence_kind)) => {
-                        if heading_kind != fence_kind {
-                            report.push_issue(
-                                line,
-                                "fence_heading_kind_mismatch",
-                                format!(
-                                    "heading kind {} does not match fenced block kind {}",
-                                    heading_kind.as_code(),
-                                    fence_kind.as_code()
-                                ),
-                                Some(fence_kind),
-                            );
-                        }
-                        Some(fence_kind)
-                    }
-                    (Some(heading_kind), None) => Some(heading_kind),
-                    (None, Some(fence_kind)) => Some(fence_kind),
-                    (None, None) => None,
-                };
-
-                let Some(kind) = resolved_kind else {
-                    continue;
-                };
-
-                if !is_json {
-                    report.push_issue(
-                        line,
-                        "unexpected_fence_language",
-                        format!(
-                            "{} block must use JSON fenced code block (`json` language)",
-                            kind.as_code()
-                        ),
-                        Some(kind),
-                    );
-                    continue;
+                if let Some(extracted) = resolve_block(
+                    report,
+                    active_heading_kind,
+                    block.info.trim(),
+                    node_line(node),
+                    block.literal.clone(),
+                ) {
+                    blocks.push(extracted);
                 }
-                blocks.push(ExtractedBlock {
-                    kind,
-                    line,
-                    json_payload: payload,
-                });
             }
             _ => {}
         }
@@ -181,169 +193,231 @@ fn has_empty_source_nodes(source_nodes: &[HmasSourceNode]) -> bool {
         .any(|node| node.node_id.trim().is_empty())
 }
 
-pub fn validate_blackboard_markdown(markdown: &str) -> HmasValidationReport {
-    let mut report = HmasValidationReport::ok();
-    let blocks = collect_blocks(markdown, &mut report);
+fn decode_payload(kind: HmasRecordKind, json: &str) -> Result<HmasPayload, serde_json::Error> {
+    Ok(match kind {
+        HmasRecordKind::Task => HmasPayload::Task(serde_json::from_str(json)?),
+        HmasRecordKind::Evidence => HmasPayload::Evidence(serde_json::from_str(json)?),
+        HmasRecordKind::Conclusion => HmasPayload::Conclusion(serde_json::from_str(json)?),
+        HmasRecordKind::DigitalThread => HmasPayload::DigitalThread(serde_json::from_str(json)?),
+    })
+}
 
-    let mut digital_thread_requirements = HashSet::new();
-    let mut conclusion_requirements = Vec::new();
+/// Apply kind-specific required-field rules to one decoded payload.
+fn check_payload(report: &mut HmasValidationReport, line: usize, payload: &HmasPayload) {
+    let kind = Some(payload.kind());
+    let requirement_id = match payload {
+        HmasPayload::Task(task) => &task.requirement_id,
+        HmasPayload::Evidence(evidence) => &evidence.requirement_id,
+        HmasPayload::Conclusion(conclusion) => &conclusion.requirement_id,
+        HmasPayload::DigitalThread(thread) => &thread.requirement_id,
+    };
+    if requirement_id.trim().is_empty() {
+        report.push_issue(
+            line,
+            "missing_requirement_id",
+            format!(
+                "{}.requirement_id must be non-empty",
+                payload.kind().as_code()
+            ),
+            kind,
+        );
+    }
 
-    for block in blocks {
-        match block.kind {
-            HmasRecordKind::Task => {
-                report.task_count += 1;
-                match serde_json::from_str::<HmasTaskPayload>(&block.json_payload) {
-                    Ok(payload) => {
-                        if payload.requirement_id.trim().is_empty() {
-                            report.push_issue(
-                                block.line,
-                                "missing_requirement_id",
-                                "task.requirement_id must be non-empty",
-                                Some(block.kind),
-                            );
-                        }
-                        if payload.hard_constraints.is_empty() {
-                            report.push_issue(
-                                block.line,
-                                "missing_hard_constraints",
-                                "task.hard_constraints must be non-empty",
-                                Some(block.kind),
-                            );
-                        }
-                    }
-                    Err(err) => report.push_issue(
-                        block.line,
-                        "invalid_json_payload",
-                        format!("failed to decode task payload: {err}"),
-                        Some(block.kind),
-                    ),
-                }
+    match payload {
+        HmasPayload::Task(task) => {
+            if task.hard_constraints.is_empty() {
+                report.push_issue(
+                    line,
+                    "missing_hard_constraints",
+                    "task.hard_constraints must be non-empty",
+                    kind,
+                );
             }
-            HmasRecordKind::Evidence => {
-                report.evidence_count += 1;
-                match serde_json::from_str::<HmasEvidencePayload>(&block.json_payload) {
-                    Ok(payload) => {
-                        if payload.requirement_id.trim().is_empty() {
-                            report.push_issue(
-                                block.line,
-                                "missing_requirement_id",
-                                "evidence.requirement_id must be non-empty",
-                                Some(block.kind),
-                            );
-                        }
-                    }
-                    Err(err) => report.push_issue(
-                        block.line,
-                        "invalid_json_payload",
-                        format!("failed to decode evidence payload: {err}"),
-                        Some(block.kind),
-                    ),
-                }
+        }
+        HmasPayload::Evidence(_) => {}
+        HmasPayload::Conclusion(conclusion) => {
+            if !(0.0..=1.0).contains(&conclusion.confidence_score) {
+                report.push_issue(
+                    line,
+                    "invalid_confidence_score",
+                    "conclusion.confidence_score must be between 0 and 1",
+                    kind,
+                );
             }
-            HmasRecordKind::Conclusion => {
-                report.conclusion_count += 1;
-                match serde_json::from_str::<HmasConclusionPayload>(&block.json_payload) {
-                    Ok(payload) => {
-                        let requirement_id = payload.requirement_id.trim().to_string();
-                        if requirement_id.is_empty() {
-                            report.push_issue(
-                                block.line,
-                                "missing_requirement_id",
-                                "conclusion.requirement_id must be non-empty",
-                                Some(block.kind),
-                            );
-                        } else {
-                            conclusion_requirements.push((requirement_id, block.line));
-                        }
-                        if !(0.0..=1.0).contains(&payload.confidence_score) {
-                            report.push_issue(
-                                block.line,
-                                "invalid_confidence_score",
-                                "conclusion.confidence_score must be between 0 and 1",
-                                Some(block.kind),
-                            );
-                        }
-                    }
-                    Err(err) => report.push_issue(
-                        block.line,
-                        "invalid_json_payload",
-                        format!("failed to decode conclusion payload: {err}"),
-                        Some(block.kind),
-                    ),
-                }
+        }
+        HmasPayload::DigitalThread(thread) => {
+            if thread.source_nodes_accessed.is_empty() {
+                report.push_issue(
+                    line,
+                    "missing_source_nodes",
+                    "digital_thread.source_nodes_accessed must be non-empty",
+                    kind,
+                );
+            } else if has_empty_source_nodes(&thread.source_nodes_accessed) {
+                report.push_issue(
+                    line,
+                    "empty_source_node_id",
+                    "digital_thread.source_nodes_accessed[*].node_id must be non-empty",
+                    kind,
+                );
             }
-            HmasRecordKind::DigitalThread => {
-                report.digital_thread_count += 1;
-                match serde_json::from_str::<HmasDigitalThreadPayload>(&block.json_payload) {
-                    Ok(payload) => {
-                        let requirement_id = payload.requirement_id.trim();
-                        if requirement_id.is_empty() {
-                            report.push_issue(
-                                block.line,
-                                "missing_requirement_id",
-                                "digital_thread.requirement_id must be non-empty",
-                                Some(block.kind),
-                            );
-                        } else {
-                            digital_thread_requirements.insert(requirement_id.to_string());
-                        }
-
-                        if payload.source_nodes_accessed.is_empty() {
-                            report.push_issue(
-                                block.line,
-                                "missing_source_nodes",
-                                "digital_thread.source_nodes_accessed must be non-empty",
-                                Some(block.kind),
-                            );
-                        } else if has_empty_source_nodes(&payload.source_nodes_accessed) {
-                            report.push_issue(
-                                block.line,
-                                "empty_source_node_id",
-                                "digital_thread.source_nodes_accessed[*].node_id must be non-empty",
-                                Some(block.kind),
-                            );
-                        }
-
-                        if payload.hard_constraints_checked.is_empty() {
-                            report.push_issue(
-                                block.line,
-                                "missing_constraints_checked",
-                                "digital_thread.hard_constraints_checked must be non-empty",
-                                Some(block.kind),
-                            );
-                        }
-                        if !(0.0..=1.0).contains(&payload.confidence_score) {
-                            report.push_issue(
-                                block.line,
-                                "invalid_confidence_score",
-                                "digital_thread.confidence_score must be between 0 and 1",
-                                Some(block.kind),
-                            );
-                        }
-                    }
-                    Err(err) => report.push_issue(
-                        block.line,
-                        "invalid_json_payload",
-                        format!("failed to decode digital_thread payload: {err}"),
-                        Some(block.kind),
-                    ),
-                }
+
+            if thread.hard_constraints_checked.is_empty() {
+                report.push_issue(
+                    line,
+                    "missing_constraints_checked",
+                    "digital_thread.hard_constraints_checked must be non-empty",
+                    kind,
+                );
+            }
+            if !(0.0..=1.0).contains(&thread.confidence_score) {
+                report.push_issue(
+                    line,
+                    "invalid_confidence_score",
+                    "digital_thread.confidence_score must be between 0 and 1",
+                    kind,
+                );
             }
         }
     }
+}
 
-    for (requirement_id, line) in conclusion_requirements {
-        if !digital_thread_requirements.contains(&requirement_id) {
+impl HmasRecordKind {
+    /// Validate a typed payload against this kind's required fields.
+    ///
+    /// Issues carry line `0` because the payload has no source location.
+    ///
+    /// # Errors
+    ///
+    /// Returns a report listing every violated rule, including a kind mismatch.
+    pub fn validate_payload(self, payload: &HmasPayload) -> Result<(), HmasValidationReport> {
+        let mut report = HmasValidationReport::ok();
+        if payload.kind() != self {
             report.push_issue(
-                line,
-                "missing_digital_thread",
+                0,
+                "payload_kind_mismatch",
                 format!(
-                    "conclusion requirement_id={requirement_id} has no matching digital_thread payload"
+                    "expected {} payload but got {}",
+                    self.as_code(),
+                    payload.kind().as_code()
                 ),
-                Some(HmasRecordKind::Conclusion),
+                Some(payload.kind()),
             );
         }
+        check_payload(&mut report, 0, payload);
+        if report.valid { Ok(()) } else { Err(report) }
+    }
+}
+
+impl HmasPayload {
+    /// Parse every HMAS record in `markdown` into typed payloads.
+    ///
+    /// Cross-record rules (such as conclusion/digital-thread pairing) are not
+    /// applied; use [`validate_blackboard_markdown`] for full blackboard checks.
+    ///
+    /// # Errors
+    ///
+    /// Returns the validation report if any record is malformed or violates
+    /// its kind-specific rules.
+    pub fn from_markdown(markdown: &str) -> Result<Vec<Self>, HmasValidationReport> {
+        let mut report = HmasValidationReport::ok();
+        let blocks = collect_blocks(markdown, &mut report);
+        let mut payloads = Vec::with_capacity(blocks.len());
+        for block in &blocks {
+            match decode_payload(block.kind, &block.json_payload) {
+                Ok(payload) => {
+                    check_payload(&mut report, block.line, &payload);
+                    payloads.push(payload);
+                }
+                Err(err) => report.push_issue(
+                    block.line,
+                    "invalid_json_payload",
+                    format!("failed to decode {} payload: {err}", block.kind.as_code()),
+                    Some(block.kind),
+                ),
+            }
+        }
+        if report.valid {
+            Ok(payloads)
+        } else {
+            Err(report)
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct CrossRecordState {
+    digital_thread_requirements: HashSet<String>,
+    conclusion_requirements: Vec<(String, usize)>,
+}
+
+impl CrossRecordState {
+    fn validate_block(&mut self, report: &mut HmasValidationReport, block: &ExtractedBlock) {
+        match block.kind {
+            HmasRecordKind::Task => report.task_count += 1,
+            HmasRecordKind::Evidence => report.evidence_count += 1,
+            HmasRecordKind::Conclusion => report.conclusion_count += 1,
+            HmasRecordKind::DigitalThread => report.digital_thread_count += 1,
+        }
+
+        let payload = match decode_payload(block.kind, &block.json_payload) {
+            Ok(payload) => payload,
+            Err(err) => {
+                report.push_issue(
+                    block.line,
+                    "invalid_json_payload",
+                    format!("failed to decode {} payload: {err}", block.kind.as_code()),
+                    Some(block.kind),
+                );
+                return;
+            }
+        };
+
+        check_payload(report, block.line, &payload);
+        match &payload {
+            HmasPayload::Conclusion(conclusion) => {
+                let requirement_id = conclusion.requirement_id.trim();
+                if !requirement_id.is_empty() {
+                    self.conclusion_requirements
+                        .push((requirement_id.to_string(), block.line));
+                }
+            }
+            HmasPayload::DigitalThread(thread) => {
+                let requirement_id = thread.requirement_id.trim();
+                if !requirement_id.is_empty() {
+                    self.digital_thread_requirements
+                        .insert(requirement_id.to_string());
+                }
+            }
+            HmasPayload::Task(_) | HmasPayload::Evidence(_) => {}
+        }
+    }
+
+    fn finish(self, report: &mut HmasValidationReport) {
+        for (requirement_id, line) in self.conclusion_requirements {
+            if !self.digital_thread_requirements.contains(&requirement_id) {
+                report.push_issue(
+                    line,
+                    "missing_digital_thread",
+                    format!(
+                        "conclusion requirement_id={requirement_id} has no matching digital_thread payload"
+                    ),
+                    Some(HmasRecordKind::Conclusion),
+                );
+            }
+        }
+    }
+}
+
+pub fn validate_blackboard_markdown(markdown: &str) -> HmasValidationReport {
+    let mut report = HmasValidationReport::ok();
+    let blocks = collect_blocks(markdown, &mut report);
+
+    let mut state = CrossRecordState::default();
+    for block in &blocks {
+        state.validate_block(&mut report, block);
     }
+    state.finish(&mut report);
 
     report
 }
@@ -357,3 +431,200 @@ pub fn validate_blackboard_file(path: &Path) -> Result<HmasValidationReport, Str
     })?;
     Ok(validate_blackboard_markdown(&content))
 }
+
+/// Fence opened while streaming: marker char, marker length, info string, start line.
+#[derive(Debug)]
+struct OpenFence {
+    marker: char,
+    len: usize,
+    info: String,
+    line: usize,
+    payload: String,
+}
+
+fn fence_marker(line: &str) -> Option<(char, usize, &str)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let marker = trimmed
+        .chars()
+        .next()
+        .filter(|ch| *ch == '`' || *ch == '~')?;
+    let len = trimmed.chars().take_while(|ch| *ch == marker).count();
+    if len < 3 {
+        return None;
+    }
+    let rest = &trimmed[len..];
+    if marker == '`' && rest.contains('`') {
+        return None;
+    }
+    Some((marker, len, rest.trim()))
+}
+
+fn atx_heading_text(line: &str) -> Option<String> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let level = trimmed.chars().take_while(|ch| *ch == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    let text = rest.trim().trim_end_matches('#').trim();
+    Some(text.replace('`', ""))
+}
+
+/// Incremental blackboard validator yielding issues as records are read.
+///
+/// Records are validated one fenced block at a time, so early issues surface
+/// before the rest of the input is consumed. Cross-record checks (such as a
+/// conclusion without a matching digital thread) are emitted once the input
+/// is exhausted.
+pub struct HmasBlackboardStream<R> {
+    lines: std::io::Lines<R>,
+    line_no: usize,
+    active_heading_kind: Option<HmasRecordKind>,
+    open_fence: Option<OpenFence>,
+    state: Option<CrossRecordState>,
+    report: HmasValidationReport,
+    emitted: usize,
+}
+
+impl<R: BufRead> HmasBlackboardStream<R> {
+    /// Start streaming validation over a buffered markdown reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            line_no: 0,
+            active_heading_kind: None,
+            open_fence: None,
+            state: Some(CrossRecordState::default()),
+            report: HmasValidationReport::ok(),
+            emitted: 0,
+        }
+    }
+
+    /// Report accumulated from the records consumed so far.
+    #[must_use]
+    pub fn report(&self) -> &HmasValidationReport {
+        &self.report
+    }
+
+    /// Drain the remaining input and return the full report.
+    #[must_use]
+    pub fn into_report(mut self) -> HmasValidationReport {
+        while self.next().is_some() {}
+        self.report
+    }
+
+    fn close_fence(&mut self, fence: OpenFence) {
+        let Some(state) = self.state.as_mut() else {
+            return;
+        };
+        if let Some(block) = resolve_block(
+            &mut self.report,
+            self.active_heading_kind,
+            &fence.info,
+            fence.line,
+            fence.payload,
+        ) {
+            state.validate_block(&mut self.report, &block);
+        }
+    }
+
+    fn finish(&mut self) {
+        if let Some(fence) = self.open_fence.take() {
+            self.close_fence(fence);
+        }
+        if let Some(state) = self.state.take() {
+            state.finish(&mut self.report);
+        }
+    }
+
+    fn consume_line(&mut self, line: &str) {
+        if let Some(fence) = self.open_fence.as_mut() {
+            if let Some((marker, len, rest)) = fence_marker(line)
+                && marker == fence.marker
+                && len >= fence.len
+                && rest.is_empty()
+            {
+                if let Some(fence) = self.open_fence.take() {
+                    self.close_fence(fence);
+                }
+            } else {
+                fence.payload.push_str(line);
+                fence.payload.push('\n');
+            }
+            return;
+        }
+
+        if let Some((marker, len, info)) = fence_marker(line) {
+            self.open_fence = Some(OpenFence {
+                marker,
+                len,
+                info: info.to_string(),
+                line: self.line_no,
+                payload: String::new(),
+            });
+        } else if let Some(text) = atx_heading_text(line) {
+            self.active_heading_kind = HmasRecordKind::from_heading_text(&text);
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for HmasBlackboardStream<R> {
+    type Item = HmasValidationIssue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.emitted >= self.report.issues.len() {
+            self.state.as_ref()?;
+            match self.lines.next() {
+                Some(Ok(line)) => {
+                    self.line_no += 1;
+                    self.consume_line(&line);
+                }
+                Some(Err(err)) => {
+                    self.report.push_issue(
+                        self.line_no + 1,
+                        "read_error",
+                        format!("failed to read blackboard markdown: {err}"),
+                        None,
+                    );
+                    self.open_fence = None;
+                    self.state = None;
+                }
+                None => self.finish(),
+            }
+        }
+        let issue = self.report.issues[self.emitted].clone();
+        self.emitted += 1;
+        Some(issue)
+    }
+}
+
+/// Stream-validate blackboard markdown from any buffered reader.
+pub fn stream_blackboard_markdown<R: BufRead>(reader: R) -> HmasBlackboardStream<R> {
+    HmasBlackboardStream::new(reader)
+}
+
+/// Stream-validate a blackboard markdown file without loading it into memory.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened.
+pub fn stream_blackboard_file(
+    path: &Path,
+) -> Result<HmasBlackboardStream<BufReader<File>>, String> {
+    let file = File::open(path).map_err(|err| {
+        format!(
+            "failed to open blackboard markdown file {}: {err}",
+            path.display()
+        )
+    })?;
+    Ok(HmasBlackboardStream::new(BufReader::new(file)))
+}
@@ -2,10 +2,10 @@ mod blackboard;
 mod protocol;
 
 pub use blackboard::{
-    HmasValidationIssue, HmasValidationReport, validate_blackboard_file,
-    validate_blackboard_markdown,
+    HmasBlackboardStream, HmasValidationIssue, HmasValidationReport, stream_blackboard_file,
+    stream_blackboard_markdown, validate_blackboard_file, validate_blackboard_markdown,
 };
 pub use protocol::{
-    HmasConclusionPayload, HmasDigitalThreadPayload, HmasEvidencePayload, HmasRecordKind,
-    HmasSourceNode, HmasTaskPayload,
+    HmasConclusionPayload, HmasDigitalThreadPayload, HmasEvidencePayload, HmasPayload,
+    HmasRecordKind, HmasSourceNode, HmasTaskPayload,
 };
@@ -54,6 +54,17 @@ impl HmasRecordKind {
         }
     }
 
+    /// Label used in `#### [LABEL]` blackboard headings.
+    #[must_use]
+    pub const fn heading_label(self) -> &'static str {
+        match self {
+            Self::Task => "TASK",
+            Self::Evidence => "EVIDENCE",
+            Self::Conclusion => "CONCLUSION",
+            Self::DigitalThread => "DIGITAL THREAD",
+        }
+    }
+
     #[must_use]
     pub const fn as_code(self) -> &'static str {
         match self {
@@ -111,3 +122,42 @@ pub struct HmasDigitalThreadPayload {
     pub hard_constraints_checked: Vec<String>,
     pub confidence_score: f64,
 }
+
+/// Typed HMAS record payload tagged by its [`HmasRecordKind`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum HmasPayload {
+    Task(HmasTaskPayload),
+    Evidence(HmasEvidencePayload),
+    Conclusion(HmasConclusionPayload),
+    DigitalThread(HmasDigitalThreadPayload),
+}
+
+impl HmasPayload {
+    #[must_use]
+    pub const fn kind(&self) -> HmasRecordKind {
+        match self {
+            Self::Task(_) => HmasRecordKind::Task,
+            Self::Evidence(_) => HmasRecordKind::Evidence,
+            Self::Conclusion(_) => HmasRecordKind::Conclusion,
+            Self::DigitalThread(_) => HmasRecordKind::DigitalThread,
+        }
+    }
+
+    /// Render the payload as a blackboard section (`#### [KIND]` + JSON fence).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload cannot be serialized to JSON.
+    pub fn to_markdown(&self) -> Result<String, serde_json::Error> {
+        let json = match self {
+            Self::Task(payload) => serde_json::to_string_pretty(payload)?,
+            Self::Evidence(payload) => serde_json::to_string_pretty(payload)?,
+            Self::Conclusion(payload) => serde_json::to_string_pretty(payload)?,
+            Self::DigitalThread(payload) => serde_json::to_string_pretty(payload)?,
+        };
+        Ok(format!(
+            "#### [{}]\n```json\n{json}\n```\n",
+            self.kind().heading_label()
+        ))
+    }
+}
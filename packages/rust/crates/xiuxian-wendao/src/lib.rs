@@ -92,20 +92,23 @@ pub use dependency_indexer::{
     DependencyIndexer, DependencyStats, ExternalSymbol, SymbolIndex, SymbolKind,
 };
 pub use enhancer::{
-    EnhancedNote, EntityRefData, InferredRelation, NoteFrontmatter, NoteInput, RefStatsData,
-    enhance_note, enhance_notes_batch, parse_frontmatter,
+    EnhancedNote, EnhancerConfig, EntityRefData, InferredRelation, NoteFrontmatter, NoteInput,
+    RefStatsData, enhance_note, enhance_note_with_config, enhance_notes_batch,
+    enhance_notes_batch_with_progress, parse_frontmatter,
 };
 pub use enhancer_py::{
     PyEnhancedNote, PyInferredRelation, PyNoteFrontmatter, link_graph_enhance_note,
     link_graph_enhance_notes_batch, link_graph_parse_frontmatter,
 };
 pub use entity::{
-    Entity, EntitySearchQuery, EntityType, GraphStats, MultiHopOptions, Relation, RelationType,
+    Entity, EntitySearchQuery, EntityType, GraphStats, MultiHopOptions, RELATION_SOURCE_DOCS_KEY,
+    Relation, RelationType,
 };
 pub use graph::{KnowledgeGraph, QueryIntent, SkillDoc, SkillRegistrationResult, extract_intent};
 pub use hmas::{
-    HmasConclusionPayload, HmasDigitalThreadPayload, HmasEvidencePayload, HmasRecordKind,
-    HmasSourceNode, HmasTaskPayload, HmasValidationIssue, HmasValidationReport,
+    HmasBlackboardStream, HmasConclusionPayload, HmasDigitalThreadPayload, HmasEvidencePayload,
+    HmasPayload, HmasRecordKind, HmasSourceNode, HmasTaskPayload, HmasValidationIssue,
+    HmasValidationReport, stream_blackboard_file, stream_blackboard_markdown,
     validate_blackboard_file, validate_blackboard_markdown,
 };
 pub use link_graph::{
@@ -126,7 +129,7 @@ pub use link_graph_py::{
 };
 pub use link_graph_refs::{
     LinkGraphEntityRef, LinkGraphRefStats, extract_entity_refs, find_notes_referencing_entity,
-    get_ref_stats,
+    get_ref_stats, get_ref_stats_with_known, validate_refs,
 };
 pub use link_graph_refs_py::{
     PyLinkGraphEntityRef, PyLinkGraphRefStats, link_graph_count_refs,
@@ -134,7 +137,9 @@ pub use link_graph_refs_py::{
     link_graph_is_valid_ref, link_graph_parse_entity_ref,
 };
 pub use storage::KnowledgeStorage;
-pub use sync::{DiscoveryOptions, FileChange, SyncEngine, SyncManifest, SyncResult};
+pub use sync::{
+    Discovery, DiscoveryOptions, FileChange, FileEntry, SyncEngine, SyncManifest, SyncResult,
+};
 pub use types::{KnowledgeCategory, KnowledgeEntry, KnowledgeSearchQuery, KnowledgeStats};
 pub use unified_symbol::{SymbolSource, UnifiedIndexStats, UnifiedSymbol, UnifiedSymbolIndex};
 pub use unified_symbol_py::{PyUnifiedIndexStats, PyUnifiedSymbol, PyUnifiedSymbolIndex};
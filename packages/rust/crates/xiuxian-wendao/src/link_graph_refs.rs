@@ -184,6 +184,29 @@ pub fn parse_entity_ref(text: &str) -> Option<LinkGraphEntityRef> {
     ))
 }
 
+/// Find entity references that do not resolve to a known entity.
+///
+/// Matching is by exact entity name; the `#type` hint is ignored.
+///
+/// # Arguments
+///
+/// * `refs` - Entity references extracted from a note
+/// * `known` - Names of entities present in the graph
+///
+/// # Returns
+///
+/// The unresolved (dangling) references, in input order
+#[must_use]
+pub fn validate_refs(
+    refs: &[LinkGraphEntityRef],
+    known: &HashSet<String>,
+) -> Vec<LinkGraphEntityRef> {
+    refs.iter()
+        .filter(|entity_ref| !known.contains(&entity_ref.name))
+        .cloned()
+        .collect()
+}
+
 /// Entity reference statistics for a note.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LinkGraphRefStats {
@@ -193,6 +216,12 @@ pub struct LinkGraphRefStats {
     pub unique_entities: usize,
     /// References by type
     pub by_type: Vec<(String, usize)>,
+    /// References whose target is not a known note (0 when no known set was supplied)
+    #[serde(default)]
+    pub broken_refs: usize,
+    /// Unique broken target names, in first-seen order
+    #[serde(default)]
+    pub broken_targets: Vec<String>,
 }
 
 impl LinkGraphRefStats {
@@ -219,7 +248,23 @@ impl LinkGraphRefStats {
             total_refs: refs.len(),
             unique_entities: unique_names.len(),
             by_type,
+            broken_refs: 0,
+            broken_targets: Vec::new(),
+        }
+    }
+
+    /// Create stats from entity references, counting refs to notes not in `known`.
+    #[must_use]
+    pub fn from_refs_with_known(refs: &[LinkGraphEntityRef], known: &HashSet<String>) -> Self {
+        let mut stats = Self::from_refs(refs);
+        let broken = validate_refs(refs, known);
+        stats.broken_refs = broken.len();
+        for entity_ref in broken {
+            if !stats.broken_targets.contains(&entity_ref.name) {
+                stats.broken_targets.push(entity_ref.name);
+            }
         }
+        stats
     }
 }
 
@@ -228,3 +273,14 @@ impl LinkGraphRefStats {
 pub fn get_ref_stats(content: &str) -> LinkGraphRefStats {
     LinkGraphRefStats::from_refs(&extract_entity_refs(content))
 }
+
+/// Get statistics for entity references in content, including broken links.
+///
+/// # Arguments
+///
+/// * `content` - Note content to scan
+/// * `known` - IDs of notes that exist in the graph
+#[must_use]
+pub fn get_ref_stats_with_known(content: &str, known: &HashSet<String>) -> LinkGraphRefStats {
+    LinkGraphRefStats::from_refs_with_known(&extract_entity_refs(content), known)
+}
@@ -92,6 +92,8 @@ impl PyLinkGraphRefStats {
                 total_refs,
                 unique_entities,
                 by_type,
+                broken_refs: 0,
+                broken_targets: Vec::new(),
             },
         }
     }
@@ -111,11 +113,23 @@ impl PyLinkGraphRefStats {
         self.inner.by_type.clone()
     }
 
+    #[getter]
+    fn broken_refs(&self) -> usize {
+        self.inner.broken_refs
+    }
+
+    #[getter]
+    fn broken_targets(&self) -> Vec<String> {
+        self.inner.broken_targets.clone()
+    }
+
     fn to_dict(&self) -> String {
         let value = serde_json::json!({
             "total_refs": self.inner.total_refs,
             "unique_entities": self.inner.unique_entities,
             "by_type": self.inner.by_type,
+            "broken_refs": self.inner.broken_refs,
+            "broken_targets": self.inner.broken_targets,
         });
         to_string(&value).unwrap_or_else(|_| "{}".to_string())
     }
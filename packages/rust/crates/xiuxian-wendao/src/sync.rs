@@ -4,7 +4,7 @@
 //! for incremental knowledge base updates.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use xxhash_rust::xxh3;
 
@@ -36,6 +36,24 @@ pub struct SyncResult {
     pub unchanged: usize,
 }
 
+/// Discovered file with its optional content hash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEntry {
+    /// Absolute path of the file.
+    pub path: PathBuf,
+    /// xxhash of the content, present only when `DiscoveryOptions::hash_contents` is set.
+    pub hash: Option<String>,
+}
+
+/// File discovery result
+#[derive(Debug, Clone, Default)]
+pub struct Discovery {
+    /// Files that passed the discovery filters.
+    pub files: Vec<PathBuf>,
+    /// Symlinked directories skipped because their target was already visited.
+    pub skipped_symlinks: Vec<PathBuf>,
+}
+
 /// File discovery options
 #[derive(Debug, Clone)]
 pub struct DiscoveryOptions {
@@ -49,6 +67,8 @@ pub struct DiscoveryOptions {
     pub max_file_size: u64,
     /// Allowed file extensions.
     pub extensions: Vec<String>,
+    /// Whether to hash file contents during `scan_entries` (IO-heavy, off by default).
+    pub hash_contents: bool,
 }
 
 impl Default for DiscoveryOptions {
@@ -75,6 +95,7 @@ impl Default for DiscoveryOptions {
                 "json".to_string(),
                 "toml".to_string(),
             ],
+            hash_contents: false,
         }
     }
 }
@@ -143,12 +164,30 @@ impl SyncEngine {
     /// Discover files in project root
     #[must_use]
     pub fn discover_files(&self) -> Vec<PathBuf> {
-        let mut files = Vec::new();
-        self.discover_recursive(&self.project_root, &mut files);
-        files
+        self.discover().files
+    }
+
+    /// Discover files in project root, reporting skipped symlink cycles
+    ///
+    /// Symlinked directories are followed, but each canonical directory is
+    /// visited at most once so symlink cycles cannot loop forever.
+    #[must_use]
+    pub fn discover(&self) -> Discovery {
+        let mut discovery = Discovery::default();
+        let mut visited = HashSet::new();
+        if let Ok(root) = std::fs::canonicalize(&self.project_root) {
+            visited.insert(root);
+        }
+        self.discover_recursive(&self.project_root, &mut visited, &mut discovery);
+        discovery
     }
 
-    fn discover_recursive(&self, dir: &Path, files: &mut Vec<PathBuf>) {
+    fn discover_recursive(
+        &self,
+        dir: &Path,
+        visited: &mut HashSet<PathBuf>,
+        discovery: &mut Discovery,
+    ) {
         if let Ok(entries) = std::fs::read_dir(dir) {
             for entry in entries.flatten() {
                 let path = entry.path();
@@ -156,7 +195,17 @@ impl SyncEngine {
                     if self.should_skip_dir(&path) {
                         continue;
                     }
-                    self.discover_recursive(&path, files);
+                    let Ok(canonical) = std::fs::canonicalize(&path) else {
+                        continue;
+                    };
+                    if !visited.insert(canonical) {
+                        if path.is_symlink() {
+                            log::debug!("Skipping already-visited symlink: {}", path.display());
+                            discovery.skipped_symlinks.push(path);
+                        }
+                        continue;
+                    }
+                    self.discover_recursive(&path, visited, discovery);
                 } else if path.is_file() {
                     // Skip hidden files
                     if self.options.skip_hidden
@@ -166,7 +215,7 @@ impl SyncEngine {
                         continue;
                     }
                     if self.should_include_file(&path) {
-                        files.push(path);
+                        discovery.files.push(path);
                     }
                 }
             }
@@ -260,6 +309,25 @@ impl SyncEngine {
         result
     }
 
+    /// Discover files and build an inventory of `FileEntry` records
+    ///
+    /// Hashes are computed with [`Self::compute_file_hash`] only when
+    /// `hash_contents` is enabled; otherwise every `hash` is `None`.
+    #[must_use]
+    pub fn scan_entries(&self) -> Vec<FileEntry> {
+        self.discover_files()
+            .into_iter()
+            .map(|path| {
+                let hash = if self.options.hash_contents {
+                    Self::compute_file_hash(&path)
+                } else {
+                    None
+                };
+                FileEntry { path, hash }
+            })
+            .collect()
+    }
+
     /// Get project root
     #[must_use]
     pub fn project_root(&self) -> &PathBuf {
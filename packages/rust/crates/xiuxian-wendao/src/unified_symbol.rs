@@ -31,6 +31,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::dependency_indexer::SymbolKind;
+
 /// Source type for a symbol
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SymbolSource {
@@ -92,6 +94,18 @@ impl UnifiedSymbol {
         matches!(self.source, SymbolSource::Project)
     }
 
+    /// Parsed kind; unrecognized kind strings map to [`SymbolKind::Unknown`].
+    #[must_use]
+    pub fn symbol_kind(&self) -> SymbolKind {
+        SymbolKind::from_tag(&self.kind).unwrap_or(SymbolKind::Unknown)
+    }
+
+    /// Returns true when `kinds` is empty or contains this symbol's kind.
+    #[must_use]
+    pub fn matches_kinds(&self, kinds: &[SymbolKind]) -> bool {
+        kinds.is_empty() || kinds.contains(&self.symbol_kind())
+    }
+
     /// Returns crate name for both project and external symbols.
     #[must_use]
     pub fn crate_or_local(&self) -> &str {
@@ -172,25 +186,91 @@ impl UnifiedSymbolIndex {
     /// Search across both project and external symbols.
     #[must_use]
     pub fn search_unified(&self, pattern: &str, limit: usize) -> Vec<&UnifiedSymbol> {
+        self.search_unified_with_kinds(pattern, &[], limit)
+    }
+
+    /// Like [`Self::search_unified`], restricted to `kinds` (empty matches every kind).
+    #[must_use]
+    pub fn search_unified_with_kinds(
+        &self,
+        pattern: &str,
+        kinds: &[SymbolKind],
+        limit: usize,
+    ) -> Vec<&UnifiedSymbol> {
         let pattern = pattern.to_lowercase();
         let mut results: Vec<&UnifiedSymbol> = self
             .symbols
             .iter()
-            .filter(|s| s.name.to_lowercase().contains(&pattern))
+            .filter(|s| s.name.to_lowercase().contains(&pattern) && s.matches_kinds(kinds))
             .collect();
 
         results.truncate(limit);
         results
     }
 
+    /// Fuzzy search by symbol name, tolerant of typos such as `cnofig` for `config`.
+    ///
+    /// Each symbol is scored by normalized edit-distance similarity (0.0-1.0) against
+    /// its full name and against each `_`/`::`-separated segment; segment matches are
+    /// discounted slightly. Returns the `limit` best symbols with a positive score,
+    /// highest first.
+    #[must_use]
+    pub fn search_fuzzy(&self, query: &str, limit: usize) -> Vec<(UnifiedSymbol, f32)> {
+        self.search_fuzzy_with_kinds(query, &[], limit)
+    }
+
+    /// Like [`Self::search_fuzzy`], restricted to `kinds` (empty matches every kind).
+    #[must_use]
+    pub fn search_fuzzy_with_kinds(
+        &self,
+        query: &str,
+        kinds: &[SymbolKind],
+        limit: usize,
+    ) -> Vec<(UnifiedSymbol, f32)> {
+        let query = query.to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut scored: Vec<(&UnifiedSymbol, f32)> = self
+            .symbols
+            .iter()
+            .filter(|s| s.matches_kinds(kinds))
+            .map(|s| (s, fuzzy_name_score(&query, &s.name.to_lowercase())))
+            .filter(|(_, score)| *score > 0.0)
+            .collect();
+        scored.sort_by(|a, b| {
+            b.1.total_cmp(&a.1)
+                .then_with(|| a.0.name.len().cmp(&b.0.name.len()))
+                .then_with(|| a.0.name.cmp(&b.0.name))
+        });
+        scored
+            .into_iter()
+            .take(limit)
+            .map(|(s, score)| (s.clone(), score))
+            .collect()
+    }
+
     /// Search only project symbols.
     #[must_use]
     pub fn search_project(&self, pattern: &str, limit: usize) -> Vec<&UnifiedSymbol> {
+        self.search_project_with_kinds(pattern, &[], limit)
+    }
+
+    /// Like [`Self::search_project`], restricted to `kinds` (empty matches every kind).
+    #[must_use]
+    pub fn search_project_with_kinds(
+        &self,
+        pattern: &str,
+        kinds: &[SymbolKind],
+        limit: usize,
+    ) -> Vec<&UnifiedSymbol> {
         let pattern = pattern.to_lowercase();
         let results: Vec<&UnifiedSymbol> = self
             .symbols
             .iter()
-            .filter(|s| s.name.to_lowercase().contains(&pattern) && s.is_project())
+            .filter(|s| {
+                s.name.to_lowercase().contains(&pattern) && s.is_project() && s.matches_kinds(kinds)
+            })
             .take(limit)
             .collect();
 
@@ -200,11 +280,26 @@ impl UnifiedSymbolIndex {
     /// Search only external symbols.
     #[must_use]
     pub fn search_external(&self, pattern: &str, limit: usize) -> Vec<&UnifiedSymbol> {
+        self.search_external_with_kinds(pattern, &[], limit)
+    }
+
+    /// Like [`Self::search_external`], restricted to `kinds` (empty matches every kind).
+    #[must_use]
+    pub fn search_external_with_kinds(
+        &self,
+        pattern: &str,
+        kinds: &[SymbolKind],
+        limit: usize,
+    ) -> Vec<&UnifiedSymbol> {
         let pattern = pattern.to_lowercase();
         let results: Vec<&UnifiedSymbol> = self
             .symbols
             .iter()
-            .filter(|s| s.name.to_lowercase().contains(&pattern) && s.is_external())
+            .filter(|s| {
+                s.name.to_lowercase().contains(&pattern)
+                    && s.is_external()
+                    && s.matches_kinds(kinds)
+            })
             .take(limit)
             .collect();
 
@@ -218,12 +313,28 @@ impl UnifiedSymbolIndex {
         crate_name: &str,
         pattern: &str,
         limit: usize,
+    ) -> Vec<&UnifiedSymbol> {
+        self.search_crate_with_kinds(crate_name, pattern, &[], limit)
+    }
+
+    /// Like [`Self::search_crate`], restricted to `kinds` (empty matches every kind).
+    #[must_use]
+    pub fn search_crate_with_kinds(
+        &self,
+        crate_name: &str,
+        pattern: &str,
+        kinds: &[SymbolKind],
+        limit: usize,
     ) -> Vec<&UnifiedSymbol> {
         let pattern = pattern.to_lowercase();
         let results: Vec<&UnifiedSymbol> = self
             .symbols
             .iter()
-            .filter(|s| s.crate_name == crate_name && s.name.to_lowercase().contains(&pattern))
+            .filter(|s| {
+                s.crate_name == crate_name
+                    && s.name.to_lowercase().contains(&pattern)
+                    && s.matches_kinds(kinds)
+            })
             .take(limit)
             .collect();
 
@@ -297,6 +408,31 @@ impl UnifiedSymbolIndex {
     }
 }
 
+/// Discount applied when the query matches a name segment rather than the whole name.
+const FUZZY_SEGMENT_WEIGHT: f32 = 0.9;
+
+/// Best similarity of `query` against `name` or one of its segments (both lowercase).
+fn fuzzy_name_score(query: &str, name: &str) -> f32 {
+    let whole = edit_similarity(query, name);
+    let segment = name
+        .split(|c: char| c == '_' || c == ':')
+        .filter(|seg| !seg.is_empty() && *seg != name)
+        .map(|seg| edit_similarity(query, seg) * FUZZY_SEGMENT_WEIGHT)
+        .fold(0.0_f32, f32::max);
+    whole.max(segment)
+}
+
+/// `1 - distance / max_len`, so identical strings score 1.0 and disjoint ones 0.0.
+#[allow(clippy::cast_precision_loss)]
+fn edit_similarity(a: &str, b: &str) -> f32 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 0.0;
+    }
+    let distance = crate::graph::levenshtein_distance(a, b);
+    1.0 - distance as f32 / max_len as f32
+}
+
 /// Statistics for unified symbol index.
 #[derive(Debug, Default, Clone, serde::Serialize)]
 pub struct UnifiedIndexStats {
@@ -351,6 +487,61 @@ mod tests {
         assert_eq!(ext_results.len(), 2);
     }
 
+    #[test]
+    fn test_search_fuzzy() {
+        let mut index = UnifiedSymbolIndex::new();
+        index.add_project_symbol("spawn", "fn", "src/lib.rs:1", "mycrate");
+        index.add_project_symbol("connect", "fn", "src/lib.rs:2", "mycrate");
+        index.add_project_symbol("load_config", "fn", "src/lib.rs:3", "mycrate");
+        index.add_external_symbol("Config", "struct", "config.rs:10", "config");
+        index.add_project_symbol("render", "fn", "src/lib.rs:4", "mycrate");
+
+        let results = index.search_fuzzy("cnofig", 3);
+        let names: Vec<&str> = results.iter().map(|(s, _)| s.name.as_str()).collect();
+        assert_eq!(names[..2], ["Config", "load_config"]);
+        assert!(results.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert!(results[0].1 > 0.6);
+
+        assert!(index.search_fuzzy("", 5).is_empty());
+    }
+
+    #[test]
+    fn test_search_kind_filter() {
+        let mut index = UnifiedSymbolIndex::new();
+        index.add_project_symbol("parse_config", "fn", "src/lib.rs:1", "mycrate");
+        index.add_project_symbol("ParseConfig", "struct", "src/lib.rs:5", "mycrate");
+
+        assert_eq!(index.search_unified("parseconfig", 10).len(), 1);
+        assert_eq!(index.search_unified("config", 10).len(), 2);
+
+        let structs = index.search_unified_with_kinds("config", &[SymbolKind::Struct], 10);
+        assert_eq!(structs.len(), 1);
+        assert_eq!(structs[0].name, "ParseConfig");
+        assert_eq!(structs[0].symbol_kind(), SymbolKind::Struct);
+
+        let fuzzy = index.search_fuzzy_with_kinds("parse_config", &[SymbolKind::Struct], 10);
+        assert_eq!(fuzzy.len(), 1);
+        assert_eq!(fuzzy[0].0.name, "ParseConfig");
+
+        let types = [SymbolKind::Struct, SymbolKind::Enum, SymbolKind::Trait];
+        assert_eq!(
+            index.search_project_with_kinds("config", &types, 10).len(),
+            1
+        );
+        assert!(
+            index
+                .search_external_with_kinds("config", &types, 10)
+                .is_empty()
+        );
+        assert_eq!(
+            index
+                .search_crate_with_kinds("mycrate", "config", &[SymbolKind::Function], 10)
+                .first()
+                .map(|s| s.name.as_str()),
+            Some("parse_config")
+        );
+    }
+
     #[test]
     fn test_external_usage() {
         let mut index = UnifiedSymbolIndex::new();
@@ -3,6 +3,7 @@
 //! This module provides Python bindings for searching across both project symbols
 //! and external dependency symbols in a unified way.
 
+use crate::dependency_indexer::SymbolKind;
 use crate::unified_symbol::{SymbolSource, UnifiedIndexStats, UnifiedSymbol, UnifiedSymbolIndex};
 use omni_macros::py_from;
 use pyo3::prelude::*;
@@ -179,43 +180,84 @@ impl PyUnifiedSymbolIndex {
     }
 
     /// Search across both project and external symbols.
-    #[pyo3(signature = (pattern, limit))]
-    fn search_unified(&self, pattern: &str, limit: usize) -> Vec<PyUnifiedSymbol> {
-        self.inner
-            .search_unified(pattern, limit)
+    #[pyo3(signature = (pattern, limit, kinds=None))]
+    fn search_unified(
+        &self,
+        pattern: &str,
+        limit: usize,
+        kinds: Option<Vec<String>>,
+    ) -> PyResult<Vec<PyUnifiedSymbol>> {
+        Ok(self
+            .inner
+            .search_unified_with_kinds(pattern, &parse_kinds(kinds)?, limit)
             .into_iter()
             .map(|s| PyUnifiedSymbol { inner: s.clone() })
-            .collect()
+            .collect())
+    }
+
+    /// Fuzzy search by symbol name; returns `(symbol, score)` pairs, best first.
+    #[pyo3(signature = (query, limit, kinds=None))]
+    fn search_fuzzy(
+        &self,
+        query: &str,
+        limit: usize,
+        kinds: Option<Vec<String>>,
+    ) -> PyResult<Vec<(PyUnifiedSymbol, f32)>> {
+        Ok(self
+            .inner
+            .search_fuzzy_with_kinds(query, &parse_kinds(kinds)?, limit)
+            .into_iter()
+            .map(|(s, score)| (PyUnifiedSymbol { inner: s }, score))
+            .collect())
     }
 
     /// Search only project symbols.
-    #[pyo3(signature = (pattern, limit))]
-    fn search_project(&self, pattern: &str, limit: usize) -> Vec<PyUnifiedSymbol> {
-        self.inner
-            .search_project(pattern, limit)
+    #[pyo3(signature = (pattern, limit, kinds=None))]
+    fn search_project(
+        &self,
+        pattern: &str,
+        limit: usize,
+        kinds: Option<Vec<String>>,
+    ) -> PyResult<Vec<PyUnifiedSymbol>> {
+        Ok(self
+            .inner
+            .search_project_with_kinds(pattern, &parse_kinds(kinds)?, limit)
             .into_iter()
             .map(|s| PyUnifiedSymbol { inner: s.clone() })
-            .collect()
+            .collect())
     }
 
     /// Search only external symbols.
-    #[pyo3(signature = (pattern, limit))]
-    fn search_external(&self, pattern: &str, limit: usize) -> Vec<PyUnifiedSymbol> {
-        self.inner
-            .search_external(pattern, limit)
+    #[pyo3(signature = (pattern, limit, kinds=None))]
+    fn search_external(
+        &self,
+        pattern: &str,
+        limit: usize,
+        kinds: Option<Vec<String>>,
+    ) -> PyResult<Vec<PyUnifiedSymbol>> {
+        Ok(self
+            .inner
+            .search_external_with_kinds(pattern, &parse_kinds(kinds)?, limit)
             .into_iter()
             .map(|s| PyUnifiedSymbol { inner: s.clone() })
-            .collect()
+            .collect())
     }
 
     /// Search within a specific crate.
-    #[pyo3(signature = (crate_name, pattern, limit))]
-    fn search_crate(&self, crate_name: &str, pattern: &str, limit: usize) -> Vec<PyUnifiedSymbol> {
-        self.inner
-            .search_crate(crate_name, pattern, limit)
+    #[pyo3(signature = (crate_name, pattern, limit, kinds=None))]
+    fn search_crate(
+        &self,
+        crate_name: &str,
+        pattern: &str,
+        limit: usize,
+        kinds: Option<Vec<String>>,
+    ) -> PyResult<Vec<PyUnifiedSymbol>> {
+        Ok(self
+            .inner
+            .search_crate_with_kinds(crate_name, pattern, &parse_kinds(kinds)?, limit)
             .into_iter()
             .map(|s| PyUnifiedSymbol { inner: s.clone() })
-            .collect()
+            .collect())
     }
 
     /// Find where an external crate's symbols are used in the project.
@@ -263,9 +305,16 @@ impl PyUnifiedSymbolIndex {
     }
 
     /// Search unified and return JSON.
-    #[pyo3(signature = (pattern, limit))]
-    fn search_unified_json(&self, pattern: &str, limit: usize) -> String {
-        let results = self.inner.search_unified(pattern, limit);
+    #[pyo3(signature = (pattern, limit, kinds=None))]
+    fn search_unified_json(
+        &self,
+        pattern: &str,
+        limit: usize,
+        kinds: Option<Vec<String>>,
+    ) -> PyResult<String> {
+        let results = self
+            .inner
+            .search_unified_with_kinds(pattern, &parse_kinds(kinds)?, limit);
         let json_results: Vec<serde_json::Value> = results
             .iter()
             .map(|s| {
@@ -278,10 +327,25 @@ impl PyUnifiedSymbolIndex {
                 })
             })
             .collect();
-        serde_json::to_string(&json_results).unwrap_or_else(|_| "[]".to_string())
+        Ok(serde_json::to_string(&json_results).unwrap_or_else(|_| "[]".to_string()))
     }
 }
 
+/// Convert Python kind tags (`"fn"`, `"struct"`, ...) into a kind filter; `None` matches all kinds.
+///
+/// Unknown tags (e.g. `"function"`) raise `ValueError` instead of silently matching nothing.
+fn parse_kinds(kinds: Option<Vec<String>>) -> PyResult<Vec<SymbolKind>> {
+    kinds
+        .unwrap_or_default()
+        .iter()
+        .map(|tag| {
+            SymbolKind::from_tag(tag).ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!("unknown symbol kind: {tag:?}"))
+            })
+        })
+        .collect()
+}
+
 /// Register unified symbol module with Python.
 ///
 /// # Errors
@@ -45,6 +45,29 @@ fn test_symbol_index_search() {
     assert_eq!(results[0].kind, SymbolKind::Struct);
 }
 
+#[test]
+fn test_symbol_kind_tags_round_trip() {
+    let kinds = [
+        SymbolKind::Struct,
+        SymbolKind::Enum,
+        SymbolKind::Trait,
+        SymbolKind::Function,
+        SymbolKind::Method,
+        SymbolKind::Field,
+        SymbolKind::Impl,
+        SymbolKind::Mod,
+        SymbolKind::Const,
+        SymbolKind::Static,
+        SymbolKind::TypeAlias,
+        SymbolKind::Unknown,
+    ];
+    for kind in kinds {
+        assert_eq!(SymbolKind::from_tag(kind.as_tag()), Some(kind));
+    }
+    assert_eq!(SymbolKind::from_tag("fn"), Some(SymbolKind::Function));
+    assert_eq!(SymbolKind::from_tag("function"), None);
+}
+
 #[test]
 fn test_dependency_config_load() {
     // Test loading config from actual references.yaml
@@ -88,3 +88,192 @@ ast_symbols_external:
     let search_results = indexer.search("test_function", 10);
     assert!(!search_results.is_empty(), "Should find test_function");
 }
+
+/// Only packages listed in `include_packages` are symbol-indexed.
+#[test]
+fn test_indexer_honors_include_packages() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_root = temp_dir.path().to_str().unwrap();
+
+    for (name, symbol) in [
+        ("alpha", "AlphaStruct"),
+        ("beta", "BetaStruct"),
+        ("gamma", "GammaStruct"),
+    ] {
+        fs::create_dir_all(format!("{temp_root}/{name}/src")).unwrap();
+        fs::write(
+            format!("{temp_root}/{name}/Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\n"),
+        )
+        .unwrap();
+        fs::write(
+            format!("{temp_root}/{name}/src/lib.rs"),
+            format!("pub struct {symbol};\n"),
+        )
+        .unwrap();
+    }
+
+    let config_path = format!("{temp_root}/references.yaml");
+    let config_content = r#"
+ast_symbols_external:
+  - type: rust
+    manifests:
+      - "**/Cargo.toml"
+ast_symbols_external_filter:
+  include_packages:
+    - beta
+"#;
+    fs::write(&config_path, config_content).unwrap();
+
+    let mut indexer = DependencyIndexer::new(temp_root, Some(&config_path));
+    let result = indexer.build(false);
+
+    assert_eq!(result.errors, 0);
+    assert_eq!(result.crates_indexed, 1);
+    assert_eq!(indexer.get_indexed(), vec!["beta".to_string()]);
+    assert_eq!(indexer.symbol_index.get_crates(), vec!["beta"]);
+    assert_eq!(indexer.search("BetaStruct", 10).len(), 1);
+    assert!(indexer.search("AlphaStruct", 10).is_empty());
+    assert!(indexer.search("GammaStruct", 10).is_empty());
+}
+
+/// An unchanged lockfile skips the rebuild; editing it triggers a full reindex.
+#[test]
+fn test_indexer_skips_rebuild_when_lockfile_unchanged() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_root = temp_dir.path().to_str().unwrap();
+
+    fs::create_dir_all(format!("{temp_root}/src")).unwrap();
+    fs::write(
+        format!("{temp_root}/Cargo.toml"),
+        "[package]\nname = \"locked\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    fs::write(
+        format!("{temp_root}/src/lib.rs"),
+        "pub struct LockedStruct;\n",
+    )
+    .unwrap();
+    fs::write(format!("{temp_root}/Cargo.lock"), "version = 3\n").unwrap();
+    let config_path = format!("{temp_root}/references.yaml");
+    fs::write(
+        &config_path,
+        "ast_symbols_external:\n  - type: rust\n    manifests:\n      - \"**/Cargo.toml\"\n",
+    )
+    .unwrap();
+
+    let mut indexer = DependencyIndexer::new(temp_root, Some(&config_path));
+    let first = indexer.build(false);
+    assert!(!first.skipped);
+    assert!(first.lockfile_hash.is_some());
+    assert_eq!(first.crates_indexed, 1);
+
+    let second = indexer.build(false);
+    assert!(second.skipped);
+    assert_eq!(second.files_processed, 0);
+    assert_eq!(second.lockfile_hash, first.lockfile_hash);
+    assert_eq!(second.total_symbols, first.total_symbols);
+    assert_eq!(indexer.search("LockedStruct", 10).len(), 1);
+
+    fs::write(
+        format!("{temp_root}/Cargo.lock"),
+        "version = 3\n\n[[package]]\nname = \"serde\"\n",
+    )
+    .unwrap();
+    let third = indexer.build(false);
+    assert!(!third.skipped);
+    assert_ne!(third.lockfile_hash, first.lockfile_hash);
+    assert_eq!(third.files_processed, 1);
+    // Rebuilding replaces the index instead of duplicating symbols.
+    assert_eq!(indexer.search("LockedStruct", 10).len(), 1);
+}
+
+/// A build with failed crates is not cached as up to date, so the next build retries them.
+#[test]
+fn test_indexer_retries_build_with_errors_when_lockfile_unchanged() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_root = temp_dir.path().to_str().unwrap();
+
+    fs::create_dir_all(format!("{temp_root}/good/src")).unwrap();
+    fs::write(
+        format!("{temp_root}/good/Cargo.toml"),
+        "[package]\nname = \"good\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    fs::write(
+        format!("{temp_root}/good/src/lib.rs"),
+        "pub struct GoodStruct;\n",
+    )
+    .unwrap();
+    fs::create_dir_all(format!("{temp_root}/broken")).unwrap();
+    fs::write(format!("{temp_root}/broken/Cargo.toml"), "[package\n").unwrap();
+    fs::write(format!("{temp_root}/Cargo.lock"), "version = 3\n").unwrap();
+    let config_path = format!("{temp_root}/references.yaml");
+    fs::write(
+        &config_path,
+        "ast_symbols_external:\n  - type: rust\n    manifests:\n      - \"**/Cargo.toml\"\n",
+    )
+    .unwrap();
+
+    let mut indexer = DependencyIndexer::new(temp_root, Some(&config_path));
+    let first = indexer.build(false);
+    assert_eq!(first.errors, 1);
+    assert_eq!(first.crates_indexed, 1);
+
+    let second = indexer.build(false);
+    assert!(!second.skipped);
+    assert_eq!(second.errors, 1);
+
+    fs::write(
+        format!("{temp_root}/broken/Cargo.toml"),
+        "[package]\nname = \"broken\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    let third = indexer.build(false);
+    assert!(!third.skipped);
+    assert_eq!(third.errors, 0);
+    assert_eq!(third.crates_indexed, 2);
+
+    let fourth = indexer.build(false);
+    assert!(fourth.skipped);
+    assert_eq!(fourth.crates_indexed, 2);
+}
+
+/// The lockfile hash survives reopening the indexer from its cache.
+#[test]
+fn test_reopened_indexer_skips_rebuild_when_lockfile_unchanged() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_root = temp_dir.path().to_str().unwrap();
+
+    fs::create_dir_all(format!("{temp_root}/src")).unwrap();
+    fs::write(
+        format!("{temp_root}/Cargo.toml"),
+        "[package]\nname = \"reopened\"\nversion = \"0.2.0\"\n",
+    )
+    .unwrap();
+    fs::write(
+        format!("{temp_root}/src/lib.rs"),
+        "pub struct ReopenedStruct;\n",
+    )
+    .unwrap();
+    fs::write(format!("{temp_root}/Cargo.lock"), "version = 3\n").unwrap();
+    let config_path = format!("{temp_root}/references.yaml");
+    fs::write(
+        &config_path,
+        "ast_symbols_external:\n  - type: rust\n    manifests:\n      - \"**/Cargo.toml\"\n",
+    )
+    .unwrap();
+
+    let first = DependencyIndexer::new(temp_root, Some(&config_path)).build(false);
+    assert!(!first.skipped);
+
+    let mut reopened = DependencyIndexer::new(temp_root, Some(&config_path));
+    reopened.load_index().unwrap();
+    let second = reopened.build(false);
+    assert!(second.skipped);
+    assert_eq!(second.lockfile_hash, first.lockfile_hash);
+    assert_eq!(second.crates_indexed, 1);
+    assert_eq!(second.total_symbols, first.total_symbols);
+    assert_eq!(reopened.get_indexed(), vec!["reopened".to_string()]);
+    assert_eq!(reopened.search("ReopenedStruct", 10).len(), 1);
+}
@@ -5,8 +5,8 @@
 
 use serde_json::json;
 use tempfile::TempDir;
-use xiuxian_wendao::graph::{KnowledgeGraph, SkillDoc, entity_from_dict};
-use xiuxian_wendao::{Entity, EntityType, Relation, RelationType};
+use xiuxian_wendao::graph::{ImportanceConfig, KnowledgeGraph, SkillDoc, entity_from_dict};
+use xiuxian_wendao::{Entity, EntityType, RELATION_SOURCE_DOCS_KEY, Relation, RelationType};
 
 // ---------------------------------------------------------------------------
 // CRUD
@@ -58,6 +58,142 @@ fn test_add_relation() {
     assert_eq!(graph.get_stats().total_relations, 1);
 }
 
+#[test]
+fn test_compute_importance_ranks_hub_highest() {
+    let graph = KnowledgeGraph::new();
+    for name in ["Hub", "A", "B", "C", "D"] {
+        graph
+            .add_entity(Entity::new(
+                format!("concept:{}", name.to_lowercase()),
+                name.to_string(),
+                EntityType::Concept,
+                String::new(),
+            ))
+            .unwrap();
+    }
+    for (source, target) in [
+        ("A", "Hub"),
+        ("B", "Hub"),
+        ("C", "Hub"),
+        ("D", "Hub"),
+        ("A", "B"),
+    ] {
+        graph
+            .add_relation(Relation::new(
+                source.to_string(),
+                target.to_string(),
+                RelationType::RelatedTo,
+                String::new(),
+            ))
+            .unwrap();
+    }
+
+    let importance = graph.compute_importance();
+    assert_eq!(importance.len(), 5);
+    assert!((importance["Hub"] - 1.0).abs() < f32::EPSILON);
+    for name in ["A", "B", "C", "D"] {
+        assert!(importance[name] < 0.5, "{name}: {importance:?}");
+    }
+    // B also receives a relation from A, so it outranks the plain spokes.
+    assert!(importance["B"] > importance["C"]);
+
+    // Without damping every entity is equally likely.
+    let uniform = graph.compute_importance_with(&ImportanceConfig {
+        damping: 0.0,
+        ..ImportanceConfig::default()
+    });
+    assert!(uniform.values().all(|v| (v - 1.0).abs() < 1e-6));
+    assert!(KnowledgeGraph::new().compute_importance().is_empty());
+}
+
+#[test]
+fn test_detect_clusters_splits_disconnected_subgraphs() {
+    let graph = KnowledgeGraph::new();
+    for name in ["Rust", "Cargo", "Tokio", "Python", "Pip", "Loner"] {
+        graph
+            .add_entity(Entity::new(
+                format!("tool:{}", name.to_lowercase()),
+                name.to_string(),
+                EntityType::Tool,
+                String::new(),
+            ))
+            .unwrap();
+    }
+    for (source, target) in [("Cargo", "Rust"), ("Tokio", "Rust"), ("Pip", "Python")] {
+        graph
+            .add_relation(Relation::new(
+                source.to_string(),
+                target.to_string(),
+                RelationType::RelatedTo,
+                String::new(),
+            ))
+            .unwrap();
+    }
+
+    let clusters = graph.detect_clusters();
+    assert_eq!(
+        clusters,
+        vec![
+            vec!["tool:cargo", "tool:rust", "tool:tokio"],
+            vec!["tool:pip", "tool:python"],
+            vec!["tool:loner"],
+        ]
+    );
+}
+
+#[test]
+fn test_duplicate_relation_merges_confidence_and_source_docs() {
+    let graph = KnowledgeGraph::new();
+    for (id, name) in [("tool:tokio", "Tokio"), ("tool:rust", "Rust")] {
+        graph
+            .add_entity(Entity::new(
+                id.to_string(),
+                name.to_string(),
+                EntityType::Tool,
+                String::new(),
+            ))
+            .unwrap();
+    }
+    let inferred = |doc: &str| {
+        Relation::new(
+            "Tokio".to_string(),
+            "Rust".to_string(),
+            RelationType::DependsOn,
+            "Tokio is written in Rust".to_string(),
+        )
+        .with_source_doc(Some(doc.to_string()))
+        .with_confidence(0.6)
+    };
+
+    graph.add_relation(inferred("docs/a.md")).unwrap();
+    graph.add_relation(inferred("docs/b.md")).unwrap();
+
+    let relations = graph.get_all_relations();
+    assert_eq!(relations.len(), 1);
+    let merged = &relations[0];
+    assert!(
+        (merged.confidence - 0.84).abs() < 1e-6,
+        "{}",
+        merged.confidence
+    );
+    assert_eq!(merged.source_doc.as_deref(), Some("docs/a.md"));
+    assert_eq!(merged.source_docs(), vec!["docs/a.md", "docs/b.md"]);
+    assert_eq!(
+        merged.metadata[RELATION_SOURCE_DOCS_KEY],
+        json!(["docs/a.md", "docs/b.md"])
+    );
+
+    // Re-adding from an already recorded doc keeps the doc list unique and adds no evidence.
+    graph.add_relation(inferred("docs/a.md")).unwrap();
+    let readded = &graph.get_all_relations()[0];
+    assert_eq!(readded.source_docs().len(), 2);
+    assert!(
+        (readded.confidence - merged.confidence).abs() < 1e-6,
+        "{}",
+        readded.confidence
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Multi-hop search
 // ---------------------------------------------------------------------------
@@ -450,6 +586,39 @@ fn test_search_entities_alias_match() {
     assert_eq!(results[0].name, "Claude Code");
 }
 
+#[test]
+fn test_alias_resolution_is_case_insensitive() {
+    let graph = KnowledgeGraph::new();
+
+    let mut entity = Entity::new(
+        "tool:claude_code".to_string(),
+        "Claude Code".to_string(),
+        EntityType::Tool,
+        "AI coding assistant".to_string(),
+    );
+    entity.aliases = vec!["CC".to_string()];
+    graph.add_entity(entity).unwrap();
+    graph
+        .add_entity(Entity::new(
+            "concept:cc_license".to_string(),
+            "Creative Commons".to_string(),
+            EntityType::Concept,
+            "Content licenses".to_string(),
+        ))
+        .unwrap();
+
+    let results = graph.search_entities("CC", 10);
+    assert_eq!(results[0].name, "Claude Code");
+    assert_eq!(graph.search_entities("cc", 10)[0].name, "Claude Code");
+
+    for query in ["CC", "cc", "Claude Code"] {
+        let found = graph.get_entity_by_name(query);
+        assert_eq!(found.map(|e| e.id), Some("tool:claude_code".to_string()));
+    }
+    assert!(graph.get_entity_by_name("claude code").is_none());
+    assert!(graph.get_entity_by_name("unknown").is_none());
+}
+
 #[test]
 fn test_search_entities_token_overlap() {
     let graph = KnowledgeGraph::new();
@@ -1,4 +1,10 @@
-use xiuxian_wendao::{HmasRecordKind, validate_blackboard_markdown};
+use std::cell::Cell;
+use std::io::{BufReader, Read};
+use std::rc::Rc;
+use xiuxian_wendao::{
+    HmasPayload, HmasRecordKind, HmasTaskPayload, stream_blackboard_markdown,
+    validate_blackboard_markdown,
+};
 
 const VALID_BLACKBOARD: &str = r#"
 ### Sub-Task: Stock Signal
@@ -134,3 +140,130 @@ fn test_validate_blackboard_requires_constraints_and_sources() {
             .any(|row| row.code == "invalid_confidence_score")
     );
 }
+
+struct CountingReader<R> {
+    inner: R,
+    consumed: Rc<Cell<usize>>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.consumed.set(self.consumed.get() + read);
+        Ok(read)
+    }
+}
+
+#[test]
+fn test_stream_blackboard_reports_early_issue_before_reading_everything() {
+    let mut payload = String::from(
+        "#### [TASK]\n```json\n{\"requirement_id\": \"REQ-1\", \"objective\": \"x\", \"hard_constraints\": []}\n```\n",
+    );
+    for _ in 0..2_000 {
+        payload.push_str(VALID_BLACKBOARD);
+    }
+
+    let consumed = Rc::new(Cell::new(0));
+    let reader = CountingReader {
+        inner: payload.as_bytes(),
+        consumed: Rc::clone(&consumed),
+    };
+    let mut stream = stream_blackboard_markdown(BufReader::with_capacity(1024, reader));
+
+    let first = stream.next();
+    let Some(first) = first else {
+        panic!("expected an early issue");
+    };
+    assert_eq!(first.code, "missing_hard_constraints");
+    assert_eq!(first.line, 2);
+    assert!(
+        consumed.get() < payload.len() / 100,
+        "stream consumed {} of {} bytes",
+        consumed.get(),
+        payload.len()
+    );
+    assert_eq!(stream.report().task_count, 1);
+}
+
+#[test]
+fn test_stream_blackboard_matches_full_validation() {
+    for payload in [
+        VALID_BLACKBOARD,
+        "#### [CONCLUSION]\n```json\n{\"requirement_id\": \"REQ-9\", \"summary\": \"s\", \"confidence_score\": 0.5, \"hard_constraints_checked\": [\"R\"]}\n```\n",
+    ] {
+        let streamed = stream_blackboard_markdown(payload.as_bytes()).into_report();
+        assert_eq!(streamed, validate_blackboard_markdown(payload));
+    }
+}
+
+#[test]
+fn test_validate_payload_reports_missing_required_task_field() {
+    let payload = HmasPayload::Task(HmasTaskPayload {
+        requirement_id: "REQ-7".to_string(),
+        objective: "Summarize filings".to_string(),
+        hard_constraints: Vec::new(),
+        assigned_to: None,
+    });
+
+    let Err(report) = HmasRecordKind::Task.validate_payload(&payload) else {
+        panic!("task payload without hard constraints must fail validation");
+    };
+    assert!(!report.valid);
+    assert_eq!(report.issues.len(), 1);
+    assert_eq!(report.issues[0].code, "missing_hard_constraints");
+    assert!(report.issues[0].message.contains("task.hard_constraints"));
+
+    let Err(mismatch) = HmasRecordKind::Evidence.validate_payload(&payload) else {
+        panic!("kind mismatch must fail validation");
+    };
+    assert!(
+        mismatch
+            .issues
+            .iter()
+            .any(|row| row.code == "payload_kind_mismatch")
+    );
+}
+
+#[test]
+fn test_payload_markdown_round_trip() {
+    let payload = HmasPayload::Task(HmasTaskPayload {
+        requirement_id: "REQ-8".to_string(),
+        objective: "Check liquidity".to_string(),
+        hard_constraints: vec!["NOT_ST_STOCK".to_string()],
+        assigned_to: Some("worker-02".to_string()),
+    });
+    assert!(HmasRecordKind::Task.validate_payload(&payload).is_ok());
+
+    let markdown = payload
+        .to_markdown()
+        .unwrap_or_else(|error| panic!("render payload: {error}"));
+    assert!(markdown.starts_with("#### [TASK]"));
+    let parsed = HmasPayload::from_markdown(&markdown)
+        .unwrap_or_else(|report| panic!("parse payload: {:?}", report.issues));
+    assert_eq!(parsed, vec![payload]);
+
+    let parsed_all = HmasPayload::from_markdown(VALID_BLACKBOARD)
+        .unwrap_or_else(|report| panic!("parse blackboard: {:?}", report.issues));
+    let kinds: Vec<HmasRecordKind> = parsed_all.iter().map(HmasPayload::kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            HmasRecordKind::Task,
+            HmasRecordKind::Evidence,
+            HmasRecordKind::Conclusion,
+            HmasRecordKind::DigitalThread,
+        ]
+    );
+    let rendered: String = parsed_all
+        .iter()
+        .map(|row| {
+            row.to_markdown()
+                .unwrap_or_else(|error| panic!("render payload: {error}"))
+        })
+        .collect();
+    assert_eq!(
+        HmasPayload::from_markdown(&rendered)
+            .unwrap_or_else(|report| panic!("reparse: {:?}", report.issues)),
+        parsed_all
+    );
+}
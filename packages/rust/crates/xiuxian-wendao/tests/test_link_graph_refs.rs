@@ -1,8 +1,9 @@
 //! Tests for LinkGraph entity reference extraction.
 
+use std::collections::HashSet;
 use xiuxian_wendao::link_graph_refs::{
     LinkGraphEntityRef, LinkGraphRefStats, extract_entity_refs, find_notes_referencing_entity,
-    get_ref_stats, is_valid_entity_ref, parse_entity_ref,
+    get_ref_stats, get_ref_stats_with_known, is_valid_entity_ref, parse_entity_ref, validate_refs,
 };
 
 #[test]
@@ -165,3 +166,33 @@ fn test_get_ref_stats() {
     assert_eq!(stats.total_refs, 3);
     assert_eq!(stats.unique_entities, 3);
 }
+
+#[test]
+fn test_validate_refs_returns_unresolved() {
+    let refs = extract_entity_refs(
+        "See [[FactoryPattern]], [[SingletonPattern#py]] and [[ObserverPatern]].",
+    );
+    let known: HashSet<String> = ["FactoryPattern", "SingletonPattern", "ObserverPattern"]
+        .iter()
+        .map(|name| (*name).to_string())
+        .collect();
+
+    let unresolved = validate_refs(&refs, &known);
+    assert_eq!(unresolved.len(), 1);
+    assert_eq!(unresolved[0].name, "ObserverPatern");
+    assert_eq!(unresolved[0].original, "[[ObserverPatern]]");
+}
+
+#[test]
+fn test_ref_stats_counts_broken_links() {
+    let content = "Links to [[ExistingNote]] and [[MissingNote#doc]].";
+    let known: HashSet<String> = HashSet::from(["ExistingNote".to_string()]);
+
+    let stats = get_ref_stats_with_known(content, &known);
+    assert_eq!(stats.total_refs, 2);
+    assert_eq!(stats.broken_refs, 1);
+    assert_eq!(stats.broken_targets, vec!["MissingNote".to_string()]);
+
+    // Without a known set nothing is reported as broken
+    assert_eq!(get_ref_stats(content).broken_refs, 0);
+}
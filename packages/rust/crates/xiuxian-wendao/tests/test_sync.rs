@@ -256,3 +256,77 @@ fn test_custom_discovery_options() {
     assert_eq!(files.len(), 1);
     assert!(files[0].extension().map(|e| e == "rs").unwrap_or(false));
 }
+
+/// Test SyncEngine inventory with content hashing enabled
+#[test]
+fn test_scan_entries_with_hashes() {
+    use xiuxian_wendao::{DiscoveryOptions, SyncEngine};
+
+    let temp_dir = TempDir::new().unwrap();
+    let doc_path = temp_dir.path().join("notes.md");
+    fs::write(&doc_path, "# Notes").unwrap();
+    fs::write(temp_dir.path().join("main.rs"), "fn main() {}").unwrap();
+
+    let manifest_path = temp_dir.path().join("manifest.json");
+    let options = DiscoveryOptions {
+        hash_contents: true,
+        ..Default::default()
+    };
+    let engine = SyncEngine::new(temp_dir.path(), &manifest_path).with_options(options);
+
+    let entries = engine.scan_entries();
+    assert_eq!(entries.len(), 2);
+    assert!(
+        entries
+            .iter()
+            .all(|e| e.hash.as_ref().is_some_and(|h| !h.is_empty()))
+    );
+
+    // Hashes are stable across scans
+    assert_eq!(engine.scan_entries(), entries);
+
+    // Changing a file changes its hash
+    let doc_hash = |entries: &[xiuxian_wendao::FileEntry]| {
+        entries
+            .iter()
+            .find(|e| e.path == doc_path)
+            .and_then(|e| e.hash.clone())
+    };
+    let before = doc_hash(&entries);
+    fs::write(&doc_path, "# Notes, revised").unwrap();
+    let after = doc_hash(&engine.scan_entries());
+    assert!(before.is_some());
+    assert_ne!(before, after);
+
+    // Hashing is off by default
+    let plain = SyncEngine::new(temp_dir.path(), &manifest_path);
+    assert!(plain.scan_entries().iter().all(|e| e.hash.is_none()));
+}
+
+/// Test SyncEngine discovery terminates on a symlink cycle and reports the skipped link
+#[cfg(unix)]
+#[test]
+fn test_discover_symlink_cycle() {
+    use xiuxian_wendao::SyncEngine;
+
+    let temp_dir = TempDir::new().unwrap();
+    let skill_dir = temp_dir.path().join("skill");
+    fs::create_dir_all(skill_dir.join("scripts")).unwrap();
+    fs::write(
+        skill_dir.join("scripts").join("tool.py"),
+        "def tool(): pass",
+    )
+    .unwrap();
+
+    // scripts/loop -> skill, forming a cycle skill/scripts/loop/scripts/loop/...
+    let link = skill_dir.join("scripts").join("loop");
+    std::os::unix::fs::symlink(&skill_dir, &link).unwrap();
+
+    let manifest_path = temp_dir.path().join("manifest.json");
+    let engine = SyncEngine::new(temp_dir.path(), &manifest_path);
+    let discovery = engine.discover();
+
+    assert_eq!(discovery.files.len(), 1);
+    assert!(discovery.files[0].ends_with("scripts/tool.py"));
+    assert_eq!(discovery.skipped_symlinks, vec![link]);
+}